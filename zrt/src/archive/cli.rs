@@ -0,0 +1,142 @@
+use anyhow::Result;
+use clap::Args;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use crate::archive::{archivable, archive_moves};
+use crate::core::frontmatter;
+use crate::core::links::{apply_relocation, relocate};
+use crate::core::scanner::ScanOptions;
+use crate::init::ZrtConfig;
+use crate::output::{moves, scan};
+use crate::query::Query;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser, Debug)]
+    struct TestArgs {
+        #[command(flatten)]
+        archive: ArchiveArgs,
+    }
+
+    #[test]
+    fn test_should_default_to_the_archive_folder_without_tagging() {
+        // REQ-ARCHIVE-003
+        let args = TestArgs::parse_from(["program"]).archive;
+
+        assert!(args.query.is_none());
+        assert_eq!(args.to, PathBuf::from("archive"));
+        assert_eq!(args.days, None);
+        assert_eq!(args.tag, None);
+        assert!(!args.dry_run);
+    }
+
+    #[test]
+    fn test_should_tag_archived_notes_on_request() {
+        // REQ-ARCHIVE-004
+        let args = TestArgs::parse_from(["program", "--tag", "--days", "180"]).archive;
+        assert_eq!(args.tag.as_deref(), Some("archived"));
+        assert_eq!(args.days, Some(180));
+
+        let args = TestArgs::parse_from(["program", "--tag=old", "FROM #done"]).archive;
+        assert_eq!(args.tag.as_deref(), Some("old"));
+        assert!(args.query.is_some());
+    }
+
+    #[test]
+    fn test_should_not_take_the_query_as_the_tag() -> Result<()> {
+        // REQ-ARCHIVE-004
+        let args = TestArgs::parse_from(["program", "--tag", "FROM #refactored"]).archive;
+
+        assert_eq!(args.tag.as_deref(), Some("archived"));
+        assert_eq!(
+            args.query,
+            Some("FROM #refactored".parse().map_err(anyhow::Error::msg)?)
+        );
+        Ok(())
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+#[derive(Args, Debug)]
+pub struct ArchiveArgs {
+    /// Notes to archive, as a `zrt query` (default: FROM #<done tag>),
+    /// e.g. "FROM #refactored WHERE words < 50"
+    pub query: Option<Query>,
+
+    /// Archive folder, relative to the first scanned directory
+    #[arg(long, default_value = "archive")]
+    pub to: PathBuf,
+
+    /// Only archive notes not modified in at least this many days
+    #[arg(long)]
+    pub days: Option<u64>,
+
+    /// Add a tag to archived notes, given as --tag=TAG (default: archived)
+    #[arg(
+        long,
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "archived",
+        value_name = "TAG"
+    )]
+    pub tag: Option<String>,
+
+    /// Show what would move without changing any file
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Directories to scan (space-separated, defaults to current directory)
+    #[arg(short = 'd', long = "dir", num_args = 0.., default_values = &["."])]
+    pub directories: Vec<PathBuf>,
+
+    /// Directories to exclude (space-separated)
+    #[arg(short, long, num_args = 0..)]
+    pub exclude: Vec<String>,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+pub fn run(args: ArchiveArgs, config: &ZrtConfig) -> Result<()> {
+    let query = match args.query {
+        Some(query) => query,
+        None => format!("FROM #{}", config.refactor.done_tag)
+            .parse()
+            .map_err(anyhow::Error::msg)?,
+    };
+    let exclude_dirs: Vec<&str> = args.exclude.iter().map(String::as_str).collect();
+    let options = ScanOptions::new(&args.directories).exclude(&exclude_dirs);
+    let scanner = scan::attach(options).build()?;
+
+    let roots: Vec<PathBuf> = scanner.roots().map(Path::to_path_buf).collect();
+    let folder = roots
+        .first()
+        .map_or_else(|| args.to.clone(), |root| root.join(&args.to));
+    let paths = archivable(&scanner, &query, args.days, SystemTime::now())?;
+    let relocated = relocate(&scanner, &archive_moves(&paths, &roots, &folder))?;
+
+    for line in moves::report(&relocated, args.dry_run) {
+        println!("{line}");
+    }
+    if args.dry_run {
+        return Ok(());
+    }
+    apply_relocation(&relocated)?;
+    if let Some(tag) = &args.tag {
+        for note in relocated.iter().filter(|note| note.moves()) {
+            frontmatter::edit(&note.to, |fm| fm.add_tag(tag))?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,122 @@
+pub mod cli;
+
+use anyhow::Result;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use crate::core::scanner::Scanner;
+use crate::query::{Query, run_query};
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::scanner::ScanOptions;
+    use std::fs;
+    use std::time::Duration;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_should_keep_folders_under_the_archive() {
+        // REQ-ARCHIVE-001
+        let roots = [PathBuf::from("/v"), PathBuf::from("/w")];
+        let paths = [
+            PathBuf::from("/v/a.md"),
+            PathBuf::from("/v/notes/b.md"),
+            PathBuf::from("/w/c.md"),
+            PathBuf::from("/v/archive/old.md"),
+        ];
+
+        let moves = archive_moves(&paths, &roots, Path::new("/v/archive"));
+
+        assert_eq!(
+            moves,
+            vec![
+                (PathBuf::from("/v/a.md"), PathBuf::from("/v/archive/a.md")),
+                (
+                    PathBuf::from("/v/notes/b.md"),
+                    PathBuf::from("/v/archive/notes/b.md")
+                ),
+                (PathBuf::from("/w/c.md"), PathBuf::from("/v/archive/c.md")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_should_select_matching_notes_untouched_for_days() -> Result<()> {
+        // REQ-ARCHIVE-002
+        let dir = TempDir::new()?;
+        fs::write(dir.path().join("a.md"), "---\ntags: [refactored]\n---\nA")?;
+        fs::write(dir.path().join("b.md"), "---\ntags: [to_refactor]\n---\nB")?;
+        let scanner = ScanOptions::new([dir.path()]).build()?;
+        let query: Query = "FROM #refactored".parse().map_err(anyhow::Error::msg)?;
+        let later = SystemTime::now() + Duration::from_secs(10 * 86_400);
+
+        assert_eq!(
+            archivable(&scanner, &query, None, SystemTime::now())?,
+            vec![dir.path().join("a.md")]
+        );
+        assert_eq!(
+            archivable(&scanner, &query, Some(9), later)?,
+            vec![dir.path().join("a.md")]
+        );
+        assert!(archivable(&scanner, &query, Some(11), later)?.is_empty());
+        Ok(())
+    }
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+/// Notes matching `query` that were last modified at least `days` days
+/// before `now`, if given; notes whose modification time cannot be read are
+/// then left out.
+///
+/// # Errors
+///
+/// Returns an error if the scan fails.
+pub fn archivable(
+    scanner: &Scanner,
+    query: &Query,
+    days: Option<u64>,
+    now: SystemTime,
+) -> Result<Vec<PathBuf>> {
+    let paths = run_query(scanner, query)?.into_iter().map(|row| row.path);
+    let Some(days) = days else {
+        return Ok(paths.collect());
+    };
+    Ok(paths
+        .filter(|path| {
+            scanner
+                .metadata(path)
+                .ok()
+                .and_then(|meta| meta.modified)
+                .is_some_and(|modified| {
+                    now.duration_since(modified)
+                        .is_ok_and(|elapsed| elapsed.as_secs() / 86_400 >= days)
+                })
+        })
+        .collect())
+}
+
+/// Where each note goes in the archive `folder`: its path relative to the
+/// scanned directory it was found in, under the folder. Notes already in the
+/// archive stay where they are.
+#[must_use]
+pub fn archive_moves(
+    paths: &[PathBuf],
+    roots: &[PathBuf],
+    folder: &Path,
+) -> Vec<(PathBuf, PathBuf)> {
+    paths
+        .iter()
+        .filter(|path| !path.starts_with(folder))
+        .filter_map(|path| {
+            let relative = roots.iter().find_map(|root| path.strip_prefix(root).ok())?;
+            Some((path.clone(), folder.join(relative)))
+        })
+        .collect()
+}
@@ -0,0 +1,111 @@
+use anyhow::Result;
+use clap::Args;
+use std::path::PathBuf;
+
+use crate::assets::{AssetReport, check_assets};
+use crate::core::scanner::ScanOptions;
+use crate::output::files_from::FilesFromArgs;
+use crate::output::scan;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assets::NoteAssets;
+    use clap::Parser;
+
+    #[derive(Parser, Debug)]
+    struct TestArgs {
+        #[command(flatten)]
+        assets: AssetsArgs,
+    }
+
+    #[test]
+    fn test_should_accept_summary_flag() {
+        // REQ-ASSET-003
+        assert!(!TestArgs::parse_from(["program"]).assets.summary);
+        assert!(
+            TestArgs::parse_from(["program", "--summary"])
+                .assets
+                .summary
+        );
+    }
+
+    #[test]
+    fn test_should_summarize_the_vault() {
+        // REQ-ASSET-003
+        let report = AssetReport {
+            notes: vec![NoteAssets {
+                path: PathBuf::from("a.md"),
+                references: 4,
+                broken: vec!["gone.png".to_owned()],
+            }],
+        };
+
+        assert_eq!(
+            summary(&report),
+            "1 of 4 asset references broken in 1 of 1 notes"
+        );
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+#[derive(Args, Debug)]
+pub struct AssetsArgs {
+    /// Directories to scan (space-separated, defaults to current directory)
+    #[arg(short = 'd', long = "dir", num_args = 0.., default_values = &["."])]
+    pub directories: Vec<PathBuf>,
+
+    /// Directories to exclude (space-separated)
+    #[arg(short, long, num_args = 0..)]
+    pub exclude: Vec<String>,
+
+    /// Only print the vault-wide totals
+    #[arg(long)]
+    pub summary: bool,
+
+    #[command(flatten)]
+    pub files_from: FilesFromArgs,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+pub fn run(args: AssetsArgs) -> Result<()> {
+    let exclude_dirs: Vec<&str> = args.exclude.iter().map(String::as_str).collect();
+
+    let options = args
+        .files_from
+        .apply(ScanOptions::new(&args.directories).exclude(&exclude_dirs))?;
+    let scanner = scan::attach(options).build()?;
+    let report = check_assets(&scanner)?;
+
+    if !args.summary {
+        for note in &report.notes {
+            for target in &note.broken {
+                println!("{}: {target}", note.path.display());
+            }
+        }
+        if report.broken() > 0 {
+            println!();
+        }
+    }
+    println!("{}", summary(&report));
+    Ok(())
+}
+
+fn summary(report: &AssetReport) -> String {
+    format!(
+        "{} of {} asset references broken in {} of {} notes",
+        report.broken(),
+        report.references(),
+        report.notes_with_broken(),
+        report.notes.len()
+    )
+}
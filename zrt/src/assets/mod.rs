@@ -0,0 +1,160 @@
+pub mod cli;
+
+use anyhow::Result;
+use std::path::PathBuf;
+
+use crate::core::links::{AssetFiles, extract_assets};
+use crate::core::scanner::Scanner;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::scanner::ScanOptions;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_should_report_broken_references_per_note() -> Result<()> {
+        // REQ-ASSET-001
+        // Given notes embedding present and missing files
+        let dir = TempDir::new()?;
+        fs::create_dir(dir.path().join("attachments"))?;
+        fs::write(dir.path().join("attachments/chart.png"), "")?;
+        fs::write(
+            dir.path().join("a.md"),
+            "![chart](attachments/chart.png) ![gone](gone.png) ![[slides.pdf]]",
+        )?;
+        fs::write(dir.path().join("b.md"), "![[chart.png]] [[note]]")?;
+        fs::write(dir.path().join("c.md"), "no assets")?;
+        let scanner = ScanOptions::new([dir.path()]).build()?;
+
+        // When checking asset references
+        let report = check_assets(&scanner)?;
+
+        // Then each note lists its missing targets
+        let broken: Vec<_> = report
+            .notes
+            .iter()
+            .map(|note| (note.path.clone(), note.broken.clone()))
+            .collect();
+        assert_eq!(
+            broken,
+            vec![
+                (
+                    dir.path().join("a.md"),
+                    vec!["gone.png".to_owned(), "slides.pdf".to_owned()]
+                ),
+                (dir.path().join("b.md"), vec![]),
+                (dir.path().join("c.md"), vec![]),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_total_references_across_the_vault() {
+        // REQ-ASSET-002
+        let report = AssetReport {
+            notes: vec![
+                NoteAssets {
+                    path: PathBuf::from("a.md"),
+                    references: 3,
+                    broken: vec!["gone.png".to_owned()],
+                },
+                NoteAssets {
+                    path: PathBuf::from("b.md"),
+                    references: 1,
+                    broken: vec![],
+                },
+            ],
+        };
+
+        assert_eq!(report.references(), 4);
+        assert_eq!(report.broken(), 1);
+        assert_eq!(report.notes_with_broken(), 1);
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// A note's references to images and embedded files.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteAssets {
+    pub path: PathBuf,
+    /// Asset references in the note
+    pub references: usize,
+    /// Targets of the references that point at missing files, in order
+    pub broken: Vec<String>,
+}
+
+/// Asset references of every scanned note, in walk order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AssetReport {
+    pub notes: Vec<NoteAssets>,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+impl AssetReport {
+    /// Asset references across the vault.
+    #[inline]
+    #[must_use]
+    pub fn references(&self) -> usize {
+        self.notes.iter().map(|note| note.references).sum()
+    }
+
+    /// Asset references across the vault that point at missing files.
+    #[inline]
+    #[must_use]
+    pub fn broken(&self) -> usize {
+        self.notes.iter().map(|note| note.broken.len()).sum()
+    }
+
+    /// Notes with at least one broken asset reference.
+    #[inline]
+    #[must_use]
+    pub fn notes_with_broken(&self) -> usize {
+        self.notes
+            .iter()
+            .filter(|note| !note.broken.is_empty())
+            .count()
+    }
+}
+
+/// Check every markdown note's image and embed references against the
+/// scanned files.
+///
+/// # Errors
+///
+/// Returns an error if the scan fails or a note cannot be read.
+pub fn check_assets(scanner: &Scanner) -> Result<AssetReport> {
+    let files = scanner.files()?;
+    let index = AssetFiles::new(files.iter().cloned());
+
+    let mut report = AssetReport::default();
+    for note in scanner.notes() {
+        let note = note?;
+        if !scanner.is_note(&note.path) {
+            continue;
+        }
+        let assets = extract_assets(note.body());
+        let broken = assets
+            .iter()
+            .filter(|asset| !index.resolves(&note.path, asset))
+            .map(|asset| asset.target.clone())
+            .collect();
+        report.notes.push(NoteAssets {
+            path: note.path,
+            references: assets.len(),
+            broken,
+        });
+    }
+    Ok(report)
+}
@@ -0,0 +1,88 @@
+use anyhow::Result;
+use clap::Args;
+use std::path::PathBuf;
+
+use crate::output::scan;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser, Debug)]
+    struct TestArgs {
+        #[command(flatten)]
+        backlinks: BacklinksArgs,
+    }
+
+    #[test]
+    fn test_should_accept_note_as_positional_argument() {
+        // REQ-BACK-005
+
+        // Given / When
+        let args = TestArgs::parse_from(["program", "my-note"]);
+
+        // Then
+        assert_eq!(args.backlinks.note, "my-note");
+    }
+
+    #[test]
+    fn test_should_require_note() {
+        // REQ-BACK-005
+
+        // Given / When
+        let result = TestArgs::try_parse_from(["program"]);
+
+        // Then
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_should_default_to_current_directory() {
+        // REQ-BACK-006
+
+        // Given / When
+        let args = TestArgs::parse_from(["program", "my-note"]);
+
+        // Then
+        assert_eq!(args.backlinks.directories, vec![PathBuf::from(".")]);
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+#[derive(Args, Debug)]
+pub struct BacklinksArgs {
+    /// Note to find backlinks for (path, name without extension, title or alias)
+    pub note: String,
+
+    /// Directories to scan (space-separated, defaults to current directory)
+    #[arg(short = 'd', long = "dir", num_args = 0.., default_values = &["."])]
+    pub directories: Vec<PathBuf>,
+
+    /// Directories to exclude (space-separated)
+    #[arg(short, long, num_args = 0..)]
+    pub exclude: Vec<String>,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+pub fn run(args: BacklinksArgs) -> Result<()> {
+    let exclude_dirs: Vec<&str> = args.exclude.iter().map(String::as_str).collect();
+
+    let scanner = scan::scanner(&args.directories, &exclude_dirs)?;
+    let files = crate::backlinks::find_backlinks_in(&scanner, &args.note)?;
+
+    for file in &files {
+        println!("{}", file.display());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,126 @@
+pub mod cli;
+
+use anyhow::{Result, bail};
+use std::path::PathBuf;
+
+use crate::core::links::LinkIndex;
+use crate::core::scanner::{ScanOptions, Scanner};
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn create_test_file(dir: &TempDir, name: &str, content: &str) -> Result<PathBuf> {
+        let path = dir.path().join(name);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, content)?;
+        Ok(path)
+    }
+
+    #[test]
+    fn test_should_list_notes_linking_to_target() -> Result<()> {
+        // REQ-BACK-001
+
+        // Given
+        let dir = TempDir::new()?;
+        create_test_file(&dir, "a.md", "See [[target]]")?;
+        create_test_file(&dir, "b.md", "Also [target](target.md)")?;
+        create_test_file(&dir, "c.md", "Unrelated [[other]]")?;
+        create_test_file(&dir, "target.md", "The target")?;
+
+        // When
+        let files = find_backlinks(&[dir.path().to_path_buf()], "target", &[])?;
+
+        // Then
+        assert_eq!(files.len(), 2);
+        assert!(files.iter().any(|f| f.ends_with("a.md")));
+        assert!(files.iter().any(|f| f.ends_with("b.md")));
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_accept_note_as_path() -> Result<()> {
+        // REQ-BACK-002
+
+        // Given
+        let dir = TempDir::new()?;
+        create_test_file(&dir, "a.md", "[[target]]")?;
+        let target = create_test_file(&dir, "target.md", "---\naliases: [Goal]\n---\n")?;
+
+        // When
+        let files = find_backlinks(
+            &[dir.path().to_path_buf()],
+            &target.display().to_string(),
+            &[],
+        )?;
+        let by_alias = find_backlinks(&[dir.path().to_path_buf()], "goal", &[])?;
+
+        // Then
+        assert_eq!(files.len(), 1);
+        assert_eq!(by_alias, files);
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_list_each_linking_note_once() -> Result<()> {
+        // REQ-BACK-003
+
+        // Given
+        let dir = TempDir::new()?;
+        create_test_file(&dir, "a.md", "[[target]] and again [[target|it]]")?;
+        create_test_file(&dir, "target.md", "[[target]] links to itself")?;
+
+        // When
+        let files = find_backlinks(&[dir.path().to_path_buf()], "target", &[])?;
+
+        // Then
+        assert_eq!(files.len(), 1);
+        assert!(files[0].ends_with("a.md"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_fail_when_note_not_found() -> Result<()> {
+        // REQ-BACK-004
+        let dir = TempDir::new()?;
+        create_test_file(&dir, "a.md", "[[target]]")?;
+
+        let result = find_backlinks(&[dir.path().to_path_buf()], "target", &[]);
+
+        assert!(result.is_err());
+        Ok(())
+    }
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+/// Find every note that links to `note`.
+/// `note` may be a path to the file, its name without extension, its title
+/// or one of its aliases.
+pub fn find_backlinks(dirs: &[PathBuf], note: &str, exclude: &[&str]) -> Result<Vec<PathBuf>> {
+    find_backlinks_in(&ScanOptions::new(dirs).exclude(exclude).build()?, note)
+}
+
+/// [`find_backlinks`] using an already configured scanner
+pub fn find_backlinks_in(scanner: &Scanner, note: &str) -> Result<Vec<PathBuf>> {
+    let index = LinkIndex::build_in(scanner)?;
+
+    let Some(target) = index.find_note(note) else {
+        bail!("Note not found: {note}");
+    };
+
+    Ok(index
+        .backlinks(target)
+        .into_iter()
+        .map(|i| index.notes[i].path.clone())
+        .collect())
+}
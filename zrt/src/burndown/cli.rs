@@ -0,0 +1,128 @@
+use anyhow::Result;
+use clap::Args;
+use std::path::PathBuf;
+
+use crate::burndown::{Measure, remaining, render_chart};
+use crate::core::history::{HISTORY_FILE, load_history};
+use crate::trend::{Period, points};
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser, Debug)]
+    struct TestArgs {
+        #[command(flatten)]
+        burndown: BurndownArgs,
+    }
+
+    #[test]
+    fn test_should_default_to_files_per_snapshot() {
+        // REQ-BURN-004
+
+        // Given / When
+        let args = TestArgs::parse_from(["program"]);
+
+        // Then
+        assert_eq!(args.burndown.measure, Measure::Files);
+        assert!(args.burndown.by.is_none());
+        assert_eq!(args.burndown.height, 10);
+        assert_eq!(args.burndown.history, PathBuf::from(".zrt/history.jsonl"));
+    }
+
+    #[test]
+    fn test_should_accept_words_by_week() {
+        // REQ-BURN-004
+
+        // Given / When
+        let args = TestArgs::parse_from([
+            "program",
+            "--measure",
+            "words",
+            "--by",
+            "week",
+            "--height",
+            "5",
+        ]);
+
+        // Then
+        assert_eq!(args.burndown.measure, Measure::Words);
+        assert_eq!(args.burndown.by, Some(Period::Week));
+        assert_eq!(args.burndown.height, 5);
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+#[derive(Args, Debug)]
+pub struct BurndownArgs {
+    /// Count remaining files or words
+    #[arg(long, value_enum, default_value = "files")]
+    pub measure: Measure,
+
+    /// Group by day or ISO week instead of showing every snapshot
+    #[arg(long, value_enum)]
+    pub by: Option<Period>,
+
+    /// Chart height in rows
+    #[arg(long, default_value = "10")]
+    pub height: usize,
+
+    /// History file to read snapshots from
+    #[arg(long, default_value = HISTORY_FILE)]
+    pub history: PathBuf,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+pub fn run(args: BurndownArgs) -> Result<()> {
+    let history = load_history(&args.history)?;
+    if history.is_empty() {
+        anyhow::bail!(
+            "No snapshots found in {}; record one with `zrt snapshot`",
+            args.history.display()
+        );
+    }
+
+    let points = points(&history, args.by);
+    let values = remaining(&points, args.measure);
+    let max = values.iter().copied().max().unwrap_or(0).to_string();
+    let rows = render_chart(&values, args.height.max(1));
+
+    let last_row = rows.len() - 1;
+    for (i, row) in rows.iter().enumerate() {
+        let label = match i {
+            0 => max.as_str(),
+            _ if i == last_row => "0",
+            _ => "",
+        };
+        println!("{label:>width$} │{row}", width = max.len());
+    }
+    println!(
+        "{:>width$} └{}",
+        "",
+        "─".repeat(values.len()),
+        width = max.len()
+    );
+
+    if let (Some(first), Some(last)) = (points.first(), points.last()) {
+        println!(
+            "{:>width$}  {} → {}: {} remaining",
+            "",
+            first.period,
+            last.period,
+            values.last().copied().unwrap_or(0),
+            width = max.len()
+        );
+    }
+
+    Ok(())
+}
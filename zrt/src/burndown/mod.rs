@@ -0,0 +1,110 @@
+pub mod cli;
+
+use clap::ValueEnum;
+
+use crate::trend::TrendPoint;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(todo_files: usize, todo_words: usize) -> TrendPoint {
+        TrendPoint {
+            period: String::new(),
+            percentage: 0.0,
+            done_files: 0,
+            todo_files,
+            todo_words,
+            files: 0,
+        }
+    }
+
+    #[test]
+    fn test_should_take_remaining_files_or_words() {
+        // REQ-BURN-001
+        let points = vec![point(4, 400), point(2, 150)];
+
+        assert_eq!(remaining(&points, Measure::Files), vec![4, 2]);
+        assert_eq!(remaining(&points, Measure::Words), vec![400, 150]);
+    }
+
+    #[test]
+    fn test_should_render_columns_scaled_to_maximum() {
+        // REQ-BURN-002
+
+        // Given
+        let values = [4, 2, 1, 0];
+
+        // When
+        let rows = render_chart(&values, 2);
+
+        // Then
+        assert_eq!(rows, vec!["█   ", "██▄ "]);
+    }
+
+    #[test]
+    fn test_should_render_blank_chart_when_nothing_remains() {
+        // REQ-BURN-003
+        assert_eq!(render_chart(&[0, 0], 2), vec!["  ", "  "]);
+        assert!(render_chart(&[], 2).iter().all(String::is_empty));
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// What the burndown counts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Measure {
+    /// Notes still tagged to refactor
+    Files,
+    /// Words in notes still tagged to refactor
+    Words,
+}
+
+const BLOCKS: [char; 9] = [' ', '▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+/// Remaining work at each point.
+pub fn remaining(points: &[TrendPoint], measure: Measure) -> Vec<usize> {
+    points
+        .iter()
+        .map(|point| match measure {
+            Measure::Files => point.todo_files,
+            Measure::Words => point.todo_words,
+        })
+        .collect()
+}
+
+/// Render values as a column chart `height` rows tall, top row first,
+/// one column per value scaled to the largest value in eighth-block steps.
+pub fn render_chart(values: &[usize], height: usize) -> Vec<String> {
+    let max = values.iter().copied().max().unwrap_or(0);
+    let steps: Vec<usize> = values
+        .iter()
+        .map(|&value| {
+            if max == 0 {
+                0
+            } else {
+                ((value * height * 8) as f64 / max as f64).round() as usize
+            }
+        })
+        .collect();
+
+    (0..height)
+        .rev()
+        .map(|row| {
+            steps
+                .iter()
+                .map(|&step| BLOCKS[step.saturating_sub(row * 8).min(8)])
+                .collect()
+        })
+        .collect()
+}
@@ -0,0 +1,109 @@
+use anyhow::Result;
+use clap::Args;
+use std::path::PathBuf;
+
+use crate::check::{Baseline, regressions};
+use crate::core::links::LinkIndex;
+use crate::init::ZrtConfig;
+use crate::output::scan;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser, Debug)]
+    struct TestArgs {
+        #[command(flatten)]
+        check: CheckArgs,
+    }
+
+    #[test]
+    fn test_should_default_to_baseline_in_current_directory() {
+        // REQ-CHECK-004
+        let args = TestArgs::parse_from(["program"]);
+
+        assert_eq!(args.check.baseline, PathBuf::from("zrt-baseline.json"));
+        assert!(!args.check.update_baseline);
+    }
+
+    #[test]
+    fn test_should_accept_baseline_path_and_update() {
+        // REQ-CHECK-004
+        let args = TestArgs::parse_from([
+            "program",
+            "--baseline",
+            "ci/baseline.json",
+            "--update-baseline",
+        ]);
+
+        assert_eq!(args.check.baseline, PathBuf::from("ci/baseline.json"));
+        assert!(args.check.update_baseline);
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+#[derive(Args, Debug)]
+pub struct CheckArgs {
+    /// Directories to scan (space-separated, defaults to current directory)
+    #[arg(short = 'd', long = "dir", num_args = 0.., default_values = &["."])]
+    pub directories: Vec<PathBuf>,
+
+    /// Directories to exclude (space-separated)
+    #[arg(short, long, num_args = 0..)]
+    pub exclude: Vec<String>,
+
+    /// Baseline file to compare against
+    #[arg(long, default_value = "zrt-baseline.json")]
+    pub baseline: PathBuf,
+
+    /// Record the current state as the new baseline when nothing got worse
+    #[arg(long)]
+    pub update_baseline: bool,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+pub fn run(args: CheckArgs, config: &ZrtConfig) -> Result<()> {
+    let exclude_dirs: Vec<&str> = args.exclude.iter().map(String::as_str).collect();
+
+    let index = LinkIndex::build_in(&scan::scanner(&args.directories, &exclude_dirs)?)?;
+    let current = Baseline::from_notes(
+        &index.notes,
+        &config.refactor.done_tag,
+        &config.refactor.todo_tag,
+    );
+
+    if args.update_baseline && !args.baseline.exists() {
+        current.save(&args.baseline)?;
+        println!("Created {}", args.baseline.display());
+        return Ok(());
+    }
+
+    let baseline = Baseline::load(&args.baseline)?;
+    let found = regressions(&baseline, &current);
+    for regression in &found {
+        eprintln!("{regression}");
+    }
+    if !found.is_empty() {
+        anyhow::bail!("Check failed against {}", args.baseline.display());
+    }
+
+    println!(
+        "OK: {:.2}% done (baseline {:.2}%), {} untagged (baseline {})",
+        current.percentage, baseline.percentage, current.untagged_files, baseline.untagged_files
+    );
+    if args.update_baseline && current != baseline {
+        current.save(&args.baseline)?;
+        println!("Updated {}", args.baseline.display());
+    }
+    Ok(())
+}
@@ -0,0 +1,232 @@
+pub mod cli;
+
+use anyhow::{Context as _, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::Path;
+
+use crate::core::history::percentage;
+use crate::core::links::NoteNode;
+use crate::core::status::RefactorStatus;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::links::note_from_content;
+    use tempfile::TempDir;
+
+    fn baseline(percentage: f64, untagged_files: usize) -> Baseline {
+        Baseline {
+            percentage,
+            files: 10,
+            done_files: 2,
+            todo_files: 3,
+            untagged_files,
+        }
+    }
+
+    #[test]
+    fn test_should_summarize_notes() {
+        // REQ-CHECK-001
+        // Given a done, a todo, a differently tagged and an untagged note
+        let notes = vec![
+            note_from_content(
+                Path::new("a.md"),
+                "---\ntags: [refactored]\n---\none two three",
+            ),
+            note_from_content(Path::new("b.md"), "---\ntags: [to_refactor]\n---\none"),
+            note_from_content(Path::new("c.md"), "---\ntags: [idea]\n---\none two"),
+            note_from_content(Path::new("d.md"), "no tags here"),
+        ];
+
+        // When summarizing them
+        let summary = Baseline::from_notes(&notes, "refactored", "to_refactor");
+
+        // Then counts and the done word percentage are recorded
+        assert_eq!(summary.files, 4);
+        assert_eq!(summary.done_files, 1);
+        assert_eq!(summary.todo_files, 1);
+        assert_eq!(summary.untagged_files, 1);
+        assert!((summary.percentage - 33.33).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_should_pass_when_nothing_got_worse() {
+        // REQ-CHECK-002
+        assert!(regressions(&baseline(40.0, 5), &baseline(40.0, 5)).is_empty());
+        assert!(regressions(&baseline(40.0, 5), &baseline(45.0, 3)).is_empty());
+    }
+
+    #[test]
+    fn test_should_fail_when_percentage_drops_or_untagged_grows() {
+        // REQ-CHECK-002
+        let found = regressions(&baseline(40.0, 5), &baseline(39.5, 6));
+
+        assert_eq!(
+            found,
+            vec![
+                Regression::PercentageDropped {
+                    baseline: 40.0,
+                    current: 39.5
+                },
+                Regression::UntaggedGrew {
+                    baseline: 5,
+                    current: 6
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_should_save_and_load_baseline() -> anyhow::Result<()> {
+        // REQ-CHECK-003
+        let dir = TempDir::new()?;
+        let path = dir.path().join("zrt-baseline.json");
+
+        baseline(12.5, 4).save(&path)?;
+        let loaded = Baseline::load(&path)?;
+
+        assert_eq!(loaded, baseline(12.5, 4));
+        assert!(std::fs::read_to_string(&path)?.ends_with("}\n"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_explain_missing_baseline() {
+        // REQ-CHECK-003
+        let error = Baseline::load(Path::new("/definitely/not/here.json"))
+            .err()
+            .map(|e| e.to_string());
+
+        assert!(error.is_some_and(|e| e.contains("--update-baseline")));
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// The committed state a vault is checked against.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Baseline {
+    /// Percentage of words in notes tagged done, rounded to 2 decimal places
+    pub percentage: f64,
+    pub files: usize,
+    pub done_files: usize,
+    pub todo_files: usize,
+    /// Notes without any tags
+    pub untagged_files: usize,
+}
+
+/// A way the current scan is worse than the baseline.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Regression {
+    PercentageDropped { baseline: f64, current: f64 },
+    UntaggedGrew { baseline: usize, current: usize },
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+impl Baseline {
+    /// Summarizes scanned notes.
+    #[inline]
+    #[must_use]
+    pub fn from_notes(notes: &[NoteNode], done_tag: &str, todo_tag: &str) -> Self {
+        let mut baseline = Self {
+            percentage: 0.0,
+            files: notes.len(),
+            done_files: 0,
+            todo_files: 0,
+            untagged_files: 0,
+        };
+        let mut done_words = 0;
+        for note in notes {
+            match RefactorStatus::of(&note.tags, done_tag, todo_tag) {
+                RefactorStatus::Done => {
+                    baseline.done_files += 1;
+                    done_words += note.words;
+                }
+                RefactorStatus::Todo => baseline.todo_files += 1,
+                RefactorStatus::Untracked => {}
+            }
+            if note.tags.is_empty() {
+                baseline.untagged_files += 1;
+            }
+        }
+        let words = notes.iter().map(|note| note.words).sum();
+        baseline.percentage = percentage(done_words, words);
+        baseline
+    }
+
+    /// Reads a baseline file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or is not a baseline.
+    #[inline]
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path).with_context(|| {
+            format!(
+                "Failed to read baseline {}; create it with --update-baseline",
+                path.display()
+            )
+        })?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse baseline {}", path.display()))
+    }
+
+    /// Writes the baseline as pretty-printed JSON, so diffs stay readable in review.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be written.
+    #[inline]
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let mut content = serde_json::to_string_pretty(self)?;
+        content.push('\n');
+        std::fs::write(path, content)
+            .with_context(|| format!("Failed to write baseline {}", path.display()))
+    }
+}
+
+impl fmt::Display for Regression {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PercentageDropped { baseline, current } => {
+                write!(
+                    f,
+                    "done percentage fell from {baseline:.2}% to {current:.2}%"
+                )
+            }
+            Self::UntaggedGrew { baseline, current } => {
+                write!(f, "untagged notes grew from {baseline} to {current}")
+            }
+        }
+    }
+}
+
+/// Everything that got worse between `baseline` and `current`.
+#[inline]
+#[must_use]
+pub fn regressions(baseline: &Baseline, current: &Baseline) -> Vec<Regression> {
+    let mut found = Vec::new();
+    if current.percentage < baseline.percentage {
+        found.push(Regression::PercentageDropped {
+            baseline: baseline.percentage,
+            current: current.percentage,
+        });
+    }
+    if current.untagged_files > baseline.untagged_files {
+        found.push(Regression::UntaggedGrew {
+            baseline: baseline.untagged_files,
+            current: current.untagged_files,
+        });
+    }
+    found
+}
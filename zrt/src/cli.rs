@@ -1,12 +1,153 @@
 use anyhow::Result;
-use clap::{Parser, Subcommand};
+use clap::parser::ValueSource;
+use clap::{ArgAction, CommandFactory as _, Parser, Subcommand};
+use std::ffi::OsString;
+use std::time::{Duration, Instant};
 
+use crate::core::scanner::ScanReport;
+use crate::core::timings::Stage;
+use crate::init::{ProfileConfig, ZrtConfig};
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn profile() -> ProfileConfig {
+        ProfileConfig {
+            dirs: vec!["work".to_owned(), "shared".to_owned()],
+            exclude: vec!["archive".to_owned()],
+            ..ProfileConfig::default()
+        }
+    }
+
+    fn parse(argv: &[&str]) -> Result<Args> {
+        Ok(with_profile(
+            argv.iter().map(OsString::from).collect(),
+            &profile(),
+        )?)
+    }
+
+    #[test]
+    fn test_should_accept_global_profile() {
+        // REQ-INIT-032
+        let args = Args::parse_from(["zrt", "count", "--profile", "work"]);
+
+        assert_eq!(args.profile.as_deref(), Some("work"));
+    }
+
+    #[test]
+    fn test_should_accept_comma_separated_extensions() {
+        // REQ-SCAN-016
+        let args = Args::parse_from(["zrt", "tags", "--ext", "md,txt"]);
+
+        assert_eq!(args.ext, vec!["md", "txt"]);
+    }
+
+    #[test]
+    fn test_should_accept_global_lossy() {
+        // REQ-VFS-004
+        assert!(Args::parse_from(["zrt", "count", "--lossy"]).lossy);
+        assert!(!Args::parse_from(["zrt", "count"]).lossy);
+    }
+
+    #[test]
+    fn test_should_accept_global_timings() {
+        // REQ-TIME-001
+        assert!(Args::parse_from(["zrt", "tags", "--timings"]).timings);
+        assert!(!Args::parse_from(["zrt", "tags"]).timings);
+    }
+
+    #[test]
+    fn test_should_accept_global_strict() {
+        // REQ-ERR-005
+        assert!(Args::parse_from(["zrt", "wordcount", "--strict"]).strict);
+        assert!(!Args::parse_from(["zrt", "wordcount"]).strict);
+    }
+
+    #[test]
+    fn test_should_default_directories_and_excludes_to_profile() -> Result<()> {
+        // REQ-INIT-032
+        let Commands::Count(count) = parse(&["zrt", "--profile", "work", "count"])?.command else {
+            anyhow::bail!("expected count");
+        };
+
+        assert_eq!(
+            count.directories,
+            ["work", "shared"].map(std::path::PathBuf::from)
+        );
+        assert_eq!(count.exclude, vec!["archive"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_prefer_explicit_directories_over_profile() -> Result<()> {
+        // REQ-INIT-032
+        let Commands::Tags(tags) = parse(&["zrt", "tags", "-d", "personal", "-e", "old"])?.command
+        else {
+            anyhow::bail!("expected tags");
+        };
+
+        assert_eq!(tags.directories, vec![std::path::PathBuf::from("personal")]);
+        assert_eq!(tags.exclude, vec!["old"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_keep_profile_arguments_out_of_watched_command() -> Result<()> {
+        // REQ-INIT-032
+        let Commands::Watch(watch) = parse(&["zrt", "watch", "--", "count"])?.command else {
+            anyhow::bail!("expected watch");
+        };
+
+        assert_eq!(
+            watch.directories,
+            ["work", "shared"].map(std::path::PathBuf::from)
+        );
+        assert_eq!(watch.command, vec!["count"]);
+        Ok(())
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 pub struct Args {
     #[command(subcommand)]
     pub command: Commands,
+
+    /// Never show a progress bar for long scans
+    #[arg(long, global = true)]
+    pub no_progress: bool,
+
+    /// Log what scans do to stderr (-v for skipped files and their reason, -vv for every file)
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    pub verbose: u8,
+
+    /// Use the directories, excludes, tags and thresholds of a profile in .zrt/config.toml
+    #[arg(long, global = true, value_name = "NAME")]
+    pub profile: Option<String>,
+
+    /// Only scan files with these extensions (comma-separated, e.g. md,txt)
+    #[arg(long, global = true, value_delimiter = ',', value_name = "EXT")]
+    pub ext: Vec<String>,
+
+    /// Read notes that are not valid UTF-8 with replacement characters instead of skipping them
+    #[arg(long, global = true)]
+    pub lossy: bool,
+
+    /// Fail on the first directory or file that cannot be read instead of skipping it
+    #[arg(long, global = true)]
+    pub strict: bool,
+
+    /// Report on stderr where scan time went and the slowest notes
+    #[arg(long, global = true)]
+    pub timings: bool,
 }
 
 #[derive(Subcommand, Debug)]
@@ -38,18 +179,397 @@ pub enum Commands {
     /// Find the most connected notes for a given tag
     #[command(alias = "con")]
     Connected(crate::connected::cli::ConnectedArgs),
+
+    /// List notes that link to a given note
+    #[command(alias = "bl")]
+    Backlinks(crate::backlinks::cli::BacklinksArgs),
+
+    /// Export the note link graph
+    #[command(alias = "g")]
+    Graph(crate::graph::cli::GraphArgs),
+
+    /// List links between notes or report link degree statistics
+    #[command(alias = "l")]
+    Links(crate::links::cli::LinksArgs),
+
+    /// Find disconnected clusters of notes
+    #[command(alias = "cl")]
+    Clusters(crate::clusters::cli::ClustersArgs),
+
+    /// Rank central notes that still need refactoring
+    #[command(alias = "h")]
+    Hubs(crate::hubs::cli::HubsArgs),
+
+    /// Record current progress statistics in the history file
+    #[command(alias = "snap")]
+    Snapshot(crate::snapshot::cli::SnapshotArgs),
+
+    /// Compare two snapshots, or the latest snapshot with the current state
+    #[command(alias = "d")]
+    Diff(crate::diff::cli::DiffArgs),
+
+    /// Run every vault health check and summarize the problems by severity
+    #[command(alias = "dr")]
+    Doctor(crate::doctor::cli::DoctorArgs),
+
+    /// Show progress over time from the snapshot history
+    #[command(alias = "tr")]
+    Trend(crate::trend::cli::TrendArgs),
+
+    /// List progress history from snapshots or reconstruct it from git
+    #[command(alias = "hist")]
+    History(crate::history::cli::HistoryArgs),
+
+    /// Chart remaining notes to refactor over time
+    #[command(alias = "bd")]
+    Burndown(crate::burndown::cli::BurndownArgs),
+
+    /// Show refactoring progress overall or per folder
+    #[command(alias = "p")]
+    Progress(crate::progress::cli::ProgressArgs),
+
+    /// Summarize totals, untagged notes, progress, the largest todo notes and tags at once
+    #[command(alias = "ov")]
+    Overview(crate::overview::cli::OverviewArgs),
+
+    /// Report consecutive days of refactoring progress
+    #[command(alias = "st")]
+    Streak(crate::streak::cli::StreakArgs),
+
+    /// Fuzzy-find notes by title, file name or alias
+    #[command(alias = "f")]
+    Find(crate::find::cli::FindArgs),
+
+    /// Find notes with a FROM/WHERE/SORT/LIMIT query over tags, metrics and frontmatter
+    #[command(alias = "q")]
+    Query(crate::query::cli::QueryArgs),
+
+    /// Pick a random note to refactor
+    #[command(alias = "r")]
+    Random(crate::random::cli::RandomArgs),
+
+    /// Suggest the most valuable note to refactor next
+    #[command(alias = "n")]
+    Next(crate::next::cli::NextArgs),
+
+    /// Export notes, tags and links to a SQLite database
+    #[command(alias = "x")]
+    Export(crate::export::cli::ExportArgs),
+
+    /// Fail if progress fell behind a committed baseline
+    #[command(alias = "ck")]
+    Check(crate::check::cli::CheckArgs),
+
+    /// Check staged notes from a git pre-commit hook, or install the hook
+    #[command(alias = "hk")]
+    Hook(crate::hook::cli::HookArgs),
+
+    /// Serve vault statistics over HTTP
+    #[command(alias = "sv")]
+    Serve(crate::serve::cli::ServeArgs),
+
+    /// List notes without body text
+    #[command(alias = "em")]
+    Empty(crate::empty::cli::EmptyArgs),
+
+    /// Count open and closed task checkboxes per note and in total
+    #[command(alias = "tk")]
+    Tasks(crate::tasks::cli::TasksArgs),
+
+    /// List overdue and upcoming notes by their due date
+    #[command(alias = "du")]
+    Due(crate::due::cli::DueArgs),
+
+    /// Count notes per state of a frontmatter status workflow
+    #[command(alias = "wf")]
+    Workflow(crate::workflow::cli::WorkflowArgs),
+
+    /// Check notes against the vault's conventions
+    #[command(alias = "li")]
+    Lint(crate::lint::cli::LintArgs),
+
+    /// Find notes with near-identical content
+    #[command(alias = "dup")]
+    Duplicates(crate::duplicates::cli::DuplicatesArgs),
+
+    /// List notes that have not been modified in a long time
+    #[command(alias = "sl")]
+    Stale(crate::stale::cli::StaleArgs),
+
+    /// Report image and embed references to missing files
+    #[command(alias = "as")]
+    Assets(crate::assets::cli::AssetsArgs),
+
+    /// Print note heading outlines or check their structure
+    #[command(alias = "ol")]
+    Outline(crate::outline::cli::OutlineArgs),
+
+    /// Suggest where long notes could be split
+    #[command(alias = "split")]
+    SuggestSplits(crate::suggest_splits::cli::SuggestSplitsArgs),
+
+    /// Extract frontmatter fields of every note as a table
+    #[command(alias = "ex")]
+    Extract(crate::extract::cli::ExtractArgs),
+
+    /// Print JSON Schemas of the machine-readable outputs
+    #[command(alias = "sch")]
+    Schema(crate::schema::cli::SchemaArgs),
+
+    /// Summarize changes since a date as a Markdown digest
+    #[command(alias = "sum")]
+    Summary(crate::summary::cli::SummaryArgs),
+
+    /// Report streaks, words and missing days of daily notes
+    #[command(alias = "j")]
+    Journal(crate::journal::cli::JournalArgs),
+
+    /// Run configured analyzer plugins and summarize their metrics
+    #[command(alias = "pl")]
+    Plugins(crate::plugins::cli::PluginsArgs),
+
+    /// Move finished notes into an archive folder, fixing links to them
+    #[command(alias = "ar")]
+    Archive(crate::archive::cli::ArchiveArgs),
+
+    /// Rename notes to a file name pattern, fixing links to them
+    #[command(alias = "rn")]
+    Rename(crate::rename::cli::RenameArgs),
+
+    /// Move notes with a tag into a folder, fixing links to and from them
+    #[command(alias = "mv")]
+    Move(crate::move_notes::cli::MoveArgs),
+
+    /// Keep an ordered queue of notes to refactor next
+    #[command(alias = "qu")]
+    Queue(crate::queue::cli::QueueArgs),
+
+    /// Re-run a command whenever notes change
+    #[command(alias = "w")]
+    Watch(crate::watch::cli::WatchArgs),
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+/// Parse the process arguments, exiting with usage help when they are invalid.
+#[must_use]
+pub fn parse() -> Args {
+    parse_from(std::env::args_os()).unwrap_or_else(|e| e.exit())
+}
+
+/// Parse `argv`, defaulting `-d` and `-e` of every command to those of the
+/// profile given with `--profile`.
+///
+/// # Errors
+///
+/// Returns an error if the arguments are invalid or name an unknown profile.
+pub fn parse_from<I, T>(argv: I) -> Result<Args, clap::Error>
+where
+    I: IntoIterator<Item = T>,
+    T: Into<OsString> + Clone,
+{
+    let argv: Vec<OsString> = argv.into_iter().map(Into::into).collect();
+    let args = Args::try_parse_from(&argv)?;
+    let Some(name) = &args.profile else {
+        return Ok(args);
+    };
+    let config = ZrtConfig::load_or_default();
+    let profile = config
+        .profile(name)
+        .map_err(|e| Args::command().error(clap::error::ErrorKind::InvalidValue, e.to_string()))?;
+    with_profile(argv, profile)
+}
+
+/// Parse `argv` with the profile's directories and excludes passed to a
+/// command given no `-d` or `-e` of its own.
+fn with_profile(mut argv: Vec<OsString>, profile: &ProfileConfig) -> Result<Args, clap::Error> {
+    let command = Args::command();
+    let matches = command.clone().try_get_matches_from(&argv)?;
+    let mut extra = Vec::new();
+    if let Some((name, sub_matches)) = matches.subcommand()
+        && sub_matches.subcommand().is_none()
+        && let Some(sub) = command.find_subcommand(name)
+    {
+        for (id, values) in [
+            ("directories", &profile.dirs),
+            ("exclude", &profile.exclude),
+        ] {
+            let Some(long) = sub
+                .get_arguments()
+                .find(|arg| arg.get_id() == id)
+                .and_then(clap::Arg::get_long)
+            else {
+                continue;
+            };
+            if sub_matches.value_source(id) != Some(ValueSource::CommandLine) {
+                extra.extend(
+                    values
+                        .iter()
+                        .map(|v| OsString::from(format!("--{long}={v}"))),
+                );
+            }
+        }
+    }
+    // Before any `--`, so the arguments never reach a command `watch` re-runs.
+    let end = argv.iter().position(|a| a == "--").unwrap_or(argv.len());
+    argv.splice(end..end, extra);
+    Args::try_parse_from(&argv)
 }
 
 #[inline]
 pub fn run(args: Args) -> Result<()> {
-    match args.command {
+    crate::output::logging::init(args.verbose);
+    crate::output::progress::set_enabled(!args.no_progress);
+    let config = ZrtConfig::load_or_default_with(args.profile.as_deref());
+    let profile = args.profile;
+    crate::output::scan::configure(crate::output::scan::ScanSettings {
+        extensions: args.ext.clone(),
+        strict: args.strict,
+        lossy: args.lossy,
+        formats: config.frontmatter.formats.clone(),
+        timings: args.timings,
+    });
+    let started = args.timings.then(Instant::now);
+    let lossy = args.lossy;
+    // `lint` and `doctor` report broken frontmatter themselves
+    let linting = matches!(args.command, Commands::Lint(_) | Commands::Doctor(_));
+    let result = match args.command {
         Commands::Init(args) => crate::init::cli::run(args),
-        Commands::Wordcount(args) => crate::wordcount::cli::run(args),
+        Commands::Wordcount(args) => crate::wordcount::cli::run(args, &config),
         Commands::Search(args) => crate::search::cli::run(args),
         Commands::Count(args) => crate::count::cli::run(args),
         Commands::Similar(args) => crate::similar::cli::run(args),
         Commands::Tags(args) => crate::tags::cli::run(args),
         Commands::Connected(args) => crate::connected::cli::run(args),
+        Commands::Backlinks(args) => crate::backlinks::cli::run(args),
+        Commands::Graph(args) => crate::graph::cli::run(args, &config),
+        Commands::Links(args) => crate::links::cli::run(args, &config),
+        Commands::Clusters(args) => crate::clusters::cli::run(args, &config),
+        Commands::Hubs(args) => crate::hubs::cli::run(args, &config),
+        Commands::Snapshot(args) => crate::snapshot::cli::run(args, &config),
+        Commands::Diff(args) => crate::diff::cli::run(args, &config),
+        Commands::Doctor(args) => crate::doctor::cli::run(args, &config),
+        Commands::Trend(args) => crate::trend::cli::run(args),
+        Commands::History(args) => crate::history::cli::run(args, &config),
+        Commands::Burndown(args) => crate::burndown::cli::run(args),
+        Commands::Progress(args) => crate::progress::cli::run(args, &config),
+        Commands::Overview(args) => crate::overview::cli::run(args, &config),
+        Commands::Streak(args) => crate::streak::cli::run(args),
+        Commands::Find(args) => crate::find::cli::run(args),
+        Commands::Query(args) => crate::query::cli::run(args, &config),
+        Commands::Random(args) => crate::random::cli::run(args, &config),
+        Commands::Next(args) => crate::next::cli::run(args, &config),
+        Commands::Export(args) => crate::export::cli::run(args, &config),
+        Commands::Check(args) => crate::check::cli::run(args, &config),
+        Commands::Hook(args) => crate::hook::cli::run(args, &config),
+        Commands::Serve(args) => crate::serve::cli::run(args, &config),
+        Commands::Empty(args) => crate::empty::cli::run(args),
+        Commands::Tasks(args) => crate::tasks::cli::run(args),
+        Commands::Due(args) => crate::due::cli::run(args, &config),
+        Commands::Workflow(args) => crate::workflow::cli::run(args, &config),
+        Commands::Lint(args) => crate::lint::cli::run(args, &config),
+        Commands::Duplicates(args) => crate::duplicates::cli::run(args),
+        Commands::Stale(args) => crate::stale::cli::run(args),
+        Commands::Assets(args) => crate::assets::cli::run(args),
+        Commands::Outline(args) => crate::outline::cli::run(args),
+        Commands::SuggestSplits(args) => crate::suggest_splits::cli::run(args, &config),
+        Commands::Extract(args) => crate::extract::cli::run(args),
+        Commands::Schema(args) => crate::schema::cli::run(args),
+        Commands::Summary(args) => crate::summary::cli::run(args, &config),
+        Commands::Journal(args) => crate::journal::cli::run(args, &config),
+        Commands::Plugins(args) => crate::plugins::cli::run(args, &config),
+        Commands::Archive(args) => crate::archive::cli::run(args, &config),
+        Commands::Rename(args) => crate::rename::cli::run(args, &config),
+        Commands::Move(args) => crate::move_notes::cli::run(args),
+        Commands::Queue(args) => crate::queue::cli::run(args, &config),
+        Commands::Watch(args) => crate::watch::cli::run(args, &config, profile.as_deref()),
+    };
+    if let Some(started) = started {
+        report_timings(started.elapsed());
+    }
+    let report = crate::output::scan::take_report();
+    if !report.binaries.is_empty() {
+        tracing::debug!("skipped {} binary files", report.binaries.len());
+    }
+    report_scan_errors(&report);
+    report_non_utf8(&report, lossy);
+    if !linting {
+        report_invalid_frontmatter(&report);
+    }
+    result
+}
+
+/// Show where the run's time went, stage by stage, and the slowest notes.
+fn report_timings(total: Duration) {
+    let report = crate::output::scan::take_timings();
+    eprintln!("Timings (stages add up across threads):");
+    eprintln!("  {:<22}{total:.1?}", "total");
+    for (stage, spent) in report.stages {
+        // Ignore matching happens during traversal, so it is shown within it
+        let label = if stage == Stage::IgnoreMatching {
+            format!("  {stage}")
+        } else {
+            stage.to_string()
+        };
+        eprintln!("  {label:<22}{spent:.1?}");
+    }
+    if !report.slowest.is_empty() {
+        eprintln!("Slowest notes:");
+        for (path, spent) in report.slowest {
+            eprintln!("  {spent:>9.1?}  {}", path.display());
+        }
     }
 }
 
+/// Summarize the entries scans skipped because they could not be read.
+fn report_scan_errors(report: &ScanReport) {
+    if report.errors.is_empty() {
+        return;
+    }
+    eprintln!(
+        "Skipped {} entries that could not be read (fail on them with --strict):",
+        report.errors.len()
+    );
+    for (path, error) in &report.errors {
+        eprintln!("  {}: {error}", path.display());
+    }
+}
+
+/// Name the notes that were not valid UTF-8, so counts are never silently short.
+fn report_non_utf8(report: &ScanReport, lossy: bool) {
+    let files = &report.not_utf8;
+    if files.is_empty() {
+        return;
+    }
+    if lossy {
+        eprintln!(
+            "Read {} files with invalid UTF-8 using replacement characters:",
+            files.len()
+        );
+    } else {
+        eprintln!(
+            "Skipped {} files that are not valid UTF-8 (read them with --lossy):",
+            files.len()
+        );
+    }
+    for file in files {
+        eprintln!("  {}", file.display());
+    }
+}
+
+/// Warn about notes whose frontmatter failed to parse, since their tags were ignored.
+fn report_invalid_frontmatter(report: &ScanReport) {
+    let invalid = &report.invalid_frontmatter;
+    if invalid.is_empty() {
+        return;
+    }
+    eprintln!(
+        "Warning: {} files have frontmatter that failed to parse, so their tags were ignored:",
+        invalid.len()
+    );
+    for (path, error) in invalid {
+        eprintln!("  {}: {error}", path.display());
+    }
+}
@@ -0,0 +1,111 @@
+use anyhow::Result;
+use clap::Args;
+use std::path::PathBuf;
+
+use crate::clusters::find_clusters;
+use crate::core::links::LinkIndex;
+use crate::graph::{GraphFilter, build_graph};
+use crate::init::ZrtConfig;
+use crate::output::scan;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser, Debug)]
+    struct TestArgs {
+        #[command(flatten)]
+        clusters: ClustersArgs,
+    }
+
+    #[test]
+    fn test_should_default_min_size_to_one() {
+        // REQ-CLUSTER-004
+
+        // Given / When
+        let args = TestArgs::parse_from(["program"]);
+
+        // Then
+        assert_eq!(args.clusters.min_size, 1);
+        assert!(!args.clusters.list);
+    }
+
+    #[test]
+    fn test_should_accept_min_size_and_list_flags() {
+        // REQ-CLUSTER-004, REQ-CLUSTER-005
+
+        // Given / When
+        let args = TestArgs::parse_from(["program", "--min-size", "3", "--list"]);
+
+        // Then
+        assert_eq!(args.clusters.min_size, 3);
+        assert!(args.clusters.list);
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+#[derive(Args, Debug)]
+pub struct ClustersArgs {
+    /// Directories to scan (space-separated, defaults to current directory)
+    #[arg(short = 'd', long = "dir", num_args = 0.., default_values = &["."])]
+    pub directories: Vec<PathBuf>,
+
+    /// Directories to exclude (space-separated)
+    #[arg(short, long, num_args = 0..)]
+    pub exclude: Vec<String>,
+
+    /// Only show clusters with at least this many notes
+    #[arg(long, default_value = "1")]
+    pub min_size: usize,
+
+    /// List the notes in each cluster
+    #[arg(long)]
+    pub list: bool,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+pub fn run(args: ClustersArgs, config: &ZrtConfig) -> Result<()> {
+    let exclude_dirs: Vec<&str> = args.exclude.iter().map(String::as_str).collect();
+
+    let index = LinkIndex::build_in(&scan::scanner(&args.directories, &exclude_dirs)?)?;
+    let graph = build_graph(
+        &index,
+        &GraphFilter::default(),
+        &config.refactor.done_tag,
+        &config.refactor.todo_tag,
+    );
+
+    for cluster in find_clusters(&graph)
+        .iter()
+        .filter(|c| c.notes.len() >= args.min_size)
+    {
+        let first = cluster
+            .notes
+            .first()
+            .map(|p| p.display().to_string())
+            .unwrap_or_default();
+        println!(
+            "{} {:.2} {}",
+            cluster.notes.len(),
+            cluster.percentage(),
+            first
+        );
+        if args.list {
+            for note in &cluster.notes {
+                println!("  {}", note.display());
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,123 @@
+pub mod cli;
+
+use std::path::PathBuf;
+
+use crate::core::status::RefactorStatus;
+use crate::graph::{Graph, components};
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::links::{LinkIndex, note_from_content};
+    use crate::graph::{GraphFilter, build_graph};
+    use std::path::Path;
+
+    fn graph(notes: &[(&str, &str)]) -> Graph {
+        let index = LinkIndex::from_notes(
+            notes
+                .iter()
+                .map(|(path, content)| note_from_content(Path::new(path), content))
+                .collect(),
+        );
+        build_graph(&index, &GraphFilter::default(), "refactored", "to_refactor")
+    }
+
+    #[test]
+    fn test_should_report_cluster_sizes() {
+        // REQ-CLUSTER-001
+
+        // Given
+        let graph = graph(&[("/v/a.md", "[[b]]"), ("/v/b.md", ""), ("/v/c.md", "")]);
+
+        // When
+        let clusters = find_clusters(&graph);
+
+        // Then
+        assert_eq!(clusters.len(), 2);
+        assert_eq!(clusters[0].notes.len(), 2);
+        assert_eq!(clusters[1].notes, vec![PathBuf::from("/v/c.md")]);
+    }
+
+    #[test]
+    fn test_should_compute_refactor_percentage_by_words() {
+        // REQ-CLUSTER-002
+
+        // Given: 4 of 5 words in the cluster (the link counts as one) are in a refactored note
+        let graph = graph(&[
+            (
+                "/v/a.md",
+                "---\ntags: [refactored]\n---\none two three [[b]]",
+            ),
+            ("/v/b.md", "---\ntags: [to_refactor]\n---\nfour"),
+        ]);
+
+        // When
+        let clusters = find_clusters(&graph);
+
+        // Then
+        assert_eq!(clusters[0].done_words, 4);
+        assert_eq!(clusters[0].total_words, 5);
+        assert!((clusters[0].percentage() - 80.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_should_report_zero_percent_for_empty_cluster() {
+        // REQ-CLUSTER-003
+        let graph = graph(&[("/v/a.md", "")]);
+
+        let clusters = find_clusters(&graph);
+
+        assert!(clusters[0].percentage().abs() < f64::EPSILON);
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// A group of notes connected to each other by links but not to the rest of the vault.
+#[derive(Debug)]
+pub struct Cluster {
+    pub notes: Vec<PathBuf>,
+    pub done_words: usize,
+    pub total_words: usize,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+impl Cluster {
+    /// Percentage of the cluster's words in refactored notes, rounded to 2 decimals.
+    #[inline]
+    #[must_use]
+    pub fn percentage(&self) -> f64 {
+        if self.total_words == 0 {
+            return 0.0;
+        }
+        let percentage = (self.done_words as f64 / self.total_words as f64) * 100.0;
+        (percentage * 100.0).round() / 100.0
+    }
+}
+
+/// Find disconnected clusters of notes, largest first.
+pub fn find_clusters(graph: &Graph) -> Vec<Cluster> {
+    components(graph)
+        .into_iter()
+        .map(|members| {
+            let nodes = members.iter().map(|&i| &graph.nodes[i]);
+            Cluster {
+                notes: nodes.clone().map(|n| n.path.clone()).collect(),
+                done_words: nodes
+                    .clone()
+                    .filter(|n| n.status == RefactorStatus::Done)
+                    .map(|n| n.words)
+                    .sum(),
+                total_words: nodes.map(|n| n.words).sum(),
+            }
+        })
+        .collect()
+}
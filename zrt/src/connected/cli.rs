@@ -3,6 +3,8 @@ use clap::Args;
 use std::io::{self, Read};
 use std::path::PathBuf;
 
+use crate::output::scan;
+
 // ============================================
 // TESTS
 // ============================================
@@ -92,7 +94,8 @@ pub fn run(args: ConnectedArgs) -> Result<()> {
     };
 
     let exclude_dirs: Vec<&str> = args.exclude.iter().map(String::as_str).collect();
-    let results = crate::connected::most_connected(&args.directories, &tag, &exclude_dirs)?;
+    let scanner = scan::scanner(&args.directories, &exclude_dirs)?;
+    let results = crate::connected::most_connected_in(&scanner, &tag)?;
 
     for (path, _) in results.iter().take(args.limit) {
         println!("{tag} {path}");
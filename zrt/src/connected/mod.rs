@@ -3,11 +3,9 @@ pub mod cli;
 use anyhow::Result;
 use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
-use walkdir::WalkDir;
 
-use crate::core::filter::utils::should_exclude;
-use crate::core::frontmatter::{parse_frontmatter, strip_frontmatter};
-use crate::core::ignore::load_ignore_patterns;
+use crate::core::links::{Link, LinkIndex, NoteNode};
+use crate::core::scanner::{ScanOptions, Scanner};
 
 // ============================================
 // TESTS
@@ -56,7 +54,10 @@ mod tests {
         let results = most_connected(&[dir.path().to_path_buf()], "writing", &[])?;
 
         // Then
-        let a_score = results.iter().find(|(p, _)| p.ends_with("a.md")).map(|(_, c)| *c);
+        let a_score = results
+            .iter()
+            .find(|(p, _)| p.ends_with("a.md"))
+            .map(|(_, c)| *c);
         assert_eq!(a_score, Some(1));
         Ok(())
     }
@@ -74,7 +75,10 @@ mod tests {
         let results = most_connected(&[dir.path().to_path_buf()], "writing", &[])?;
 
         // Then
-        let b_score = results.iter().find(|(p, _)| p.ends_with("b.md")).map(|(_, c)| *c);
+        let b_score = results
+            .iter()
+            .find(|(p, _)| p.ends_with("b.md"))
+            .map(|(_, c)| *c);
         assert_eq!(b_score, Some(1));
         Ok(())
     }
@@ -93,7 +97,10 @@ mod tests {
         let results = most_connected(&[dir.path().to_path_buf()], "writing", &[])?;
 
         // Then
-        let a_score = results.iter().find(|(p, _)| p.ends_with("a.md")).map(|(_, c)| *c);
+        let a_score = results
+            .iter()
+            .find(|(p, _)| p.ends_with("a.md"))
+            .map(|(_, c)| *c);
         assert_eq!(a_score, Some(0));
         Ok(())
     }
@@ -162,32 +169,6 @@ mod tests {
 // IMPLEMENTATIONS
 // ============================================
 
-/// Extract wikilink targets from note body text.
-/// Handles [[link]] and [[link|alias]] formats, stripping directory prefixes.
-fn extract_wikilinks(body: &str) -> HashSet<String> {
-    let mut links = HashSet::new();
-    let mut remaining = body;
-
-    while let Some(start) = remaining.find("[[") {
-        remaining = &remaining[start + 2..];
-        if let Some(end) = remaining.find("]]") {
-            let raw = &remaining[..end];
-            // Strip alias: [[link|alias]] → link
-            let target = raw.split('|').next().unwrap_or(raw).trim();
-            // Strip directory prefix: [[dir/note]] → note
-            let stem = target.split('/').next_back().unwrap_or(target);
-            if !stem.is_empty() {
-                links.insert(stem.to_string());
-            }
-            remaining = &remaining[end + 2..];
-        } else {
-            break;
-        }
-    }
-
-    links
-}
-
 /// Find the most connected notes for a given tag.
 /// Returns (file_path, total_connection_score) sorted by score descending.
 /// Only connections between notes that both have the tag are counted.
@@ -196,78 +177,54 @@ pub fn most_connected(
     tag: &str,
     exclude: &[&str],
 ) -> Result<Vec<(String, usize)>> {
-    // Collect all notes: stem → (path_string, has_tag, body)
-    let mut notes: Vec<(String, String, bool, String)> = Vec::new(); // (stem, path, has_tag, body)
-
-    for dir in dirs {
-        let absolute_dir = if dir.is_absolute() {
-            dir.clone()
-        } else {
-            std::env::current_dir()?.join(dir)
-        };
-
-        let ignore_patterns = load_ignore_patterns(&absolute_dir)?;
-
-        for entry in WalkDir::new(&absolute_dir)
-            .follow_links(true)
-            .into_iter()
-            .filter_entry(|e| !should_exclude(e, exclude, Some(&ignore_patterns)))
-        {
-            let entry = entry?;
-            if !entry.file_type().is_file() {
-                continue;
-            }
-            let path = entry.path();
-            let stem = path
-                .file_stem()
-                .map(|s| s.to_string_lossy().to_string())
-                .unwrap_or_default();
-
-            if let Ok(content) = std::fs::read_to_string(path) {
-                let has_tag = parse_frontmatter(&content)
-                    .ok()
-                    .and_then(|fm| fm.tags)
-                    .map_or(false, |tags| tags.iter().any(|t| t == tag));
-                let body = strip_frontmatter(&content).to_string();
-                notes.push((stem, path.display().to_string(), has_tag, body));
-            }
-        }
-    }
+    most_connected_in(&ScanOptions::new(dirs).exclude(exclude).build()?, tag)
+}
+
+/// [`most_connected`] using an already configured scanner
+pub fn most_connected_in(scanner: &Scanner, tag: &str) -> Result<Vec<(String, usize)>> {
+    let index = LinkIndex::build_in(scanner)?;
+    let has_tag = |note: &NoteNode| note.tags.iter().any(|t| t == tag);
 
     // Set of tagged note stems for fast lookup
-    let tagged_stems: HashSet<&str> = notes
+    let tagged_stems: HashSet<&str> = index
+        .notes
         .iter()
-        .filter(|(_, _, has_tag, _)| *has_tag)
-        .map(|(stem, _, _, _)| stem.as_str())
+        .filter(|note| has_tag(note))
+        .map(|note| note.stem.as_str())
         .collect();
 
     // Build outgoing link map: stem → set of stems it links to
-    let mut outgoing: HashMap<&str, HashSet<String>> = HashMap::new();
-    for (stem, _, _, body) in &notes {
-        let links = extract_wikilinks(body);
-        outgoing.insert(stem.as_str(), links);
+    let mut outgoing: HashMap<&str, HashSet<&str>> = HashMap::new();
+    for note in &index.notes {
+        let links = note.links.iter().map(Link::stem).collect();
+        outgoing.insert(note.stem.as_str(), links);
     }
 
     // Score each tagged note
-    let mut scores: Vec<(String, usize)> = notes
+    let mut scores: Vec<(String, usize)> = index
+        .notes
         .iter()
-        .filter(|(_, _, has_tag, _)| *has_tag)
-        .map(|(stem, path, _, _)| {
-            let out_count = outgoing
-                .get(stem.as_str())
-                .map_or(0, |links| links.iter().filter(|l| tagged_stems.contains(l.as_str()) && l.as_str() != stem.as_str()).count());
-
-            let in_count = notes
+        .filter(|note| has_tag(note))
+        .map(|note| {
+            let stem = note.stem.as_str();
+            let out_count = outgoing.get(stem).map_or(0, |links| {
+                links
+                    .iter()
+                    .filter(|l| tagged_stems.contains(*l) && **l != stem)
+                    .count()
+            });
+
+            let in_count = tagged_stems
                 .iter()
-                .filter(|(other_stem, _, _, _)| other_stem != stem && tagged_stems.contains(other_stem.as_str()))
-                .filter(|(other_stem, _, _, _)| {
+                .filter(|other| **other != stem)
+                .filter(|other| {
                     outgoing
-                        .get(other_stem.as_str())
-                        .map_or(false, |links| links.contains(stem.as_str()))
+                        .get(**other)
+                        .is_some_and(|links| links.contains(stem))
                 })
                 .count();
 
-            (path.clone(), out_count + in_count)
+            (note.path.display().to_string(), out_count + in_count)
         })
         .collect();
 
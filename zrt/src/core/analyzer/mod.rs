@@ -0,0 +1,259 @@
+use anyhow::{Context as _, Result};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::core::frontmatter::{frontmatter_value, strip_frontmatter};
+use crate::core::scanner::Scanner;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::scanner::ScanOptions;
+    use crate::core::vfs::MemoryFs;
+
+    /// Counts exclamation marks and reports the `rating` property.
+    struct Excitement;
+
+    impl Analyzer for Excitement {
+        fn name(&self) -> &str {
+            "excitement"
+        }
+
+        fn analyze(&mut self, note: &NoteInput<'_>) -> Result<Metrics> {
+            let mut metrics = Metrics::new();
+            metrics.insert("bangs".to_owned(), note.body.matches('!').count() as f64);
+            if let Some(rating) = note.frontmatter["rating"].as_f64() {
+                metrics.insert("rating".to_owned(), rating);
+            }
+            Ok(metrics)
+        }
+    }
+
+    fn scanner() -> Result<Scanner> {
+        let vfs = MemoryFs::new()
+            .with_file("/vault/a.md", "---\nrating: 4\n---\nWow! Yes!")
+            .with_file("/vault/b.md", "Calm.")
+            .with_file("/vault/c.md", "---\nrating: 2\n---\nOh!");
+        Ok(ScanOptions::new(["/vault"]).vfs(vfs).build()?)
+    }
+
+    #[test]
+    fn test_should_give_analyzers_path_frontmatter_and_body() -> Result<()> {
+        // REQ-ANALYZE-001
+        let mut analyzers: Vec<Box<dyn Analyzer>> = vec![Box::new(Excitement)];
+
+        let report = analyze_notes(&scanner()?, &mut analyzers)?;
+
+        let first = &report.notes[0];
+        assert_eq!(first.path, PathBuf::from("/vault/a.md"));
+        assert_eq!(first.analyzer, "excitement");
+        assert_eq!(first.metrics.get("bangs"), Some(&2.0));
+        assert_eq!(first.metrics.get("rating"), Some(&4.0));
+        assert_eq!(report.notes.len(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_summarize_each_metric_over_reporting_notes() -> Result<()> {
+        // REQ-ANALYZE-002
+        let mut analyzers: Vec<Box<dyn Analyzer>> = vec![Box::new(Excitement)];
+
+        let report = analyze_notes(&scanner()?, &mut analyzers)?;
+
+        // Only the two notes with a rating count towards it
+        let summary: Vec<(&str, usize, f64, f64, f64, f64)> = report
+            .summaries
+            .iter()
+            .map(|s| (s.metric.as_str(), s.notes, s.total, s.mean(), s.min, s.max))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("bangs", 3, 3.0, 1.0, 0.0, 2.0),
+                ("rating", 2, 6.0, 3.0, 2.0, 4.0)
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_name_analyzer_and_note_when_analysis_fails() -> Result<()> {
+        // REQ-ANALYZE-003
+        struct Broken;
+        impl Analyzer for Broken {
+            fn name(&self) -> &str {
+                "broken"
+            }
+            fn analyze(&mut self, _note: &NoteInput<'_>) -> Result<Metrics> {
+                anyhow::bail!("no metrics today")
+            }
+        }
+        let mut analyzers: Vec<Box<dyn Analyzer>> = vec![Box::new(Broken)];
+
+        let error = analyze_notes(&scanner()?, &mut analyzers)
+            .err()
+            .map(|e| format!("{e:#}"));
+
+        assert_eq!(
+            error.as_deref(),
+            Some("Analyzer broken failed on /vault/a.md: no metrics today")
+        );
+        Ok(())
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// Named values an analyzer reports for one note.
+pub type Metrics = BTreeMap<String, f64>;
+
+/// A note as an [`Analyzer`] sees it. Serializes to the JSON plugins receive.
+#[derive(Debug, Clone, Serialize)]
+pub struct NoteInput<'a> {
+    pub path: &'a Path,
+    /// Every frontmatter property, `null` when the note has none or it is
+    /// not valid YAML
+    pub frontmatter: Value,
+    /// The note without its frontmatter
+    pub body: &'a str,
+}
+
+/// A per-note analysis contributing custom metrics to a report, so rules
+/// specific to one vault don't need changes to zrt itself.
+pub trait Analyzer {
+    /// Name shown next to the analyzer's metrics
+    fn name(&self) -> &str;
+
+    /// Metrics for one note. Metrics left out count as not applying to the
+    /// note rather than as zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the note cannot be analyzed.
+    fn analyze(&mut self, note: &NoteInput<'_>) -> Result<Metrics>;
+}
+
+/// The metrics one analyzer reported for one note.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NoteMetrics {
+    pub path: PathBuf,
+    pub analyzer: String,
+    pub metrics: Metrics,
+}
+
+/// One metric summarized over the notes that reported it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetricSummary {
+    pub analyzer: String,
+    pub metric: String,
+    /// Notes reporting the metric
+    pub notes: usize,
+    pub total: f64,
+    pub min: f64,
+    pub max: f64,
+}
+
+/// Everything the analyzers reported over a scan.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AnalysisReport {
+    /// Per-note metrics in walk order, analyzers in the order given
+    pub notes: Vec<NoteMetrics>,
+    /// One summary per metric, analyzers in the order given and metrics by name
+    pub summaries: Vec<MetricSummary>,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+impl MetricSummary {
+    /// Mean over the notes reporting the metric.
+    #[inline]
+    #[must_use]
+    pub fn mean(&self) -> f64 {
+        if self.notes == 0 {
+            0.0
+        } else {
+            self.total / self.notes as f64
+        }
+    }
+
+    fn add(&mut self, value: f64) {
+        if self.notes == 0 {
+            self.min = value;
+            self.max = value;
+        } else {
+            self.min = self.min.min(value);
+            self.max = self.max.max(value);
+        }
+        self.notes += 1;
+        self.total += value;
+    }
+}
+
+/// Run every analyzer over every text file `scanner` reaches.
+///
+/// # Errors
+///
+/// Returns an error if the scan fails or an analyzer fails on a note.
+pub fn analyze_notes(
+    scanner: &Scanner,
+    analyzers: &mut [Box<dyn Analyzer>],
+) -> Result<AnalysisReport> {
+    let mut report = AnalysisReport::default();
+    let mut summaries: Vec<BTreeMap<String, MetricSummary>> =
+        vec![BTreeMap::new(); analyzers.len()];
+
+    for path in scanner.files()? {
+        // Binary files are skipped, as in every other scan.
+        let Ok(content) = scanner.read_to_string(&path) else {
+            continue;
+        };
+        let frontmatter = frontmatter_value(&content)
+            .ok()
+            .and_then(|value| serde_json::to_value(value).ok())
+            .unwrap_or(Value::Null);
+        let note = NoteInput {
+            path: &path,
+            frontmatter,
+            body: strip_frontmatter(&content),
+        };
+
+        for (analyzer, summaries) in analyzers.iter_mut().zip(&mut summaries) {
+            let metrics = analyzer.analyze(&note).with_context(|| {
+                format!("Analyzer {} failed on {}", analyzer.name(), path.display())
+            })?;
+            for (metric, value) in &metrics {
+                summaries
+                    .entry(metric.clone())
+                    .or_insert_with(|| MetricSummary {
+                        analyzer: analyzer.name().to_owned(),
+                        metric: metric.clone(),
+                        notes: 0,
+                        total: 0.0,
+                        min: 0.0,
+                        max: 0.0,
+                    })
+                    .add(*value);
+            }
+            report.notes.push(NoteMetrics {
+                path: path.clone(),
+                analyzer: analyzer.name().to_owned(),
+                metrics,
+            });
+        }
+    }
+
+    report.summaries = summaries
+        .into_iter()
+        .flat_map(BTreeMap::into_values)
+        .collect();
+    Ok(report)
+}
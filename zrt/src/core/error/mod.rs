@@ -0,0 +1,154 @@
+use std::io;
+use std::path::PathBuf;
+use thiserror::Error;
+
+// ===== TESTS =====
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ignore::load_ignore_patterns;
+    use crate::core::patterns::Patterns;
+    use crate::core::scanner::{NoteRecord, ScanOptions};
+    use crate::core::vfs::MemoryFs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_should_report_missing_directory() {
+        // REQ-ERR-001
+        // Given a scan over a directory that does not exist
+        let options = ScanOptions::new(["/definitely/not/here"]);
+
+        // When scanning
+        let result = options.files();
+
+        // Then the error names the directory
+        assert!(matches!(
+            result,
+            Err(ZrtError::DirectoryNotFound(ref dir)) if dir.ends_with("not/here")
+        ));
+    }
+
+    #[test]
+    fn test_should_report_invalid_pattern() {
+        // REQ-ERR-002
+        // Given an ignore pattern with an unclosed brace
+        let mut patterns = Patterns::default();
+
+        // When adding it
+        let result = patterns.add_pattern("*.{md");
+
+        // Then the error carries the pattern
+        assert!(matches!(
+            result,
+            Err(ZrtError::InvalidPattern { ref pattern, .. }) if pattern == "*.{md"
+        ));
+    }
+
+    #[test]
+    fn test_should_report_bad_ignore_file_pattern() -> anyhow::Result<()> {
+        // REQ-ERR-002
+        // Given a .zrtignore with an invalid glob
+        let temp_dir = TempDir::new()?;
+        std::fs::write(temp_dir.path().join(".zrtignore"), "[unclosed\n")?;
+
+        // When loading the ignore patterns
+        let result = load_ignore_patterns(temp_dir.path());
+
+        // Then the error is an invalid pattern
+        assert!(matches!(result, Err(ZrtError::InvalidPattern { .. })));
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_report_unreadable_file() {
+        // REQ-ERR-003
+        // Given a file that does not exist
+        let path = std::path::Path::new("/definitely/not/here.md");
+
+        // When reading it as a note
+        let result = NoteRecord::try_read(path);
+
+        // Then the error names the file
+        assert!(matches!(
+            result,
+            Err(ZrtError::UnreadableFile { ref path, .. }) if path.ends_with("here.md")
+        ));
+    }
+
+    #[test]
+    fn test_should_convert_into_anyhow() {
+        // REQ-ERR-004
+        // Given a library error
+        let error = ZrtError::DirectoryNotFound(PathBuf::from("notes"));
+
+        // When crossing into the CLI boundary
+        let error: anyhow::Error = error.into();
+
+        // Then the message is preserved and the variant recoverable
+        assert_eq!(error.to_string(), "Directory not found: notes");
+        assert!(error.downcast_ref::<ZrtError>().is_some());
+    }
+
+    #[test]
+    fn test_should_collect_scan_errors() -> anyhow::Result<()> {
+        // REQ-ERR-005
+        let scanner = ScanOptions::new(["/vault"])
+            .vfs(MemoryFs::new().with_file("/vault/a.md", "one"))
+            .build()?;
+        let error = io::Error::from(io::ErrorKind::PermissionDenied);
+
+        scanner.record_error(std::path::Path::new("/vault/locked"), &error);
+
+        assert_eq!(
+            scanner.report().errors.get(&PathBuf::from("/vault/locked")),
+            Some(&error.to_string())
+        );
+        Ok(())
+    }
+}
+
+// ===== TYPE DEFINITIONS =====
+
+/// Errors returned by the scanning, ignore-file and pattern APIs.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum ZrtError {
+    /// A directory to scan does not exist.
+    #[error("Directory not found: {}", .0.display())]
+    DirectoryNotFound(PathBuf),
+
+    /// A `.zrtignore` file exists but could not be read.
+    #[error("Failed to read .zrtignore file: {}", path.display())]
+    IgnoreFile {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+
+    /// An ignore pattern is not valid glob syntax.
+    #[error("Invalid pattern: {pattern} ({message})")]
+    InvalidPattern { pattern: String, message: String },
+
+    /// A note could not be read as UTF-8 text.
+    #[error("Failed to read file: {}", path.display())]
+    UnreadableFile {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+
+    /// A directory entry could not be walked.
+    #[error("Failed to walk {}", path.display())]
+    Walk {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+
+    /// The current directory could not be determined for a relative path.
+    #[error("Failed to resolve the current directory")]
+    CurrentDir(#[source] io::Error),
+}
+
+pub type Result<T, E = ZrtError> = core::result::Result<T, E>;
@@ -1,10 +1,45 @@
 use crate::core::patterns::Patterns;
+use std::fmt;
+use std::path::Path;
+
+/// Lowercase `extensions` without a leading `.`, dropping empty ones.
+#[must_use]
+pub fn normalize_extensions<I, S>(extensions: I) -> Vec<String>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    extensions
+        .into_iter()
+        .map(|e| e.as_ref().trim().trim_start_matches('.').to_lowercase())
+        .filter(|e| !e.is_empty())
+        .collect()
+}
+
+/// Whether `path` has one of the normalized `extensions`, or `extensions` is empty.
+#[inline]
+#[must_use]
+pub fn has_extension(path: &Path, extensions: &[String]) -> bool {
+    extensions.is_empty()
+        || path.extension().and_then(|e| e.to_str()).is_some_and(|e| {
+            extensions
+                .iter()
+                .any(|allowed| allowed.eq_ignore_ascii_case(e))
+        })
+}
 
 /// Checks if a directory entry is hidden (starts with '.' except for temp directories)
 #[inline]
 #[must_use]
 pub fn is_hidden(entry: &walkdir::DirEntry) -> bool {
-    entry.file_name().to_str().is_some_and(|s| {
+    is_hidden_path(entry.path())
+}
+
+/// Checks if a path's final component is hidden (starts with '.' except for temp directories)
+#[inline]
+#[must_use]
+pub fn is_hidden_path(path: &Path) -> bool {
+    path.file_name().and_then(|n| n.to_str()).is_some_and(|s| {
         // Don't consider temp directories as hidden
         if s.starts_with(".tmp") {
             return false;
@@ -31,28 +66,75 @@ pub fn should_exclude(
     exclude_dirs: &[&str],
     ignore_patterns: Option<&Patterns>,
 ) -> bool {
-    if is_hidden(entry) {
-        return true;
+    let is_dir = entry.file_type().is_dir();
+    should_exclude_path(entry.path(), is_dir, exclude_dirs, ignore_patterns)
+}
+
+/// Same rules as [`should_exclude`] for a path whose type is already known,
+/// for walks that do not go through `walkdir`.
+pub fn should_exclude_path(
+    path: &Path,
+    is_dir: bool,
+    exclude_dirs: &[&str],
+    ignore_patterns: Option<&Patterns>,
+) -> bool {
+    exclusion_reason(path, is_dir, exclude_dirs, ignore_patterns).is_some()
+}
+
+/// Why [`should_exclude_path`] would skip a path, or `None` if it is kept.
+pub fn exclusion_reason(
+    path: &Path,
+    is_dir: bool,
+    exclude_dirs: &[&str],
+    ignore_patterns: Option<&Patterns>,
+) -> Option<ExclusionReason> {
+    if is_hidden_path(path) {
+        return Some(ExclusionReason::Hidden);
     }
 
-    if let Some(path_str) = entry.path().to_str() {
+    if let Some(path_str) = path.to_str() {
         for dir in exclude_dirs {
-            if entry.file_type().is_dir() && entry.file_name().to_str() == Some(*dir) {
-                return true;
+            if is_dir && path.file_name().and_then(|n| n.to_str()) == Some(*dir) {
+                return Some(ExclusionReason::ExcludedDir);
             }
             if path_str.contains(&format!("/{dir}/")) {
-                return true;
+                return Some(ExclusionReason::ExcludedDir);
             }
         }
     }
 
     if let Some(patterns) = ignore_patterns {
-        if patterns.matches(entry.path()) {
-            return true;
+        if patterns.matches(path) {
+            return Some(ExclusionReason::Ignored);
         }
     }
 
-    false
+    None
+}
+
+/// The rule that excluded a path from a scan.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExclusionReason {
+    /// The name starts with a dot
+    Hidden,
+    /// It is, or is inside, a directory passed with `--exclude`
+    ExcludedDir,
+    /// It matches a `.zrtignore` pattern
+    Ignored,
+    /// Its extension is not one passed with `--ext`
+    Extension,
+}
+
+impl fmt::Display for ExclusionReason {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Hidden => "hidden",
+            Self::ExcludedDir => "excluded directory",
+            Self::Ignored => "matches .zrtignore",
+            Self::Extension => "extension not in --ext",
+        })
+    }
 }
 
 #[cfg(test)]
@@ -136,4 +218,45 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_exclusion_reason() -> Result<()> {
+        let mut patterns = Patterns::default();
+        patterns.add_pattern("drafts/")?;
+
+        let reason = |path: &str, is_dir| {
+            exclusion_reason(Path::new(path), is_dir, &["archive"], Some(&patterns))
+        };
+
+        assert_eq!(
+            reason("/vault/.obsidian", true),
+            Some(ExclusionReason::Hidden)
+        );
+        assert_eq!(
+            reason("/vault/archive", true),
+            Some(ExclusionReason::ExcludedDir)
+        );
+        assert_eq!(
+            reason("/vault/archive/old.md", false),
+            Some(ExclusionReason::ExcludedDir)
+        );
+        assert_eq!(
+            reason("/vault/drafts/a.md", false),
+            Some(ExclusionReason::Ignored)
+        );
+        assert_eq!(reason("/vault/note.md", false), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_has_extension() {
+        let extensions = normalize_extensions([".MD", "txt", ""]);
+
+        assert_eq!(extensions, vec!["md", "txt"]);
+        assert!(has_extension(Path::new("/vault/note.md"), &extensions));
+        assert!(has_extension(Path::new("/vault/Note.TXT"), &extensions));
+        assert!(!has_extension(Path::new("/vault/photo.png"), &extensions));
+        assert!(!has_extension(Path::new("/vault/Makefile"), &extensions));
+        assert!(has_extension(Path::new("/vault/Makefile"), &[]));
+    }
 }
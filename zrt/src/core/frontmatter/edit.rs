@@ -0,0 +1,765 @@
+use anyhow::{Context as _, Result, bail};
+use serde_yaml_ng::Value;
+use std::fs;
+use std::ops::Range;
+use std::path::Path;
+
+use super::{Frontmatter, is_closing, parse_frontmatter, without_bom};
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn edited(
+        content: &str,
+        f: impl FnOnce(&mut FrontmatterEditor) -> Result<bool>,
+    ) -> Result<String> {
+        Ok(edit_content(content, f)?.0)
+    }
+
+    #[test]
+    fn test_should_leave_untouched_lines_as_written() -> Result<()> {
+        // REQ-EDIT-001
+        let content = "---\n# reviewed weekly\n'title': \"Systems\"  # quoted\nstatus: draft\nrating: 4\n---\nBody";
+
+        assert_eq!(
+            edited(content, |fm| {
+                fm.set("status", "evergreen");
+                Ok(true)
+            })?,
+            "---\n# reviewed weekly\n'title': \"Systems\"  # quoted\nstatus: evergreen\nrating: 4\n---\nBody"
+        );
+        assert_eq!(
+            edited(content, |fm| {
+                fm.set("created", "2024-01-31");
+                Ok(fm.remove("rating"))
+            })?,
+            "---\n# reviewed weekly\n'title': \"Systems\"  # quoted\nstatus: draft\ncreated: 2024-01-31\n---\nBody"
+        );
+        // Values that would read back as something else are quoted
+        assert_eq!(
+            edited("---\n---\n", |fm| {
+                fm.set("status", "true");
+                fm.set("note", "a: b");
+                Ok(true)
+            })?,
+            "---\nstatus: 'true'\nnote: 'a: b'\n---\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_keep_the_layout_of_tag_lists() -> Result<()> {
+        // REQ-EDIT-002
+        let flow = "---\ntags: [idea, to_refactor]\ntitle: T\n---\nBody";
+        assert_eq!(
+            edited(flow, |fm| fm.add_tag("#draft"))?,
+            "---\ntags: [idea, to_refactor, draft]\ntitle: T\n---\nBody"
+        );
+        assert_eq!(
+            edited(flow, |fm| fm.add_tag("a,b"))?,
+            "---\ntags: [idea, to_refactor, 'a,b']\ntitle: T\n---\nBody"
+        );
+        let block = "---\r\ntags:\r\n    - idea\r\n    - to_refactor\r\ntitle: T\r\n...\r\nBody";
+        assert_eq!(
+            edited(block, |fm| fm.remove_tag("to_refactor"))?,
+            "---\r\ntags:\r\n    - idea\r\ntitle: T\r\n...\r\nBody"
+        );
+        assert_eq!(
+            edited("---\ntitle: T\n---\n", |fm| fm.add_tag("idea"))?,
+            "---\ntitle: T\ntags:\n  - idea\n---\n"
+        );
+        // Nothing to do leaves the note byte for byte
+        assert_eq!(edited(flow, |fm| fm.add_tag("idea"))?, flow);
+        assert_eq!(edited(flow, |fm| fm.remove_tag("missing"))?, flow);
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_edit_only_the_tag_entries_that_change() -> Result<()> {
+        // REQ-EDIT-005
+        // Entries that are numbers or maps keep their type
+        let mixed = "---\ntags: [refactored, 2024, {nested: x}]\n---\n";
+        assert_eq!(
+            edited(mixed, |fm| fm.add_tag("archived"))?,
+            "---\ntags: [refactored, 2024, {nested: x}, archived]\n---\n"
+        );
+        assert_eq!(
+            edited(mixed, |fm| fm.remove_tag("refactored"))?,
+            "---\ntags: [2024, {nested: x}]\n---\n"
+        );
+        assert_eq!(
+            edited(mixed, |fm| fm.remove_tag("2024"))?,
+            "---\ntags: [refactored, {nested: x}]\n---\n"
+        );
+        assert_eq!(
+            edited("---\ntags: [a, 'b, c', a]\n---\n", |fm| fm.remove_tag("a"))?,
+            "---\ntags: ['b, c']\n---\n"
+        );
+        let block = "---\ntags:\n  - a\n  - nested: x\n    more: y\n  - 7\n---\n";
+        assert_eq!(
+            edited(block, |fm| {
+                fm.remove_tag("a")?;
+                fm.add_tag("b")
+            })?,
+            "---\ntags:\n  - nested: x\n    more: y\n  - 7\n  - b\n---\n"
+        );
+        // A single value becomes a flow list on its own line
+        assert_eq!(
+            edited("---\ntags: foo # one\n---\n", |fm| fm.add_tag("bar"))?,
+            "---\ntags: [foo, bar]\n---\n"
+        );
+        assert_eq!(
+            edited("---\ntags: \"foo bar\"\n---\n", |fm| fm.remove_tag("foo"))?,
+            "---\ntags: [bar]\n---\n"
+        );
+        assert_eq!(
+            edited("---\ntags: [idea, done]\n---\n", |fm| {
+                fm.set_tags(&["done".to_owned(), "new".to_owned()])?;
+                Ok(true)
+            })?,
+            "---\ntags: [done, new]\n---\n"
+        );
+        assert!(edited("---\ntags: {a: b}\n---\n", |fm| fm.add_tag("c")).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_add_frontmatter_to_notes_without_it() -> Result<()> {
+        // REQ-EDIT-003
+        assert_eq!(
+            edited("\u{feff}# Title\r\nBody", |fm| fm.add_tag("idea"))?,
+            "\u{feff}---\r\ntags:\r\n  - idea\r\n---\r\n# Title\r\nBody"
+        );
+        assert_eq!(edited("Body", |_| Ok(false))?, "Body");
+        assert!(edited("---\ntags: [unclosed\n---\n", |fm| fm.add_tag("a")).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_write_a_note_only_when_it_changed() -> Result<()> {
+        // REQ-EDIT-004
+        let dir = TempDir::new()?;
+        let path = dir.path().join("note.md");
+        fs::write(&path, "---\ntags: [idea]\n---\nBody")?;
+
+        assert!(edit(&path, |fm| fm.add_tag("draft"))?);
+        assert_eq!(
+            fs::read_to_string(&path)?,
+            "---\ntags: [idea, draft]\n---\nBody"
+        );
+        assert!(!edit(&path, |fm| fm.add_tag("draft"))?);
+        assert_eq!(fs::read_dir(dir.path())?.count(), 1);
+        Ok(())
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// A note's frontmatter being edited line by line: changing a property
+/// rewrites only that property's lines, so the order, comments and quoting
+/// of every other line stay exactly as written.
+#[derive(Debug, Clone)]
+pub struct FrontmatterEditor {
+    /// The lines between the delimiters, without line breaks
+    lines: Vec<String>,
+    changed: bool,
+}
+
+/// How a list property is laid out, kept when it is rewritten.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ListStyle {
+    /// `key: [a, b]`
+    Flow,
+    /// `key:` followed by `- a` lines with this indentation
+    Block(String),
+}
+
+/// The lines of a `tags` property, edited entry by entry so that entries
+/// which are not tags, and the way every other entry is written, stay as
+/// they were.
+#[derive(Debug, Clone)]
+struct TagLines {
+    lines: Vec<String>,
+    style: TagStyle,
+}
+
+/// How a `tags` property holds its entries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TagStyle {
+    /// `tags:` without a value
+    Empty,
+    /// `tags: [a, b]`, possibly over several lines
+    Flow,
+    /// `tags:` followed by `- a` lines with this indentation
+    Block(String),
+    /// `tags: a` or `tags: a, b`
+    Scalar,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+impl FrontmatterEditor {
+    /// The frontmatter as edited so far.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the frontmatter is not valid YAML.
+    #[inline]
+    pub fn frontmatter(&self) -> Result<Frontmatter> {
+        parse_frontmatter(&format!("---\n{}\n---\n", self.lines.join("\n")))
+    }
+
+    /// The note's tags as edited so far.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the frontmatter is not valid YAML.
+    #[inline]
+    pub fn tags(&self) -> Result<Vec<String>> {
+        Ok(self.frontmatter()?.tags.unwrap_or_default())
+    }
+
+    /// Set `key` to `value`, rewriting its lines in place, or adding it at
+    /// the end when the note does not have it yet.
+    #[inline]
+    pub fn set(&mut self, key: &str, value: impl Into<Value>) {
+        let value = value.into();
+        let span = self.span(key);
+        let (name, style) = match &span {
+            Some(span) => (
+                self.lines[span.start][..key_len(&self.lines[span.start])].to_owned(),
+                self.list_style(span),
+            ),
+            None => (render_scalar(&Value::String(key.to_owned())), None),
+        };
+        let style = style.unwrap_or_else(|| ListStyle::Block("  ".to_owned()));
+        let lines = render_property(&name, &value, &style);
+        match span {
+            Some(span) if self.lines[span.clone()] == lines[..] => {}
+            Some(span) => {
+                self.lines.splice(span, lines);
+                self.changed = true;
+            }
+            None => {
+                self.lines.extend(lines);
+                self.changed = true;
+            }
+        }
+    }
+
+    /// Remove `key` and its value, returning whether the note had it.
+    #[inline]
+    pub fn remove(&mut self, key: &str) -> bool {
+        let Some(span) = self.span(key) else {
+            return false;
+        };
+        self.lines.drain(span);
+        self.changed = true;
+        true
+    }
+
+    /// Add `tag`, without any leading `#`, unless the note already has it.
+    /// Returns whether it was added. The other entries stay as written, see
+    /// [`FrontmatterEditor::remove_tag`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the frontmatter is not valid YAML or `tags` is
+    /// not a list or a single tag.
+    #[inline]
+    pub fn add_tag(&mut self, tag: &str) -> Result<bool> {
+        let tag = tag.trim().trim_start_matches('#');
+        if tag.is_empty() || self.tags()?.iter().any(|t| t == tag) {
+            return Ok(false);
+        }
+        let Some(span) = self.span("tags") else {
+            self.set("tags", vec![Value::String(tag.to_owned())]);
+            return Ok(true);
+        };
+        let mut list = TagLines::read(&self.lines[span.clone()])?;
+        if list.style == TagStyle::Empty {
+            self.set("tags", vec![Value::String(tag.to_owned())]);
+            return Ok(true);
+        }
+        list.add(tag);
+        self.lines.splice(span, list.lines);
+        self.changed = true;
+        Ok(true)
+    }
+
+    /// Remove `tag`, with or without a leading `#`. Returns whether the note
+    /// had it. Only the entries naming it are removed: numbers stay
+    /// unquoted, and entries that are not tags, such as maps, stay too.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the frontmatter is not valid YAML or `tags` is
+    /// not a list or a single tag.
+    #[inline]
+    pub fn remove_tag(&mut self, tag: &str) -> Result<bool> {
+        let tag = tag.trim().trim_start_matches('#');
+        if !self.tags()?.iter().any(|t| t == tag) {
+            return Ok(false);
+        }
+        let Some(span) = self.span("tags") else {
+            return Ok(false);
+        };
+        let mut list = TagLines::read(&self.lines[span.clone()])?;
+        if !list.remove(tag) {
+            return Ok(false);
+        }
+        self.lines.splice(span, list.lines);
+        self.changed = true;
+        Ok(true)
+    }
+
+    /// Change the note's tags to `tags`: tags it no longer has are removed
+    /// and new ones added at the end, each with
+    /// [`FrontmatterEditor::remove_tag`] and [`FrontmatterEditor::add_tag`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the frontmatter is not valid YAML or `tags` is
+    /// not a list or a single tag.
+    #[inline]
+    pub fn set_tags(&mut self, tags: &[String]) -> Result<()> {
+        for tag in self.tags()? {
+            if !tags.contains(&tag) {
+                self.remove_tag(&tag)?;
+            }
+        }
+        for tag in tags {
+            self.add_tag(tag)?;
+        }
+        Ok(())
+    }
+
+    /// The lines of `key`'s property: its own line and every indented or
+    /// `- ` line after it.
+    fn span(&self, key: &str) -> Option<Range<usize>> {
+        let start = self
+            .lines
+            .iter()
+            .position(|line| key_of(line).is_some_and(|(name, _)| unquote(name) == key))?;
+        let mut end = start + 1;
+        for (i, line) in self.lines.iter().enumerate().skip(start + 1) {
+            if line.starts_with([' ', '\t', '-']) {
+                end = i + 1;
+            } else if !line.trim().is_empty() {
+                break;
+            }
+        }
+        Some(start..end)
+    }
+
+    /// The layout of the list at `span`, if it holds one.
+    fn list_style(&self, span: &Range<usize>) -> Option<ListStyle> {
+        let (_, rest) = key_of(&self.lines[span.start])?;
+        if rest.trim_start().starts_with('[') {
+            return Some(ListStyle::Flow);
+        }
+        let item = self.lines[span.clone()]
+            .iter()
+            .skip(1)
+            .find(|line| line.trim_start().starts_with('-'))?;
+        let indent = &item[..item.len() - item.trim_start().len()];
+        Some(ListStyle::Block(indent.to_owned()))
+    }
+}
+
+impl TagLines {
+    /// Find how the `tags` property on `lines` is written.
+    fn read(lines: &[String]) -> Result<Self> {
+        let lines = lines.to_vec();
+        let (_, rest) = key_of(&lines[0]).unwrap_or_default();
+        let value = rest.trim_start();
+        let style = if value.starts_with('[') {
+            TagStyle::Flow
+        } else if !(value.is_empty() || value.starts_with('#')) {
+            if value.starts_with(['{', '|', '>']) {
+                bail!("tags is not a list");
+            }
+            TagStyle::Scalar
+        } else if lines.len() == 1 {
+            TagStyle::Empty
+        } else if let Some(item) = lines[1..].iter().find(|l| !l.trim().is_empty()) {
+            let trimmed = item.trim_start();
+            if trimmed != "-" && !trimmed.starts_with("- ") {
+                bail!("tags is not a list");
+            }
+            TagStyle::Block(item[..item.len() - trimmed.len()].to_owned())
+        } else {
+            TagStyle::Empty
+        };
+        Ok(Self { lines, style })
+    }
+
+    /// Append `tag` after the last entry.
+    fn add(&mut self, tag: &str) {
+        let item = Value::String(tag.to_owned());
+        match &self.style {
+            TagStyle::Empty => {}
+            TagStyle::Block(indent) => {
+                let line = format!("{indent}- {}", render_scalar(&item));
+                self.lines.push(line);
+            }
+            TagStyle::Flow => {
+                let mut text = self.lines.join("\n");
+                let (items, close) = flow_items(&text);
+                let rendered = render_flow_item(&item);
+                match items.last() {
+                    Some(last) => text.insert_str(last.end, &format!(", {rendered}")),
+                    None => text.insert_str(close, &rendered),
+                }
+                self.set_text(&text);
+            }
+            TagStyle::Scalar => {
+                let mut items = self.scalar_items();
+                items.push(render_flow_item(&item));
+                self.write_flow(&items);
+            }
+        }
+    }
+
+    /// Remove every entry naming `tag`, returning whether there was one.
+    fn remove(&mut self, tag: &str) -> bool {
+        match &self.style {
+            TagStyle::Empty => false,
+            TagStyle::Block(indent) => {
+                let starts: Vec<usize> = (1..self.lines.len())
+                    .filter(|&i| {
+                        self.lines[i]
+                            .strip_prefix(indent.as_str())
+                            .is_some_and(|item| item == "-" || item.starts_with("- "))
+                    })
+                    .collect();
+                let mut removed = false;
+                for (n, &start) in starts.iter().enumerate().rev() {
+                    let entry = self.lines[start][indent.len() + 1..].trim();
+                    if names_tag(entry, tag) {
+                        let end = starts.get(n + 1).copied().unwrap_or(self.lines.len());
+                        self.lines.drain(start..end);
+                        removed = true;
+                    }
+                }
+                removed
+            }
+            TagStyle::Flow => {
+                let mut text = self.lines.join("\n");
+                let (items, _) = flow_items(&text);
+                let mut removed = false;
+                let mut kept: Option<&Range<usize>> = None;
+                for (i, item) in items.iter().enumerate().rev() {
+                    if !names_tag(&text[item.clone()], tag) {
+                        kept = Some(item);
+                        continue;
+                    }
+                    // Take the separator after the entry, or before the last one
+                    let cut = match (kept, i.checked_sub(1)) {
+                        (Some(next), _) => item.start..next.start,
+                        (None, Some(prev)) => items[prev].end..item.end,
+                        (None, None) => item.clone(),
+                    };
+                    text.replace_range(cut, "");
+                    removed = true;
+                }
+                if removed {
+                    self.set_text(&text);
+                }
+                removed
+            }
+            TagStyle::Scalar => {
+                let items = self.scalar_items();
+                let kept: Vec<String> = items
+                    .iter()
+                    .filter(|item| !names_tag(item, tag))
+                    .cloned()
+                    .collect();
+                if kept.len() == items.len() {
+                    return false;
+                }
+                self.write_flow(&kept);
+                true
+            }
+        }
+    }
+
+    /// The tags of a single-value `tags`, as flow list entries: a plain value
+    /// split like [`Frontmatter`] reads it, each part as written.
+    fn scalar_items(&self) -> Vec<String> {
+        let (_, rest) = key_of(&self.lines[0]).unwrap_or_default();
+        let value = strip_comment(rest.trim());
+        let parts: Vec<String> = match serde_yaml_ng::from_str::<Value>(value) {
+            Ok(Value::String(text)) if value.starts_with(['\'', '"']) => text
+                .split(|c: char| c == ',' || c.is_whitespace())
+                .filter(|part| !part.is_empty())
+                .map(|part| render_flow_item(&Value::String(part.to_owned())))
+                .collect(),
+            _ => value
+                .split(|c: char| c == ',' || c.is_whitespace())
+                .filter(|part| !part.is_empty())
+                .map(str::to_owned)
+                .collect(),
+        };
+        parts
+    }
+
+    /// Write the property as a flow list of `items` on its first line.
+    fn write_flow(&mut self, items: &[String]) {
+        let (name, _) = key_of(&self.lines[0]).unwrap_or_default();
+        self.lines = vec![format!("{name}: [{}]", items.join(", "))];
+        self.style = TagStyle::Flow;
+    }
+
+    fn set_text(&mut self, text: &str) {
+        self.lines = text.split('\n').map(str::to_owned).collect();
+    }
+}
+
+/// Run `f` on the frontmatter of the note at `path`, writing the note back
+/// only if `f` changed it. The new content replaces the note atomically.
+///
+/// # Errors
+///
+/// Returns an error if the note cannot be read or written, or if `f` fails.
+#[inline]
+pub fn edit<R>(path: &Path, f: impl FnOnce(&mut FrontmatterEditor) -> Result<R>) -> Result<R> {
+    let content =
+        fs::read_to_string(path).with_context(|| format!("Cannot read {}", path.display()))?;
+    let (edited, result) = edit_content(&content, f)?;
+    if edited != content {
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        let temp = path.with_file_name(format!(".{name}.zrt-edit"));
+        fs::write(&temp, edited)
+            .and_then(|()| fs::rename(&temp, path))
+            .with_context(|| format!("Cannot write {}", path.display()))?;
+    }
+    Ok(result)
+}
+
+/// Run `f` on the frontmatter of a note's `content`, returning the content
+/// with `f`'s changes and what `f` returned. Lines `f` did not change keep
+/// their exact text, and a note `f` did not change is returned as it was.
+/// Notes without frontmatter get a block, after any byte order mark, once
+/// something is set. New lines use the note's line breaks.
+///
+/// # Errors
+///
+/// Returns an error if `f` fails.
+#[inline]
+pub fn edit_content<R>(
+    content: &str,
+    f: impl FnOnce(&mut FrontmatterEditor) -> Result<R>,
+) -> Result<(String, R)> {
+    let unmarked = without_bom(content);
+    let bom = &content[..content.len() - unmarked.len()];
+    let newline = if unmarked.contains("\r\n") {
+        "\r\n"
+    } else {
+        "\n"
+    };
+    let lines: Vec<&str> = unmarked.split_inclusive('\n').collect();
+    let is_open = lines.first().is_some_and(|l| l.trim_end() == "---");
+    let close = lines
+        .iter()
+        .skip(1)
+        .position(|line| is_closing(line.trim_end_matches('\n')))
+        .map(|i| i + 1)
+        .filter(|_| is_open);
+
+    let mut editor = FrontmatterEditor {
+        lines: close.map_or_else(Vec::new, |close| {
+            lines[1..close]
+                .iter()
+                .map(|line| line.trim_end_matches(['\r', '\n']).to_owned())
+                .collect()
+        }),
+        changed: false,
+    };
+    let result = f(&mut editor)?;
+    if !editor.changed {
+        return Ok((content.to_owned(), result));
+    }
+
+    let mut edited = bom.to_owned();
+    let body = match close {
+        Some(close) => {
+            edited.push_str(lines[0]);
+            lines[close..].concat()
+        }
+        None => {
+            edited.push_str("---");
+            edited.push_str(newline);
+            unmarked.to_owned()
+        }
+    };
+    for line in &editor.lines {
+        edited.push_str(line);
+        edited.push_str(newline);
+    }
+    if close.is_none() {
+        edited.push_str("---");
+        edited.push_str(newline);
+    }
+    edited.push_str(&body);
+    Ok((edited, result))
+}
+
+/// A top-level property line split into its key as written and the text
+/// after the colon.
+fn key_of(line: &str) -> Option<(&str, &str)> {
+    if line.starts_with([' ', '\t', '#', '-']) || line.trim().is_empty() {
+        return None;
+    }
+    let len = key_len(line);
+    let rest = line[len..].strip_prefix(':')?;
+    (rest.is_empty() || rest.starts_with([' ', '\t'])).then(|| (&line[..len], rest))
+}
+
+/// Length of the key at the start of `line`, quotes included.
+fn key_len(line: &str) -> usize {
+    match line.chars().next() {
+        Some(quote @ ('\'' | '"')) => line[1..].find(quote).map_or(line.len(), |end| end + 2),
+        _ => line.find(':').unwrap_or(line.len()),
+    }
+}
+
+fn unquote(key: &str) -> &str {
+    ['\'', '"']
+        .into_iter()
+        .find_map(|q| key.strip_prefix(q)?.strip_suffix(q))
+        .unwrap_or(key)
+}
+
+/// The lines of a `name` property holding `value`, lists laid out in `style`.
+fn render_property(name: &str, value: &Value, style: &ListStyle) -> Vec<String> {
+    match value {
+        Value::Sequence(items) if items.is_empty() => vec![format!("{name}: []")],
+        Value::Sequence(items) if items.iter().all(|i| !i.is_sequence() && !i.is_mapping()) => {
+            match style {
+                ListStyle::Flow => {
+                    let items: Vec<String> = items.iter().map(render_flow_item).collect();
+                    vec![format!("{name}: [{}]", items.join(", "))]
+                }
+                ListStyle::Block(indent) => std::iter::once(format!("{name}:"))
+                    .chain(
+                        items
+                            .iter()
+                            .map(|i| format!("{indent}- {}", render_scalar(i))),
+                    )
+                    .collect(),
+            }
+        }
+        Value::Sequence(_) | Value::Mapping(_) | Value::Tagged(_) => {
+            let yaml = serde_yaml_ng::to_string(value).unwrap_or_default();
+            std::iter::once(format!("{name}:"))
+                .chain(yaml.lines().map(|line| format!("  {line}")))
+                .collect()
+        }
+        scalar => vec![format!("{name}: {}", render_scalar(scalar))],
+    }
+}
+
+/// A scalar inside `[...]`, where commas and brackets need quotes too.
+fn render_flow_item(value: &Value) -> String {
+    let rendered = render_scalar(value);
+    if rendered.contains([',', '[', ']', '{', '}']) && !rendered.starts_with(['\'', '"']) {
+        return format!("'{}'", rendered.replace('\'', "''"));
+    }
+    rendered
+}
+
+/// A scalar as YAML, left unquoted whenever it reads back as the same value.
+fn render_scalar(value: &Value) -> String {
+    if let Value::String(text) = value {
+        let plain = !text.is_empty()
+            && text.trim() == text
+            && !text.contains(": ")
+            && !text.contains(" #")
+            && !text.starts_with([
+                '[', '{', '#', '&', '*', '!', '|', '>', '\'', '"', '%', '@', '`',
+            ])
+            && serde_yaml_ng::from_str::<Value>(text).is_ok_and(|parsed| parsed == *value);
+        if plain {
+            return text.clone();
+        }
+    }
+    serde_yaml_ng::to_string(value)
+        .unwrap_or_default()
+        .trim_end()
+        .to_owned()
+}
+
+/// The byte ranges of the top-level entries of the flow list in `text`,
+/// trimmed, and where its closing `]` is. Entries may be quoted or be
+/// nested lists and maps.
+fn flow_items(text: &str) -> (Vec<Range<usize>>, usize) {
+    let open = text.find('[').map_or(text.len(), |open| open + 1);
+    let mut items = Vec::new();
+    let mut item: Option<Range<usize>> = None;
+    let mut depth = 0_usize;
+    let mut chars = text[open..].char_indices().map(|(i, c)| (open + i, c));
+    while let Some((i, c)) = chars.next() {
+        let mut end = i + c.len_utf8();
+        match c {
+            '\'' | '"' => {
+                let mut escaped = false;
+                for (j, next) in chars.by_ref() {
+                    end = j + next.len_utf8();
+                    if c == '"' && next == '\\' && !escaped {
+                        escaped = true;
+                        continue;
+                    }
+                    if next == c && !escaped {
+                        break;
+                    }
+                    escaped = false;
+                }
+            }
+            '[' | '{' => depth += 1,
+            ']' if depth == 0 => {
+                items.extend(item);
+                return (items, i);
+            }
+            ']' | '}' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                items.extend(item.take());
+                continue;
+            }
+            c if c.is_whitespace() => continue,
+            _ => {}
+        }
+        match &mut item {
+            Some(item) => item.end = end,
+            None => item = Some(i..end),
+        }
+    }
+    items.extend(item);
+    (items, text.len())
+}
+
+/// Whether the list entry written as `entry` is `tag`, read like
+/// [`Frontmatter`] reads tags.
+fn names_tag(entry: &str, tag: &str) -> bool {
+    let text = match serde_yaml_ng::from_str::<Value>(strip_comment(entry)) {
+        Ok(Value::String(text)) => text,
+        Ok(Value::Number(number)) => number.to_string(),
+        Ok(Value::Bool(flag)) => flag.to_string(),
+        _ => return false,
+    };
+    text.trim().trim_start_matches('#') == tag
+}
+
+/// `value` without a trailing ` # comment`.
+fn strip_comment(value: &str) -> &str {
+    value.find(" #").map_or(value, |at| value[..at].trim_end())
+}
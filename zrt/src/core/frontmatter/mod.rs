@@ -1,5 +1,13 @@
+mod edit;
+
 use anyhow::{Result, anyhow};
-use serde::Deserialize;
+use chrono::{NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_yaml_ng::{Mapping, Value};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+pub use edit::{FrontmatterEditor, edit, edit_content};
 
 // ============================================
 // TESTS
@@ -8,6 +16,17 @@ use serde::Deserialize;
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_should_expose_every_property_as_a_value() -> Result<()> {
+        // REQ-PARSE-004
+        let value = frontmatter_value("---\nstatus: draft\nrating: 4\n---\nBody")?;
+
+        assert_eq!(value["status"].as_str(), Some("draft"));
+        assert_eq!(value["rating"].as_u64(), Some(4));
+        assert!(frontmatter_value("No frontmatter")?.is_null());
+        Ok(())
+    }
+
     // Parse frontmatter tests
     #[test]
     fn test_parse_frontmatter_empty_file() {
@@ -36,6 +55,145 @@ Content here";
     }
 
     // Frontmatter model tests
+    #[test]
+    fn test_should_parse_frontmatter_after_bom_or_with_crlf() -> Result<()> {
+        // REQ-PARSE-005
+        let tags = |content: &str| -> Result<_> { Ok(parse_frontmatter(content)?.tags) };
+        let expected = Some(vec!["refactor".to_owned()]);
+
+        assert_eq!(tags("\u{feff}---\ntags: [refactor]\n---\nBody")?, expected);
+        assert_eq!(tags("---\r\ntags: [refactor]\r\n---\r\nBody")?, expected);
+        assert_eq!(
+            tags("\u{feff}---\r\ntags: [refactor]\r\n---\r\nBody")?,
+            expected
+        );
+        assert_eq!(
+            frontmatter_value("\u{feff}---\r\nstatus: draft\r\n---\r\n")?["status"],
+            "draft"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_end_frontmatter_at_document_end_marker() -> Result<()> {
+        // REQ-PARSE-006
+        let content = "---\ntags: [refactor]\n...\nBody\n---\nmore";
+        assert_eq!(
+            parse_frontmatter(content)?.tags,
+            Some(vec!["refactor".to_owned()])
+        );
+
+        // A second YAML document stays out of the frontmatter
+        let content = "---\ntags: [a]\n...\n---\nstatus: draft\n...\nBody";
+        let frontmatter = parse_frontmatter(content)?;
+        assert_eq!(frontmatter.tags, Some(vec!["a".to_owned()]));
+        assert_eq!(frontmatter.property("status"), None);
+        assert_eq!(
+            frontmatter_value(content)?["status"],
+            serde_yaml_ng::Value::Null
+        );
+        Ok(())
+    }
+
+    fn header(content: &str, format: MetadataFormat) -> Result<Option<(Frontmatter, &str)>> {
+        let Some((fields, end)) = metadata_header(content, &[format]) else {
+            return Ok(None);
+        };
+        let body = &without_bom(content)[end..];
+        Ok(Some((
+            serde_yaml_ng::from_value(Value::Mapping(fields))?,
+            body,
+        )))
+    }
+
+    #[test]
+    fn test_should_read_pandoc_title_blocks() -> Result<()> {
+        // REQ-PARSE-007
+        let content = "% Systems Thinking\n  and Feedback\n% Ada; Grace\n% 2024-01-31\n\nBody";
+        let (frontmatter, body) = header(content, MetadataFormat::Pandoc)?.unwrap_or_default();
+
+        assert_eq!(
+            frontmatter.title().as_deref(),
+            Some("Systems Thinking and Feedback")
+        );
+        assert_eq!(
+            frontmatter.property_list("author"),
+            Some(vec!["Ada".to_owned(), "Grace".to_owned()])
+        );
+        assert_eq!(frontmatter.created(), NaiveDate::from_ymd_opt(2024, 1, 31));
+        assert_eq!(body, "\n\nBody");
+
+        // Empty fields are skipped, and only `%` lines start a block
+        let (frontmatter, _) =
+            header("%\n% Ada\nBody", MetadataFormat::Pandoc)?.unwrap_or_default();
+        assert_eq!(frontmatter.title(), None);
+        assert_eq!(frontmatter.property("author").as_deref(), Some("Ada"));
+        assert!(header("%% comment %%\nBody", MetadataFormat::Pandoc)?.is_none());
+        assert!(header("Title: x\n", MetadataFormat::Pandoc)?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_read_multimarkdown_metadata() -> Result<()> {
+        // REQ-PARSE-008
+        let content = "Title: Systems Thinking\r\nTags: to_refactor, idea\r\nBase Header Level: 2\r\nAliases: ST\r\n    Systems\r\n\r\nBody";
+        let (frontmatter, body) =
+            header(content, MetadataFormat::MultiMarkdown)?.unwrap_or_default();
+
+        assert_eq!(frontmatter.title().as_deref(), Some("Systems Thinking"));
+        assert_eq!(
+            frontmatter.tags,
+            Some(vec!["to_refactor".to_owned(), "idea".to_owned()])
+        );
+        assert_eq!(
+            frontmatter.property("baseheaderlevel").as_deref(),
+            Some("2")
+        );
+        assert_eq!(frontmatter.aliases, vec!["ST Systems"]);
+        assert_eq!(body, "\r\n\r\nBody");
+
+        // Prose and links on the first lines are not metadata
+        for content in [
+            "Just a body",
+            "https://example.com\n",
+            "Note: x\nplain text",
+            "\nTitle: x",
+        ] {
+            assert!(
+                header(content, MetadataFormat::MultiMarkdown)?.is_none(),
+                "{content}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_ignore_other_headers_unless_enabled() -> Result<()> {
+        // REQ-PARSE-009
+        assert!(metadata_header("% Title\nBody", &[]).is_none());
+        assert_eq!(
+            parse_frontmatter("---\ntags: [a]\n---\n")?.tags,
+            Some(vec!["a".to_owned()])
+        );
+
+        // Formats are passed explicitly, and YAML frontmatter always wins
+        let content = "Tags: a, b\n\nBody";
+        let formats = [MetadataFormat::MultiMarkdown];
+        assert_eq!(parse_frontmatter(content)?.tags, None);
+        assert_eq!(strip_frontmatter(content), content);
+        assert_eq!(
+            parse_frontmatter_in(content, &formats)?.tags,
+            Some(vec!["a".to_owned(), "b".to_owned()])
+        );
+        assert_eq!(strip_frontmatter_in(content, &formats), "\n\nBody");
+        assert_eq!(countable_text_in(content, false, &formats), "\nBody");
+        assert_eq!(
+            frontmatter_value_in("---\ntags: [a]\n---\n", &formats)?["tags"][0],
+            Value::from("a")
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_frontmatter_deserialize() {
         let yaml = "
@@ -54,6 +212,212 @@ Content here";
         assert!(frontmatter.tags.is_none());
     }
 
+    #[test]
+    fn test_should_read_title_field() {
+        // REQ-MODEL-003
+        let content = "---\ntitle: My Note\ntags: [a]\n---\nBody";
+        let result = parse_frontmatter(content).unwrap();
+        assert_eq!(result.title(), Some("My Note".to_owned()));
+    }
+
+    #[test]
+    fn test_should_stringify_scalar_title_without_losing_tags() {
+        // REQ-MODEL-004
+        let content = "---\ntitle: 2024\ntags: [a]\n---\nBody";
+        let result = parse_frontmatter(content).unwrap();
+        assert_eq!(result.title(), Some("2024".to_owned()));
+        assert_eq!(result.tags.unwrap(), vec!["a"]);
+    }
+
+    #[test]
+    fn test_should_strip_hash_prefix_from_tags() {
+        // REQ-MODEL-005
+        let content = "---\ntags:\n  - \"#to_refactor\"\n  - idea\n---\nBody";
+        let result = parse_frontmatter(content).unwrap();
+        assert_eq!(result.tags.unwrap(), vec!["to_refactor", "idea"]);
+    }
+
+    #[test]
+    fn test_should_split_single_string_tags() {
+        // REQ-MODEL-005
+        let content = "---\ntags: \"#draft #to_refactor, idea\"\n---\nBody";
+        let result = parse_frontmatter(content).unwrap();
+        assert_eq!(result.tags.unwrap(), vec!["draft", "to_refactor", "idea"]);
+    }
+
+    #[test]
+    fn test_should_keep_readable_tags_beside_odd_values() -> Result<()> {
+        // REQ-MODEL-010
+        let content =
+            "---\ntags: [2024, true, 1.5, idea, nested: x, [a, b], ~]\ntitle: T\n---\nBody";
+        let frontmatter = parse_frontmatter(content)?;
+
+        assert_eq!(
+            frontmatter.tags,
+            Some(vec![
+                "2024".to_owned(),
+                "true".to_owned(),
+                "1.5".to_owned(),
+                "idea".to_owned()
+            ])
+        );
+        assert_eq!(frontmatter.skipped_tags(), ["nested: x", "- a - b"]);
+        assert_eq!(frontmatter.title().as_deref(), Some("T"));
+
+        assert_eq!(
+            parse_frontmatter("---\ntags: 2024\n---\n")?.tags,
+            Some(vec!["2024".to_owned()])
+        );
+        let mapping = parse_frontmatter("---\ntags:\n  a: b\n---\n")?;
+        assert_eq!(mapping.tags, None);
+        assert_eq!(mapping.skipped_tags(), ["a: b"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_treat_empty_tags_property_as_missing() {
+        // REQ-MODEL-005
+        let content = "---\ntags:\naliases:\ncssclass: wide\n---\nBody";
+        let result = parse_frontmatter(content).unwrap();
+        assert!(result.tags.is_none());
+        assert!(result.aliases.is_empty());
+    }
+
+    #[test]
+    fn test_should_read_aliases() {
+        // REQ-MODEL-006
+        let content = "---\naliases:\n  - Systems Thinking\n  - ST\ncssclasses: [wide]\n---\nBody";
+        let result = parse_frontmatter(content).unwrap();
+        assert_eq!(result.aliases, vec!["Systems Thinking", "ST"]);
+    }
+
+    #[test]
+    fn test_should_read_single_legacy_alias() {
+        // REQ-MODEL-006
+        let content = "---\nalias: Systems Thinking\n---\nBody";
+        let result = parse_frontmatter(content).unwrap();
+        assert_eq!(result.aliases, vec!["Systems Thinking"]);
+    }
+
+    #[test]
+    fn test_should_read_due_date_or_deadline() {
+        // REQ-MODEL-007
+        let due = |content: &str| parse_frontmatter(content).unwrap().due();
+
+        assert_eq!(
+            due("---\ndue: 2024-05-01\n---\n"),
+            NaiveDate::from_ymd_opt(2024, 5, 1)
+        );
+        assert_eq!(
+            due("---\ndeadline: \"2024-05-02T09:30\"\n---\n"),
+            NaiveDate::from_ymd_opt(2024, 5, 2)
+        );
+        assert_eq!(
+            due("---\ndue: 2024-05-01\ndeadline: 2024-06-01\n---\n"),
+            NaiveDate::from_ymd_opt(2024, 5, 1)
+        );
+        assert_eq!(due("---\ndue: someday\ntags: [a]\n---\n"), None);
+        assert_eq!(due("---\ntags: [a]\n---\n"), None);
+    }
+
+    #[test]
+    fn test_should_read_created_or_date() {
+        // REQ-MODEL-009
+        let created = |content: &str| parse_frontmatter(content).unwrap().created();
+
+        assert_eq!(
+            created("---\ncreated: 2023-02-03 10:15\n---\n"),
+            NaiveDate::from_ymd_opt(2023, 2, 3)
+        );
+        assert_eq!(
+            created("---\ndate: 2023-02-04\n---\n"),
+            NaiveDate::from_ymd_opt(2023, 2, 4)
+        );
+        assert_eq!(created("---\ncreated: yesterday\n---\n"), None);
+    }
+
+    #[test]
+    fn test_should_read_dates_from_zettel_ids() {
+        // REQ-CREATED-001
+        let date = |name: &str| filename_date(Path::new(name));
+        let jan31 = NaiveDate::from_ymd_opt(2024, 1, 31);
+
+        assert_eq!(date("notes/202401311542-systems-thinking.md"), jan31);
+        assert_eq!(date("20240131154210 Systems.md"), jan31);
+        assert_eq!(date("20240131.md"), jan31);
+        assert_eq!(date("2024-01-31 meeting.md"), jan31);
+        assert_eq!(date("202413311542-bad-month.md"), None);
+        assert_eq!(date("1234-idea.md"), None);
+        assert_eq!(date("systems-thinking.md"), None);
+    }
+
+    #[test]
+    fn test_should_find_zettel_ids_in_file_names() {
+        // REQ-CREATED-003
+        assert_eq!(zettel_id("202401311542-systems"), Some("202401311542"));
+        assert_eq!(zettel_id("20240131154210 Systems"), Some("20240131154210"));
+        assert_eq!(zettel_id("20240131 daily"), None);
+        assert_eq!(zettel_id("systems"), None);
+    }
+
+    #[test]
+    fn test_should_prefer_frontmatter_over_zettel_id() {
+        // REQ-CREATED-002
+        let path = Path::new("202401311542-systems-thinking.md");
+
+        assert_eq!(
+            note_created("---\ncreated: 2023-12-01\n---\nBody", path),
+            NaiveDate::from_ymd_opt(2023, 12, 1)
+        );
+        assert_eq!(
+            note_created("Body", path),
+            NaiveDate::from_ymd_opt(2024, 1, 31)
+        );
+        assert_eq!(note_created("Body", Path::new("idea.md")), None);
+    }
+
+    #[test]
+    fn test_should_expose_other_properties_as_text() {
+        // REQ-MODEL-008
+        let content = "---\ntags: [a]\nstatus: draft\npriority: 2\nrelated: [x]\n---\nBody";
+        let result = parse_frontmatter(content).unwrap();
+
+        assert_eq!(result.property("status").as_deref(), Some("draft"));
+        assert_eq!(result.property("priority").as_deref(), Some("2"));
+        assert_eq!(result.priority().as_deref(), Some("2"));
+        assert_eq!(result.property("related"), None);
+        assert_eq!(result.property("missing"), None);
+        assert_eq!(result.property_list("related"), Some(vec!["x".to_owned()]));
+        assert_eq!(
+            result.property_list("status"),
+            Some(vec!["draft".to_owned()])
+        );
+        assert_eq!(result.property_list("missing"), None);
+        assert_eq!(result.tags, Some(vec!["a".to_owned()]));
+    }
+
+    // Title tests
+    #[test]
+    fn test_should_prefer_frontmatter_title() {
+        // REQ-TITLE-001
+        let content = "---\ntitle: Systems Thinking\n---\n# Heading\nBody";
+        assert_eq!(note_title(content).as_deref(), Some("Systems Thinking"));
+    }
+
+    #[test]
+    fn test_should_fall_back_to_first_heading() {
+        // REQ-TITLE-001
+        let content = "---\ntags: [a]\n---\nIntro\n```\n# not a heading\n```\n## Sub\n#  Feedback Loops #\n# Later";
+        assert_eq!(note_title(content).as_deref(), Some("Feedback Loops"));
+    }
+
+    #[test]
+    fn test_should_have_no_title_without_title_or_heading() {
+        // REQ-TITLE-002
+        assert_eq!(note_title("---\ntitle: \"  \"\n---\n#hashtag\nBody"), None);
+        assert_eq!(note_title(""), None);
+    }
+
     // Strip frontmatter tests
     #[test]
     fn test_should_return_body_when_frontmatter_present() {
@@ -82,24 +446,342 @@ Content here";
         let content = "---\ntags: [refactor]\n---";
         assert_eq!(strip_frontmatter(content), "");
     }
+
+    #[test]
+    fn test_should_strip_frontmatter_after_bom_or_with_crlf() {
+        // REQ-STRIP-006
+        assert_eq!(
+            strip_frontmatter("\u{feff}---\ntags: [refactor]\n---\nBody"),
+            "\nBody"
+        );
+        assert_eq!(
+            strip_frontmatter("\u{feff}---\r\ntags: [refactor]\r\n---\r\nBody"),
+            "\r\nBody"
+        );
+        assert_eq!(
+            countable_text("\u{feff}---\r\ntags: [refactor]\r\n---\r\nOne two", false),
+            "One two"
+        );
+        assert_eq!(strip_frontmatter("\u{feff}Body"), "\u{feff}Body");
+    }
+
+    #[test]
+    fn test_should_strip_frontmatter_up_to_document_end_marker() {
+        // REQ-STRIP-007
+        assert_eq!(
+            strip_frontmatter("---\ntags: [a]\n...\n---\nstatus: draft\n...\nBody"),
+            "\n---\nstatus: draft\n...\nBody"
+        );
+        assert_eq!(
+            strip_frontmatter("---\r\ntitle: a---b\r\n...\r\nBody"),
+            "\r\nBody"
+        );
+        assert_eq!(strip_frontmatter("---\ntags: [a]\n..."), "");
+    }
+
+    #[test]
+    fn test_should_count_body_unless_frontmatter_included() {
+        // REQ-STRIP-005
+        let content = "---\ntags: [refactor]\n---\nOne two\nthree";
+
+        assert_eq!(countable_text(content, false), "One two\nthree");
+        assert_eq!(word_count(content, false), 3);
+        assert_eq!(word_count(content, true), 7);
+        assert_eq!(countable_text("Just body", false), "Just body");
+    }
 }
 
 // ============================================
 // TYPE DEFINITIONS
 // ============================================
 
-#[derive(Deserialize, Debug, Default)]
+#[derive(Deserialize, Debug, Clone, Default)]
 pub struct Frontmatter {
+    /// Tags without any leading `#`, as Obsidian shows them
+    #[serde(default, deserialize_with = "deserialize_tags")]
     pub tags: Option<Vec<String>>,
+    /// Alternative names for the note, from `aliases` or the older `alias`
+    #[serde(default, alias = "alias", deserialize_with = "deserialize_aliases")]
+    pub aliases: Vec<String>,
+    /// Kept as a raw value so an unusual title (e.g. a number) never hides the tags
+    #[serde(default)]
+    title: Option<serde_yaml_ng::Value>,
+    /// Raw `due` and `deadline` values, read through [`Frontmatter::due`]
+    #[serde(default)]
+    due: Option<serde_yaml_ng::Value>,
+    #[serde(default)]
+    deadline: Option<serde_yaml_ng::Value>,
+    /// Every other property, read through [`Frontmatter::property`]
+    #[serde(flatten)]
+    extra: BTreeMap<String, serde_yaml_ng::Value>,
+    /// `tags` entries left out, read through [`Frontmatter::skipped_tags`]
+    #[serde(skip)]
+    skipped_tags: Vec<String>,
+}
+
+/// A metadata header format read when a note has no YAML frontmatter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MetadataFormat {
+    /// Pandoc title block: up to three `%` lines holding the title, the
+    /// authors separated by `;`, and the date
+    Pandoc,
+    /// MultiMarkdown metadata: `Key: value` lines up to the first blank line,
+    /// with indented lines continuing the previous value
+    MultiMarkdown,
+}
+
+/// A property Obsidian accepts either as a YAML list or as a single string.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum OneOrMany {
+    One(String),
+    Many(Vec<String>),
 }
 
 // ============================================
 // IMPLEMENTATIONS
 // ============================================
 
+impl Frontmatter {
+    /// The `title` field, with numbers and booleans converted to text.
+    #[inline]
+    #[must_use]
+    pub fn title(&self) -> Option<String> {
+        scalar_text(self.title.as_ref()?)
+    }
+
+    /// Entries of `tags` that are lists or maps rather than tags, as inline
+    /// YAML, found by [`parse_frontmatter`].
+    #[inline]
+    #[must_use]
+    pub fn skipped_tags(&self) -> &[String] {
+        &self.skipped_tags
+    }
+
+    /// The `priority` property, trimmed, or `None` when it is missing or blank.
+    #[inline]
+    #[must_use]
+    pub fn priority(&self) -> Option<String> {
+        let priority = self.property("priority")?;
+        let priority = priority.trim();
+        (!priority.is_empty()).then(|| priority.to_owned())
+    }
+
+    /// A property without a dedicated field, such as `status`, as text.
+    ///
+    /// Numbers and booleans are converted; lists, maps and nulls count as missing.
+    #[inline]
+    #[must_use]
+    pub fn property(&self, name: &str) -> Option<String> {
+        scalar_text(self.extra.get(name)?)
+    }
+
+    /// A property without a dedicated field as a list of texts: each scalar
+    /// item of a list, or a single scalar as a one-item list.
+    #[inline]
+    #[must_use]
+    pub fn property_list(&self, name: &str) -> Option<Vec<String>> {
+        match self.extra.get(name)? {
+            serde_yaml_ng::Value::Sequence(items) => {
+                Some(items.iter().filter_map(scalar_text).collect())
+            }
+            value => scalar_text(value).map(|text| vec![text]),
+        }
+    }
+
+    /// The date from `due`, or from `deadline` when there is no `due`.
+    ///
+    /// Accepts `YYYY-MM-DD`, optionally followed by a time, which is ignored.
+    /// Values that are not dates count as missing.
+    #[inline]
+    #[must_use]
+    pub fn due(&self) -> Option<NaiveDate> {
+        [&self.due, &self.deadline]
+            .into_iter()
+            .flatten()
+            .find_map(|value| leading_date(value.as_str()?))
+    }
+
+    /// The date from `created`, or from `date` when there is no `created`.
+    ///
+    /// Accepts the same formats as [`Frontmatter::due`].
+    #[inline]
+    #[must_use]
+    pub fn created(&self) -> Option<NaiveDate> {
+        ["created", "date"]
+            .into_iter()
+            .find_map(|name| leading_date(&self.property(name)?))
+    }
+}
+
+/// A `YYYY-MM-DD` date at the start of `text`, ignoring anything after it.
+fn leading_date(text: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(text.trim().get(..10)?, "%Y-%m-%d").ok()
+}
+
+fn scalar_text(value: &serde_yaml_ng::Value) -> Option<String> {
+    match value {
+        serde_yaml_ng::Value::String(s) => Some(s.clone()),
+        serde_yaml_ng::Value::Number(n) => Some(n.to_string()),
+        serde_yaml_ng::Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+/// Accepts `tags: [a, "#b"]`, `tags: "#a #b"` and `tags: a, b`, dropping `#` prefixes and blanks.
+///
+/// Numbers and booleans become text, and list entries that are lists or maps
+/// are left out (see [`Frontmatter::skipped_tags`]), so one odd value never
+/// hides the note's other tags.
+fn deserialize_tags<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Vec<String>>, D::Error> {
+    let raw: Vec<String> = match Option::<Value>::deserialize(deserializer)? {
+        None | Some(Value::Null | Value::Mapping(_)) => return Ok(None),
+        Some(Value::Sequence(items)) => items.iter().filter_map(scalar_text).collect(),
+        Some(value) => scalar_text(&value)
+            .unwrap_or_default()
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .map(str::to_owned)
+            .collect(),
+    };
+    Ok(Some(
+        raw.iter()
+            .map(|tag| tag.trim().trim_start_matches('#'))
+            .filter(|tag| !tag.is_empty())
+            .map(str::to_owned)
+            .collect(),
+    ))
+}
+
+/// The entries of a raw `tags` value that cannot be tags, as inline YAML.
+fn unreadable_tags(tags: &Value) -> Vec<String> {
+    let entries = match tags {
+        Value::Sequence(items) => items.iter().collect(),
+        Value::Mapping(_) => vec![tags],
+        _ => Vec::new(),
+    };
+    entries
+        .into_iter()
+        .filter(|entry| entry.is_sequence() || entry.is_mapping())
+        .filter_map(|entry| serde_yaml_ng::to_string(entry).ok())
+        .map(|yaml| yaml.trim().replace('\n', " "))
+        .collect()
+}
+
+/// Accepts a single alias or a list of them; aliases may contain spaces, so a string is never split.
+fn deserialize_aliases<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<String>, D::Error> {
+    let aliases = match Option::<OneOrMany>::deserialize(deserializer)? {
+        None => Vec::new(),
+        Some(OneOrMany::One(alias)) => vec![alias],
+        Some(OneOrMany::Many(aliases)) => aliases,
+    };
+    Ok(aliases
+        .into_iter()
+        .map(|alias| alias.trim().to_owned())
+        .filter(|alias| !alias.is_empty())
+        .collect())
+}
+
+/// A note's title: the frontmatter `title`, or else the text of the first
+/// level-one `# heading` outside fenced code blocks. Blank titles count as
+/// missing.
+#[inline]
+#[must_use]
+pub fn note_title(content: &str) -> Option<String> {
+    title_from(&parse_frontmatter(content).unwrap_or_default(), content)
+}
+
+/// [`note_title`] with the note's frontmatter already parsed.
+#[inline]
+#[must_use]
+pub fn title_from(frontmatter: &Frontmatter, content: &str) -> Option<String> {
+    title_from_body(frontmatter, strip_frontmatter(content))
+}
+
+/// [`title_from`] with the frontmatter already stripped from the note.
+#[inline]
+#[must_use]
+pub fn title_from_body(frontmatter: &Frontmatter, body: &str) -> Option<String> {
+    frontmatter
+        .title()
+        .map(|title| title.trim().to_owned())
+        .filter(|title| !title.is_empty())
+        .or_else(|| first_heading(body))
+}
+
+/// When a note was created: the frontmatter `created` (or `date`) property,
+/// or else the timestamp its file name starts with.
+#[inline]
+#[must_use]
+pub fn note_created(content: &str, path: &Path) -> Option<NaiveDate> {
+    parse_frontmatter(content)
+        .ok()
+        .and_then(|frontmatter| frontmatter.created())
+        .or_else(|| filename_date(path))
+}
+
+/// The date a file name starts with: a zettel ID (`YYYYMMDDHHMM`,
+/// `YYYYMMDDHHMMSS` or `YYYYMMDD`) or a `YYYY-MM-DD` date.
+#[inline]
+#[must_use]
+pub fn filename_date(path: &Path) -> Option<NaiveDate> {
+    let stem = path.file_stem()?.to_str()?;
+    let digits = stem
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(stem.len());
+    let id = &stem[..digits];
+    match id.len() {
+        8 => NaiveDate::parse_from_str(id, "%Y%m%d").ok(),
+        12 => NaiveDateTime::parse_from_str(id, "%Y%m%d%H%M")
+            .ok()
+            .map(|time| time.date()),
+        14 => NaiveDateTime::parse_from_str(id, "%Y%m%d%H%M%S")
+            .ok()
+            .map(|time| time.date()),
+        4 => leading_date(stem),
+        _ => None,
+    }
+}
+
+/// The zettel ID a file stem starts with: 12 or 14 digits.
+#[inline]
+#[must_use]
+pub fn zettel_id(stem: &str) -> Option<&str> {
+    let digits = stem
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(stem.len());
+    matches!(digits, 12 | 14).then(|| &stem[..digits])
+}
+
+fn first_heading(body: &str) -> Option<String> {
+    let mut in_fence = false;
+    for line in body.lines() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence {
+            continue;
+        }
+        if let Some(heading) = trimmed.strip_prefix("# ") {
+            let heading = heading.trim().trim_end_matches('#').trim_end();
+            if !heading.is_empty() {
+                return Some(heading.to_owned());
+            }
+        }
+    }
+    None
+}
+
 /// Parses YAML frontmatter from markdown content.
 ///
-/// Frontmatter must be enclosed between `---` delimiters at the start of the content.
+/// Frontmatter must be enclosed between `---` delimiters at the start of the
+/// content; see [`parse_frontmatter_in`] to also read other header formats.
 ///
 /// # Arguments
 ///
@@ -116,42 +798,283 @@ pub struct Frontmatter {
 /// * The YAML cannot be deserialized into the Frontmatter struct
 #[inline]
 pub fn parse_frontmatter(content: &str) -> Result<Frontmatter> {
-    let mut content_iter = content.lines();
+    parse_frontmatter_in(content, &[])
+}
+
+/// [`parse_frontmatter`], reading a header in one of `formats` instead when
+/// the content has no YAML frontmatter.
+///
+/// # Errors
+///
+/// Returns an error if the frontmatter or header cannot be deserialized.
+#[inline]
+pub fn parse_frontmatter_in(content: &str, formats: &[MetadataFormat]) -> Result<Frontmatter> {
+    let Some(frontmatter_str) = frontmatter_yaml(content) else {
+        return match metadata_header(content, formats) {
+            Some((fields, _)) => serde_yaml_ng::from_value(Value::Mapping(fields))
+                .map_err(|e| anyhow!("Failed to parse front matter: {}", e)),
+            None => Ok(Frontmatter::default()),
+        };
+    };
+
+    // Parse YAML, noting tags that have to be left out
+    let value: Value = serde_yaml_ng::from_str(&frontmatter_str)
+        .map_err(|e| anyhow!("Failed to parse front matter: {}", e))?;
+    if value.is_null() {
+        return Ok(Frontmatter::default());
+    }
+    let skipped_tags = value.get("tags").map(unreadable_tags).unwrap_or_default();
+    let mut frontmatter: Frontmatter = serde_yaml_ng::from_value(value)
+        .map_err(|e| anyhow!("Failed to parse front matter: {}", e))?;
+    frontmatter.skipped_tags = skipped_tags;
+    Ok(frontmatter)
+}
+
+/// Every frontmatter property as a generic YAML value, for callers that need
+/// properties the [`Frontmatter`] model does not name. Notes without
+/// frontmatter give `Null`.
+///
+/// # Errors
+///
+/// Returns an error if the frontmatter contains invalid YAML syntax.
+#[inline]
+pub fn frontmatter_value(content: &str) -> Result<serde_yaml_ng::Value> {
+    frontmatter_value_in(content, &[])
+}
+
+/// [`frontmatter_value`], reading a header in one of `formats` instead when
+/// the content has no YAML frontmatter.
+///
+/// # Errors
+///
+/// Returns an error if the frontmatter contains invalid YAML syntax.
+#[inline]
+pub fn frontmatter_value_in(
+    content: &str,
+    formats: &[MetadataFormat],
+) -> Result<serde_yaml_ng::Value> {
+    let Some(frontmatter_str) = frontmatter_yaml(content) else {
+        return Ok(
+            metadata_header(content, formats).map_or(Value::Null, |(fields, _)| fields.into())
+        );
+    };
+    serde_yaml_ng::from_str(&frontmatter_str)
+        .map_err(|e| anyhow!("Failed to parse front matter: {}", e))
+}
+
+/// The YAML between the opening `---` line and the closing `---` or `...`
+/// line, if the note starts with one. Only this first YAML document is the
+/// frontmatter; any that follow belong to the body.
+fn frontmatter_yaml(content: &str) -> Option<String> {
+    // `lines` also drops the `\r` of CRLF line endings
+    let mut content_iter = without_bom(content).lines();
 
     // Check for frontmatter delimiter
     if content_iter.next() != Some("---") {
-        return Ok(Frontmatter::default());
+        return None;
     }
 
     // Collect frontmatter content
     let mut frontmatter_str = String::new();
     for line in content_iter {
-        if line == "---" {
+        if is_closing(line) {
             break;
         }
         frontmatter_str.push_str(line);
         frontmatter_str.push('\n');
     }
+    Some(frontmatter_str)
+}
 
-    // Parse YAML
-    serde_yaml_ng::from_str(&frontmatter_str)
-        .map_err(|e| anyhow!("Failed to parse front matter: {}", e))
+/// Whether `line` ends the frontmatter: YAML's `---` document separator or
+/// its `...` document end marker.
+fn is_closing(line: &str) -> bool {
+    matches!(line.strip_suffix('\r').unwrap_or(line), "---" | "...")
 }
 
 /// Strip YAML frontmatter from content and return body only
 ///
-/// Frontmatter is identified by starting with `---` and ending with another `---` line.
-/// If no valid frontmatter is found, returns the original content.
+/// Frontmatter is identified by starting with a `---` line and ending with a
+/// `---` or `...` line. If no valid frontmatter is found, returns the
+/// original content.
 pub fn strip_frontmatter(content: &str) -> &str {
-    if !content.starts_with("---") {
-        return content;
+    strip_frontmatter_in(content, &[])
+}
+
+/// [`strip_frontmatter`], also stripping a header in one of `formats` from
+/// content without YAML frontmatter.
+#[inline]
+#[must_use]
+pub fn strip_frontmatter_in<'a>(content: &'a str, formats: &[MetadataFormat]) -> &'a str {
+    let unmarked = without_bom(content);
+    let mut lines = unmarked.split_inclusive('\n');
+    if lines.next().map(str::trim_end) != Some("---") {
+        return metadata_header(content, formats).map_or(content, |(_, end)| &unmarked[end..]);
     }
 
-    // Find the closing ---
-    if let Some(end) = content[3..].find("---") {
-        let body_start = 3 + end + 3; // Skip past second ---
-        return content.get(body_start..).unwrap_or("");
+    // Find the closing line, keeping the line break after it in the body
+    let mut offset = 0;
+    for line in unmarked.split_inclusive('\n') {
+        if offset > 0 && is_closing(line.trim_end_matches('\n')) {
+            return &unmarked[offset + 3..];
+        }
+        offset += line.len();
     }
 
     content
 }
+
+/// The properties of a Pandoc title block or MultiMarkdown metadata header
+/// at the start of `content`, trying each of `formats` in turn, with where
+/// the header ends (after any byte order mark, before its last line break).
+///
+/// Pandoc fields become `title`, `author` (a list for several authors) and
+/// `date`; MultiMarkdown keys are lowercased without spaces. Values are text,
+/// so `Tags: a, b` reads like a single-string `tags` property.
+#[must_use]
+pub fn metadata_header(content: &str, formats: &[MetadataFormat]) -> Option<(Mapping, usize)> {
+    let content = without_bom(content);
+    formats.iter().find_map(|format| match format {
+        MetadataFormat::Pandoc => pandoc_title_block(content),
+        MetadataFormat::MultiMarkdown => multimarkdown_header(content),
+    })
+}
+
+fn pandoc_title_block(content: &str) -> Option<(Mapping, usize)> {
+    let mut fields: Vec<String> = Vec::new();
+    let mut end = 0;
+    for line in content.split_inclusive('\n') {
+        let text = line.trim_end();
+        // `%%` starts an Obsidian comment, not a field
+        if let Some(field) = text.strip_prefix('%').filter(|f| !f.starts_with('%')) {
+            if fields.len() == 3 {
+                break;
+            }
+            fields.push(field.trim().to_owned());
+        } else if let Some(last) = fields.last_mut().filter(|_| text.starts_with([' ', '\t'])) {
+            // Indented lines continue the field
+            last.push(' ');
+            last.push_str(text.trim());
+        } else {
+            break;
+        }
+        end += line.len();
+    }
+    if fields.is_empty() {
+        return None;
+    }
+    let mut mapping = Mapping::new();
+    for (name, value) in ["title", "author", "date"].into_iter().zip(fields) {
+        if value.is_empty() {
+            continue;
+        }
+        let value = if name == "author" && value.contains(';') {
+            Value::Sequence(
+                value
+                    .split(';')
+                    .map(str::trim)
+                    .filter(|author| !author.is_empty())
+                    .map(|author| Value::String(author.to_owned()))
+                    .collect(),
+            )
+        } else {
+            Value::String(value)
+        };
+        mapping.insert(name.into(), value);
+    }
+    Some((mapping, header_end(content, end)))
+}
+
+fn multimarkdown_header(content: &str) -> Option<(Mapping, usize)> {
+    let mut fields: Vec<(String, String)> = Vec::new();
+    let mut end = 0;
+    for line in content.split_inclusive('\n') {
+        let text = line.trim_end();
+        if text.is_empty() {
+            break;
+        }
+        if text.starts_with([' ', '\t']) {
+            let (_, last) = fields.last_mut()?;
+            last.push(' ');
+            last.push_str(text.trim());
+        } else {
+            let (key, value) = text.split_once(':')?;
+            // A colon inside a word, as in a URL, does not make a key
+            let valid = key.starts_with(|c: char| c.is_ascii_alphanumeric())
+                && key
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, ' ' | '_' | '-'))
+                && (value.is_empty() || value.starts_with([' ', '\t']));
+            if !valid {
+                return None;
+            }
+            let key = key.replace(' ', "").to_lowercase();
+            fields.push((key, value.trim().to_owned()));
+        }
+        end += line.len();
+    }
+    if fields.is_empty() {
+        return None;
+    }
+    let mapping = fields
+        .into_iter()
+        .filter(|(_, value)| !value.is_empty())
+        .map(|(key, value)| (Value::String(key), Value::String(value)))
+        .collect();
+    Some((mapping, header_end(content, end)))
+}
+
+/// `end`, the offset after the header's last line, moved back before that
+/// line's break so the body keeps it, as it does after YAML frontmatter.
+fn header_end(content: &str, end: usize) -> usize {
+    let header = &content[..end];
+    let trimmed = header
+        .strip_suffix('\n')
+        .map_or(header, |h| h.strip_suffix('\r').unwrap_or(h));
+    trimmed.len()
+}
+
+/// `content` without the UTF-8 byte order mark some Windows editors write
+/// before the first line, so the frontmatter delimiter is still found.
+#[inline]
+#[must_use]
+pub fn without_bom(content: &str) -> &str {
+    content.strip_prefix('\u{feff}').unwrap_or(content)
+}
+
+/// The part of a note whose words and lines are counted: the body without
+/// its frontmatter and the line break closing it, or everything if
+/// `include_frontmatter` is set.
+#[inline]
+#[must_use]
+pub fn countable_text(content: &str, include_frontmatter: bool) -> &str {
+    countable_text_in(content, include_frontmatter, &[])
+}
+
+/// [`countable_text`], also leaving out a header in one of `formats`, see
+/// [`strip_frontmatter_in`].
+#[inline]
+#[must_use]
+pub fn countable_text_in<'a>(
+    content: &'a str,
+    include_frontmatter: bool,
+    formats: &[MetadataFormat],
+) -> &'a str {
+    if include_frontmatter {
+        return content;
+    }
+    let body = strip_frontmatter_in(content, formats);
+    body.strip_prefix("\r\n")
+        .or_else(|| body.strip_prefix('\n'))
+        .unwrap_or(body)
+}
+
+/// Whitespace-separated words in [`countable_text`], so every listing and
+/// statistic counts a note the same way.
+#[inline]
+#[must_use]
+pub fn word_count(content: &str, include_frontmatter: bool) -> usize {
+    countable_text(content, include_frontmatter)
+        .split_whitespace()
+        .count()
+}
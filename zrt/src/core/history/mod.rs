@@ -0,0 +1,374 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::core::links::{LinkIndex, NoteNode};
+use crate::core::scanner::{ScanOptions, Scanner};
+use crate::core::status::RefactorStatus;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::links::note_from_content;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn notes(notes: &[(&str, &str)]) -> Vec<NoteNode> {
+        notes
+            .iter()
+            .map(|(path, content)| note_from_content(Path::new(path), content))
+            .collect()
+    }
+
+    fn at(seconds: i64) -> DateTime<Utc> {
+        DateTime::from_timestamp(seconds, 0).unwrap()
+    }
+
+    #[test]
+    fn test_should_summarize_notes_into_snapshot() {
+        // REQ-HIST-001
+
+        // Given
+        let notes = notes(&[
+            (
+                "/v/a.md",
+                "---\ntags: [refactored, rust]\n---\none two three",
+            ),
+            ("/v/b.md", "---\ntags: [to_refactor, rust]\n---\none"),
+            ("/v/c.md", "four five six seven eight nine"),
+        ]);
+
+        // When
+        let snapshot = Snapshot::from_notes(at(0), &notes, "refactored", "to_refactor");
+
+        // Then
+        assert_eq!(snapshot.files, 3);
+        assert_eq!(snapshot.words, 10);
+        assert_eq!(snapshot.done_files, 1);
+        assert_eq!(snapshot.todo_files, 1);
+        assert_eq!(snapshot.done_words, 3);
+        assert_eq!(snapshot.todo_words, 1);
+        assert_eq!(snapshot.percentage, 30.0);
+        assert_eq!(snapshot.tags.get("rust"), Some(&2));
+        assert_eq!(snapshot.tags.get("refactored"), Some(&1));
+        assert_eq!(snapshot.notes["/v/b.md"].status, RefactorStatus::Todo);
+        assert_eq!(snapshot.notes["/v/c.md"].words, 6);
+    }
+
+    #[test]
+    fn test_should_report_zero_percentage_for_empty_vault() {
+        // REQ-HIST-002
+        let snapshot = Snapshot::from_notes(at(0), &[], "refactored", "to_refactor");
+        assert_eq!(snapshot.files, 0);
+        assert_eq!(snapshot.percentage, 0.0);
+    }
+
+    #[test]
+    fn test_should_append_and_load_history() -> Result<()> {
+        // REQ-HIST-003
+
+        // Given
+        let dir = TempDir::new()?;
+        let path = dir.path().join(".zrt/history.jsonl");
+        let first = Snapshot::from_notes(at(0), &[], "refactored", "to_refactor");
+        let second = Snapshot::from_notes(
+            at(86_400),
+            &notes(&[("/v/a.md", "---\ntags: [refactored]\n---\nword")]),
+            "refactored",
+            "to_refactor",
+        );
+
+        // When
+        append_snapshot(&path, &first)?;
+        append_snapshot(&path, &second)?;
+        let history = load_history(&path)?;
+
+        // Then
+        assert_eq!(history, vec![first, second]);
+        assert_eq!(fs::read_to_string(&path)?.lines().count(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_load_empty_history_when_file_missing() -> Result<()> {
+        // REQ-HIST-004
+        let dir = TempDir::new()?;
+        let history = load_history(&dir.path().join("history.jsonl"))?;
+        assert!(history.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_load_latest_snapshot() -> Result<()> {
+        // REQ-HIST-007
+        let dir = TempDir::new()?;
+        let path = dir.path().join("history.jsonl");
+        assert!(latest_snapshot(&path).is_err());
+
+        append_snapshot(&path, &Snapshot::from_notes(at(0), &[], "done", "todo"))?;
+        append_snapshot(&path, &Snapshot::from_notes(at(60), &[], "done", "todo"))?;
+
+        assert_eq!(latest_snapshot(&path)?.timestamp, at(60));
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_fail_on_malformed_history_line() -> Result<()> {
+        // REQ-HIST-005
+        let dir = TempDir::new()?;
+        let path = dir.path().join("history.jsonl");
+        fs::write(&path, "not json\n")?;
+
+        let result = load_history(&path);
+
+        assert!(result.is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_capture_snapshot_from_directory() -> Result<()> {
+        // REQ-HIST-006
+
+        // Given
+        let dir = TempDir::new()?;
+        fs::write(
+            dir.path().join("a.md"),
+            "---\ntags: [refactored]\n---\nalpha beta",
+        )?;
+        fs::write(dir.path().join("b.md"), "gamma delta")?;
+        fs::write(dir.path().join("image.png"), "binary")?;
+
+        // When
+        let snapshot = Snapshot::capture(
+            &[dir.path().to_path_buf()],
+            &[],
+            "refactored",
+            "to_refactor",
+        )?;
+
+        // Then
+        assert_eq!(snapshot.files, 2);
+        assert_eq!(snapshot.percentage, 50.0);
+        Ok(())
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// Default location of the snapshot history, relative to the vault root.
+pub const HISTORY_FILE: &str = ".zrt/history.jsonl";
+
+/// A note's refactor status and size at the time of a snapshot.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NoteSnapshot {
+    pub status: RefactorStatus,
+    pub words: usize,
+}
+
+/// Vault statistics recorded at one point in time.
+///
+/// Snapshots are stored one per line in [`HISTORY_FILE`] and drive the
+/// diff, trend and burndown commands.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Snapshot {
+    pub timestamp: DateTime<Utc>,
+    pub files: usize,
+    pub words: usize,
+    pub done_files: usize,
+    pub todo_files: usize,
+    pub done_words: usize,
+    pub todo_words: usize,
+    /// Percentage of words in notes tagged done, rounded to 2 decimal places
+    pub percentage: f64,
+    /// Number of notes carrying each tag
+    pub tags: BTreeMap<String, usize>,
+    /// Status and word count of every note, keyed by path
+    #[serde(default)]
+    pub notes: BTreeMap<String, NoteSnapshot>,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+impl Snapshot {
+    /// Summarizes already scanned notes into a snapshot taken at `timestamp`.
+    #[inline]
+    #[must_use]
+    pub fn from_notes(
+        timestamp: DateTime<Utc>,
+        notes: &[NoteNode],
+        done_tag: &str,
+        todo_tag: &str,
+    ) -> Self {
+        let mut snapshot = Self {
+            timestamp,
+            files: 0,
+            words: 0,
+            done_files: 0,
+            todo_files: 0,
+            done_words: 0,
+            todo_words: 0,
+            percentage: 0.0,
+            tags: BTreeMap::new(),
+            notes: BTreeMap::new(),
+        };
+
+        for note in notes {
+            let status = RefactorStatus::of(&note.tags, done_tag, todo_tag);
+            snapshot.files += 1;
+            snapshot.words += note.words;
+            match status {
+                RefactorStatus::Done => {
+                    snapshot.done_files += 1;
+                    snapshot.done_words += note.words;
+                }
+                RefactorStatus::Todo => {
+                    snapshot.todo_files += 1;
+                    snapshot.todo_words += note.words;
+                }
+                RefactorStatus::Untracked => {}
+            }
+            for tag in &note.tags {
+                *snapshot.tags.entry(tag.clone()).or_insert(0) += 1;
+            }
+            snapshot.notes.insert(
+                note_key(&note.path),
+                NoteSnapshot {
+                    status,
+                    words: note.words,
+                },
+            );
+        }
+
+        snapshot.percentage = percentage(snapshot.done_words, snapshot.words);
+        snapshot
+    }
+
+    /// Scans the given directories and records their current statistics.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a directory cannot be scanned.
+    #[inline]
+    pub fn capture(
+        dirs: &[PathBuf],
+        exclude: &[&str],
+        done_tag: &str,
+        todo_tag: &str,
+    ) -> Result<Self> {
+        Self::capture_in(
+            &ScanOptions::new(dirs).exclude(exclude).build()?,
+            done_tag,
+            todo_tag,
+        )
+    }
+
+    /// [`Snapshot::capture`] using an already configured scanner.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the scan fails.
+    #[inline]
+    pub fn capture_in(scanner: &Scanner, done_tag: &str, todo_tag: &str) -> Result<Self> {
+        let index = LinkIndex::build_in(scanner)?;
+        Ok(Self::from_notes(
+            Utc::now(),
+            &index.notes,
+            done_tag,
+            todo_tag,
+        ))
+    }
+}
+
+/// Appends a snapshot as one JSON line, creating the file and its directory if needed.
+///
+/// # Errors
+///
+/// Returns an error if the history file cannot be written.
+#[inline]
+pub fn append_snapshot(path: &Path, snapshot: &Snapshot) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+    }
+
+    let line = serde_json::to_string(snapshot).context("Failed to serialize snapshot")?;
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("Failed to open history file: {}", path.display()))?;
+    writeln!(file, "{line}")
+        .with_context(|| format!("Failed to write history file: {}", path.display()))
+}
+
+/// Loads every snapshot from a history file in recorded order.
+/// A missing file is an empty history.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read or a line is not a valid snapshot.
+#[inline]
+pub fn load_history(path: &Path) -> Result<Vec<Snapshot>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read history file: {}", path.display()))?;
+
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            serde_json::from_str(line).with_context(|| {
+                format!("Invalid snapshot on line {} of {}", i + 1, path.display())
+            })
+        })
+        .collect()
+}
+
+/// Loads the most recent snapshot from a history file.
+///
+/// # Errors
+///
+/// Returns an error if the history cannot be loaded or holds no snapshots.
+#[inline]
+pub fn latest_snapshot(path: &Path) -> Result<Snapshot> {
+    load_history(path)?
+        .pop()
+        .with_context(|| format!("No snapshots found in {}", path.display()))
+}
+
+/// Share of `part` in `total` as a percentage rounded to 2 decimal places.
+#[inline]
+#[must_use]
+pub fn percentage(part: usize, total: usize) -> f64 {
+    if total == 0 {
+        return 0.0;
+    }
+    let percentage = (part as f64 / total as f64) * 100.0;
+    (percentage * 100.0).round() / 100.0
+}
+
+/// Key a note by its path relative to the current directory when possible,
+/// as in [`Snapshot::notes`].
+#[must_use]
+pub fn note_key(path: &Path) -> String {
+    std::env::current_dir()
+        .ok()
+        .and_then(|cwd| path.strip_prefix(cwd).ok().map(Path::to_path_buf))
+        .unwrap_or_else(|| path.to_path_buf())
+        .to_string_lossy()
+        .to_string()
+}
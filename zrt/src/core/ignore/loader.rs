@@ -1,8 +1,9 @@
+use crate::core::error::{Result, ZrtError};
 use crate::core::patterns::Patterns;
-use anyhow::{Context as _, Result};
+use crate::core::vfs::{RealFs, Vfs};
 use std::collections::HashSet;
-use std::fs;
 use std::path::{Path, PathBuf};
+use tracing::{debug, trace};
 
 /// Loads ignore patterns from .zrtignore files starting from the given directory
 /// and recursively checking parent directories until a file is found.
@@ -17,12 +18,21 @@ use std::path::{Path, PathBuf};
 ///
 /// # Errors
 ///
-/// This function may return an error if:
-/// * The .zrtignore file exists but cannot be read
-/// * The file contains invalid pattern syntax
-/// * File system operations fail during the search
+/// * [`ZrtError::IgnoreFile`] if the .zrtignore file exists but cannot be read
+/// * [`ZrtError::InvalidPattern`] if the file contains invalid pattern syntax
 #[inline]
 pub fn load_ignore_patterns(dir: &Path) -> Result<Patterns> {
+    load_ignore_patterns_in(&RealFs, dir)
+}
+
+/// Same as [`load_ignore_patterns`], reading through the given filesystem.
+///
+/// # Errors
+///
+/// * [`ZrtError::IgnoreFile`] if the .zrtignore file exists but cannot be read
+/// * [`ZrtError::InvalidPattern`] if the file contains invalid pattern syntax
+#[inline]
+pub fn load_ignore_patterns_in(vfs: &dyn Vfs, dir: &Path) -> Result<Patterns> {
     let mut patterns = Patterns::new(PathBuf::new());
 
     let mut current_dir = dir.to_path_buf();
@@ -34,14 +44,19 @@ pub fn load_ignore_patterns(dir: &Path) -> Result<Patterns> {
 
         let ignore_file = current_dir.join(".zrtignore");
 
-        if ignore_file.exists() {
-            let content = fs::read_to_string(&ignore_file).with_context(|| {
-                format!("Failed to read .zrtignore file: {}", ignore_file.display())
-            })?;
+        if vfs.exists(&ignore_file) {
+            let content =
+                vfs.read_to_string(&ignore_file)
+                    .map_err(|source| ZrtError::IgnoreFile {
+                        path: ignore_file.clone(),
+                        source,
+                    })?;
 
             for line in content.lines() {
+                trace!(pattern = line, "adding ignore pattern");
                 patterns.add_pattern(line)?;
             }
+            debug!(path = %ignore_file.display(), "loaded ignore patterns");
 
             break;
         }
@@ -49,6 +64,7 @@ pub fn load_ignore_patterns(dir: &Path) -> Result<Patterns> {
         if let Some(parent) = current_dir.parent() {
             current_dir = parent.to_path_buf();
         } else {
+            debug!(dir = %dir.display(), "no .zrtignore found");
             break;
         }
     }
@@ -59,6 +75,7 @@ pub fn load_ignore_patterns(dir: &Path) -> Result<Patterns> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use anyhow::Result;
     use std::path::PathBuf;
 
     #[test]
@@ -100,6 +117,19 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_load_ignore_patterns_from_parent_in_memory() -> Result<()> {
+        let vfs = crate::core::vfs::MemoryFs::new()
+            .with_file("/vault/.zrtignore", "drafts/\n")
+            .with_file("/vault/notes/a.md", "content");
+
+        let patterns = load_ignore_patterns_in(&vfs, Path::new("/vault/notes"))?;
+
+        assert!(patterns.matches("/vault/notes/drafts/b.md"));
+        assert!(!patterns.matches("/vault/notes/a.md"));
+        Ok(())
+    }
+
     #[test]
     fn test_todo_chores_ignore() -> Result<()> {
         let temp_dir = tempfile::tempdir()?;
@@ -1,3 +1,3 @@
 mod loader;
 
-pub use loader::load_ignore_patterns;
+pub use loader::{load_ignore_patterns, load_ignore_patterns_in};
@@ -0,0 +1,217 @@
+use std::path::{Path, PathBuf};
+
+use super::{
+    Link, LinkKind, clean_target, code_end, code_ranges, is_local_target, markdown_target,
+    normalize_path,
+};
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_should_extract_images_and_embedded_files() {
+        // REQ-LINKS-008
+        let body = "![chart](img/chart.png \"Chart\") ![[slides.pdf|Slides]] ![[other-note]] \
+             ![remote](https://example.com/a.png) [doc](spec.pdf) ![space](my%20pic.jpg)";
+
+        let assets = extract_assets(body);
+
+        assert_eq!(
+            assets,
+            vec![
+                Link::markdown("img/chart.png"),
+                Link::wiki("slides.pdf"),
+                Link::markdown("my%20pic.jpg"),
+            ]
+        );
+        assert!(extract_assets("`![[slides.pdf]]`\n```\n![x](x.png)\n```").is_empty());
+    }
+
+    #[test]
+    fn test_should_recognize_asset_names() {
+        // REQ-LINKS-008
+        assert!(is_asset_name("slides.pdf"));
+        assert!(is_asset_name("img/Photo.JPEG"));
+        assert!(!is_asset_name("other-note"));
+        assert!(!is_asset_name("note.md"));
+        assert!(!is_asset_name("Dr. Smith"));
+    }
+
+    #[test]
+    fn test_should_resolve_assets_next_to_note_or_anywhere_in_vault() -> Result<()> {
+        // REQ-LINKS-010
+        // Given a vault with an attachments folder
+        let dir = TempDir::new()?;
+        fs::create_dir_all(dir.path().join("notes"))?;
+        fs::create_dir_all(dir.path().join("attachments/img"))?;
+        fs::write(dir.path().join("attachments/img/chart.png"), "")?;
+        fs::write(dir.path().join("notes/my pic.jpg"), "")?;
+        let files = AssetFiles::new([
+            dir.path().join("attachments/img/chart.png"),
+            dir.path().join("notes/my pic.jpg"),
+        ]);
+        let note = dir.path().join("notes/a.md");
+
+        // Then relative paths, vault paths and bare embedded names all resolve
+        assert!(files.resolves(&note, &Link::markdown("../attachments/img/chart.png")));
+        assert!(files.resolves(&note, &Link::markdown("attachments/img/chart.png")));
+        assert!(files.resolves(&note, &Link::markdown("my%20pic.jpg")));
+        assert!(files.resolves(&note, &Link::wiki("Chart.png")));
+        assert!(!files.resolves(&note, &Link::wiki("missing.png")));
+        assert!(!files.resolves(&note, &Link::markdown("img/missing.png")));
+        assert_eq!(
+            files.targets(&note, &Link::markdown("../attachments/img/chart.png")),
+            vec![0]
+        );
+        assert_eq!(files.targets(&note, &Link::wiki("my pic.jpg")), vec![1]);
+        assert!(files.targets(&note, &Link::wiki("missing.png")).is_empty());
+        Ok(())
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// Every scanned file, for checking that asset references point somewhere.
+#[derive(Debug, Default)]
+pub struct AssetFiles {
+    /// Normalized, lowercased paths with `/` separators and a leading `/`
+    paths: Vec<String>,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+/// Extracts references to files from note body text, in order of appearance:
+/// local images (`![alt](img.png)`) and embedded files (`![[file.pdf]]`).
+///
+/// Embedded notes such as `![[other-note]]` are links, not assets, and are
+/// left to [`super::extract_links`], and so are references in code.
+#[inline]
+#[must_use]
+pub fn extract_assets(body: &str) -> Vec<Link> {
+    let code = code_ranges(body);
+    let mut assets = Vec::new();
+    let mut rest = body;
+
+    while let Some(start) = rest.find("![") {
+        let at = body.len() - rest.len() + start;
+        if let Some(end) = code_end(&code, at) {
+            rest = &body[end..];
+            continue;
+        }
+        let after = &rest[start + 2..];
+        if let Some(inner) = after.strip_prefix('[') {
+            let Some(end) = inner.find("]]") else {
+                break;
+            };
+            if let Some(target) = clean_target(&inner[..end], &['|', '#']) {
+                if is_asset_name(target) {
+                    assets.push(Link::wiki(target));
+                }
+            }
+            rest = &inner[end + 2..];
+            continue;
+        }
+
+        rest = after;
+        if let Some((target, remaining)) = markdown_target(after) {
+            // Drop an optional image title: ![alt](img.png "Title")
+            let target = target.split(" \"").next().unwrap_or(target);
+            let target = target.trim_start_matches('<').trim_end_matches('>');
+            if is_local_target(target) {
+                if let Some(target) = clean_target(target, &['#', '?']) {
+                    assets.push(Link::markdown(target));
+                }
+            }
+            rest = remaining;
+        }
+    }
+
+    assets
+}
+
+/// Whether a wikilink target names a file other than a note: it has a short
+/// alphanumeric extension that is not `md`.
+pub(super) fn is_asset_name(target: &str) -> bool {
+    Path::new(target)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| {
+            (1..=5).contains(&ext.len())
+                && ext.chars().all(|c| c.is_ascii_alphanumeric())
+                && !ext.eq_ignore_ascii_case("md")
+        })
+}
+
+impl AssetFiles {
+    #[inline]
+    #[must_use]
+    pub fn new(paths: impl IntoIterator<Item = PathBuf>) -> Self {
+        Self {
+            paths: paths.into_iter().map(|path| key(&path)).collect(),
+        }
+    }
+
+    /// Whether `link`, found in the note at `note`, points at an existing file.
+    ///
+    /// Markdown references are first tried relative to the note's folder. Any
+    /// reference also resolves when a scanned file's path ends with the
+    /// target, ignoring case, which covers vault-relative paths and bare
+    /// embedded file names.
+    #[inline]
+    #[must_use]
+    pub fn resolves(&self, note: &Path, link: &Link) -> bool {
+        let target = link.target.replace("%20", " ");
+        let base = note.parent().unwrap_or_else(|| Path::new(""));
+        if link.kind == LinkKind::Markdown && base.join(&target).is_file() {
+            return true;
+        }
+
+        let suffix = key(Path::new(target.trim_start_matches("./")));
+        self.paths.iter().any(|path| path.ends_with(&suffix))
+    }
+
+    /// Positions, in the order given to [`AssetFiles::new`], of the files
+    /// `link` in the note at `note` points at: the file relative to the note
+    /// for a markdown reference, else every file whose path ends with the
+    /// target, as in [`AssetFiles::resolves`].
+    #[inline]
+    #[must_use]
+    pub fn targets(&self, note: &Path, link: &Link) -> Vec<usize> {
+        let target = link.target.replace("%20", " ");
+        let base = note.parent().unwrap_or_else(|| Path::new(""));
+        if link.kind == LinkKind::Markdown {
+            let relative = key(&base.join(&target));
+            if let Some(found) = self.paths.iter().position(|path| *path == relative) {
+                return vec![found];
+            }
+        }
+
+        let suffix = key(Path::new(target.trim_start_matches("./")));
+        (0..self.paths.len())
+            .filter(|&i| self.paths[i].ends_with(&suffix))
+            .collect()
+    }
+}
+
+fn key(path: &Path) -> String {
+    let text = normalize_path(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+        .to_lowercase();
+    if text.starts_with('/') {
+        text
+    } else {
+        format!("/{text}")
+    }
+}
@@ -0,0 +1,488 @@
+mod assets;
+mod relocate;
+mod resolver;
+
+use anyhow::Result;
+use chrono::NaiveDate;
+use std::collections::HashMap;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+use crate::core::note::NoteAnalysis;
+use crate::core::scanner::{ScanOptions, Scanner};
+use crate::core::tasks::{TaskCounts, count_tasks};
+use assets::is_asset_name;
+
+pub use assets::{AssetFiles, extract_assets};
+pub use relocate::{Relocated, apply_relocation, relative_path, relocate, replace_links};
+pub use resolver::{NoteNames, find_note_in, find_note_named, normalize_path};
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn create_test_file(dir: &TempDir, name: &str, content: &str) -> Result<PathBuf> {
+        let path = dir.path().join(name);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, content)?;
+        Ok(path)
+    }
+
+    #[test]
+    fn test_should_extract_wikilinks() {
+        // REQ-LINKS-001
+
+        // Given
+        let body = "See [[alpha]] and [[beta]].";
+
+        // When
+        let links = extract_links(body);
+
+        // Then
+        assert_eq!(links.len(), 2);
+        assert_eq!(links[0], Link::wiki("alpha"));
+        assert_eq!(links[1], Link::wiki("beta"));
+    }
+
+    #[test]
+    fn test_should_strip_alias_and_heading_from_wikilinks() {
+        // REQ-LINKS-002
+
+        // Given
+        let body = "[[alpha|Alpha note]] [[beta#Section]] [[dir/gamma#Part|G]]";
+
+        // When
+        let links = extract_links(body);
+
+        // Then
+        let targets: Vec<&str> = links.iter().map(|l| l.target.as_str()).collect();
+        assert_eq!(targets, vec!["alpha", "beta", "dir/gamma"]);
+    }
+
+    #[test]
+    fn test_should_extract_markdown_links_to_local_files() {
+        // REQ-LINKS-003
+
+        // Given
+        let body = "Read [the note](notes/alpha.md) and [section](beta.md#intro).";
+
+        // When
+        let links = extract_links(body);
+
+        // Then
+        assert_eq!(links.len(), 2);
+        assert_eq!(links[0], Link::markdown("notes/alpha.md"));
+        assert_eq!(links[1], Link::markdown("beta.md"));
+    }
+
+    #[test]
+    fn test_should_ignore_external_urls_and_images() {
+        // REQ-LINKS-004
+
+        // Given
+        let body =
+            "[site](https://example.com) [mail](mailto:a@b.c) ![img](pic.png) [anchor](#top)";
+
+        // When
+        let links = extract_links(body);
+
+        // Then
+        assert!(links.is_empty());
+    }
+
+    #[test]
+    fn test_should_leave_embedded_files_to_assets() {
+        // REQ-LINKS-009
+        let links = extract_links("![[slides.pdf]] ![[other-note]] [[v1.2]]");
+
+        assert_eq!(links, vec![Link::wiki("other-note"), Link::wiki("v1.2")]);
+    }
+
+    #[test]
+    fn test_should_ignore_links_in_code() {
+        // REQ-LINKS-014
+        let body = "[[a]] `[[b]]` ``see `[c](c.md)` ``\n```\n[[d]]\n```\n  ~~~\n[e](e.md)\n~~~\n\
+                    a `stray backtick [[f]]\n```\n[[g]]";
+
+        let links = extract_links(body);
+
+        assert_eq!(links, vec![Link::wiki("a"), Link::wiki("f")]);
+    }
+
+    #[test]
+    fn test_should_derive_stem_from_link_target() {
+        // REQ-LINKS-005
+        assert_eq!(Link::wiki("dir/alpha").stem(), "alpha");
+        assert_eq!(Link::markdown("../notes/beta.md").stem(), "beta");
+    }
+
+    #[test]
+    fn test_should_build_index_of_markdown_notes() -> Result<()> {
+        // REQ-LINKS-006
+
+        // Given
+        let dir = TempDir::new()?;
+        create_test_file(&dir, "a.md", "---\ntags: [writing]\n---\nLinks to [[b]]")?;
+        create_test_file(&dir, "b.md", "No links here")?;
+        create_test_file(&dir, "image.png", "[[a]]")?;
+
+        // When
+        let index = LinkIndex::build(&[dir.path().to_path_buf()], &[])?;
+
+        // Then
+        assert_eq!(index.notes.len(), 2);
+        let a = index.find_by_stem("a").map(|i| &index.notes[i]).unwrap();
+        assert_eq!(a.tags, vec!["writing"]);
+        assert_eq!(a.links, vec![Link::wiki("b")]);
+        assert_eq!(a.words, 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_not_index_links_in_code() -> Result<()> {
+        // REQ-LINKS-014
+        // Given a note that only mentions a link inside code
+        let dir = TempDir::new()?;
+        create_test_file(&dir, "codelink.md", "Target")?;
+        create_test_file(&dir, "a.md", "```\n[[codelink]]\n```\nUse `[[codelink]]`")?;
+
+        // When
+        let index = LinkIndex::build(&[dir.path().to_path_buf()], &[])?;
+
+        // Then it does not link to the note
+        let a = index.find_by_stem("a").map(|i| &index.notes[i]).unwrap();
+        assert!(a.links.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_respect_excluded_directories() -> Result<()> {
+        // REQ-LINKS-007
+
+        // Given
+        let dir = TempDir::new()?;
+        create_test_file(&dir, "a.md", "[[b]]")?;
+        create_test_file(&dir, "excluded/b.md", "[[a]]")?;
+
+        // When
+        let index = LinkIndex::build(&[dir.path().to_path_buf()], &["excluded"])?;
+
+        // Then
+        assert_eq!(index.notes.len(), 1);
+        assert!(index.find_by_stem("b").is_none());
+        Ok(())
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// The syntax a link was written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LinkKind {
+    /// `[[target]]`, `[[target|alias]]` or `[[target#heading]]`
+    Wiki,
+    /// `[text](target.md)`
+    Markdown,
+}
+
+/// An outgoing link found in a note body.
+///
+/// `target` holds the path-like part of the link with any alias,
+/// heading or anchor removed, exactly as written otherwise.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Link {
+    pub target: String,
+    pub kind: LinkKind,
+}
+
+/// A markdown note together with the metadata needed to build a link graph.
+#[derive(Debug, Clone)]
+pub struct NoteNode {
+    pub path: PathBuf,
+    pub stem: String,
+    /// Frontmatter title or first `# heading`, falling back to the file stem
+    pub title: String,
+    /// Alternative names from the `aliases` frontmatter property
+    pub aliases: Vec<String>,
+    pub tags: Vec<String>,
+    pub words: usize,
+    /// Frontmatter `created` date, else the zettel ID in the file name
+    pub created: Option<NaiveDate>,
+    /// Task checkboxes in the body
+    pub tasks: TaskCounts,
+    pub links: Vec<Link>,
+}
+
+/// Every scanned note and its outgoing links.
+///
+/// Notes are addressed by their position in `notes`; commands such as
+/// backlinks or graph export build on top of this index.
+#[derive(Debug, Default)]
+pub struct LinkIndex {
+    pub notes: Vec<NoteNode>,
+    by_stem: HashMap<String, Vec<usize>>,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+impl Link {
+    #[inline]
+    #[must_use]
+    pub fn wiki(target: &str) -> Self {
+        Self {
+            target: target.to_owned(),
+            kind: LinkKind::Wiki,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn markdown(target: &str) -> Self {
+        Self {
+            target: target.to_owned(),
+            kind: LinkKind::Markdown,
+        }
+    }
+
+    /// File name of the target without directories or a `.md` extension.
+    #[inline]
+    #[must_use]
+    pub fn stem(&self) -> &str {
+        let name = self.target.rsplit('/').next().unwrap_or(&self.target);
+        name.strip_suffix(".md").unwrap_or(name)
+    }
+}
+
+impl LinkIndex {
+    /// Builds an index from already collected notes.
+    #[inline]
+    #[must_use]
+    pub fn from_notes(notes: Vec<NoteNode>) -> Self {
+        let mut by_stem: HashMap<String, Vec<usize>> = HashMap::new();
+        for (i, note) in notes.iter().enumerate() {
+            by_stem.entry(note.stem.to_lowercase()).or_default().push(i);
+        }
+        Self { notes, by_stem }
+    }
+
+    /// Scans markdown notes in the given directories and records their links.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a directory cannot be walked or the ignore
+    /// patterns cannot be loaded.
+    #[inline]
+    pub fn build(dirs: &[PathBuf], exclude: &[&str]) -> Result<Self> {
+        Self::build_in(&ScanOptions::new(dirs).exclude(exclude).build()?)
+    }
+
+    /// [`LinkIndex::build`] over the notes of an already configured scanner.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the scan fails.
+    #[inline]
+    pub fn build_in(scanner: &Scanner) -> Result<Self> {
+        let mut notes = Vec::new();
+        for note in scanner.notes() {
+            let note = note?;
+            if scanner.is_note(&note.path) {
+                notes.push(note_from_analysis(&note));
+            }
+        }
+        Ok(Self::from_notes(notes))
+    }
+
+    /// Index of the first note whose file stem equals `stem`, ignoring case.
+    #[inline]
+    #[must_use]
+    pub fn find_by_stem(&self, stem: &str) -> Option<usize> {
+        self.stem_matches(stem).first().copied()
+    }
+
+    /// Indices of every note whose file stem equals `stem`, ignoring case.
+    #[inline]
+    #[must_use]
+    pub fn stem_matches(&self, stem: &str) -> &[usize] {
+        self.by_stem
+            .get(&stem.to_lowercase())
+            .map_or(&[], Vec::as_slice)
+    }
+}
+
+/// Builds a [`NoteNode`] from a note's path and raw content.
+#[inline]
+#[must_use]
+pub fn note_from_content(path: &Path, content: &str) -> NoteNode {
+    note_from_analysis(&NoteAnalysis::from_content(path, content.to_owned()))
+}
+
+/// Builds a [`NoteNode`] from an already analyzed note.
+#[inline]
+#[must_use]
+pub fn note_from_analysis(note: &NoteAnalysis) -> NoteNode {
+    let stem = note
+        .path
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    NoteNode {
+        path: note.path.clone(),
+        title: note.title().unwrap_or_else(|| stem.clone()),
+        stem,
+        aliases: note.frontmatter.aliases.clone(),
+        tags: note.tags.clone(),
+        words: note.words,
+        created: note.created(),
+        tasks: count_tasks(&note.content),
+        links: extract_links(note.body()),
+    }
+}
+
+/// Extracts wikilinks and local markdown links from note body text, in order of appearance.
+/// Links inside fenced code blocks and inline code spans are code, not links.
+#[inline]
+#[must_use]
+pub fn extract_links(body: &str) -> Vec<Link> {
+    let code = code_ranges(body);
+    let mut links = Vec::new();
+    let mut pos = 0;
+
+    while let Some(found) = body[pos..].find('[') {
+        let start = pos + found;
+        if let Some(end) = code_end(&code, start) {
+            pos = end;
+            continue;
+        }
+        if body[start..].starts_with("[[") {
+            let after = &body[start + 2..];
+            let Some(end) = after.find("]]") else {
+                break;
+            };
+            let is_embed = body[..start].ends_with('!');
+            if let Some(target) = clean_target(&after[..end], &['|', '#']) {
+                // Embedded files are assets; embedded notes still link
+                if !(is_embed && is_asset_name(target)) {
+                    links.push(Link::wiki(target));
+                }
+            }
+            pos = start + 2 + end + 2;
+            continue;
+        }
+
+        let is_image = body[..start].ends_with('!');
+        pos = start + 1;
+        if let Some((target, remaining)) = markdown_target(&body[pos..]) {
+            if !is_image && is_local_target(target) {
+                if let Some(target) = clean_target(target, &['#', '?']) {
+                    links.push(Link::markdown(target));
+                }
+            }
+            pos = body.len() - remaining.len();
+        }
+    }
+
+    links
+}
+
+/// Byte ranges of the fenced code blocks and inline code spans in `body`, in
+/// order. Fences are recognized like [`crate::core::frontmatter::note_title`]
+/// does; an unclosed fence runs to the end of the body.
+fn code_ranges(body: &str) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+    let mut fence_start = None;
+    let mut text_start = 0;
+    let mut offset = 0;
+    for line in body.split_inclusive('\n') {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            match fence_start.take() {
+                Some(start) => {
+                    ranges.push(start..offset + line.len());
+                    text_start = offset + line.len();
+                }
+                None => {
+                    inline_code(body, text_start..offset, &mut ranges);
+                    fence_start = Some(offset);
+                }
+            }
+        }
+        offset += line.len();
+    }
+    match fence_start {
+        Some(start) => ranges.push(start..body.len()),
+        None => inline_code(body, text_start..body.len(), &mut ranges),
+    }
+    ranges
+}
+
+/// Add the inline code spans in `text` to `ranges`: a run of backticks up to
+/// the next run of the same length. A run without one is literal text.
+fn inline_code(body: &str, text: Range<usize>, ranges: &mut Vec<Range<usize>>) {
+    let run = |at: usize| {
+        body.as_bytes()[at..text.end]
+            .iter()
+            .take_while(|&&b| b == b'`')
+            .count()
+    };
+    let mut pos = text.start;
+    while let Some(found) = body[pos..text.end].find('`') {
+        let start = pos + found;
+        let ticks = run(start);
+        pos = start + ticks;
+        let mut search = pos;
+        while let Some(found) = body[search..text.end].find('`') {
+            let close = search + found;
+            let len = run(close);
+            if len == ticks {
+                ranges.push(start..close + len);
+                pos = close + len;
+                break;
+            }
+            search = close + len;
+        }
+    }
+}
+
+/// Where the code range holding byte `at` ends, if `at` is inside one.
+fn code_end(code: &[Range<usize>], at: usize) -> Option<usize> {
+    code.iter()
+        .find(|range| range.contains(&at))
+        .map(|range| range.end)
+}
+
+/// Parses `text](target)` and returns the target plus the text after the link.
+fn markdown_target(rest: &str) -> Option<(&str, &str)> {
+    let close = rest.find(']')?;
+    if rest[..close].contains('[') {
+        return None;
+    }
+    let after = rest[close + 1..].strip_prefix('(')?;
+    let end = after.find(')')?;
+    Some((after[..end].trim(), &after[end + 1..]))
+}
+
+fn is_local_target(target: &str) -> bool {
+    !target.is_empty()
+        && !target.starts_with('#')
+        && !target.contains("://")
+        && !target.starts_with("mailto:")
+}
+
+/// Cuts the target at the first separator and trims it, dropping empty results.
+fn clean_target<'a>(raw: &'a str, separators: &[char]) -> Option<&'a str> {
+    let target = raw.split(separators).next().unwrap_or(raw).trim();
+    (!target.is_empty()).then_some(target)
+}
@@ -0,0 +1,436 @@
+use anyhow::{Context, Result, bail};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use super::resolver::decode_spaces;
+use super::{
+    Link, LinkIndex, LinkKind, clean_target, code_end, code_ranges, is_local_target,
+    markdown_target, normalize_path, note_from_analysis,
+};
+use crate::core::note::NoteAnalysis;
+use crate::core::scanner::Scanner;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::scanner::ScanOptions;
+    use crate::core::vfs::MemoryFs;
+
+    #[test]
+    fn test_should_replace_link_targets_in_place() {
+        // REQ-LINKS-011
+        let body =
+            "[[a|A]] ![[b#Part]] [a.md](a.md#top) ![pic](<img/x.png> \"X\") [site](https://x.y)";
+
+        let mut seen = Vec::new();
+        let replaced = replace_links(body, |link| {
+            seen.push(link.clone());
+            Some(link.target.to_uppercase())
+        });
+
+        assert_eq!(
+            replaced,
+            "[[A|A]] ![[B#Part]] [a.md](A.MD#top) ![pic](<IMG/X.PNG> \"X\") [site](https://x.y)"
+        );
+        assert_eq!(
+            seen,
+            vec![
+                Link::wiki("a"),
+                Link::wiki("b"),
+                Link::markdown("a.md"),
+                Link::markdown("img/x.png"),
+            ]
+        );
+        assert_eq!(replace_links(body, |_| None), body);
+    }
+
+    #[test]
+    fn test_should_leave_links_in_code_as_written() {
+        // REQ-LINKS-014
+        let body = "[[a]] `[[a]]` ``[a](a.md) ` x``\n```md\n[[a]]\n```\n~~~\n[a](a.md)\n~~~\n[[a]]";
+
+        let replaced = replace_links(body, |_| Some("b".to_owned()));
+
+        assert_eq!(
+            replaced,
+            "[[b]] `[[a]]` ``[a](a.md) ` x``\n```md\n[[a]]\n```\n~~~\n[a](a.md)\n~~~\n[[b]]"
+        );
+    }
+
+    #[test]
+    fn test_should_write_paths_relative_to_a_folder() {
+        // REQ-LINKS-012
+        let to = Path::new("/v/notes/a.md");
+        assert_eq!(relative_path(Path::new("/v/notes"), to), "a.md");
+        assert_eq!(
+            relative_path(Path::new("/v/archive/x"), to),
+            "../../notes/a.md"
+        );
+        assert_eq!(relative_path(Path::new("/v"), to), "notes/a.md");
+    }
+
+    #[test]
+    fn test_should_update_links_broken_by_moving_notes() -> Result<()> {
+        // REQ-LINKS-013
+        // Given notes linking to each other with wikilinks and relative paths
+        let vfs = MemoryFs::new()
+            .with_file(
+                "/v/notes/a.md",
+                "---\ntags: [done]\n---\n[[b]] [[notes/b|B]] [b](b.md) ![](img/x.png) [c](../c.md)",
+            )
+            .with_file("/v/notes/b.md", "[[a]] [a](a.md) [gone](gone.md)")
+            .with_file("/v/notes/img/x.png", "")
+            .with_file("/v/c.md", "[a](notes/a.md#intro) [[a]]");
+        let scanner = ScanOptions::new(["/v"]).vfs(vfs).build()?;
+
+        // When a is archived and b is renamed
+        let moves = vec![
+            (
+                PathBuf::from("/v/notes/a.md"),
+                PathBuf::from("/v/archive/notes/a.md"),
+            ),
+            (
+                PathBuf::from("/v/notes/b.md"),
+                PathBuf::from("/v/notes/b2.md"),
+            ),
+        ];
+        let relocated = relocate(&scanner, &moves)?;
+
+        // Then moved notes point where they did, and links to them follow
+        let found: Vec<(&Path, &Path, Option<&str>)> = relocated
+            .iter()
+            .map(|r| (r.from.as_path(), r.to.as_path(), r.content.as_deref()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (
+                    Path::new("/v/notes/a.md"),
+                    Path::new("/v/archive/notes/a.md"),
+                    Some(
+                        "---\ntags: [done]\n---\n[[b2]] [[notes/b2|B]] [b](../../notes/b2.md) \
+                         ![](../../notes/img/x.png) [c](../../c.md)"
+                    ),
+                ),
+                (
+                    Path::new("/v/notes/b.md"),
+                    Path::new("/v/notes/b2.md"),
+                    Some("[[a]] [a](../archive/notes/a.md) [gone](gone.md)"),
+                ),
+                (
+                    Path::new("/v/c.md"),
+                    Path::new("/v/c.md"),
+                    Some("[a](archive/notes/a.md#intro) [[a]]"),
+                ),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_refuse_moving_two_notes_to_one_path() -> Result<()> {
+        // REQ-LINKS-013
+        let vfs = MemoryFs::new()
+            .with_file("/v/a/n.md", "")
+            .with_file("/v/b/n.md", "");
+        let scanner = ScanOptions::new(["/v"]).vfs(vfs).build()?;
+        let moves = vec![
+            (PathBuf::from("/v/a/n.md"), PathBuf::from("/v/n.md")),
+            (PathBuf::from("/v/b/n.md"), PathBuf::from("/v/n.md")),
+        ];
+
+        let error = relocate(&scanner, &moves).unwrap_err().to_string();
+
+        assert_eq!(error, "/v/a/n.md and /v/b/n.md would both move to /v/n.md");
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_refuse_rewriting_notes_read_lossily() -> Result<()> {
+        // REQ-LINKS-013
+        // Given a Latin-1 note linking to the note being moved, in a lossy scan
+        let vfs = MemoryFs::new()
+            .with_file("/v/a.md", "")
+            .with_file("/v/latin.md", b"caf\xe9 [a](a.md)".to_vec())
+            .with_file("/v/other.md", b"caf\xe9".to_vec());
+        let scanner = ScanOptions::new(["/v"]).vfs(vfs).lossy(true).build()?;
+        let moves = [(PathBuf::from("/v/a.md"), PathBuf::from("/v/b.md"))];
+
+        // When planning the move, then the note is left unwritten
+        let error = relocate(&scanner, &moves).unwrap_err().to_string();
+        assert_eq!(
+            error,
+            "Cannot rewrite links in /v/latin.md: it is not valid UTF-8"
+        );
+
+        // A lossily read note whose links do not change is no obstacle
+        let moves = [(PathBuf::from("/v/other.md"), PathBuf::from("/v/b.md"))];
+        assert_eq!(relocate(&scanner, &moves)?.len(), 1);
+        Ok(())
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// A note to move or rewrite after moving notes, see [`relocate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Relocated {
+    pub from: PathBuf,
+    /// Where the note ends up; `from` for notes whose links changed only
+    pub to: PathBuf,
+    /// The note's new content, when links in it changed
+    pub content: Option<String>,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+impl Relocated {
+    #[inline]
+    #[must_use]
+    pub fn moves(&self) -> bool {
+        self.from != self.to
+    }
+}
+
+/// Plan moving notes from the first to the second path of each pair, fixing
+/// the links moving them breaks: relative markdown links and images in moved
+/// notes keep pointing at the same files, links to moved notes follow them,
+/// and wikilinks to a renamed note use its new name.
+///
+/// Every scanned note is checked for links, so scan the whole vault. Links
+/// that did not resolve are left as they are. Moved notes come first, in the
+/// order given, then the other notes to rewrite in walk order.
+///
+/// # Errors
+///
+/// Returns an error if the scan fails, two notes would move to one path, or
+/// links change in a note that is not valid UTF-8.
+pub fn relocate(scanner: &Scanner, moves: &[(PathBuf, PathBuf)]) -> Result<Vec<Relocated>> {
+    let mut moved: HashMap<PathBuf, PathBuf> = HashMap::new();
+    let mut destinations: HashMap<PathBuf, &Path> = HashMap::new();
+    for (from, to) in moves {
+        let to = normalize_path(to);
+        if let Some(other) = destinations.insert(to.clone(), from) {
+            bail!(
+                "{} and {} would both move to {}",
+                other.display(),
+                from.display(),
+                to.display()
+            );
+        }
+        moved.insert(normalize_path(from), to);
+    }
+
+    let files = scanner.files()?;
+    let known: HashSet<PathBuf> = files.iter().map(|path| normalize_path(path)).collect();
+    let mut notes = Vec::new();
+    for path in &files {
+        if !scanner.is_note(path) {
+            continue;
+        }
+        if let Some(content) = scanner.read_note(path)? {
+            notes.push(NoteAnalysis::from_content(path, content));
+        }
+    }
+    // Notes decoded lossily would lose their original bytes when written back
+    let not_utf8 = scanner.report().not_utf8;
+    let index = LinkIndex::from_notes(notes.iter().map(note_from_analysis).collect());
+
+    let mut relocated = Vec::new();
+    for (i, note) in notes.iter().enumerate() {
+        let from = normalize_path(&note.path);
+        let to = moved.get(&from).cloned().unwrap_or_else(|| from.clone());
+        let body = note.body();
+        let relinked = replace_links(body, |link| match link.kind {
+            LinkKind::Markdown => {
+                let base = from.parent().unwrap_or_else(|| Path::new(""));
+                let target = normalize_path(&base.join(decode_spaces(&link.target)));
+                if !known.contains(&target) {
+                    return None;
+                }
+                let new_target = moved.get(&target).unwrap_or(&target);
+                if from == to && *new_target == target {
+                    return None;
+                }
+                let base = to.parent().unwrap_or_else(|| Path::new(""));
+                let path = relative_path(base, new_target);
+                Some(if link.target.contains("%20") {
+                    path.replace(' ', "%20")
+                } else {
+                    path
+                })
+            }
+            LinkKind::Wiki => {
+                let target = index.resolve(i, link)?;
+                let new_path = moved.get(&normalize_path(&index.notes[target].path))?;
+                let renamed = wiki_target(&link.target, new_path);
+                (!renamed.eq_ignore_ascii_case(&link.target)).then_some(renamed)
+            }
+        });
+        let content = (relinked != body).then(|| {
+            format!(
+                "{}{relinked}",
+                &note.content[..note.content.len() - body.len()]
+            )
+        });
+        if content.is_some() && not_utf8.contains(&note.path) {
+            bail!(
+                "Cannot rewrite links in {}: it is not valid UTF-8",
+                note.path.display()
+            );
+        }
+        if from != to || content.is_some() {
+            relocated.push(Relocated { from, to, content });
+        }
+    }
+
+    let order: HashMap<PathBuf, usize> = moves
+        .iter()
+        .enumerate()
+        .map(|(i, (from, _))| (normalize_path(from), i))
+        .collect();
+    relocated.sort_by_key(|r| order.get(&r.from).map_or((1, 0), |&i| (0, i)));
+    Ok(relocated)
+}
+
+/// Move and rewrite notes as planned by [`relocate`], creating folders as
+/// needed. Nothing is changed when a note would replace an existing file.
+///
+/// # Errors
+///
+/// Returns an error if a destination exists or a file cannot be written,
+/// moved or removed.
+pub fn apply_relocation(relocated: &[Relocated]) -> Result<()> {
+    if let Some(taken) = relocated.iter().find(|r| r.moves() && r.to.exists()) {
+        bail!(
+            "Cannot move {}: {} already exists",
+            taken.from.display(),
+            taken.to.display()
+        );
+    }
+    for note in relocated {
+        if let Some(parent) = note.to.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Cannot create {}", parent.display()))?;
+        }
+        match &note.content {
+            Some(content) => {
+                fs::write(&note.to, content)
+                    .with_context(|| format!("Cannot write {}", note.to.display()))?;
+                if note.moves() {
+                    fs::remove_file(&note.from)
+                        .with_context(|| format!("Cannot remove {}", note.from.display()))?;
+                }
+            }
+            None => fs::rename(&note.from, &note.to).with_context(|| {
+                format!(
+                    "Cannot move {} to {}",
+                    note.from.display(),
+                    note.to.display()
+                )
+            })?,
+        }
+    }
+    Ok(())
+}
+
+/// Rewrite link targets in note body text. `replace` sees every wikilink and
+/// every local markdown link or image, in order of appearance, and returns
+/// the target to write instead, if any. Aliases, headings, anchors and image
+/// titles around the target stay as written, and so does anything in fenced
+/// code blocks or inline code spans.
+#[inline]
+#[must_use]
+pub fn replace_links(body: &str, mut replace: impl FnMut(&Link) -> Option<String>) -> String {
+    let code = code_ranges(body);
+    let mut replaced = String::with_capacity(body.len());
+    let mut copied = 0;
+    let mut pos = 0;
+
+    while let Some(found) = body[pos..].find('[') {
+        let start = pos + found;
+        if let Some(end) = code_end(&code, start) {
+            pos = end;
+            continue;
+        }
+        let (at, link) = if body[start..].starts_with("[[") {
+            let inner = start + 2;
+            let Some(len) = body[inner..].find("]]") else {
+                break;
+            };
+            pos = inner + len + 2;
+            let raw = &body[inner..inner + len];
+            let Some(target) = clean_target(raw, &['|', '#']) else {
+                continue;
+            };
+            (inner + raw.find(target).unwrap_or(0), Link::wiki(target))
+        } else {
+            pos = start + 1;
+            let Some((target, remaining)) = markdown_target(&body[pos..]) else {
+                continue;
+            };
+            let destination = pos + body[pos..].find("](").unwrap_or(0) + 2;
+            pos = body.len() - remaining.len();
+            let target = target.split(" \"").next().unwrap_or(target);
+            let target = target.trim_start_matches('<').trim_end_matches('>');
+            if !is_local_target(target) {
+                continue;
+            }
+            let Some(target) = clean_target(target, &['#', '?']) else {
+                continue;
+            };
+            let at = destination + body[destination..pos].find(target).unwrap_or(0);
+            (at, Link::markdown(target))
+        };
+
+        if let Some(new_target) = replace(&link) {
+            replaced.push_str(&body[copied..at]);
+            replaced.push_str(&new_target);
+            copied = at + link.target.len();
+        }
+    }
+
+    replaced.push_str(&body[copied..]);
+    replaced
+}
+
+/// The path of `to` relative to the folder `from`, with `/` separators, for
+/// a markdown link written in a note in `from`.
+#[inline]
+#[must_use]
+pub fn relative_path(from: &Path, to: &Path) -> String {
+    let from: Vec<Component<'_>> = from.components().collect();
+    let to: Vec<Component<'_>> = to.components().collect();
+    let common = from.iter().zip(&to).take_while(|(a, b)| a == b).count();
+    let mut parts = vec![String::from(".."); from.len() - common];
+    parts.extend(
+        to[common..]
+            .iter()
+            .map(|part| part.as_os_str().to_string_lossy().to_string()),
+    );
+    parts.join("/")
+}
+
+/// A wikilink target naming the note at `path` the way `old` named it: as
+/// many trailing folders, and a `.md` extension only if `old` had one.
+fn wiki_target(old: &str, path: &Path) -> String {
+    let parts: Vec<String> = path
+        .components()
+        .filter(|part| matches!(part, Component::Normal(_)))
+        .map(|part| part.as_os_str().to_string_lossy().to_string())
+        .collect();
+    let keep = old.split('/').count().min(parts.len());
+    let target = parts[parts.len() - keep..].join("/");
+    match target.strip_suffix(".md") {
+        Some(stem) if !old.ends_with(".md") => stem.to_owned(),
+        _ => target,
+    }
+}
@@ -0,0 +1,274 @@
+use std::path::{Component, Path, PathBuf};
+
+use super::{Link, LinkIndex, LinkKind};
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::links::note_from_content;
+
+    fn index(notes: &[(&str, &str)]) -> LinkIndex {
+        LinkIndex::from_notes(
+            notes
+                .iter()
+                .map(|(path, content)| note_from_content(Path::new(path), content))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_should_resolve_wikilink_by_stem_ignoring_case() {
+        // REQ-LINKS-101
+
+        // Given
+        let index = index(&[("/v/a.md", "[[Beta]]"), ("/v/sub/beta.md", "")]);
+
+        // When
+        let resolved = index.resolve(0, &index.notes[0].links[0]);
+
+        // Then
+        assert_eq!(resolved, Some(1));
+    }
+
+    #[test]
+    fn test_should_prefer_path_match_for_wikilinks_with_folders() {
+        // REQ-LINKS-102
+
+        // Given
+        let index = index(&[
+            ("/v/a.md", "[[work/beta]]"),
+            ("/v/home/beta.md", ""),
+            ("/v/work/beta.md", ""),
+        ]);
+
+        // When
+        let resolved = index.resolve(0, &index.notes[0].links[0]);
+
+        // Then
+        assert_eq!(resolved, Some(2));
+    }
+
+    #[test]
+    fn test_should_resolve_markdown_link_relative_to_source_note() {
+        // REQ-LINKS-103
+
+        // Given
+        let index = index(&[
+            ("/v/notes/a.md", "[b](../other/b.md)"),
+            ("/v/notes/b.md", ""),
+            ("/v/other/b.md", ""),
+        ]);
+
+        // When
+        let resolved = index.resolve(0, &index.notes[0].links[0]);
+
+        // Then
+        assert_eq!(resolved, Some(2));
+    }
+
+    #[test]
+    fn test_should_not_resolve_missing_targets() {
+        // REQ-LINKS-104
+        let index = index(&[("/v/a.md", "[[missing]] [m](missing.md)")]);
+        assert_eq!(index.resolve(0, &index.notes[0].links[0]), None);
+        assert_eq!(index.resolve(0, &index.notes[0].links[1]), None);
+    }
+
+    #[test]
+    fn test_should_find_note_by_path_or_stem() {
+        // REQ-LINKS-105
+        let index = index(&[("/v/./a.md", ""), ("/v/b.md", "")]);
+        assert_eq!(index.find_note("/v/a.md"), Some(0));
+        assert_eq!(index.find_note("b"), Some(1));
+        assert_eq!(index.find_note("b.md"), Some(1));
+        assert_eq!(index.find_note("c"), None);
+    }
+
+    #[test]
+    fn test_should_find_note_by_title_or_alias() {
+        // REQ-LINKS-106
+        let index = index(&[
+            (
+                "/v/202401311542.md",
+                "---\naliases: [Loops]\n---\n# Feedback loops",
+            ),
+            ("/v/loops.md", ""),
+            ("/v/c.md", "---\ntitle: Stocks\naliases: Flows\n---\n"),
+        ]);
+        assert_eq!(index.find_note("feedback LOOPS"), Some(0));
+        assert_eq!(index.find_note("flows"), Some(2));
+        assert_eq!(index.find_note("Stocks"), Some(2));
+        // A file name still wins over another note's alias
+        assert_eq!(index.find_note("loops"), Some(1));
+        assert_eq!(index.find_note("Feedback"), None);
+    }
+
+    #[test]
+    fn test_should_normalize_dot_components() {
+        assert_eq!(
+            normalize_path(Path::new("/v/./notes/../a.md")),
+            PathBuf::from("/v/a.md")
+        );
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// What a note can be looked up by on the command line, see [`find_note_named`].
+#[derive(Debug, Clone, Copy)]
+pub struct NoteNames<'a> {
+    pub path: &'a Path,
+    pub title: Option<&'a str>,
+    pub aliases: &'a [String],
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+impl LinkIndex {
+    /// Resolves a link written in note `from` to the index of the note it points at.
+    ///
+    /// Markdown links are resolved relative to the linking note's folder.
+    /// Wikilinks are resolved by file stem; when several notes share a stem,
+    /// a wikilink that names folders picks the note whose path ends with them.
+    /// Unresolved markdown links fall back to stem matching as well.
+    #[inline]
+    #[must_use]
+    pub fn resolve(&self, from: usize, link: &Link) -> Option<usize> {
+        if link.kind == LinkKind::Markdown {
+            let source = self.notes.get(from)?;
+            let base = source.path.parent().unwrap_or_else(|| Path::new(""));
+            let target = normalize_path(&base.join(decode_spaces(&link.target)));
+            if let Some(found) = self.find_by_path(&target) {
+                return Some(found);
+            }
+        }
+
+        let candidates = self.stem_matches(link.stem());
+        if candidates.len() > 1 && link.target.contains('/') {
+            let suffix = link.target.trim_end_matches(".md").to_lowercase();
+            let by_folder = candidates.iter().copied().find(|&i| {
+                let path = self.notes[i].path.with_extension("");
+                path.to_string_lossy().to_lowercase().ends_with(&suffix)
+            });
+            if by_folder.is_some() {
+                return by_folder;
+            }
+        }
+        candidates.first().copied()
+    }
+
+    /// Looks up a note given on the command line by path, note name, title
+    /// or alias, see [`find_note_named`].
+    #[inline]
+    #[must_use]
+    pub fn find_note(&self, query: &str) -> Option<usize> {
+        let names: Vec<NoteNames<'_>> = self
+            .notes
+            .iter()
+            .map(|n| NoteNames {
+                path: &n.path,
+                title: Some(&n.title),
+                aliases: &n.aliases,
+            })
+            .collect();
+        find_note_named(&names, query)
+    }
+
+    /// Indices of the notes linking to `target`, in scan order and without duplicates.
+    #[inline]
+    #[must_use]
+    pub fn backlinks(&self, target: usize) -> Vec<usize> {
+        (0..self.notes.len())
+            .filter(|&source| source != target)
+            .filter(|&source| {
+                self.notes[source]
+                    .links
+                    .iter()
+                    .any(|link| self.resolve(source, link) == Some(target))
+            })
+            .collect()
+    }
+
+    fn find_by_path(&self, path: &Path) -> Option<usize> {
+        self.notes
+            .iter()
+            .position(|note| normalize_path(&note.path) == path)
+    }
+}
+
+/// Finds the note named by `query` among `paths`: an exact path (absolute or
+/// relative to the current directory) wins, otherwise the first file whose stem
+/// matches, ignoring case and an optional `.md` extension.
+#[inline]
+#[must_use]
+pub fn find_note_in(paths: &[&Path], query: &str) -> Option<usize> {
+    let as_path = Path::new(query);
+    let absolute = if as_path.is_absolute() {
+        as_path.to_path_buf()
+    } else {
+        std::env::current_dir().ok()?.join(as_path)
+    };
+    let wanted = normalize_path(&absolute);
+    if let Some(found) = paths.iter().position(|p| normalize_path(p) == wanted) {
+        return Some(found);
+    }
+
+    let stem = as_path
+        .file_stem()
+        .map_or_else(|| query.to_owned(), |s| s.to_string_lossy().to_string())
+        .to_lowercase();
+    paths.iter().position(|p| {
+        p.file_stem()
+            .is_some_and(|s| s.to_string_lossy().to_lowercase() == stem)
+    })
+}
+
+/// Finds the note named by `query` as [`find_note_in`] does, falling back to
+/// the first note whose title, then whose alias, equals `query`, ignoring case.
+#[inline]
+#[must_use]
+pub fn find_note_named(notes: &[NoteNames<'_>], query: &str) -> Option<usize> {
+    let paths: Vec<&Path> = notes.iter().map(|n| n.path).collect();
+    find_note_in(&paths, query).or_else(|| {
+        let wanted = query.trim().to_lowercase();
+        notes
+            .iter()
+            .position(|n| n.title.is_some_and(|t| t.trim().to_lowercase() == wanted))
+            .or_else(|| {
+                notes.iter().position(|n| {
+                    n.aliases
+                        .iter()
+                        .any(|alias| alias.trim().to_lowercase() == wanted)
+                })
+            })
+    })
+}
+
+/// Lexically removes `.` and `..` components so paths can be compared without
+/// touching the filesystem.
+#[inline]
+#[must_use]
+pub fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other.as_os_str()),
+        }
+    }
+    normalized
+}
+
+pub(super) fn decode_spaces(target: &str) -> String {
+    target.replace("%20", " ")
+}
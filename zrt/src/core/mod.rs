@@ -1,4 +1,14 @@
+pub mod analyzer;
+pub mod error;
 pub mod filter;
 pub mod frontmatter;
+pub mod history;
 pub mod ignore;
+pub mod links;
+pub mod note;
 pub mod patterns;
+pub mod scanner;
+pub mod status;
+pub mod tasks;
+pub mod timings;
+pub mod vfs;
@@ -0,0 +1,258 @@
+use chrono::NaiveDate;
+use std::io;
+use std::path::{Path, PathBuf};
+use tracing::{debug, warn};
+
+use crate::core::frontmatter::{
+    Frontmatter, MetadataFormat, countable_text_in, filename_date, parse_frontmatter_in,
+    strip_frontmatter_in, title_from_body,
+};
+use crate::core::timings::{Stage, Timings, time};
+use crate::core::vfs::{RealFs, Vfs};
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::vfs::MemoryFs;
+
+    #[test]
+    fn test_should_split_note_into_frontmatter_tags_body_and_words() {
+        // REQ-NOTE-001
+        let note = NoteAnalysis::from_content(
+            Path::new("a.md"),
+            "---\ntags: [todo, '#idea']\n---\nOne two\nthree".to_owned(),
+        );
+
+        assert_eq!(note.tags, vec!["todo", "idea"]);
+        assert_eq!(note.body(), "\nOne two\nthree");
+        assert_eq!(note.words, 3);
+        assert_eq!(note.word_count(true), 8);
+        assert_eq!(note.countable_text(false).lines().count(), 2);
+        assert!(note.has_any_tag(&["idea", "done"]));
+        assert!(note.frontmatter_error.is_none());
+        assert_eq!(note.title(), None);
+    }
+
+    #[test]
+    fn test_should_keep_body_when_frontmatter_is_invalid() {
+        // REQ-NOTE-002
+        let note = NoteAnalysis::from_content(
+            Path::new("a.md"),
+            "---\ntags: [unclosed\n---\nStill counted".to_owned(),
+        );
+
+        assert!(note.tags.is_empty());
+        assert!(note.frontmatter.tags.is_none());
+        assert!(
+            note.frontmatter_error
+                .as_deref()
+                .is_some_and(|error| error.contains("front matter"))
+        );
+        assert_eq!(note.words, 2);
+    }
+
+    #[test]
+    fn test_should_read_through_a_vfs() -> anyhow::Result<()> {
+        // REQ-NOTE-003
+        let vfs = MemoryFs::new()
+            .with_file("/vault/a.md", "one two")
+            .with_file("/vault/image.md", [0x00, 0x01]);
+
+        let note = NoteAnalysis::read_in(&vfs, Path::new("/vault/a.md"))?;
+
+        assert_eq!(
+            (note.path.as_path(), note.words),
+            (Path::new("/vault/a.md"), 2)
+        );
+        assert!(NoteAnalysis::read_in(&vfs, Path::new("/vault/image.md")).is_err());
+        assert!(NoteAnalysis::read_frontmatter_in(&vfs, Path::new("/vault/image.md")).is_err());
+        Ok(())
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// A note read once and parsed once: its frontmatter, tags, body and word
+/// count, shared by every scan so they all count a note the same way.
+#[derive(Debug, Clone)]
+pub struct NoteAnalysis {
+    pub path: PathBuf,
+    /// The whole file, frontmatter included
+    pub content: String,
+    /// Parsed frontmatter, the default when it is missing or invalid
+    pub frontmatter: Frontmatter,
+    /// Why the frontmatter could not be parsed, if it could not
+    pub frontmatter_error: Option<String>,
+    /// Frontmatter tags without any leading `#`
+    pub tags: Vec<String>,
+    /// Words in the body, excluding frontmatter
+    pub words: usize,
+    /// Where the body starts in `content`, after the frontmatter or header
+    body_start: usize,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+impl NoteAnalysis {
+    /// Analyze a note's content.
+    #[inline]
+    #[must_use]
+    pub fn from_content(path: &Path, content: String) -> Self {
+        Self::from_content_in(path, content, &[])
+    }
+
+    /// Analyze a note's content, reading a header in one of `formats` when
+    /// it has no YAML frontmatter, see [`parse_frontmatter_in`].
+    #[inline]
+    #[must_use]
+    pub fn from_content_in(path: &Path, content: String, formats: &[MetadataFormat]) -> Self {
+        Self::from_content_timed(path, content, formats, None)
+    }
+
+    /// [`NoteAnalysis::from_content_in`], adding the time spent parsing
+    /// frontmatter and counting words to `timings`.
+    pub(crate) fn from_content_timed(
+        path: &Path,
+        content: String,
+        formats: &[MetadataFormat],
+        timings: Option<&Timings>,
+    ) -> Self {
+        let parsed = time(timings, Stage::Frontmatter, || {
+            parse_frontmatter_in(&content, formats)
+        });
+        let (frontmatter, frontmatter_error) = match parsed {
+            Ok(frontmatter) => (frontmatter, None),
+            Err(error) => {
+                debug!(path = %path.display(), %error, "cannot parse frontmatter");
+                (Frontmatter::default(), Some(error.to_string()))
+            }
+        };
+        if !frontmatter.skipped_tags().is_empty() {
+            warn!(
+                path = %path.display(),
+                skipped = %frontmatter.skipped_tags().join(", "),
+                "ignoring tags that are not text"
+            );
+        }
+        let tags = frontmatter.tags.clone().unwrap_or_default();
+        let words = time(timings, Stage::WordCount, || {
+            countable_text_in(&content, false, formats)
+                .split_whitespace()
+                .count()
+        });
+        let body_start = content.len() - strip_frontmatter_in(&content, formats).len();
+        Self {
+            path: path.to_path_buf(),
+            content,
+            frontmatter,
+            frontmatter_error,
+            tags,
+            words,
+            body_start,
+        }
+    }
+
+    /// Read and analyze a note from disk.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read as text.
+    #[inline]
+    pub fn read(path: &Path) -> io::Result<Self> {
+        Self::read_in(&RealFs, path)
+    }
+
+    /// Read and analyze a note through the given filesystem.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read as text.
+    #[inline]
+    pub fn read_in(vfs: &dyn Vfs, path: &Path) -> io::Result<Self> {
+        Ok(Self::from_content(path, vfs.read_to_string(path)?))
+    }
+
+    /// Read and analyze only a note's frontmatter from disk, see
+    /// [`NoteAnalysis::read_frontmatter_in`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read as text.
+    #[inline]
+    pub fn read_frontmatter(path: &Path) -> io::Result<Self> {
+        Self::read_frontmatter_in(&RealFs, path)
+    }
+
+    /// Read and analyze only as much of a note as its frontmatter needs, for
+    /// scans that look at tags alone. The body may not be read, so `content`,
+    /// `words` and a heading title are only reliable from [`NoteAnalysis::read_in`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read as text.
+    #[inline]
+    pub fn read_frontmatter_in(vfs: &dyn Vfs, path: &Path) -> io::Result<Self> {
+        Ok(Self::from_content(path, vfs.read_frontmatter(path, false)?))
+    }
+
+    /// The note without its frontmatter.
+    #[inline]
+    #[must_use]
+    pub fn body(&self) -> &str {
+        &self.content[self.body_start..]
+    }
+
+    /// The text whose words and lines are counted, see
+    /// [`countable_text`](crate::core::frontmatter::countable_text).
+    #[inline]
+    #[must_use]
+    pub fn countable_text(&self, include_frontmatter: bool) -> &str {
+        if include_frontmatter {
+            return &self.content;
+        }
+        let body = self.body();
+        body.strip_prefix("\r\n")
+            .or_else(|| body.strip_prefix('\n'))
+            .unwrap_or(body)
+    }
+
+    /// Words in the body, or in the whole file if `include_frontmatter` is set.
+    #[inline]
+    #[must_use]
+    pub fn word_count(&self, include_frontmatter: bool) -> usize {
+        if include_frontmatter {
+            self.content.split_whitespace().count()
+        } else {
+            self.words
+        }
+    }
+
+    /// Frontmatter title or first `# heading`, see [`crate::core::frontmatter::note_title`].
+    #[inline]
+    #[must_use]
+    pub fn title(&self) -> Option<String> {
+        title_from_body(&self.frontmatter, self.body())
+    }
+
+    /// Frontmatter `created` date, else the zettel ID in the file name.
+    #[inline]
+    #[must_use]
+    pub fn created(&self) -> Option<NaiveDate> {
+        self.frontmatter
+            .created()
+            .or_else(|| filename_date(&self.path))
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn has_any_tag(&self, tags: &[&str]) -> bool {
+        self.tags.iter().any(|t| tags.contains(&t.as_str()))
+    }
+}
@@ -1,8 +1,8 @@
-use anyhow::{Context as _, Result};
+use crate::core::error::{Result, ZrtError};
 use glob::Pattern;
 use std::path::{Path, PathBuf};
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct Patterns {
     /// Collection of ignore patterns with metadata.
     /// Each tuple contains:
@@ -32,15 +32,9 @@ impl Patterns {
     ///
     /// # Errors
     ///
-    /// This function may return an error if:
+    /// Returns [`ZrtError::InvalidPattern`] if:
     /// * The pattern contains invalid glob syntax
     /// * The pattern has mismatched braces in extension groups
-    ///
-    /// # Panics
-    ///
-    /// This function will panic if:
-    /// * A pattern contains an opening brace `{` without a matching closing brace `}`
-    /// * A pattern contains a closing brace `}` without a matching opening brace `{`
     #[inline]
     pub fn add_pattern(&mut self, pattern: &str) -> Result<()> {
         let pattern = pattern.trim();
@@ -92,16 +86,15 @@ impl Patterns {
         if glob_pattern.contains('{') {
             let (prefix, suffix) = glob_pattern
                 .split_once('{')
-                .ok_or_else(|| anyhow::anyhow!("Invalid pattern: missing opening brace"))?;
+                .ok_or_else(|| invalid(pattern, "missing opening brace"))?;
             let (extensions, rest) = suffix
                 .split_once('}')
-                .ok_or_else(|| anyhow::anyhow!("Invalid pattern: missing closing brace"))?;
+                .ok_or_else(|| invalid(pattern, "missing closing brace"))?;
             let extensions: Vec<&str> = extensions.split(',').map(str::trim).collect();
 
             for ext in extensions {
                 let full_pattern = format!("{prefix}{ext}{rest}").replace("[GLOBSTAR]", "**");
-                let pattern_compiled = Pattern::new(&full_pattern)
-                    .with_context(|| format!("Invalid pattern: {full_pattern}"))?;
+                let pattern_compiled = compile(&full_pattern)?;
                 self.patterns
                     .push((pattern_compiled, is_negation, is_anchored));
             }
@@ -109,19 +102,16 @@ impl Patterns {
         }
         if is_bare_filename && !is_anchored {
             let path_pattern = format!("**/{pattern_str_for_later}");
-            let compiled = Pattern::new(&path_pattern)
-                .with_context(|| format!("Invalid path pattern: {path_pattern}"))?;
+            let compiled = compile(&path_pattern)?;
             self.patterns.push((compiled, is_negation, false));
-            let pattern_compiled = Pattern::new(&pattern_str_for_later)
-                .with_context(|| format!("Invalid filename pattern: {pattern_str_for_later}"))?;
+            let pattern_compiled = compile(&pattern_str_for_later)?;
             self.patterns.push((pattern_compiled, is_negation, false));
 
             return Ok(());
         }
 
         let glob_pattern = glob_pattern.replace("[GLOBSTAR]", "**");
-        let compiled = Pattern::new(&glob_pattern)
-            .with_context(|| format!("Invalid pattern: {glob_pattern}"))?;
+        let compiled = compile(&glob_pattern)?;
         self.patterns.push((compiled, is_negation, is_anchored));
         Ok(())
     }
@@ -171,6 +161,17 @@ impl Patterns {
     }
 }
 
+fn invalid(pattern: &str, message: &str) -> ZrtError {
+    ZrtError::InvalidPattern {
+        pattern: pattern.to_owned(),
+        message: message.to_owned(),
+    }
+}
+
+fn compile(pattern: &str) -> Result<Pattern> {
+    Pattern::new(pattern).map_err(|e| invalid(pattern, e.msg))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,1917 @@
+use chrono::NaiveDate;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use tracing::{debug, trace};
+
+use crate::core::error::{Result, ZrtError};
+use crate::core::filter::utils::{
+    ExclusionReason, exclusion_reason, has_extension, normalize_extensions,
+};
+use crate::core::frontmatter::MetadataFormat;
+use crate::core::history::percentage;
+use crate::core::ignore::load_ignore_patterns_in;
+use crate::core::note::NoteAnalysis;
+use crate::core::patterns::Patterns;
+use crate::core::status::RefactorStatus;
+use crate::core::timings::{Stage, Timings, time};
+use crate::core::vfs::{
+    EntryKind, FileMetadata, RealFs, Vfs, is_binary_error, is_not_utf8_error, lossy_text,
+};
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::vfs::MemoryFs;
+    use anyhow::Result;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn create_test_file(dir: &TempDir, name: &str, content: &str) -> Result<PathBuf> {
+        let path = dir.path().join(name);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, content)?;
+        Ok(path)
+    }
+
+    fn vault() -> Result<TempDir> {
+        let dir = TempDir::new()?;
+        create_test_file(
+            &dir,
+            "done.md",
+            "---\ntags: [refactored]\n---\none two three",
+        )?;
+        create_test_file(&dir, "todo.md", "---\ntags: [to_refactor]\n---\none")?;
+        create_test_file(&dir, "plain.md", "one two three four")?;
+        create_test_file(
+            &dir,
+            "sub/deep/note.md",
+            "---\ntags: [to_refactor]\n---\none two",
+        )?;
+        create_test_file(&dir, "archive/old.md", "---\ntags: [refactored]\n---\none")?;
+        create_test_file(&dir, ".zrtignore", "ignored.md\n")?;
+        create_test_file(&dir, "ignored.md", "---\ntags: [refactored]\n---\none")?;
+        Ok(dir)
+    }
+
+    #[test]
+    fn test_should_count_files_with_defaults() -> Result<()> {
+        // REQ-SCAN-001
+        let dir = vault()?;
+        let options = ScanOptions::new([dir.path()]);
+
+        assert_eq!(options.count_files(&[])?, 5);
+        assert_eq!(options.count_files(&["refactored"])?, 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_sum_words_for_tags() -> Result<()> {
+        // REQ-SCAN-002
+        let dir = vault()?;
+
+        let stats = ScanOptions::new([dir.path()]).word_stats(&["to_refactor"])?;
+
+        assert_eq!(
+            stats,
+            SinglePatternStats {
+                files: 2,
+                words: 3,
+                ..Default::default()
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_measure_each_pattern_in_one_pass() -> Result<()> {
+        // REQ-SCAN-019
+        let dir = vault()?;
+
+        let (stats, total) = ScanOptions::new([dir.path()]).pattern_stats(&[
+            "to_refactor",
+            "refactored",
+            "missing",
+        ])?;
+
+        let measured: Vec<_> = stats.iter().map(|s| (s.files, s.words)).collect();
+        assert_eq!(measured, vec![(2, 3), (2, 4), (0, 0)]);
+        assert_eq!((total.files, total.words), (5, 11));
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_leave_out_notes_with_excepted_tags() -> Result<()> {
+        // REQ-SCAN-020
+        let dir = vault()?;
+        let options = ScanOptions::new([dir.path()]).except_tags(["to_refactor"]);
+        let scanner = options.clone().build()?;
+        let mut visited = 0;
+        scanner.visit(|_| visited += 1)?;
+
+        assert_eq!(options.count_files(&[])?, 3);
+        assert_eq!(options.count_files(&["to_refactor"])?, 0);
+        assert_eq!(options.word_stats(&[])?.words, 8);
+        assert_eq!(scanner.iter().count(), 3);
+        assert_eq!(scanner.notes().count(), 3);
+        assert_eq!(visited, 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_compare_done_and_todo() -> Result<()> {
+        // REQ-SCAN-003
+        let dir = vault()?;
+
+        let stats = ScanOptions::new([dir.path()])
+            .exclude(["archive"])
+            .compare("refactored", "to_refactor")?;
+
+        assert_eq!(
+            stats.done,
+            SinglePatternStats {
+                files: 1,
+                words: 3,
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            stats.todo,
+            SinglePatternStats {
+                files: 2,
+                words: 3,
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            stats.total,
+            SinglePatternStats {
+                files: 4,
+                words: 10,
+                ..Default::default()
+            }
+        );
+        assert_eq!(stats.percentage(), 30.0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_list_matched_files_when_requested() -> Result<()> {
+        // REQ-SCAN-010
+        // Given a vault with two todo notes
+        let dir = vault()?;
+        let options = ScanOptions::new([dir.path()]);
+
+        // When gathering statistics with and without file lists
+        let unlisted = options.word_stats(&["to_refactor"])?;
+        let listed = options
+            .clone()
+            .list_files(true)
+            .compare("refactored", "to_refactor")?;
+
+        // Then paths are only recorded when requested
+        assert!(unlisted.matched_files.is_empty());
+        let mut todo: Vec<_> = listed
+            .todo
+            .matched_files
+            .iter()
+            .map(|p| p.strip_prefix(dir.path()).unwrap_or(p).to_path_buf())
+            .collect();
+        todo.sort();
+        assert_eq!(
+            todo,
+            vec![PathBuf::from("sub/deep/note.md"), PathBuf::from("todo.md")]
+        );
+        assert_eq!(listed.total.matched_files.len(), listed.total.files);
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_apply_depth_and_ignore_options() -> Result<()> {
+        // REQ-SCAN-004
+        let dir = vault()?;
+
+        let shallow = ScanOptions::new([dir.path()]).max_depth(1);
+        let unfiltered = ScanOptions::new([dir.path()]).respect_ignore(false);
+
+        assert_eq!(shallow.count_files(&[])?, 3);
+        // Hidden files such as .zrtignore itself are always skipped
+        assert_eq!(unfiltered.count_files(&[])?, 6);
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_stream_note_records() -> Result<()> {
+        // REQ-SCAN-006
+        let dir = vault()?;
+        let scanner = ScanOptions::new([dir.path()])
+            .exclude(["archive"])
+            .build()?;
+
+        let mut records: Vec<NoteRecord> = scanner.iter().collect::<Result<_, ZrtError>>()?;
+        records.sort_by(|a, b| a.path.cmp(&b.path));
+
+        assert_eq!(records.len(), 4);
+        assert_eq!(records[0].path, dir.path().join("done.md"));
+        assert_eq!(records[0].tags, vec!["refactored"]);
+        assert_eq!(records[0].words, 3);
+        assert_eq!(records[0].title, None);
+        assert_eq!(records[0].created, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_allow_short_circuiting_the_stream() -> Result<()> {
+        // REQ-SCAN-007
+        let dir = vault()?;
+        let scanner = ScanOptions::new([dir.path()]).build()?;
+
+        let found = scanner
+            .iter()
+            .filter_map(Result::ok)
+            .find(|note| note.words == 4);
+
+        assert_eq!(found.map(|n| n.path), Some(dir.path().join("plain.md")));
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_should_yield_error_for_broken_entries() -> Result<()> {
+        // REQ-SCAN-008
+        let dir = vault()?;
+        std::os::unix::fs::symlink(dir.path().join("gone.md"), dir.path().join("dangling.md"))?;
+        let scanner = ScanOptions::new([dir.path()]).strict(true).build()?;
+
+        let results: Vec<Result<NoteRecord, ZrtError>> = scanner.iter().collect();
+
+        assert_eq!(results.iter().filter(|r| r.is_err()).count(), 1);
+        assert_eq!(results.iter().filter(|r| r.is_ok()).count(), 5);
+        // Unless strict, the broken entry is skipped instead
+        let tolerant = ScanOptions::new([dir.path()]).strict(false).build()?;
+        assert!(tolerant.iter().all(|r| r.is_ok()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_load_ignore_patterns_once_per_scanner() -> Result<()> {
+        // REQ-SCAN-011
+        // Given a scanner built while ignored.md is listed in .zrtignore
+        let dir = vault()?;
+        let scanner = ScanOptions::new([dir.path()]).build()?;
+
+        // When the ignore file changes after the scanner was built
+        fs::write(dir.path().join(".zrtignore"), "")?;
+
+        // Then every scan keeps using the patterns loaded at construction
+        assert_eq!(scanner.count_files(&[])?, 5);
+        assert_eq!(scanner.word_stats(&["refactored"])?.files, 2);
+        assert_eq!(ScanOptions::new([dir.path()]).count_files(&[])?, 6);
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_scan_an_in_memory_vault() -> Result<()> {
+        // REQ-SCAN-012
+        // Given an in-memory vault with an ignore file, an excluded folder and a binary file
+        let vfs = MemoryFs::new()
+            .with_file("/vault/.zrtignore", "ignored.md\n")
+            .with_file("/vault/done.md", "---\ntags: [refactored]\n---\none two")
+            .with_file("/vault/sub/todo.md", "---\ntags: [to_refactor]\n---\none")
+            .with_file("/vault/archive/old.md", "---\ntags: [refactored]\n---\none")
+            .with_file("/vault/ignored.md", "---\ntags: [refactored]\n---\none")
+            .with_file("/vault/binary.md", [0xFF, 0xFE]);
+
+        // When scanning it without touching the disk
+        let scanner = ScanOptions::new(["/vault"])
+            .exclude(["archive"])
+            .vfs(vfs)
+            .build()?;
+        let stats = scanner.compare("refactored", "to_refactor")?;
+
+        // Then the same exclusion and ignore rules apply
+        assert_eq!(
+            scanner.files()?,
+            vec![
+                PathBuf::from("/vault/binary.md"),
+                PathBuf::from("/vault/done.md"),
+                PathBuf::from("/vault/sub/todo.md"),
+            ]
+        );
+        assert_eq!((stats.done.files, stats.todo.files), (1, 1));
+        assert_eq!(stats.total.words, 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_read_files_through_the_scan_vfs() -> Result<()> {
+        // REQ-SCAN-015
+        let vfs = MemoryFs::new()
+            .with_file("/vault/a.md", "one two")
+            .with_file("/vault/image.md", [0x89, b'P', b'N', b'G', 0x00]);
+        let scanner = ScanOptions::new(["/vault"]).vfs(vfs).build()?;
+
+        assert_eq!(scanner.read_to_string(Path::new("/vault/a.md"))?, "one two");
+        assert!(scanner.read_to_string(Path::new("/vault/b.md")).is_err());
+        // Binaries are skipped rather than failing commands that read notes
+        assert_eq!(scanner.read_note(Path::new("/vault/image.md"))?, None);
+        assert!(scanner.read_note(Path::new("/vault/b.md")).is_err());
+        Ok(())
+    }
+
+    /// A vault whose notes can only be read up to their frontmatter.
+    #[derive(Debug)]
+    struct FrontmatterOnly(MemoryFs);
+
+    impl Vfs for FrontmatterOnly {
+        fn read_to_string(&self, path: &Path) -> io::Result<String> {
+            Err(io::Error::other(format!("read all of {}", path.display())))
+        }
+
+        fn read_frontmatter(&self, path: &Path, _other_headers: bool) -> io::Result<String> {
+            self.0.read_to_string(path)
+        }
+
+        fn kind(&self, path: &Path, follow_links: bool) -> io::Result<EntryKind> {
+            self.0.kind(path, follow_links)
+        }
+
+        fn read_dir(&self, dir: &Path) -> io::Result<Vec<PathBuf>> {
+            self.0.read_dir(dir)
+        }
+    }
+
+    /// A vault where one directory and one file cannot be read.
+    #[derive(Debug)]
+    struct Locked(MemoryFs);
+
+    impl Locked {
+        fn check(path: &Path) -> io::Result<()> {
+            if path.ends_with("locked") || path.ends_with("secret.md") {
+                return Err(io::Error::from(io::ErrorKind::PermissionDenied));
+            }
+            Ok(())
+        }
+    }
+
+    impl Vfs for Locked {
+        fn read_to_string(&self, path: &Path) -> io::Result<String> {
+            Self::check(path)?;
+            self.0.read_to_string(path)
+        }
+
+        fn kind(&self, path: &Path, follow_links: bool) -> io::Result<EntryKind> {
+            self.0.kind(path, follow_links)
+        }
+
+        fn read_dir(&self, dir: &Path) -> io::Result<Vec<PathBuf>> {
+            Self::check(dir)?;
+            self.0.read_dir(dir)
+        }
+    }
+
+    #[test]
+    fn test_should_skip_unreadable_entries_unless_strict() -> Result<()> {
+        // REQ-SCAN-018
+        // Given a vault with an unreadable directory and an unreadable note
+        let vault = || {
+            Locked(
+                MemoryFs::new()
+                    .with_file("/vault/a.md", "---\ntags: [draft]\n---\none two")
+                    .with_file("/vault/secret.md", "---\ntags: [draft]\n---\nthree")
+                    .with_file("/vault/locked/b.md", "four"),
+            )
+        };
+
+        // When scanning tolerantly, the rest of the vault is still counted
+        let scanner = ScanOptions::new(["/vault"])
+            .strict(false)
+            .vfs(vault())
+            .build()?;
+        assert_eq!(scanner.word_stats(&[])?.files, 1);
+        assert_eq!(scanner.count_files(&["draft"])?, 1);
+        assert_eq!(scanner.notes().collect::<Result<Vec<_>, _>>()?.len(), 1);
+        let skipped = scanner.report().errors;
+        assert!(skipped.contains_key(Path::new("/vault/locked")));
+        assert!(skipped.contains_key(Path::new("/vault/secret.md")));
+        let shared = Arc::new(Mutex::new(ScanReport::default()));
+        ScanOptions::new(["/vault"])
+            .report_to(Arc::clone(&shared))
+            .vfs(vault())
+            .files()?;
+        assert!(shared.lock().is_ok_and(|report| report.errors.len() == 1));
+
+        // Then a strict scan fails on the first one instead
+        let strict = ScanOptions::new(["/vault"])
+            .strict(true)
+            .vfs(vault())
+            .build()?;
+        assert!(matches!(strict.files(), Err(ZrtError::Walk { .. })));
+        let listed = ScanOptions::new(["/vault"])
+            .paths(["/vault/a.md", "/vault/secret.md"])
+            .strict(true)
+            .vfs(vault())
+            .build()?;
+        assert!(matches!(
+            listed.word_stats(&[]),
+            Err(ZrtError::UnreadableFile { .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_count_tagged_files_from_frontmatter_alone() -> Result<()> {
+        // REQ-SCAN-017
+        let vfs = MemoryFs::new()
+            .with_file("/vault/a.md", "---\ntags: [draft]\n---\none")
+            .with_file("/vault/b.md", "---\ntags: [idea]\n---\ntwo")
+            .with_file("/vault/c.md", "three");
+        let scanner = ScanOptions::new(["/vault"])
+            .vfs(FrontmatterOnly(vfs))
+            .build()?;
+
+        assert_eq!(scanner.count_files(&["draft", "idea"])?, 2);
+        assert_eq!(scanner.count_files(&["draft"])?, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_report_progress_for_each_file() -> Result<()> {
+        // REQ-SCAN-013
+        // Given a scanner with a progress callback
+        let dir = vault()?;
+        let seen = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = Arc::clone(&seen);
+        let scanner = ScanOptions::new([dir.path()])
+            .on_progress(move |count, path| {
+                if let Ok(mut seen) = sink.lock() {
+                    seen.push((count, path.to_path_buf()));
+                }
+            })
+            .build()?;
+
+        // When scanning
+        let files = scanner.files()?;
+
+        // Then every file is reported once, counting up from 1
+        let seen = seen.lock().map_err(|e| anyhow::anyhow!("{e}"))?;
+        let counts: Vec<usize> = seen.iter().map(|(count, _)| *count).collect();
+        let paths: Vec<PathBuf> = seen.iter().map(|(_, path)| path.clone()).collect();
+        assert_eq!(counts, (1..=files.len()).collect::<Vec<_>>());
+        assert_eq!(paths, files);
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_scan_only_listed_files() -> Result<()> {
+        // REQ-SCAN-014
+        // Given a vault and a list naming some of its files, a missing file and a directory
+        let vfs = MemoryFs::new()
+            .with_file("/vault/done.md", "---\ntags: [refactored]\n---\none two")
+            .with_file("/vault/todo.md", "---\ntags: [to_refactor]\n---\none")
+            .with_file("/vault/archive/old.md", "---\ntags: [refactored]\n---\none")
+            .with_file("/vault/sub/other.md", "---\ntags: [refactored]\n---\none");
+
+        // When scanning just those paths
+        let scanner = ScanOptions::new(["/not/walked"])
+            .paths([
+                "/vault/done.md",
+                "/vault/gone.md",
+                "/vault/sub",
+                "/vault/archive/old.md",
+            ])
+            .exclude(["archive"])
+            .vfs(vfs)
+            .build()?;
+
+        // Then only the listed regular files outside excluded folders are scanned
+        assert_eq!(scanner.files()?, vec![PathBuf::from("/vault/done.md")]);
+        assert_eq!(scanner.compare("refactored", "to_refactor")?.total.words, 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_fail_to_build_for_missing_directory() {
+        // REQ-SCAN-011
+        let result = ScanOptions::new(["/definitely/not/here"]).build();
+        assert!(matches!(result, Err(ZrtError::DirectoryNotFound(_))));
+    }
+
+    #[test]
+    fn test_should_visit_every_file_with_its_record() -> Result<()> {
+        // REQ-SCAN-009
+
+        // Given
+        let dir = vault()?;
+        fs::write(dir.path().join("binary.md"), [0xFF, 0xFE, 0x00])?;
+        let scanner = ScanOptions::new([dir.path()])
+            .exclude(["archive"])
+            .build()?;
+
+        // When
+        let mut visited = 0;
+        let mut words = 0;
+        let mut unreadable = Vec::new();
+        let mut roots = Vec::new();
+        scanner.visit(|visit| {
+            visited += 1;
+            roots.push(visit.root);
+            match visit.record {
+                Some(record) => words += record.words,
+                None => unreadable.push(visit.path.to_path_buf()),
+            }
+        })?;
+
+        // Then
+        assert_eq!(visited, 5);
+        assert_eq!(words, 10);
+        assert_eq!(unreadable, vec![dir.path().join("binary.md")]);
+        assert_eq!(roots, vec![Some(0); 5]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_only_walk_files_with_given_extensions() -> Result<()> {
+        // REQ-SCAN-016
+        let vfs = MemoryFs::new()
+            .with_file("/vault/a.md", "one two")
+            .with_file("/vault/b.TXT", "three")
+            .with_file("/vault/photo.png", "not words")
+            .with_file("/vault/sub/config.yml", "key: value")
+            .with_file("/vault/sub/c.md", "four");
+        let scanner = ScanOptions::new(["/vault"])
+            .vfs(vfs)
+            .extensions([".md", "txt"])
+            .build()?;
+
+        assert_eq!(
+            scanner.files()?,
+            ["/vault/a.md", "/vault/b.TXT", "/vault/sub/c.md"].map(PathBuf::from)
+        );
+        assert!(scanner.is_note(Path::new("/vault/b.TXT")));
+        let defaults = ScanOptions::new(["/vault"]);
+        assert!(defaults.is_note(Path::new("/vault/a.MD")));
+        assert!(!defaults.is_note(Path::new("/vault/b.TXT")));
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_give_same_results_with_several_threads() -> Result<()> {
+        // REQ-SCAN-005
+        let dir = vault()?;
+        let options = ScanOptions::new([dir.path()]);
+
+        let sequential = options.compare("refactored", "to_refactor")?;
+        let parallel = options.threads(4).compare("refactored", "to_refactor")?;
+
+        assert_eq!(sequential, parallel);
+        Ok(())
+    }
+
+    /// A vault where reading `crash.md` panics.
+    #[derive(Debug)]
+    struct Crashing(MemoryFs);
+
+    impl Vfs for Crashing {
+        fn read_to_string(&self, path: &Path) -> io::Result<String> {
+            assert!(!path.ends_with("crash.md"), "reader crashed");
+            self.0.read_to_string(path)
+        }
+
+        fn kind(&self, path: &Path, follow_links: bool) -> io::Result<EntryKind> {
+            self.0.kind(path, follow_links)
+        }
+
+        fn read_dir(&self, dir: &Path) -> io::Result<Vec<PathBuf>> {
+            self.0.read_dir(dir)
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "reader crashed")]
+    fn test_should_not_hide_a_crashed_reader_thread() {
+        // REQ-SCAN-005
+        let vfs = Crashing(
+            MemoryFs::new()
+                .with_file("/vault/a.md", "one")
+                .with_file("/vault/crash.md", "two"),
+        );
+        let options = ScanOptions::new(["/vault"]).vfs(vfs).threads(2);
+
+        let _stats = options.compare("refactored", "to_refactor");
+    }
+
+    #[test]
+    fn test_should_report_binary_and_non_utf8_files() -> Result<()> {
+        // REQ-SCAN-021
+        // Given a vault with a binary file and a Latin-1 note ("café")
+        let vault = || {
+            MemoryFs::new()
+                .with_file("/vault/a.md", "one")
+                .with_file("/vault/image.md", [b'P', b'N', b'G', 0])
+                .with_file("/vault/latin1.md", [b'c', b'a', b'f', 0xE9])
+        };
+
+        // When scanning, both are skipped and recorded
+        let scanner = ScanOptions::new(["/vault"]).vfs(vault()).build()?;
+        assert_eq!(scanner.word_stats(&[])?.files, 1);
+        let report = scanner.report();
+        assert_eq!(
+            report.binaries,
+            BTreeSet::from([PathBuf::from("/vault/image.md")])
+        );
+        assert_eq!(
+            report.not_utf8,
+            BTreeSet::from([PathBuf::from("/vault/latin1.md")])
+        );
+
+        // Then a lossy scan reads the Latin-1 note with a replacement character
+        let lossy = ScanOptions::new(["/vault"])
+            .lossy(true)
+            .vfs(vault())
+            .build()?;
+        assert_eq!(lossy.word_stats(&[])?.files, 2);
+        assert_eq!(
+            lossy.read_to_string(Path::new("/vault/latin1.md"))?,
+            "caf\u{FFFD}"
+        );
+        assert_eq!(lossy.report().not_utf8.len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_decode_frontmatter_reads_like_full_reads() -> Result<()> {
+        // REQ-SCAN-022
+        // Given a Latin-1 note whose frontmatter is plain ASCII ("café" in the body)
+        let dir = TempDir::new()?;
+        let mut bytes = b"---\ntags: [draft]\n---\ncaf".to_vec();
+        bytes.push(0xE9);
+        fs::write(dir.path().join("latin1.md"), bytes)?;
+        let tagged = |scanner: &Scanner, notes: Vec<NoteAnalysis>| {
+            let names: Vec<_> = notes.iter().map(|note| note.tags.clone()).collect();
+            (names, scanner.report().not_utf8.len())
+        };
+
+        for lossy in [false, true] {
+            let scanner = ScanOptions::new([dir.path()]).lossy(lossy).build()?;
+
+            // When reading it whole and only as far as its frontmatter
+            let full = scanner.notes().collect::<Result<Vec<_>, _>>()?;
+            let full = tagged(&scanner, full);
+            let scanner = ScanOptions::new([dir.path()]).lossy(lossy).build()?;
+            let headers = scanner.note_headers().collect::<Result<Vec<_>, _>>()?;
+            let headers = tagged(&scanner, headers);
+
+            // Then both skip it, or both read it when lossy, and both report it
+            assert_eq!(full, headers);
+            let expected = if lossy {
+                vec![vec!["draft".to_owned()]]
+            } else {
+                vec![]
+            };
+            assert_eq!(full, (expected, 1));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_read_configured_header_formats() -> Result<()> {
+        // REQ-SCAN-024
+        // Given a MultiMarkdown note
+        let dir = TempDir::new()?;
+        fs::write(
+            dir.path().join("a.md"),
+            "Title: A\nTags: to_refactor\n\nOne two three",
+        )?;
+        let scan =
+            |formats: &[MetadataFormat]| ScanOptions::new([dir.path()]).formats(formats).build();
+
+        // When scanning without formats, the header is body text
+        let plain = scan(&[])?;
+        assert_eq!(plain.count_files(&["to_refactor"])?, 0);
+
+        // Then with the format set, both full and frontmatter-only reads see it
+        let scanner = scan(&[MetadataFormat::MultiMarkdown])?;
+        assert_eq!(scanner.count_files(&["to_refactor"])?, 1);
+        let notes = scanner.notes().collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(
+            (notes[0].words, notes[0].title()),
+            (3, Some("A".to_owned()))
+        );
+        assert_eq!(notes[0].body(), "\n\nOne two three");
+        let headers = scanner.note_headers().collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(headers[0].tags, vec!["to_refactor"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_report_invalid_frontmatter() -> Result<()> {
+        // REQ-SCAN-023
+        // Given a note whose frontmatter does not parse
+        let scanner = ScanOptions::new(["/vault"])
+            .vfs(
+                MemoryFs::new()
+                    .with_file("/vault/a.md", "---\ntags: [a]\n---\none")
+                    .with_file("/vault/broken.md", "---\ntags: [unclosed\n---\ntwo"),
+            )
+            .build()?;
+
+        // When counting, it still counts as untagged
+        assert_eq!(scanner.word_stats(&[])?.files, 2);
+
+        // Then it is reported with the parse error
+        let invalid = scanner.report().invalid_frontmatter;
+        assert_eq!(invalid.len(), 1);
+        assert!(invalid.contains_key(Path::new("/vault/broken.md")));
+        Ok(())
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// Configures a directory scan once so every statistic is gathered the same way.
+///
+/// ```no_run
+/// # use zrt::core::scanner::ScanOptions;
+/// let stats = ScanOptions::new(["notes"])
+///     .exclude(["archive"])
+///     .compare("refactored", "to_refactor")?;
+/// println!("{:.2}", stats.percentage());
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct ScanOptions {
+    dirs: Vec<PathBuf>,
+    /// Files to scan instead of walking `dirs`
+    paths: Option<Vec<PathBuf>>,
+    exclude: Vec<String>,
+    /// Tags whose notes are left out of the scan
+    except_tags: Vec<String>,
+    /// Extensions files must have, lowercase without the `.`; empty keeps every file
+    extensions: Vec<String>,
+    respect_ignore: bool,
+    follow_links: bool,
+    max_depth: Option<usize>,
+    threads: usize,
+    list_files: bool,
+    /// Fail on the first unreadable entry instead of skipping it
+    strict: bool,
+    /// Read invalid UTF-8 with replacement characters instead of skipping it
+    lossy: bool,
+    /// Header formats read from notes without YAML frontmatter
+    formats: Vec<MetadataFormat>,
+    vfs: Arc<dyn Vfs>,
+    progress: Option<ProgressHook>,
+    /// Where skipped entries are recorded, shared by clones
+    report: Arc<Mutex<ScanReport>>,
+    /// Where scan stages are timed; nothing is measured without one
+    timings: Option<Arc<Timings>>,
+}
+
+/// Callback told how many files have been walked and which file was reached last.
+type ProgressFn = dyn Fn(usize, &Path) + Send + Sync;
+
+#[derive(Clone)]
+struct ProgressHook(Arc<ProgressFn>);
+
+/// Streams scanned notes without collecting them first.
+///
+/// ```no_run
+/// # use zrt::core::scanner::ScanOptions;
+/// let scanner = ScanOptions::new(["notes"]).build()?;
+/// let first_long_note = scanner
+///     .iter()
+///     .filter_map(Result::ok)
+///     .find(|note| note.words > 1000);
+/// let stats = scanner.compare("refactored", "to_refactor")?;
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct Scanner {
+    options: ScanOptions,
+    roots: Vec<Root>,
+}
+
+/// What a scan skipped instead of failing, collected as it runs.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScanReport {
+    /// Entries that could not be read, with the error
+    pub errors: BTreeMap<PathBuf, String>,
+    /// Files skipped because they look binary
+    pub binaries: BTreeSet<PathBuf>,
+    /// Files that are not valid UTF-8, skipped or read lossily
+    pub not_utf8: BTreeSet<PathBuf>,
+    /// Notes whose frontmatter failed to parse, with the error, so they can
+    /// be told apart from notes that are really untagged
+    pub invalid_frontmatter: BTreeMap<PathBuf, String>,
+}
+
+/// A scanned directory with its ignore patterns, loaded once.
+#[derive(Debug, Clone)]
+struct Root {
+    dir: PathBuf,
+    patterns: Patterns,
+}
+
+/// A file reached by [`Scanner::visit`].
+#[derive(Debug, Clone, Copy)]
+pub struct Visit<'a> {
+    pub path: &'a Path,
+    /// The parsed note, or `None` if the file could not be read as text
+    pub record: Option<&'a NoteRecord>,
+    /// Position of the scanned directory the file was found under among
+    /// [`Scanner::roots`], or `None` for a listed file
+    pub root: Option<usize>,
+}
+
+/// Lazy walk over the files of every scanned directory.
+struct Walk<'a> {
+    options: &'a ScanOptions,
+    exclude: Vec<&'a str>,
+    roots: std::slice::Iter<'a, Root>,
+    /// Files given with [`ScanOptions::paths`], visited before any root
+    listed: std::slice::Iter<'a, PathBuf>,
+    current: Option<&'a Root>,
+    /// Position of `current` among the roots
+    root: Option<usize>,
+    /// Entries of the current root still to visit, with their depth
+    pending: Vec<(PathBuf, usize)>,
+    /// Canonical directories already entered, so symlink loops end
+    seen: HashSet<PathBuf>,
+    files_seen: usize,
+}
+
+/// A scanned file with the data statistics are built from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteRecord {
+    pub path: PathBuf,
+    pub tags: Vec<String>,
+    /// Words in the body, excluding frontmatter
+    pub words: usize,
+    /// The `priority` frontmatter property, if set
+    pub priority: Option<String>,
+    /// Frontmatter title or first `# heading`, if any
+    pub title: Option<String>,
+    /// Frontmatter `created` date, else the zettel ID in the file name
+    pub created: Option<NaiveDate>,
+}
+
+/// Files and words matching one tag pattern.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SinglePatternStats {
+    pub files: usize,
+    pub words: usize,
+    /// Paths of the counted files in walk order, only filled in when
+    /// [`ScanOptions::list_files`] is enabled
+    pub matched_files: Vec<PathBuf>,
+}
+
+/// Done and todo notes against the whole scan.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ComparisonStats {
+    pub done: SinglePatternStats,
+    pub todo: SinglePatternStats,
+    pub total: SinglePatternStats,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+impl fmt::Debug for ProgressHook {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ProgressHook")
+    }
+}
+
+impl Default for ScanOptions {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            dirs: vec![PathBuf::from(".")],
+            paths: None,
+            exclude: Vec::new(),
+            except_tags: Vec::new(),
+            extensions: Vec::new(),
+            respect_ignore: true,
+            follow_links: true,
+            max_depth: None,
+            threads: 1,
+            list_files: false,
+            strict: false,
+            lossy: false,
+            formats: Vec::new(),
+            vfs: Arc::new(RealFs),
+            progress: None,
+            report: Arc::default(),
+            timings: None,
+        }
+    }
+}
+
+impl ScanOptions {
+    /// Scan the given directories; relative paths resolve against the current directory.
+    #[inline]
+    #[must_use]
+    pub fn new<I, P>(dirs: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<Path>,
+    {
+        Self {
+            dirs: dirs.into_iter().map(|d| d.as_ref().to_path_buf()).collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Scan exactly these files instead of walking the directories.
+    ///
+    /// Listed files still pass the exclusion rules, but no `.zrtignore` is
+    /// loaded; paths that are missing or not regular files are skipped.
+    #[inline]
+    #[must_use]
+    pub fn paths<I, P>(mut self, files: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<Path>,
+    {
+        self.paths = Some(
+            files
+                .into_iter()
+                .map(|f| f.as_ref().to_path_buf())
+                .collect(),
+        );
+        self
+    }
+
+    /// Skip directories with these names.
+    #[inline]
+    #[must_use]
+    pub fn exclude<I, S>(mut self, dirs: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.exclude = dirs.into_iter().map(|d| d.as_ref().to_owned()).collect();
+        self
+    }
+
+    /// Leave out notes carrying any of these tags, as if they were in an
+    /// excluded directory. Notes are still read to find their tags.
+    #[inline]
+    #[must_use]
+    pub fn except_tags<I, S>(mut self, tags: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.except_tags = tags.into_iter().map(|t| t.as_ref().to_owned()).collect();
+        self
+    }
+
+    /// Whether `.zrtignore` patterns apply (default: true).
+    #[inline]
+    #[must_use]
+    pub const fn respect_ignore(mut self, respect: bool) -> Self {
+        self.respect_ignore = respect;
+        self
+    }
+
+    /// Only scan files with one of these extensions, e.g. `["md", "txt"]`, so
+    /// binary and config files never reach the word and tag counts. A leading
+    /// `.` and case are ignored; no extensions keeps every file (default).
+    #[inline]
+    #[must_use]
+    pub fn extensions<I, S>(mut self, extensions: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.extensions = normalize_extensions(extensions);
+        self
+    }
+
+    /// Whether symbolic links are followed (default: true).
+    #[inline]
+    #[must_use]
+    pub const fn follow_links(mut self, follow: bool) -> Self {
+        self.follow_links = follow;
+        self
+    }
+
+    /// Only descend this many levels; files directly in a scanned directory are depth 1.
+    #[inline]
+    #[must_use]
+    pub const fn max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = Some(depth);
+        self
+    }
+
+    /// Read files on this many threads; 0 uses the available parallelism (default: 1).
+    #[inline]
+    #[must_use]
+    pub const fn threads(mut self, threads: usize) -> Self {
+        self.threads = threads;
+        self
+    }
+
+    /// Whether statistics record the paths of the files they count (default: false).
+    #[inline]
+    #[must_use]
+    pub const fn list_files(mut self, list: bool) -> Self {
+        self.list_files = list;
+        self
+    }
+
+    /// Whether the scan fails on the first directory or file it cannot read,
+    /// instead of skipping it and recording the error on the
+    /// [`Scanner::report`] (default: false).
+    #[inline]
+    #[must_use]
+    pub const fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Whether notes that are not valid UTF-8, e.g. in Latin-1, are read with
+    /// replacement characters instead of skipped; either way they are
+    /// recorded on the [`Scanner::report`] (default: false).
+    #[inline]
+    #[must_use]
+    pub const fn lossy(mut self, lossy: bool) -> Self {
+        self.lossy = lossy;
+        self
+    }
+
+    /// Header formats read from notes without YAML frontmatter, see
+    /// [`parse_frontmatter_in`](crate::core::frontmatter::parse_frontmatter_in)
+    /// (default: none, set from `[frontmatter] formats`).
+    #[inline]
+    #[must_use]
+    pub fn formats(mut self, formats: &[MetadataFormat]) -> Self {
+        formats.clone_into(&mut self.formats);
+        self
+    }
+
+    /// Filesystem to scan (default: the local disk).
+    #[inline]
+    #[must_use]
+    pub fn vfs(mut self, vfs: impl Vfs + 'static) -> Self {
+        self.vfs = Arc::new(vfs);
+        self
+    }
+
+    /// Record what the scan skips in `report` rather than a report of its
+    /// own, so several scans can be summarized together.
+    #[inline]
+    #[must_use]
+    pub fn report_to(mut self, report: Arc<Mutex<ScanReport>>) -> Self {
+        self.report = report;
+        self
+    }
+
+    /// Add the time the scan spends in each [`Stage`] to `timings`, and keep
+    /// its slowest notes there, so a run's scans can be timed together.
+    #[inline]
+    #[must_use]
+    pub fn timings_to(mut self, timings: Arc<Timings>) -> Self {
+        self.timings = Some(timings);
+        self
+    }
+
+    /// Call `callback` with the number of files seen so far and the current
+    /// path each time the walk reaches a file, so front ends can show scan
+    /// progress; the CLI progress bar is driven by this hook too. It runs
+    /// inside the walk, so it should return quickly.
+    ///
+    /// ```no_run
+    /// # use zrt::core::scanner::ScanOptions;
+    /// let scanner = ScanOptions::new(["notes"])
+    ///     .on_progress(|files, path| eprint!("\r{files} files, at {}", path.display()))
+    ///     .build()?;
+    /// let stats = scanner.word_stats(&[])?;
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn on_progress<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(usize, &Path) + Send + Sync + 'static,
+    {
+        self.progress = Some(ProgressHook(Arc::new(callback)));
+        self
+    }
+
+    /// Whether `path` is a note rather than an attachment: it has one of the
+    /// [`ScanOptions::extensions`], or is markdown when none are set.
+    #[inline]
+    #[must_use]
+    pub fn is_note(&self, path: &Path) -> bool {
+        if self.extensions.is_empty() {
+            path.extension()
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("md"))
+        } else {
+            has_extension(path, &self.extensions)
+        }
+    }
+
+    fn record_error(&self, path: &Path, error: &dyn std::error::Error) {
+        debug!(path = %path.display(), %error, "skipping unreadable entry");
+        if let Ok(mut report) = self.report.lock() {
+            report.errors.insert(path.to_path_buf(), error.to_string());
+        }
+    }
+
+    fn time<T>(&self, stage: Stage, f: impl FnOnce() -> T) -> T {
+        time(self.timings.as_deref(), stage, f)
+    }
+
+    fn extension_reason(&self, path: &Path) -> Option<ExclusionReason> {
+        (!has_extension(path, &self.extensions)).then_some(ExclusionReason::Extension)
+    }
+
+    /// Finish configuring and create a [`Scanner`], loading each directory's
+    /// ignore patterns.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a directory does not exist or its ignore patterns
+    /// cannot be loaded.
+    #[inline]
+    pub fn build(self) -> Result<Scanner> {
+        Scanner::new(self)
+    }
+
+    /// Shortcut for [`Scanner::files`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the scanner cannot be built or the scan fails.
+    #[inline]
+    pub fn files(&self) -> Result<Vec<PathBuf>> {
+        self.clone().build()?.files()
+    }
+
+    /// Shortcut for [`Scanner::count_files`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the scanner cannot be built or the scan fails.
+    #[inline]
+    pub fn count_files(&self, tags: &[&str]) -> Result<usize> {
+        self.clone().build()?.count_files(tags)
+    }
+
+    /// Shortcut for [`Scanner::word_stats`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the scanner cannot be built or the scan fails.
+    #[inline]
+    pub fn word_stats(&self, tags: &[&str]) -> Result<SinglePatternStats> {
+        self.clone().build()?.word_stats(tags)
+    }
+
+    /// Shortcut for [`Scanner::pattern_stats`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the scanner cannot be built or the scan fails.
+    #[inline]
+    pub fn pattern_stats(
+        &self,
+        patterns: &[&str],
+    ) -> Result<(Vec<SinglePatternStats>, SinglePatternStats)> {
+        self.clone().build()?.pattern_stats(patterns)
+    }
+
+    /// Shortcut for [`Scanner::compare`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the scanner cannot be built or the scan fails.
+    #[inline]
+    pub fn compare(&self, done_tag: &str, todo_tag: &str) -> Result<ComparisonStats> {
+        self.clone().build()?.compare(done_tag, todo_tag)
+    }
+}
+
+impl Scanner {
+    /// Resolve every directory and load its ignore patterns once, so repeated
+    /// scans reuse them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a directory does not exist or its ignore patterns
+    /// cannot be loaded.
+    #[inline]
+    pub fn new(options: ScanOptions) -> Result<Self> {
+        let dirs: &[PathBuf] = if options.paths.is_some() {
+            &[]
+        } else {
+            &options.dirs
+        };
+        let mut roots = Vec::with_capacity(dirs.len());
+        for dir in dirs {
+            let absolute_dir = if dir.is_absolute() {
+                dir.clone()
+            } else {
+                std::env::current_dir()
+                    .map_err(ZrtError::CurrentDir)?
+                    .join(dir)
+            };
+            if !options.vfs.exists(&absolute_dir) {
+                return Err(ZrtError::DirectoryNotFound(dir.clone()));
+            }
+
+            let patterns = if options.respect_ignore {
+                load_ignore_patterns_in(&*options.vfs, &absolute_dir)?
+            } else {
+                Patterns::default()
+            };
+            debug!(dir = %absolute_dir.display(), "scanning directory");
+            roots.push(Root {
+                dir: absolute_dir,
+                patterns,
+            });
+        }
+        Ok(Self { options, roots })
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn options(&self) -> &ScanOptions {
+        &self.options
+    }
+
+    /// Whether a scanned file is a note, see [`ScanOptions::is_note`].
+    #[inline]
+    #[must_use]
+    pub fn is_note(&self, path: &Path) -> bool {
+        self.options.is_note(path)
+    }
+
+    /// What the scans so far skipped, see [`ScanOptions::report_to`].
+    #[inline]
+    #[must_use]
+    pub fn report(&self) -> ScanReport {
+        self.options
+            .report
+            .lock()
+            .map(|report| report.clone())
+            .unwrap_or_default()
+    }
+
+    /// Record an entry that was skipped because it could not be read, or
+    /// made sense of, on the [`Scanner::report`].
+    #[inline]
+    pub fn record_error(&self, path: &Path, error: &dyn std::error::Error) {
+        self.options.record_error(path, error);
+    }
+
+    /// The scanned directories, made absolute; none when scanning listed paths.
+    #[inline]
+    pub fn roots(&self) -> impl Iterator<Item = &Path> {
+        self.roots.iter().map(|root| root.dir.as_path())
+    }
+
+    /// Paths of every file the scan visits, in walk order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a directory cannot be walked.
+    #[inline]
+    pub fn files(&self) -> Result<Vec<PathBuf>> {
+        self.walk().collect()
+    }
+
+    /// The size and modification time of a scanned file, through the scan's
+    /// [`Vfs`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be inspected.
+    #[inline]
+    pub fn metadata(&self, path: &Path) -> Result<FileMetadata> {
+        self.options
+            .vfs
+            .metadata(path)
+            .map_err(|source| ZrtError::UnreadableFile {
+                path: path.to_path_buf(),
+                source,
+            })
+    }
+
+    /// Read a scanned file's text through the scan's [`Vfs`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read as UTF-8 text, unless the
+    /// scan is [`ScanOptions::lossy`].
+    #[inline]
+    pub fn read_to_string(&self, path: &Path) -> Result<String> {
+        self.read(path, false)
+            .map_err(|source| ZrtError::UnreadableFile {
+                path: path.to_path_buf(),
+                source,
+            })
+    }
+
+    /// Like [`Scanner::read_to_string`], but `None` for a binary file, so
+    /// commands that fail on unreadable notes still skip binaries.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or is not valid UTF-8,
+    /// unless the scan is [`ScanOptions::lossy`].
+    #[inline]
+    pub fn read_note(&self, path: &Path) -> Result<Option<String>> {
+        match self.read(path, false) {
+            Ok(content) => Ok(Some(content)),
+            Err(error) if is_binary_error(&error) => Ok(None),
+            Err(source) => Err(ZrtError::UnreadableFile {
+                path: path.to_path_buf(),
+                source,
+            }),
+        }
+    }
+
+    /// Like [`Scanner::read_note`], but also `None` for a file that
+    /// [`Scanner::skip_unreadable`] skips.
+    ///
+    /// # Errors
+    ///
+    /// In a strict scan, returns an error if the file cannot be read.
+    #[inline]
+    pub fn read_text(&self, path: &Path) -> Result<Option<String>> {
+        match self.read(path, false) {
+            Ok(content) => Ok(Some(content)),
+            Err(source) => self.skip_unreadable(path, source),
+        }
+    }
+
+    /// Read a file whole, or only as far as its frontmatter, recording
+    /// binaries and invalid UTF-8 on the report. Invalid UTF-8 is replaced
+    /// in a lossy scan.
+    fn read(&self, path: &Path, frontmatter_only: bool) -> io::Result<String> {
+        let vfs = &*self.options.vfs;
+        let read = self.options.time(Stage::Read, || {
+            if frontmatter_only {
+                vfs.read_frontmatter(path, !self.options.formats.is_empty())
+            } else {
+                vfs.read_to_string(path)
+            }
+        });
+        let error = match read {
+            Ok(content) => return Ok(content),
+            Err(error) => error,
+        };
+        let binary = is_binary_error(&error);
+        let not_utf8 = is_not_utf8_error(&error);
+        if let Ok(mut report) = self.options.report.lock() {
+            if binary {
+                report.binaries.insert(path.to_path_buf());
+            } else if not_utf8 {
+                report.not_utf8.insert(path.to_path_buf());
+            }
+        }
+        if not_utf8 && self.options.lossy {
+            debug!(path = %path.display(), "replacing invalid UTF-8");
+            lossy_text(error)
+        } else {
+            Err(error)
+        }
+    }
+
+    /// Read and analyze a note, see [`Scanner::read`], recording invalid
+    /// frontmatter on the report.
+    fn analyze(&self, path: &Path, frontmatter_only: bool) -> io::Result<NoteAnalysis> {
+        let analyze = || {
+            Ok::<_, io::Error>(NoteAnalysis::from_content_timed(
+                path,
+                self.read(path, frontmatter_only)?,
+                &self.options.formats,
+                self.options.timings.as_deref(),
+            ))
+        };
+        let note = match &self.options.timings {
+            Some(timings) => timings.time_note(path, analyze),
+            None => analyze(),
+        }?;
+        if let Some(error) = &note.frontmatter_error
+            && let Ok(mut report) = self.options.report.lock()
+        {
+            report
+                .invalid_frontmatter
+                .insert(path.to_path_buf(), error.clone());
+        }
+        Ok(note)
+    }
+
+    /// Read a scanned file's record, `None` for files that are not text or
+    /// that [`Scanner::skip_unreadable`] skips.
+    fn read_record(&self, path: &Path) -> Result<Option<NoteRecord>> {
+        match self.analyze(path, false) {
+            Ok(note) => Ok(Some(NoteRecord::from_analysis(&note))),
+            Err(source) => self.skip_unreadable(path, source),
+        }
+    }
+
+    /// [`Scanner::read_record`], also `None` for notes left out by
+    /// [`ScanOptions::except_tags`].
+    fn read_kept(&self, path: &Path) -> Result<Option<NoteRecord>> {
+        Ok(self
+            .read_record(path)?
+            .filter(|record| self.keeps(&record.tags)))
+    }
+
+    /// Skip a file that failed to read. Binaries and invalid UTF-8 are
+    /// reported on their own; other failures, e.g. a lack of permission,
+    /// fail a strict scan and are otherwise recorded.
+    fn skip_unreadable<T>(&self, path: &Path, source: io::Error) -> Result<Option<T>> {
+        if source.kind() == io::ErrorKind::InvalidData {
+            Ok(None)
+        } else if self.options.strict {
+            Err(ZrtError::UnreadableFile {
+                path: path.to_path_buf(),
+                source,
+            })
+        } else {
+            self.record_error(path, &source);
+            Ok(None)
+        }
+    }
+
+    /// Count files carrying any of `tags`, or every file when `tags` is empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the scan fails.
+    #[inline]
+    pub fn count_files(&self, tags: &[&str]) -> Result<usize> {
+        if tags.is_empty() && self.options.except_tags.is_empty() {
+            return Ok(self.files()?.len());
+        }
+        // Only the frontmatter is needed, so note bodies are never read.
+        let mut count = 0;
+        for path in self.files()? {
+            let note = match self.analyze(&path, true) {
+                Ok(note) => Some(note),
+                Err(source) => self.skip_unreadable(&path, source)?,
+            };
+            count += usize::from(match note {
+                Some(note) => self.keeps(&note.tags) && (tags.is_empty() || note.has_any_tag(tags)),
+                None => tags.is_empty(),
+            });
+        }
+        Ok(count)
+    }
+
+    /// Files and words in readable files carrying any of `tags`, or in every
+    /// readable file when `tags` is empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the scan fails.
+    #[inline]
+    pub fn word_stats(&self, tags: &[&str]) -> Result<SinglePatternStats> {
+        let list_files = self.options.list_files;
+        let mut stats = SinglePatternStats::default();
+        for record in self.records()? {
+            if tags.is_empty() || record.has_any_tag(tags) {
+                stats.add(&record, list_files);
+            }
+        }
+        Ok(stats)
+    }
+
+    /// [`Scanner::word_stats`] for `tags` next to the statistics of the whole
+    /// scan, reading and parsing each file once for both.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the scan fails.
+    #[inline]
+    pub fn word_stats_with_total(
+        &self,
+        tags: &[&str],
+    ) -> Result<(SinglePatternStats, SinglePatternStats)> {
+        let list_files = self.options.list_files;
+        let mut tagged = SinglePatternStats::default();
+        let mut total = SinglePatternStats::default();
+        for record in self.records()? {
+            total.add(&record, list_files);
+            if tags.is_empty() || record.has_any_tag(tags) {
+                tagged.add(&record, list_files);
+            }
+        }
+        Ok((tagged, total))
+    }
+
+    /// [`Scanner::word_stats`] for each tag in `patterns`, in order, next to
+    /// the statistics of the whole scan, reading and parsing each file once
+    /// for all of them rather than scanning once per tag. A file carrying
+    /// several of the tags counts towards each.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the scan fails.
+    #[inline]
+    pub fn pattern_stats(
+        &self,
+        patterns: &[&str],
+    ) -> Result<(Vec<SinglePatternStats>, SinglePatternStats)> {
+        let list_files = self.options.list_files;
+        let mut stats = vec![SinglePatternStats::default(); patterns.len()];
+        let mut total = SinglePatternStats::default();
+        for record in self.records()? {
+            total.add(&record, list_files);
+            for (pattern, stats) in patterns.iter().zip(&mut stats) {
+                if record.has_any_tag(&[pattern]) {
+                    stats.add(&record, list_files);
+                }
+            }
+        }
+        Ok((stats, total))
+    }
+
+    /// Done and todo statistics in one pass. A note carrying both tags counts as todo.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the scan fails.
+    #[inline]
+    pub fn compare(&self, done_tag: &str, todo_tag: &str) -> Result<ComparisonStats> {
+        let list_files = self.options.list_files;
+        let mut stats = ComparisonStats::default();
+        for record in self.records()? {
+            stats.total.add(&record, list_files);
+            match RefactorStatus::of(&record.tags, done_tag, todo_tag) {
+                RefactorStatus::Done => stats.done.add(&record, list_files),
+                RefactorStatus::Todo => stats.todo.add(&record, list_files),
+                RefactorStatus::Untracked => {}
+            }
+        }
+        Ok(stats)
+    }
+
+    /// Notes in walk order, read one at a time as the walk proceeds.
+    ///
+    /// Files that are not valid UTF-8 text are skipped. Entries that cannot
+    /// be read are skipped too, or in a strict scan yielded as errors as they
+    /// occur, with the walk continuing afterwards.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = Result<NoteRecord>> + '_ {
+        self.walk()
+            .filter_map(|path| path.and_then(|path| self.read_kept(&path)).transpose())
+    }
+
+    /// Like [`Scanner::iter`], but yielding each note's full analysis, for
+    /// scans that look at frontmatter properties or the body.
+    #[inline]
+    pub fn notes(&self) -> impl Iterator<Item = Result<NoteAnalysis>> + '_ {
+        self.analyses(false)
+    }
+
+    /// Like [`Scanner::notes`], but reading each note only as far as its
+    /// frontmatter, see [`NoteAnalysis::read_frontmatter_in`], for scans that
+    /// look at tags alone.
+    #[inline]
+    pub fn note_headers(&self) -> impl Iterator<Item = Result<NoteAnalysis>> + '_ {
+        self.analyses(true)
+    }
+
+    fn analyses(&self, frontmatter_only: bool) -> impl Iterator<Item = Result<NoteAnalysis>> + '_ {
+        self.walk().filter_map(move |path| {
+            path.and_then(|path| match self.analyze(&path, frontmatter_only) {
+                Ok(note) => Ok(Some(note)),
+                Err(source) => self.skip_unreadable(&path, source),
+            })
+            .map(|note| note.filter(|note| self.keeps(&note.tags)))
+            .transpose()
+        })
+    }
+
+    /// Call `visitor` for every scanned file in walk order, including files
+    /// that cannot be read as text, using the same exclusion and ignore rules
+    /// as every other scan.
+    ///
+    /// # Errors
+    ///
+    /// In a strict scan, stops at and returns the first entry that cannot be read.
+    #[inline]
+    pub fn visit<F>(&self, mut visitor: F) -> Result<()>
+    where
+        F: FnMut(Visit<'_>),
+    {
+        let mut walk = self.walk();
+        while let Some(path) = walk.next() {
+            let path = path?;
+            let root = walk.root;
+            let record = self.read_record(&path)?;
+            if record
+                .as_ref()
+                .is_some_and(|record| !self.keeps(&record.tags))
+            {
+                continue;
+            }
+            visitor(Visit {
+                path: &path,
+                record: record.as_ref(),
+                root,
+            });
+        }
+        Ok(())
+    }
+
+    /// Whether a note with `tags` stays in the scan, see [`ScanOptions::except_tags`].
+    fn keeps(&self, tags: &[String]) -> bool {
+        !tags
+            .iter()
+            .any(|tag| self.options.except_tags.contains(tag))
+    }
+
+    fn walk(&self) -> Walk<'_> {
+        Walk {
+            options: &self.options,
+            exclude: self.options.exclude.iter().map(String::as_str).collect(),
+            roots: self.roots.iter(),
+            listed: self.options.paths.as_deref().unwrap_or_default().iter(),
+            current: None,
+            root: None,
+            pending: Vec::new(),
+            seen: HashSet::new(),
+            files_seen: 0,
+        }
+    }
+
+    /// Read every scanned file, skipping those that are not valid UTF-8 text.
+    fn records(&self) -> Result<Vec<NoteRecord>> {
+        let files = self.files()?;
+        let threads = match self.options.threads {
+            0 => std::thread::available_parallelism().map_or(1, usize::from),
+            n => n,
+        };
+
+        if threads <= 1 || files.len() < 2 {
+            return files
+                .iter()
+                .filter_map(|p| self.read_kept(p).transpose())
+                .collect();
+        }
+
+        let chunk_size = files.len().div_ceil(threads);
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = files
+                .chunks(chunk_size)
+                .map(|chunk| {
+                    scope.spawn(move || {
+                        chunk
+                            .iter()
+                            .filter_map(|p| self.read_kept(p).transpose())
+                            .collect::<Result<Vec<_>>>()
+                    })
+                })
+                .collect();
+            let mut records = Vec::new();
+            for handle in handles {
+                // A reader that panicked would otherwise drop its files from the totals
+                let chunk = handle
+                    .join()
+                    .unwrap_or_else(|panic| std::panic::resume_unwind(panic));
+                records.extend(chunk?);
+            }
+            Ok(records)
+        })
+    }
+}
+
+impl Walk<'_> {
+    /// The error to yield for an entry that cannot be walked, or `None` after
+    /// recording it when the scan is not strict.
+    fn walk_error(&self, path: PathBuf, source: io::Error) -> Option<ZrtError> {
+        if self.options.strict {
+            return Some(ZrtError::Walk { path, source });
+        }
+        self.options.record_error(&path, &source);
+        None
+    }
+
+    fn next_file(&mut self) -> Option<Result<PathBuf>> {
+        let vfs = &*self.options.vfs;
+        for path in self.listed.by_ref() {
+            match vfs.kind(path, self.options.follow_links) {
+                Ok(EntryKind::File) => {}
+                Ok(_) => {
+                    debug!(path = %path.display(), reason = "not a regular file", "skipping");
+                    continue;
+                }
+                Err(error) => {
+                    debug!(path = %path.display(), %error, "skipping listed file");
+                    continue;
+                }
+            }
+            if let Some(reason) = self.options.time(Stage::IgnoreMatching, || {
+                exclusion_reason(path, false, &self.exclude, None)
+                    .or_else(|| self.options.extension_reason(path))
+            }) {
+                debug!(path = %path.display(), %reason, "skipping");
+                continue;
+            }
+            self.files_seen += 1;
+            if let Some(ProgressHook(callback)) = &self.options.progress {
+                callback(self.files_seen, path);
+            }
+            return Some(Ok(path.clone()));
+        }
+
+        loop {
+            let Some((path, depth)) = self.pending.pop() else {
+                let root = self.roots.next()?;
+                self.current = Some(root);
+                self.root = Some(self.root.map_or(0, |index| index + 1));
+                self.seen.clear();
+                self.pending.push((root.dir.clone(), 0));
+                continue;
+            };
+
+            let kind = match vfs.kind(&path, self.options.follow_links) {
+                Ok(kind) => kind,
+                Err(source) => match self.walk_error(path, source) {
+                    Some(error) => return Some(Err(error)),
+                    None => continue,
+                },
+            };
+            let patterns = self.current.map(|root| &root.patterns);
+            if let Some(reason) = self.options.time(Stage::IgnoreMatching, || {
+                exclusion_reason(&path, kind == EntryKind::Dir, &self.exclude, patterns)
+            }) {
+                debug!(path = %path.display(), %reason, "skipping");
+                continue;
+            }
+
+            match kind {
+                EntryKind::File => {
+                    if let Some(reason) = self.options.time(Stage::IgnoreMatching, || {
+                        self.options.extension_reason(&path)
+                    }) {
+                        debug!(path = %path.display(), %reason, "skipping");
+                        continue;
+                    }
+                    trace!(path = %path.display(), "visiting file");
+                    self.files_seen += 1;
+                    if let Some(ProgressHook(callback)) = &self.options.progress {
+                        callback(self.files_seen, &path);
+                    }
+                    return Some(Ok(path));
+                }
+                EntryKind::Dir => {
+                    if self.options.max_depth.is_some_and(|max| depth >= max) {
+                        debug!(path = %path.display(), reason = "max depth", "skipping");
+                        continue;
+                    }
+                    if self.options.follow_links {
+                        match vfs.canonicalize(&path) {
+                            Ok(canonical) => {
+                                if !self.seen.insert(canonical) {
+                                    debug!(path = %path.display(), reason = "already visited", "skipping");
+                                    continue;
+                                }
+                            }
+                            Err(source) => match self.walk_error(path, source) {
+                                Some(error) => return Some(Err(error)),
+                                None => continue,
+                            },
+                        }
+                    }
+                    match vfs.read_dir(&path) {
+                        Ok(children) => self
+                            .pending
+                            .extend(children.into_iter().rev().map(|c| (c, depth + 1))),
+                        Err(source) => {
+                            if let Some(error) = self.walk_error(path, source) {
+                                return Some(Err(error));
+                            }
+                        }
+                    }
+                }
+                EntryKind::Symlink | EntryKind::Other => {
+                    debug!(path = %path.display(), reason = "not a regular file", "skipping");
+                }
+            }
+        }
+    }
+}
+
+impl Iterator for Walk<'_> {
+    type Item = Result<PathBuf>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let options = self.options;
+        options.time(Stage::Traversal, || self.next_file())
+    }
+}
+
+impl NoteRecord {
+    /// Build a record from a file's content.
+    #[inline]
+    #[must_use]
+    pub fn from_content(path: &Path, content: &str) -> Self {
+        Self::from_analysis(&NoteAnalysis::from_content(path, content.to_owned()))
+    }
+
+    /// Build a record from an already analyzed note.
+    #[inline]
+    #[must_use]
+    pub fn from_analysis(note: &NoteAnalysis) -> Self {
+        Self {
+            path: note.path.clone(),
+            tags: note.tags.clone(),
+            words: note.words,
+            priority: note.frontmatter.priority(),
+            title: note.title(),
+            created: note.created(),
+        }
+    }
+
+    /// Read a record from disk.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ZrtError::UnreadableFile`] if the file cannot be read as text.
+    #[inline]
+    pub fn try_read(path: &Path) -> Result<Self> {
+        Self::try_read_in(&RealFs, path)
+    }
+
+    /// Read a record through the given filesystem.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ZrtError::UnreadableFile`] if the file cannot be read as text.
+    #[inline]
+    pub fn try_read_in(vfs: &dyn Vfs, path: &Path) -> Result<Self> {
+        NoteAnalysis::read_in(vfs, path)
+            .map(|note| Self::from_analysis(&note))
+            .map_err(|source| {
+                // Binaries were already logged when they were sniffed.
+                if !is_binary_error(&source) {
+                    debug!(path = %path.display(), error = %source, "cannot read file as text");
+                }
+                ZrtError::UnreadableFile {
+                    path: path.to_path_buf(),
+                    source,
+                }
+            })
+    }
+
+    /// Read a record from disk, or `None` if the file cannot be read as text.
+    #[inline]
+    #[must_use]
+    pub fn read(path: &Path) -> Option<Self> {
+        Self::try_read(path).ok()
+    }
+
+    /// Read a record through the given filesystem, or `None` if the file
+    /// cannot be read as text.
+    #[inline]
+    #[must_use]
+    pub fn read_in(vfs: &dyn Vfs, path: &Path) -> Option<Self> {
+        Self::try_read_in(vfs, path).ok()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn has_any_tag(&self, tags: &[&str]) -> bool {
+        self.tags.iter().any(|t| tags.contains(&t.as_str()))
+    }
+}
+
+impl SinglePatternStats {
+    fn add(&mut self, record: &NoteRecord, list_files: bool) {
+        self.files += 1;
+        self.words += record.words;
+        if list_files {
+            self.matched_files.push(record.path.clone());
+        }
+    }
+}
+
+impl ComparisonStats {
+    /// Percentage of words in done notes, rounded to 2 decimal places.
+    #[inline]
+    #[must_use]
+    pub fn percentage(&self) -> f64 {
+        percentage(self.done.words, self.total.words)
+    }
+}
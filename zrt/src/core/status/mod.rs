@@ -0,0 +1,85 @@
+use serde::{Deserialize, Serialize};
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tags(values: &[&str]) -> Vec<String> {
+        values.iter().map(|t| (*t).to_owned()).collect()
+    }
+
+    #[test]
+    fn test_should_classify_done_notes() {
+        let status =
+            RefactorStatus::of(&tags(&["idea", "refactored"]), "refactored", "to_refactor");
+        assert_eq!(status, RefactorStatus::Done);
+    }
+
+    #[test]
+    fn test_should_classify_todo_notes() {
+        let status = RefactorStatus::of(&tags(&["to_refactor"]), "refactored", "to_refactor");
+        assert_eq!(status, RefactorStatus::Todo);
+    }
+
+    #[test]
+    fn test_should_treat_notes_with_both_tags_as_todo() {
+        let status = RefactorStatus::of(
+            &tags(&["refactored", "to_refactor"]),
+            "refactored",
+            "to_refactor",
+        );
+        assert_eq!(status, RefactorStatus::Todo);
+    }
+
+    #[test]
+    fn test_should_classify_other_notes_as_untracked() {
+        let status = RefactorStatus::of(&tags(&["idea"]), "refactored", "to_refactor");
+        assert_eq!(status, RefactorStatus::Untracked);
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// Where a note stands in the refactoring workflow, derived from its tags.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RefactorStatus {
+    Done,
+    Todo,
+    Untracked,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+impl RefactorStatus {
+    /// Classifies a note by its tags. A note carrying both tags still needs work,
+    /// so the todo tag takes precedence.
+    #[inline]
+    #[must_use]
+    pub fn of(tags: &[String], done_tag: &str, todo_tag: &str) -> Self {
+        if tags.iter().any(|t| t == todo_tag) {
+            Self::Todo
+        } else if tags.iter().any(|t| t == done_tag) {
+            Self::Done
+        } else {
+            Self::Untracked
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Done => "done",
+            Self::Todo => "todo",
+            Self::Untracked => "untracked",
+        }
+    }
+}
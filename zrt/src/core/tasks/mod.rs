@@ -0,0 +1,135 @@
+use serde::Serialize;
+use std::ops::AddAssign;
+
+use crate::core::frontmatter::strip_frontmatter;
+use crate::core::history::percentage;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_should_count_open_and_closed_tasks() {
+        // REQ-TASK-001
+        let content = "\
+- [ ] split this note
+- [x] add tags
+* [X] link to index
+  + [ ] nested item
+1. [ ] numbered
+- [ ]
+- plain item
+- [?] not a task
+[ ] not a list item
+";
+
+        let tasks = count_tasks(content);
+
+        assert_eq!(tasks, TaskCounts { open: 4, closed: 2 });
+        assert_eq!(tasks.total(), 6);
+        assert!((tasks.percentage() - 33.33).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_should_skip_frontmatter_and_code_blocks() {
+        // REQ-TASK-002
+        let content = "---\nnote: \"- [ ] yaml\"\n---\n- [x] real\n```md\n- [ ] example\n```\n~~~\n- [ ] also example\n~~~\n- [ ] real too\n";
+
+        assert_eq!(count_tasks(content), TaskCounts { open: 1, closed: 1 });
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// Markdown task list items (`- [ ]` and `- [x]`) in a note or a whole vault.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct TaskCounts {
+    pub open: usize,
+    pub closed: usize,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+impl TaskCounts {
+    #[inline]
+    #[must_use]
+    pub const fn total(&self) -> usize {
+        self.open + self.closed
+    }
+
+    /// Share of tasks that are closed, rounded to two decimal places.
+    #[inline]
+    #[must_use]
+    pub fn percentage(&self) -> f64 {
+        percentage(self.closed, self.total())
+    }
+}
+
+impl AddAssign for TaskCounts {
+    #[inline]
+    fn add_assign(&mut self, other: Self) {
+        self.open += other.open;
+        self.closed += other.closed;
+    }
+}
+
+/// Count the task list items in a note's body, ignoring its frontmatter and
+/// anything inside fenced code blocks.
+#[must_use]
+pub fn count_tasks(content: &str) -> TaskCounts {
+    let mut tasks = TaskCounts::default();
+    let mut fence: Option<&str> = None;
+
+    for line in strip_frontmatter(content).lines() {
+        let trimmed = line.trim_start();
+        if let Some(marker) = fence {
+            if trimmed.starts_with(marker) {
+                fence = None;
+            }
+            continue;
+        }
+        if let Some(marker) = ["```", "~~~"].into_iter().find(|m| trimmed.starts_with(m)) {
+            fence = Some(marker);
+            continue;
+        }
+
+        match task_state(trimmed) {
+            Some(true) => tasks.closed += 1,
+            Some(false) => tasks.open += 1,
+            None => {}
+        }
+    }
+    tasks
+}
+
+/// Whether a list item is a closed task, or `None` if it is not a task.
+fn task_state(line: &str) -> Option<bool> {
+    let item = match line.strip_prefix(['-', '*', '+']) {
+        Some(rest) => rest,
+        None => {
+            let digits = line.len() - line.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+            if digits == 0 {
+                return None;
+            }
+            line[digits..].strip_prefix(['.', ')'])?
+        }
+    };
+
+    let checkbox = item.strip_prefix(' ')?.trim_start();
+    let rest = checkbox.get(3..)?;
+    if !(rest.is_empty() || rest.starts_with(char::is_whitespace)) {
+        return None;
+    }
+    match checkbox.get(..3)? {
+        "[ ]" => Some(false),
+        "[x]" | "[X]" => Some(true),
+        _ => None,
+    }
+}
@@ -0,0 +1,228 @@
+use std::cmp::Reverse;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::scanner::ScanOptions;
+    use crate::core::vfs::MemoryFs;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_should_add_up_time_per_stage() {
+        // REQ-TIME-001
+        let timings = Timings::new();
+        let sleep = || std::thread::sleep(Duration::from_millis(2));
+
+        let answer = timings.time(Stage::Frontmatter, || {
+            sleep();
+            42
+        });
+        timings.time(Stage::Frontmatter, sleep);
+
+        let report = timings.take();
+        let spent = |stage| {
+            report
+                .stages
+                .iter()
+                .find(|(s, _)| *s == stage)
+                .map(|(_, spent)| *spent)
+        };
+        assert_eq!(answer, 42);
+        assert_eq!(spent(Stage::Read), Some(Duration::ZERO));
+        assert!(spent(Stage::Frontmatter) >= Some(Duration::from_millis(4)));
+        assert_eq!(report.stages.len(), Stage::ALL.len());
+    }
+
+    #[test]
+    fn test_should_keep_the_slowest_notes_first() {
+        // REQ-TIME-002
+        let timings = Timings::new();
+
+        for millis in 0..15 {
+            timings.record_note(
+                Path::new(&format!("{millis}.md")),
+                Duration::from_millis(millis),
+            );
+        }
+
+        let slowest = timings.take().slowest;
+        assert_eq!(slowest.len(), SLOWEST);
+        assert_eq!(
+            slowest[0],
+            (PathBuf::from("14.md"), Duration::from_millis(14))
+        );
+        assert_eq!(slowest[9].0, PathBuf::from("5.md"));
+    }
+
+    #[test]
+    fn test_should_time_only_the_scans_given_the_collector() -> anyhow::Result<()> {
+        // REQ-TIME-003
+        let timings = Arc::new(Timings::new());
+        let vfs = MemoryFs::new()
+            .with_file("/vault/a.md", "one")
+            .with_file("/vault/b.md", "two");
+        let options = ScanOptions::new(["/vault"]).vfs(vfs);
+
+        options.clone().build()?.notes().count();
+        assert!(timings.take().slowest.is_empty());
+
+        options
+            .timings_to(Arc::clone(&timings))
+            .build()?
+            .notes()
+            .count();
+        let mut slowest: Vec<_> = timings.take().slowest.into_iter().map(|(p, _)| p).collect();
+        slowest.sort();
+        assert_eq!(
+            slowest,
+            vec![PathBuf::from("/vault/a.md"), PathBuf::from("/vault/b.md")]
+        );
+        Ok(())
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// How many of the slowest notes a report keeps.
+pub const SLOWEST: usize = 10;
+
+/// A part of a scan whose time is measured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    /// Listing directories and inspecting entries, ignore matching included
+    Traversal,
+    /// Checking paths against excludes, `.zrtignore` and `--ext`
+    IgnoreMatching,
+    /// Reading and decoding files
+    Read,
+    /// Parsing frontmatter YAML
+    Frontmatter,
+    /// Counting words
+    WordCount,
+}
+
+/// Time spent in each stage, summed across threads, and the slowest notes.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TimingReport {
+    pub stages: Vec<(Stage, Duration)>,
+    /// The notes that took longest to read and analyze, slowest first
+    pub slowest: Vec<(PathBuf, Duration)>,
+}
+
+/// Time measured by the scans it is given to, see
+/// [`ScanOptions::timings_to`](crate::core::scanner::ScanOptions::timings_to).
+#[derive(Debug, Default)]
+pub struct Timings {
+    /// Nanoseconds per stage, in [`Stage::ALL`] order
+    stages: [AtomicU64; Stage::ALL.len()],
+    slowest: Mutex<Vec<(PathBuf, Duration)>>,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+impl Stage {
+    pub const ALL: [Self; 5] = [
+        Self::Traversal,
+        Self::IgnoreMatching,
+        Self::Read,
+        Self::Frontmatter,
+        Self::WordCount,
+    ];
+
+    const fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl fmt::Display for Stage {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Traversal => "directory traversal",
+            Self::IgnoreMatching => "ignore matching",
+            Self::Read => "file reads",
+            Self::Frontmatter => "frontmatter parsing",
+            Self::WordCount => "word counting",
+        })
+    }
+}
+
+impl Timings {
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Run `f`, adding its duration to `stage`.
+    #[inline]
+    pub fn time<T>(&self, stage: Stage, f: impl FnOnce() -> T) -> T {
+        let started = Instant::now();
+        let result = f();
+        let nanos = u64::try_from(started.elapsed().as_nanos()).unwrap_or(u64::MAX);
+        self.stages[stage.index()].fetch_add(nanos, Ordering::Relaxed);
+        result
+    }
+
+    /// Run `f`, which reads and analyzes the note at `path`, keeping its
+    /// duration among the slowest notes.
+    #[inline]
+    pub fn time_note<T>(&self, path: &Path, f: impl FnOnce() -> T) -> T {
+        let started = Instant::now();
+        let result = f();
+        self.record_note(path, started.elapsed());
+        result
+    }
+
+    fn record_note(&self, path: &Path, spent: Duration) {
+        if let Ok(mut slowest) = self.slowest.lock() {
+            if slowest.len() == SLOWEST && slowest.last().is_some_and(|(_, s)| *s >= spent) {
+                return;
+            }
+            slowest.push((path.to_path_buf(), spent));
+            slowest.sort_by_key(|(_, spent)| Reverse(*spent));
+            slowest.truncate(SLOWEST);
+        }
+    }
+
+    /// Time measured since the last call, resetting it.
+    #[inline]
+    #[must_use]
+    pub fn take(&self) -> TimingReport {
+        TimingReport {
+            stages: Stage::ALL
+                .iter()
+                .map(|stage| {
+                    let nanos = self.stages[stage.index()].swap(0, Ordering::Relaxed);
+                    (*stage, Duration::from_nanos(nanos))
+                })
+                .collect(),
+            slowest: self
+                .slowest
+                .lock()
+                .map(|mut slowest| std::mem::take(&mut *slowest))
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// Run `f`, adding its duration to `stage` when there are `timings`.
+#[inline]
+pub fn time<T>(timings: Option<&Timings>, stage: Stage, f: impl FnOnce() -> T) -> T {
+    match timings {
+        Some(timings) => timings.time(stage, f),
+        None => f(),
+    }
+}
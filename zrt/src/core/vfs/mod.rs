@@ -0,0 +1,615 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::fs;
+use std::io::{self, BufRead as _, BufReader, Read as _};
+use std::path::{Path, PathBuf};
+use std::string::FromUtf8Error;
+use std::time::SystemTime;
+use tracing::debug;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_should_read_real_files_and_directories() -> Result<()> {
+        // REQ-VFS-001
+        // Given a directory with a file and a subdirectory
+        let dir = TempDir::new()?;
+        fs::write(dir.path().join("b.md"), "hello")?;
+        fs::create_dir(dir.path().join("a"))?;
+
+        // When listing and reading through the real filesystem
+        let entries = RealFs.read_dir(dir.path())?;
+
+        // Then entries come back sorted with their kinds
+        assert_eq!(entries, vec![dir.path().join("a"), dir.path().join("b.md")]);
+        assert_eq!(RealFs.kind(&entries[0], true)?, EntryKind::Dir);
+        assert_eq!(RealFs.kind(&entries[1], true)?, EntryKind::File);
+        assert_eq!(RealFs.read_to_string(&entries[1])?, "hello");
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_imply_directories_from_file_paths() -> Result<()> {
+        // REQ-VFS-002
+        // Given an in-memory vault
+        let vfs = MemoryFs::new()
+            .with_file("/vault/a.md", "one")
+            .with_file("/vault/sub/b.md", "two")
+            .with_file("/vault/sub/deep/c.md", "three");
+
+        // When inspecting it
+        let root = vfs.read_dir(Path::new("/vault"))?;
+
+        // Then parent directories exist without being added explicitly
+        assert_eq!(
+            root,
+            vec![PathBuf::from("/vault/a.md"), PathBuf::from("/vault/sub")]
+        );
+        assert_eq!(vfs.kind(Path::new("/vault/sub"), true)?, EntryKind::Dir);
+        assert_eq!(vfs.kind(Path::new("/vault/a.md"), true)?, EntryKind::File);
+        assert!(!vfs.exists(Path::new("/vault/missing")));
+        assert_eq!(vfs.read_to_string(Path::new("/vault/sub/b.md"))?, "two");
+        assert_eq!(vfs.metadata(Path::new("/vault/sub/deep/c.md"))?.len, 5);
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_reject_invalid_utf8_in_memory() {
+        // REQ-VFS-002
+        let vfs = MemoryFs::new().with_file("/vault/binary.md", [0xFF, 0xFE]);
+
+        let error = vfs.read_to_string(Path::new("/vault/binary.md"));
+
+        assert!(matches!(error, Err(e) if e.kind() == io::ErrorKind::InvalidData));
+    }
+
+    #[test]
+    fn test_should_report_missing_entries() {
+        // REQ-VFS-002
+        let vfs = MemoryFs::new().with_file("/vault/a.md", "one");
+
+        let kind = vfs.kind(Path::new("/elsewhere"), true);
+        let listing = vfs.read_dir(Path::new("/vault/a.md"));
+
+        assert!(matches!(kind, Err(e) if e.kind() == io::ErrorKind::NotFound));
+        assert!(listing.is_err());
+        assert!(vfs.metadata(Path::new("/vault/missing.md")).is_err());
+    }
+
+    #[test]
+    fn test_should_sniff_binary_files_from_first_block() -> Result<()> {
+        // REQ-VFS-003
+        let dir = TempDir::new()?;
+        let image = dir.path().join("image.md");
+        let mut bytes = b"PNG".to_vec();
+        bytes.push(0);
+        bytes.extend(vec![b'a'; SNIFF_LEN * 2]);
+        fs::write(&image, bytes)?;
+        fs::write(
+            dir.path().join("late.md"),
+            [vec![b'a'; SNIFF_LEN], vec![0]].concat(),
+        )?;
+
+        let error = read_text(&image).err();
+
+        assert!(error.is_some_and(|e| is_binary_error(&e)));
+        // A NUL past the first block is not sniffed
+        assert!(read_text(&dir.path().join("late.md")).is_ok());
+        assert!(is_binary(b"GIF89a\0"));
+        assert!(!is_binary("caf\u{e9}".as_bytes()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_read_only_the_frontmatter_block() -> Result<()> {
+        // REQ-VFS-005
+        // Given notes with a long body, no frontmatter, and endless frontmatter
+        let dir = TempDir::new()?;
+        let write = |name: &str, content: String| fs::write(dir.path().join(name), content);
+        write(
+            "long.md",
+            format!("---\r\ntags: [a]\r\n---\r\n{}", "word ".repeat(SNIFF_LEN)),
+        )?;
+        write("plain.md", "# Title\n---\n".to_owned())?;
+        write("endless.md", format!("---\n{}", "tags: [a]\n".repeat(100)))?;
+        write(
+            "bom.md",
+            "\u{feff}---\r\ntags: [a]\r\n---\r\nBody".to_owned(),
+        )?;
+        write("dots.md", "---\ntags: [a]\n...\nBody\n---\n".to_owned())?;
+        let read = |name: &str| read_frontmatter_block(&dir.path().join(name), 64, false);
+
+        // Then reading stops at the closing line, or before the limit
+        assert_eq!(read("long.md")?, "---\r\ntags: [a]\r\n---\r\n");
+        assert_eq!(read("plain.md")?, "");
+        assert_eq!(read("dots.md")?, "---\ntags: [a]\n...\n");
+        assert_eq!(read("bom.md")?, "\u{feff}---\r\ntags: [a]\r\n---\r\n");
+        assert_eq!(
+            read("endless.md")?,
+            format!("---\n{}", "tags: [a]\n".repeat(6))
+        );
+        assert_eq!(
+            RealFs.read_frontmatter(&dir.path().join("long.md"), false)?,
+            "---\r\ntags: [a]\r\n---\r\n"
+        );
+        // The rest is checked for UTF-8 even when a character spans two blocks
+        assert!(rest_is_utf8(vec![0xC3], &mut &[0xA9, b'!'][..])?);
+        assert!(!rest_is_utf8(Vec::new(), &mut &[b'c', 0xE9][..])?);
+        assert!(!rest_is_utf8(vec![0xC3], &mut &[][..])?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_keep_invalid_utf8_for_lossy_reads() -> Result<()> {
+        // REQ-VFS-004
+        // Given a Latin-1 note ("café")
+        let path = Path::new("/vault/latin1.md");
+        let bytes = vec![b'c', b'a', b'f', 0xE9];
+
+        // Then it is rejected, but the error carries it with a replacement character
+        let error = decode_text(path, bytes).err();
+        assert!(error.as_ref().is_some_and(is_not_utf8_error));
+        assert_eq!(
+            error.map(lossy_text).transpose()?,
+            Some("caf\u{FFFD}".to_owned())
+        );
+        assert!(lossy_text(io::Error::from(io::ErrorKind::NotFound)).is_err());
+        Ok(())
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// How many leading bytes are checked for a NUL to decide a file is binary.
+pub const SNIFF_LEN: usize = 8192;
+
+/// How many bytes of a note are read at most when only its frontmatter is
+/// needed; longer frontmatter is cut at the last whole line before it.
+pub const FRONTMATTER_LIMIT: usize = 64 * 1024;
+
+/// The error source of a file sniffed as binary.
+#[derive(Debug)]
+struct BinaryFile;
+
+/// The filesystem operations scans and ignore-file loading need, so they can
+/// run against the real disk, an in-memory tree in tests, or another backend.
+pub trait Vfs: fmt::Debug + Send + Sync {
+    /// Read a whole file as UTF-8 text.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file does not exist or is not valid UTF-8.
+    fn read_to_string(&self, path: &Path) -> io::Result<String>;
+
+    /// Read as much of a file as its frontmatter needs: at least the leading
+    /// `---` block, or with `other_headers` a header in another format, or
+    /// possibly the whole file. Tag-only scans use this so they do not load
+    /// the bodies of large notes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read as text.
+    #[inline]
+    fn read_frontmatter(&self, path: &Path, _other_headers: bool) -> io::Result<String> {
+        self.read_to_string(path)
+    }
+
+    /// What kind of entry `path` is, resolving symbolic links when `follow_links` is set.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the entry does not exist or cannot be inspected.
+    fn kind(&self, path: &Path, follow_links: bool) -> io::Result<EntryKind>;
+
+    /// Paths of the entries directly inside `dir`, sorted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `dir` is not a readable directory.
+    fn read_dir(&self, dir: &Path) -> io::Result<Vec<PathBuf>>;
+
+    /// The canonical form of `path`, used to avoid walking symlink loops.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the path cannot be resolved.
+    #[inline]
+    fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+        Ok(path.to_path_buf())
+    }
+
+    /// Whether anything exists at `path`.
+    #[inline]
+    fn exists(&self, path: &Path) -> bool {
+        self.kind(path, true).is_ok()
+    }
+
+    /// The size and modification time of the file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file does not exist or cannot be inspected,
+    /// or if the filesystem does not support it.
+    #[inline]
+    fn metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            format!("no metadata for {}", path.display()),
+        ))
+    }
+}
+
+/// What a [`Vfs`] knows about a file besides its contents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FileMetadata {
+    /// Size in bytes
+    pub len: u64,
+    /// When the file was last modified, if the filesystem records it
+    pub modified: Option<SystemTime>,
+}
+
+/// The type of a filesystem entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntryKind {
+    File,
+    Dir,
+    /// A symbolic link that was not followed
+    Symlink,
+    /// Sockets, devices and other special files
+    Other,
+}
+
+/// The local disk, through `std::fs`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RealFs;
+
+/// An in-memory tree of files; directories exist implicitly wherever a file
+/// path passes through them.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryFs {
+    files: BTreeMap<PathBuf, Vec<u8>>,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+impl fmt::Display for BinaryFile {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("binary file")
+    }
+}
+
+impl std::error::Error for BinaryFile {}
+
+impl Vfs for RealFs {
+    #[inline]
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        read_text(path)
+    }
+
+    #[inline]
+    fn read_frontmatter(&self, path: &Path, other_headers: bool) -> io::Result<String> {
+        read_frontmatter_block(path, FRONTMATTER_LIMIT, other_headers)
+    }
+
+    #[inline]
+    fn kind(&self, path: &Path, follow_links: bool) -> io::Result<EntryKind> {
+        let metadata = if follow_links {
+            fs::metadata(path)?
+        } else {
+            fs::symlink_metadata(path)?
+        };
+        let file_type = metadata.file_type();
+        Ok(if file_type.is_dir() {
+            EntryKind::Dir
+        } else if file_type.is_file() {
+            EntryKind::File
+        } else if file_type.is_symlink() {
+            EntryKind::Symlink
+        } else {
+            EntryKind::Other
+        })
+    }
+
+    #[inline]
+    fn read_dir(&self, dir: &Path) -> io::Result<Vec<PathBuf>> {
+        let mut entries = fs::read_dir(dir)?
+            .map(|entry| entry.map(|e| e.path()))
+            .collect::<io::Result<Vec<_>>>()?;
+        entries.sort();
+        Ok(entries)
+    }
+
+    #[inline]
+    fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+        fs::canonicalize(path)
+    }
+
+    #[inline]
+    fn metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+        let metadata = fs::metadata(path)?;
+        Ok(FileMetadata {
+            len: metadata.len(),
+            modified: metadata.modified().ok(),
+        })
+    }
+}
+
+impl MemoryFs {
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add or replace a file.
+    #[inline]
+    pub fn insert(&mut self, path: impl Into<PathBuf>, contents: impl Into<Vec<u8>>) {
+        self.files.insert(path.into(), contents.into());
+    }
+
+    /// Builder form of [`MemoryFs::insert`].
+    #[inline]
+    #[must_use]
+    pub fn with_file(mut self, path: impl Into<PathBuf>, contents: impl Into<Vec<u8>>) -> Self {
+        self.insert(path, contents);
+        self
+    }
+
+    fn is_dir(&self, path: &Path) -> bool {
+        self.files
+            .keys()
+            .any(|file| file != path && file.starts_with(path))
+    }
+}
+
+impl Vfs for MemoryFs {
+    #[inline]
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        let bytes = self.files.get(path).ok_or_else(|| not_found(path))?;
+        decode_text(path, bytes.clone())
+    }
+
+    #[inline]
+    fn kind(&self, path: &Path, _follow_links: bool) -> io::Result<EntryKind> {
+        if self.files.contains_key(path) {
+            Ok(EntryKind::File)
+        } else if self.is_dir(path) {
+            Ok(EntryKind::Dir)
+        } else {
+            Err(not_found(path))
+        }
+    }
+
+    #[inline]
+    fn read_dir(&self, dir: &Path) -> io::Result<Vec<PathBuf>> {
+        if !self.is_dir(dir) {
+            return Err(not_found(dir));
+        }
+        let children: BTreeSet<PathBuf> = self
+            .files
+            .keys()
+            .filter_map(|file| file.strip_prefix(dir).ok()?.components().next())
+            .map(|child| dir.join(child))
+            .collect();
+        Ok(children.into_iter().collect())
+    }
+
+    #[inline]
+    fn metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+        let bytes = self.files.get(path).ok_or_else(|| not_found(path))?;
+        Ok(FileMetadata {
+            len: bytes.len() as u64,
+            modified: None,
+        })
+    }
+}
+
+/// Whether `bytes` look like a binary file: a NUL byte within the first
+/// [`SNIFF_LEN`] bytes, which UTF-8 text practically never contains.
+#[inline]
+#[must_use]
+pub fn is_binary(bytes: &[u8]) -> bool {
+    bytes[..bytes.len().min(SNIFF_LEN)].contains(&0)
+}
+
+/// Read a note from the local disk as UTF-8 text, decoded like
+/// [`decode_text`]. Only the first block is read from a binary file before
+/// it is rejected.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read, is binary, or is not valid
+/// UTF-8.
+#[inline]
+pub fn read_text(path: &Path) -> io::Result<String> {
+    let mut file = fs::File::open(path)?;
+    let mut bytes = Vec::new();
+    file.by_ref()
+        .take(SNIFF_LEN as u64)
+        .read_to_end(&mut bytes)?;
+    if is_binary(&bytes) {
+        return Err(skip_binary(path));
+    }
+    file.read_to_end(&mut bytes)?;
+    decode_text(path, bytes)
+}
+
+/// Read only a note's leading `---` frontmatter block from the local disk,
+/// delimiters included, stopping at the closing `---` or `...` line or before
+/// `limit` bytes. With `other_headers`, for scans that read other header
+/// formats (see [`MetadataFormat`](crate::core::frontmatter::MetadataFormat)),
+/// a note without it is read up to its first blank line instead; otherwise it
+/// gives an empty string. The first block is still sniffed so binaries are
+/// rejected like [`read_text`], and the rest of the file is checked for valid
+/// UTF-8 without being kept, so a note is rejected exactly when [`read_text`]
+/// rejects it.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read, is binary, or is not valid
+/// UTF-8, with the same error as [`read_text`].
+#[inline]
+pub fn read_frontmatter_block(
+    path: &Path,
+    limit: usize,
+    other_headers: bool,
+) -> io::Result<String> {
+    let mut reader = BufReader::with_capacity(SNIFF_LEN, fs::File::open(path)?);
+    if is_binary(reader.fill_buf()?) {
+        return Err(skip_binary(path));
+    }
+    let mut bytes = Vec::new();
+    // Bytes read past the block, still to be checked
+    let mut tail = Vec::new();
+    let mut until_blank = false;
+    loop {
+        let start = bytes.len();
+        let remaining = limit.saturating_sub(start) as u64;
+        if reader
+            .by_ref()
+            .take(remaining)
+            .read_until(b'\n', &mut bytes)?
+            == 0
+        {
+            break;
+        }
+        if !bytes.ends_with(b"\n") && bytes.len() >= limit {
+            // Never decode half a line cut off by the limit.
+            tail = bytes.split_off(start);
+            break;
+        }
+        let line = bytes[start..]
+            .strip_suffix(b"\n")
+            .map_or(&bytes[start..], |line| {
+                line.strip_suffix(b"\r").unwrap_or(line)
+            });
+        // A byte order mark may precede the opening delimiter
+        let line = line.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(line);
+        if start == 0 {
+            if line == b"---" {
+                continue;
+            }
+            // Other header formats end at the first blank line
+            if line.trim_ascii().is_empty() || !other_headers {
+                tail = std::mem::take(&mut bytes);
+                break;
+            }
+            until_blank = true;
+        } else if until_blank {
+            if line.trim_ascii().is_empty() {
+                break;
+            }
+        } else if line == b"---" || line == b"..." {
+            break;
+        }
+    }
+    if std::str::from_utf8(&bytes).is_err() || !rest_is_utf8(tail, &mut reader)? {
+        // Reject the note like a full read, carrying all of it for lossy reads
+        return read_text(path);
+    }
+    decode_text(path, bytes)
+}
+
+/// Whether `pending` followed by the rest of `reader` is valid UTF-8,
+/// checked a block at a time so the text is never held in full.
+fn rest_is_utf8(mut pending: Vec<u8>, reader: &mut impl io::Read) -> io::Result<bool> {
+    let mut block = vec![0; SNIFF_LEN];
+    loop {
+        match std::str::from_utf8(&pending) {
+            Ok(_) => pending.clear(),
+            // A character split across blocks is finished by the next one
+            Err(error) if error.error_len().is_none() => {
+                pending.drain(..error.valid_up_to());
+            }
+            Err(_) => return Ok(false),
+        }
+        let read = match reader.read(&mut block) {
+            Ok(read) => read,
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+            Err(error) => return Err(error),
+        };
+        if read == 0 {
+            return Ok(pending.is_empty());
+        }
+        pending.extend_from_slice(&block[..read]);
+    }
+}
+
+/// Decode a file's contents as UTF-8 text.
+///
+/// # Errors
+///
+/// Returns an error if the contents are binary, or not valid UTF-8, in which
+/// case [`lossy_text`] recovers them.
+#[inline]
+pub fn decode_text(path: &Path, bytes: Vec<u8>) -> io::Result<String> {
+    if is_binary(&bytes) {
+        return Err(skip_binary(path));
+    }
+    String::from_utf8(bytes).map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+}
+
+/// Whether `error` is the one [`read_text`] and [`decode_text`] return for
+/// text that is not valid UTF-8.
+#[inline]
+#[must_use]
+pub fn is_not_utf8_error(error: &io::Error) -> bool {
+    error
+        .get_ref()
+        .is_some_and(|inner| inner.is::<FromUtf8Error>())
+}
+
+/// The text of a file rejected as not valid UTF-8, with invalid bytes
+/// replaced by U+FFFD, e.g. for notes in Latin-1.
+///
+/// # Errors
+///
+/// Returns `error` itself unless it is a [`is_not_utf8_error`].
+#[inline]
+pub fn lossy_text(error: io::Error) -> io::Result<String> {
+    if !is_not_utf8_error(&error) {
+        return Err(error);
+    }
+    let kind = error.kind();
+    match error
+        .into_inner()
+        .map(|inner| inner.downcast::<FromUtf8Error>())
+    {
+        Some(Ok(invalid)) => Ok(String::from_utf8_lossy(invalid.as_bytes()).into_owned()),
+        _ => Err(io::Error::from(kind)),
+    }
+}
+
+/// Whether `error` is the one [`read_text`] and [`decode_text`] return for
+/// a binary file.
+#[inline]
+#[must_use]
+pub fn is_binary_error(error: &io::Error) -> bool {
+    error
+        .get_ref()
+        .is_some_and(|inner| inner.is::<BinaryFile>())
+}
+
+fn skip_binary(path: &Path) -> io::Error {
+    debug!(path = %path.display(), reason = "binary file", "skipping");
+    io::Error::new(io::ErrorKind::InvalidData, BinaryFile)
+}
+
+fn not_found(path: &Path) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::NotFound,
+        format!("{} does not exist", path.display()),
+    )
+}
@@ -1,13 +1,26 @@
 use anyhow::Result;
-use clap::Args;
+use clap::{Args, ValueEnum};
 use std::path::PathBuf;
 
+use crate::core::scanner::ScanOptions;
+use crate::count::{Summary, Tally};
+use crate::output::chart;
+use crate::output::exec::ExecArgs;
+use crate::output::exit::NoMatches;
+use crate::output::files_from::FilesFromArgs;
+use crate::output::number;
+use crate::output::open::OpenArgs;
+use crate::output::scan;
+use crate::output::table;
+use crate::output::titles::TitleArgs;
+
 // ============================================
 // TESTS
 // ============================================
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::count::summarize_in;
     use clap::Parser;
 
     #[derive(Parser, Debug)]
@@ -40,6 +53,33 @@ mod tests {
         assert_eq!(args.count.tags, vec!["refactor"]);
     }
 
+    #[test]
+    fn test_count_combines_flags_on_one_line() -> Result<()> {
+        // REQ-COUNT-018
+        let dir = tempfile::TempDir::new()?;
+        std::fs::write(
+            dir.path().join("a.md"),
+            "---\ntags: [todo]\n---\none two three",
+        )?;
+        std::fs::write(dir.path().join("b.md"), "four")?;
+        let args = TestArgs::parse_from([
+            "program",
+            "--percentage",
+            "--files",
+            "--words",
+            "--no-gauge",
+            "todo",
+        ])
+        .count;
+        let dirs = vec![dir.path().to_path_buf()];
+
+        let summary = summarize_in(&ScanOptions::new(&dirs).build()?, &["todo"])?;
+
+        assert_eq!(args.report(&summary, &dirs), vec!["1 3 75.00"]);
+        assert_eq!(summary.total.files, 1);
+        Ok(())
+    }
+
     #[test]
     fn test_count_multiple_tags() {
         let args = TestArgs::parse_from(["program", "--files", "refactor", "draft"]);
@@ -58,6 +98,199 @@ mod tests {
         assert_eq!(args.count.directories.len(), 2);
     }
 
+    #[test]
+    fn test_count_defaults_to_current_directory() {
+        // REQ-COUNT-010
+        let args = TestArgs::parse_from(["program", "--files", "refactor"]);
+        let (tags, dirs) = args.count.targets();
+        assert_eq!(tags, vec!["refactor"]);
+        assert_eq!(dirs, vec![PathBuf::from(".")]);
+    }
+
+    #[test]
+    fn test_count_tag_option_takes_positional_directories() {
+        // REQ-COUNT-020
+        let args = TestArgs::parse_from([
+            "program",
+            "--files",
+            "-t",
+            "to_refactor",
+            "notes/",
+            "archive/",
+            "-d",
+            "work-vault/",
+        ]);
+        let (tags, dirs) = args.count.targets();
+        assert_eq!(tags, vec!["to_refactor"]);
+        assert_eq!(
+            dirs,
+            vec![
+                PathBuf::from("work-vault/"),
+                PathBuf::from("notes/"),
+                PathBuf::from("archive/")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_count_reports_each_directory_and_the_total() -> Result<()> {
+        // REQ-COUNT-021
+        let notes = tempfile::TempDir::new()?;
+        let archive = tempfile::TempDir::new()?;
+        std::fs::write(notes.path().join("a.md"), "---\ntags: [todo]\n---\nA")?;
+        std::fs::write(notes.path().join("b.md"), "---\ntags: [todo]\n---\nB")?;
+        std::fs::write(archive.path().join("c.md"), "---\ntags: [todo]\n---\nC")?;
+        let notes_dir = notes.path().display().to_string();
+        let archive_dir = archive.path().display().to_string();
+        let args =
+            TestArgs::parse_from(["program", "--files", "-t", "todo", &notes_dir, &archive_dir])
+                .count;
+        let (tags, dirs) = args.targets();
+
+        let summary = summarize_in(&ScanOptions::new(&dirs).build()?, &tags)?;
+
+        assert_eq!(
+            args.report(&summary, &dirs),
+            vec![
+                format!("2 {notes_dir}"),
+                format!("1 {archive_dir}"),
+                "3 total".to_owned()
+            ]
+        );
+        assert_eq!(summary.total.files, 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_count_reports_when_nothing_matches() -> Result<()> {
+        // REQ-COUNT-026
+        let dir = tempfile::TempDir::new()?;
+        std::fs::write(dir.path().join("a.md"), "---\ntags: [done]\n---\nA")?;
+        let dirs = vec![dir.path().to_path_buf()];
+        let scanner = ScanOptions::new(&dirs).build()?;
+
+        let summary = summarize_in(&scanner, &["todo"])?;
+        for flag in ["--files", "--words", "--percentage"] {
+            let args = TestArgs::parse_from(["program", flag, "--no-gauge", "todo"]).count;
+            assert_eq!(args.report(&summary, &dirs).len(), 1, "{flag}");
+        }
+        assert_eq!(summary.total.files, 0);
+        assert_eq!(summarize_in(&scanner, &["done"])?.total.files, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_count_reports_each_priority_and_the_total() -> Result<()> {
+        // REQ-COUNT-022
+        let dir = tempfile::TempDir::new()?;
+        std::fs::write(
+            dir.path().join("a.md"),
+            "---\ntags: [todo]\npriority: high\n---\nA",
+        )?;
+        std::fs::write(dir.path().join("b.md"), "---\ntags: [todo]\n---\nB")?;
+        let args = TestArgs::parse_from(["program", "--files", "--group-by", "priority"]).count;
+
+        let scanner = ScanOptions::new([dir.path()]).build()?;
+        let lines = args.report_by_priority(&summarize_in(&scanner, &["todo"])?);
+
+        assert_eq!(args.group_by, Some(GroupBy::Priority));
+        assert_eq!(lines, vec!["1 high", "1 none", "2 total"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_count_accepts_comma_separated_tags() {
+        // REQ-COUNT-027
+        let args = TestArgs::parse_from(["program", "--files", "-t", "a,b", "-t", "c", "notes"]);
+
+        assert_eq!(args.count.tag, vec!["a", "b", "c"]);
+        assert_eq!(
+            args.count.targets(),
+            (vec!["a", "b", "c"], vec![PathBuf::from("notes")])
+        );
+    }
+
+    #[test]
+    fn test_count_reports_each_tag_and_the_total() -> Result<()> {
+        // REQ-COUNT-028
+        let dir = tempfile::TempDir::new()?;
+        std::fs::write(dir.path().join("a.md"), "---\ntags: [todo, idea]\n---\nA B")?;
+        std::fs::write(dir.path().join("b.md"), "---\ntags: [idea]\n---\nC")?;
+        std::fs::write(dir.path().join("c.md"), "D")?;
+        let scanner = ScanOptions::new([dir.path()]).build()?;
+        let args =
+            TestArgs::parse_from(["program", "--files", "--words", "--group-by", "tag"]).count;
+
+        let tags = ["todo", "idea", "done"];
+        let summary = summarize_in(&scanner, &tags)?;
+
+        assert_eq!(
+            args.report_by_tag(&summary, &tags)?,
+            vec!["1 2 todo", "2 3 idea", "0 0 done", "2 3 total"]
+        );
+        assert_eq!(summary.total.files, 2);
+        assert!(
+            args.report_by_tag(&summarize_in(&scanner, &[])?, &[])
+                .is_err()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_count_leaves_out_excepted_tags() -> Result<()> {
+        // REQ-COUNT-029
+        let dir = tempfile::TempDir::new()?;
+        std::fs::write(dir.path().join("a.md"), "---\ntags: [done]\n---\nA B")?;
+        std::fs::write(
+            dir.path().join("b.md"),
+            "---\ntags: [done, archive]\n---\nC D E",
+        )?;
+        std::fs::write(dir.path().join("c.md"), "F G")?;
+        let dirs = vec![dir.path().to_path_buf()];
+        let args = TestArgs::parse_from([
+            "program",
+            "--words",
+            "--percentage",
+            "--no-gauge",
+            "done",
+            "--except",
+            "archive",
+        ])
+        .count;
+        let scanner = ScanOptions::new(&dirs).except_tags(&args.except).build()?;
+
+        let summary = summarize_in(&scanner, &["done"])?;
+
+        assert_eq!(args.except, vec!["archive"]);
+        assert_eq!(args.report(&summary, &dirs), vec!["2 50.00"]);
+        assert_eq!(summary.total.files, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_count_list_flag() {
+        // REQ-COUNT-019
+        let args = TestArgs::parse_from(["program", "--files", "--list", "refactor"]);
+        assert!(args.count.list);
+        assert_eq!(args.count.tags, vec!["refactor"]);
+    }
+
+    #[test]
+    fn test_count_gauge_flags() {
+        // REQ-COUNT-023
+        let args = TestArgs::parse_from(["program", "--percentage", "--gauge"]);
+        assert_eq!(args.count.percentage(62.0), chart::gauge(62.0, 20, false));
+
+        let args = TestArgs::parse_from(["program", "--percentage", "--no-gauge", "--gauge"]);
+        assert_eq!(args.count.percentage(62.0), chart::gauge(62.0, 20, false));
+
+        let args = TestArgs::parse_from(["program", "--percentage", "--gauge", "--no-gauge"]);
+        assert_eq!(args.count.percentage(62.0), "62.00");
+
+        let args = TestArgs::parse_from(["program", "--percentage"]);
+        assert_eq!(args.count.percentage(62.0), "62.00");
+    }
+
     #[test]
     fn test_count_no_exclude_defaults_to_empty() {
         let args = TestArgs::parse_from(["program", "--files"]);
@@ -72,58 +305,218 @@ mod tests {
 #[derive(Args, Debug)]
 pub struct CountArgs {
     /// Directories to scan (space-separated, defaults to current directory)
-    #[arg(short = 'd', long = "dir", num_args = 0.., default_values = &["."])]
+    #[arg(short = 'd', long = "dir", num_args = 0..)]
     pub directories: Vec<PathBuf>,
 
-    /// Tags to filter by (space-separated, omit to count all)
+    /// Tags to filter by (space-separated, omit to count all); with -t these
+    /// are directories to scan instead
     #[arg(num_args = 0..)]
     pub tags: Vec<String>,
 
+    /// Tag to filter by, repeatable or comma-separated; the positional
+    /// arguments then name directories to scan
+    #[arg(short, long = "tag", value_name = "TAG", value_delimiter = ',')]
+    pub tag: Vec<String>,
+
     /// Directories to exclude (space-separated)
     #[arg(short, long, num_args = 0..)]
     pub exclude: Vec<String>,
 
-    /// Count files
-    #[arg(long, group = "count_type")]
+    /// Leave out notes carrying any of these tags (space-separated), from
+    /// the total as well as the matches
+    #[arg(long, num_args = 1.., value_name = "TAG")]
+    pub except: Vec<String>,
+
+    /// Count files (combine with --words and --percentage to measure all in one scan)
+    #[arg(long)]
     pub files: bool,
 
     /// Count words
-    #[arg(long, group = "count_type")]
+    #[arg(long)]
     pub words: bool,
 
     /// Calculate percentage
-    #[arg(long, group = "count_type")]
+    #[arg(long)]
     pub percentage: bool,
+
+    /// Show percentages as a colored gauge (the default on a terminal)
+    #[arg(long, overrides_with = "no_gauge")]
+    pub gauge: bool,
+
+    /// Show percentages as plain numbers even on a terminal
+    #[arg(long, overrides_with = "gauge")]
+    pub no_gauge: bool,
+
+    /// Print the matched files, one per line, after the result
+    #[arg(short, long)]
+    pub list: bool,
+
+    /// Report the result per value of a frontmatter property, then the total
+    #[arg(long, value_enum, value_name = "FIELD")]
+    pub group_by: Option<GroupBy>,
+
+    #[command(flatten)]
+    pub files_from: FilesFromArgs,
+
+    #[command(flatten)]
+    pub titles: TitleArgs,
+
+    #[command(flatten)]
+    pub open: OpenArgs,
+
+    #[command(flatten)]
+    pub exec: ExecArgs,
+}
+
+/// Frontmatter properties results can be grouped by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum GroupBy {
+    Priority,
+    Tag,
 }
 
 // ============================================
 // IMPLEMENTATIONS
 // ============================================
 
+impl CountArgs {
+    /// The tags to filter by and the directories to scan.
+    ///
+    /// Positional arguments are tags unless `-t` is given, in which case they
+    /// are directories scanned after those from `-d`.
+    fn targets(&self) -> (Vec<&str>, Vec<PathBuf>) {
+        let (tags, mut dirs) = if self.tag.is_empty() {
+            (&self.tags, self.directories.clone())
+        } else {
+            let mut dirs = self.directories.clone();
+            dirs.extend(self.tags.iter().map(PathBuf::from));
+            (&self.tag, dirs)
+        };
+        if dirs.is_empty() {
+            dirs.push(PathBuf::from("."));
+        }
+        (tags.iter().map(String::as_str).collect(), dirs)
+    }
+
+    /// The output lines: the bare result for a single directory, otherwise a
+    /// `<value> <dir>` line per directory followed by `<value> total`.
+    fn report(&self, summary: &Summary, directories: &[PathBuf]) -> Vec<String> {
+        if directories.len() < 2 {
+            return vec![self.measure(&summary.total)];
+        }
+        let mut lines: Vec<String> = directories
+            .iter()
+            .zip(&summary.dirs)
+            .map(|(dir, tally)| format!("{} {}", self.measure(tally), dir.display()))
+            .collect();
+        lines.push(format!("{} total", self.measure(&summary.total)));
+        lines
+    }
+
+    /// A `<value> <priority>` line per priority, highest first, then
+    /// `<value> total`; notes without a priority are grouped as `none`.
+    fn report_by_priority(&self, summary: &Summary) -> Vec<String> {
+        let mut lines: Vec<String> = summary
+            .priorities
+            .iter()
+            .map(|group| {
+                format!(
+                    "{} {}",
+                    self.values(group.files, group.words, group.percentage()),
+                    group.priority.as_deref().unwrap_or("none")
+                )
+            })
+            .collect();
+        lines.push(format!("{} total", self.measure(&summary.total)));
+        lines
+    }
+
+    /// A `<value> <tag>` line per tag, in the order given, then `<value>
+    /// total` for files carrying any of them; a file carrying several tags
+    /// counts towards each.
+    fn report_by_tag(&self, summary: &Summary, tags: &[&str]) -> Result<Vec<String>> {
+        if tags.is_empty() {
+            anyhow::bail!("--group-by tag needs at least one tag");
+        }
+        let mut lines: Vec<String> = tags
+            .iter()
+            .zip(&summary.tags)
+            .map(|(tag, tally)| format!("{} {tag}", self.measure(tally)))
+            .collect();
+        lines.push(format!("{} total", self.measure(&summary.total)));
+        Ok(lines)
+    }
+
+    /// The count, word total and/or percentage of a tally.
+    fn measure(&self, tally: &Tally) -> String {
+        self.values(tally.files, tally.words, tally.percentage())
+    }
+
+    /// The requested measures, space-separated in files, words, percentage order.
+    fn values(&self, files: usize, words: usize, percentage: f64) -> String {
+        let mut values = Vec::new();
+        if self.files {
+            values.push(number::count(files));
+        }
+        if self.words {
+            values.push(number::count(words));
+        }
+        if self.percentage {
+            values.push(self.percentage(percentage));
+        }
+        values.join(" ")
+    }
+
+    /// A percentage as a gauge when one is wanted, otherwise to two decimals.
+    fn percentage(&self, value: f64) -> String {
+        let gauge = !self.no_gauge && (self.gauge || table::is_tty());
+        if gauge {
+            chart::gauge(value, chart::GAUGE_WIDTH, table::is_tty())
+        } else {
+            format!("{value:.2}")
+        }
+    }
+}
+
 pub fn run(args: CountArgs) -> Result<()> {
-    // Ensure exactly one flag is provided
-    let flags_set = [args.files, args.words, args.percentage]
-        .iter()
-        .filter(|&&f| f)
-        .count();
-    if flags_set != 1 {
-        anyhow::bail!("Exactly one of --files, --words, or --percentage must be specified");
+    if !(args.files || args.words || args.percentage) {
+        anyhow::bail!("At least one of --files, --words, or --percentage must be specified");
     }
 
     let exclude_dirs: Vec<&str> = args.exclude.iter().map(String::as_str).collect();
-    let tag_refs: Vec<&str> = args.tags.iter().map(String::as_str).collect();
+    let (tag_refs, mut directories) = args.targets();
+    let options = args.files_from.apply(
+        ScanOptions::new(&directories)
+            .exclude(&exclude_dirs)
+            .except_tags(&args.except),
+    )?;
+    let scanner = scan::attach(options).build()?;
+    // A file list replaces the directories, so there is nothing to break down
+    if args.files_from.files_from.is_some() {
+        directories.clear();
+    }
 
-    if args.files {
-        let count = crate::count::count_files(&args.directories, &tag_refs, &exclude_dirs)?;
-        println!("{}", count);
-    } else if args.words {
-        let count = crate::count::count_words(&args.directories, &tag_refs, &exclude_dirs)?;
-        println!("{}", count);
-    } else if args.percentage {
-        let pct =
-            crate::count::calculate_percentage(&args.directories, &tag_refs, &exclude_dirs)?;
-        println!("{:.2}", pct);
+    let summary = crate::count::summarize_in(&scanner, &tag_refs)?;
+    let lines = match args.group_by {
+        Some(GroupBy::Priority) => args.report_by_priority(&summary),
+        Some(GroupBy::Tag) => args.report_by_tag(&summary, &tag_refs)?,
+        None => args.report(&summary, &directories),
+    };
+    for line in lines {
+        println!("{line}");
     }
 
+    let listed = &summary.matched_files;
+    if args.list {
+        for path in listed {
+            println!("{}", args.titles.label(path));
+        }
+    }
+    args.exec.run(listed)?;
+    args.open.open(listed)?;
+
+    if summary.total.files == 0 {
+        return Err(NoMatches.into());
+    }
     Ok(())
 }
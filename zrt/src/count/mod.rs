@@ -1,12 +1,11 @@
 pub mod cli;
 
 use anyhow::Result;
+use std::cmp::Ordering;
 use std::path::PathBuf;
-use walkdir::WalkDir;
 
-use crate::core::filter::utils::should_exclude;
-use crate::core::frontmatter::{parse_frontmatter, strip_frontmatter};
-use crate::core::ignore::load_ignore_patterns;
+use crate::core::history::percentage;
+use crate::core::scanner::{ScanOptions, Scanner};
 
 // ============================================
 // TESTS
@@ -136,6 +135,96 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_should_read_each_file_once_for_percentage() -> Result<()> {
+        // REQ-COUNT-024
+        let dir = TempDir::new()?;
+        create_test_file(&dir, "tagged.md", "---\ntags: [refactor]\n---\nOne two")?;
+        create_test_file(&dir, "untagged.md", "Three four")?;
+        let walked = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let counter = std::sync::Arc::clone(&walked);
+        let scanner = ScanOptions::new([dir.path()])
+            .on_progress(move |_, _| {
+                counter.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            })
+            .build()?;
+
+        assert_eq!(percentage_in(&scanner, &["refactor"])?, 50.0);
+        assert_eq!(walked.load(std::sync::atomic::Ordering::Relaxed), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_tally_files_words_and_percentage_in_one_pass() -> Result<()> {
+        // REQ-COUNT-025
+        let dir = TempDir::new()?;
+        create_test_file(
+            &dir,
+            "tagged.md",
+            "---\ntags: [refactor]\n---\nOne two three",
+        )?;
+        create_test_file(&dir, "untagged.md", "Four")?;
+        create_test_file(&dir, "image.md", "\0")?;
+        let walked = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let counter = std::sync::Arc::clone(&walked);
+        let scanner = ScanOptions::new([dir.path()])
+            .on_progress(move |_, _| {
+                counter.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            })
+            .build()?;
+
+        let tagged = tally_in(&scanner, &["refactor"])?;
+
+        assert_eq!((tagged.files, tagged.words, tagged.total_words), (1, 3, 4));
+        assert_eq!(tagged.percentage(), 75.0);
+        assert_eq!(walked.load(std::sync::atomic::Ordering::Relaxed), 3);
+        // Without tags every file counts, like count_files
+        assert_eq!(tally_in(&scanner, &[])?.files, scanner.count_files(&[])?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_summarize_every_breakdown_in_one_pass() -> Result<()> {
+        // REQ-COUNT-030
+        let notes = TempDir::new()?;
+        let archive = TempDir::new()?;
+        let a = create_test_file(
+            &notes,
+            "a.md",
+            "---\ntags: [todo, idea]\npriority: high\n---\none two",
+        )?;
+        create_test_file(&notes, "b.md", "three")?;
+        let c = create_test_file(&archive, "c.md", "---\ntags: [idea]\n---\nfour five six")?;
+        let walked = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let counter = std::sync::Arc::clone(&walked);
+        let scanner = ScanOptions::new([notes.path(), archive.path()])
+            .on_progress(move |_, _| {
+                counter.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            })
+            .build()?;
+
+        let summary = summarize_in(&scanner, &["todo", "idea"])?;
+
+        assert_eq!(walked.load(std::sync::atomic::Ordering::Relaxed), 3);
+        let pairs = |tallies: &[Tally]| {
+            tallies
+                .iter()
+                .map(|t| (t.files, t.words))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!((summary.total.files, summary.total.words), (2, 5));
+        assert_eq!(pairs(&summary.dirs), vec![(1, 2), (1, 3)]);
+        assert_eq!(pairs(&summary.tags), vec![(1, 2), (2, 5)]);
+        let priorities: Vec<_> = summary
+            .priorities
+            .iter()
+            .map(|g| (g.priority.as_deref(), g.files))
+            .collect();
+        assert_eq!(priorities, vec![(Some("high"), 1), (None, 1)]);
+        assert_eq!(summary.matched_files, vec![a, c]);
+        Ok(())
+    }
+
     // Directory scanning tests
     #[test]
     fn test_should_scan_multiple_directories() -> Result<()> {
@@ -150,6 +239,25 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_should_list_matched_files() -> Result<()> {
+        // REQ-COUNT-019
+        // Given one tagged and one untagged note
+        let dir = TempDir::new()?;
+        let tagged = create_test_file(&dir, "tagged.md", "---\ntags: [refactor]\n---\nContent")?;
+        create_test_file(&dir, "untagged.md", "No tags")?;
+        let dirs = [dir.path().to_path_buf()];
+
+        // When listing the matched files
+        let matched = matched_files(&dirs, &["refactor"], &[])?;
+        let all = matched_files(&dirs, &[], &[])?;
+
+        // Then only the tagged note matches the tag, and every file matches no tags
+        assert_eq!(matched, vec![tagged]);
+        assert_eq!(all.len(), count_files(&dirs, &[], &[])?);
+        Ok(())
+    }
+
     #[test]
     fn test_should_default_to_current_directory() -> Result<()> {
         // REQ-COUNT-010
@@ -172,122 +280,263 @@ mod tests {
         assert_eq!(count, 1);
         Ok(())
     }
+
+    #[test]
+    fn test_should_group_counts_by_priority() -> Result<()> {
+        // REQ-COUNT-022
+        // Given todo notes of several priorities and a done note without one
+        let dir = TempDir::new()?;
+        create_test_file(
+            &dir,
+            "a.md",
+            "---\ntags: [to_refactor]\npriority: low\n---\none two",
+        )?;
+        create_test_file(
+            &dir,
+            "b.md",
+            "---\ntags: [to_refactor]\npriority: high\n---\none",
+        )?;
+        create_test_file(
+            &dir,
+            "c.md",
+            "---\ntags: [refactored]\npriority: high\n---\none two three",
+        )?;
+        create_test_file(&dir, "d.md", "---\ntags: [refactored]\n---\none")?;
+        let scanner = ScanOptions::new([dir.path()]).build()?;
+
+        // When grouping the todo notes by priority
+        let groups = group_by_priority(&scanner, &["to_refactor"])?;
+
+        // Then groups run from high to low priority, with unset last
+        let summary: Vec<_> = groups
+            .iter()
+            .map(|g| (g.priority.as_deref(), g.files, g.percentage()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (Some("high"), 1, 25.0),
+                (Some("low"), 1, 100.0),
+                (None, 0, 0.0)
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_order_numeric_priorities_before_names() {
+        // REQ-COUNT-022
+        let mut priorities = vec![Some("medium"), None, Some("10"), Some("urgent"), Some("2")];
+
+        priorities.sort_by(|a, b| compare_priorities(*a, *b));
+
+        assert_eq!(
+            priorities,
+            vec![Some("2"), Some("10"), Some("medium"), Some("urgent"), None]
+        );
+    }
 }
 
 // ============================================
 // TYPE DEFINITIONS
 // ============================================
 
+/// Notes sharing a `priority` frontmatter value.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PriorityGroup {
+    /// The priority, or `None` for notes without one
+    pub priority: Option<String>,
+    /// Files carrying the requested tags
+    pub files: usize,
+    /// Words in those files
+    pub words: usize,
+    /// Words in every file of the group
+    pub total_words: usize,
+}
+
+/// Everything `zrt count` reports, gathered in a single traversal.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Summary {
+    /// The whole scan
+    pub total: Tally,
+    /// One tally per scanned directory, in [`Scanner::roots`] order
+    pub dirs: Vec<Tally>,
+    /// One tally per requested tag, in the order given; a file carrying
+    /// several of them counts towards each
+    pub tags: Vec<Tally>,
+    /// Tallies per priority, as [`group_by_priority`] orders them
+    pub priorities: Vec<PriorityGroup>,
+    /// Paths of the files counted in `total`, in walk order
+    pub matched_files: Vec<PathBuf>,
+}
+
+/// Files and words carrying the requested tags next to every word scanned,
+/// so files, words and percentage come from one traversal.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Tally {
+    /// Files carrying the tags, or every file when no tags are given
+    pub files: usize,
+    /// Words in the tagged files
+    pub words: usize,
+    /// Words in every readable file
+    pub total_words: usize,
+}
+
 // ============================================
 // IMPLEMENTATIONS
 // ============================================
 
 /// Count files matching tag criteria
 pub fn count_files(dirs: &[PathBuf], tags: &[&str], exclude: &[&str]) -> Result<usize> {
-    let mut count = 0;
+    Ok(scanner(dirs, exclude)?.count_files(tags)?)
+}
 
-    for dir in dirs {
-        let absolute_dir = if dir.is_absolute() {
-            dir.clone()
-        } else {
-            std::env::current_dir()?.join(dir)
-        };
+/// Count words in files matching tag criteria
+pub fn count_words(dirs: &[PathBuf], tags: &[&str], exclude: &[&str]) -> Result<usize> {
+    Ok(scanner(dirs, exclude)?.word_stats(tags)?.words)
+}
 
-        let ignore_patterns = load_ignore_patterns(&absolute_dir)?;
+/// Paths of the files matching tag criteria, in walk order
+pub fn matched_files(dirs: &[PathBuf], tags: &[&str], exclude: &[&str]) -> Result<Vec<PathBuf>> {
+    matched_files_in(&scanner(dirs, exclude)?, tags)
+}
 
-        for entry in WalkDir::new(&absolute_dir)
-            .follow_links(true)
-            .into_iter()
-            .filter_entry(|e| !should_exclude(e, exclude, Some(&ignore_patterns)))
-        {
-            let entry = entry?;
-            if !entry.file_type().is_file() {
-                continue;
-            }
+/// Calculate percentage of words in tagged files
+pub fn calculate_percentage(dirs: &[PathBuf], tags: &[&str], exclude: &[&str]) -> Result<f64> {
+    percentage_in(&scanner(dirs, exclude)?, tags)
+}
 
-            // If no tags specified, count all files
-            if tags.is_empty() {
-                count += 1;
-                continue;
-            }
+/// [`matched_files`] using an already configured scanner
+pub fn matched_files_in(scanner: &Scanner, tags: &[&str]) -> Result<Vec<PathBuf>> {
+    Ok(summarize_in(scanner, tags)?.matched_files)
+}
+
+/// [`calculate_percentage`] using an already configured scanner
+pub fn percentage_in(scanner: &Scanner, tags: &[&str]) -> Result<f64> {
+    Ok(tally_in(scanner, tags)?.percentage())
+}
 
-            // Check if file has any of the specified tags
-            // Skip files that can't be read (binary files, permission issues, etc.)
-            if let Ok(content) = std::fs::read_to_string(entry.path()) {
-                if let Ok(frontmatter) = parse_frontmatter(&content) {
-                    if let Some(file_tags) = frontmatter.tags {
-                        if tags.iter().any(|tag| file_tags.iter().any(|ft| ft == tag)) {
-                            count += 1;
-                        }
-                    }
+/// Tallies for the whole scan, each scanned directory, each of `tags` and
+/// each priority, with the matched files, reading every file only once.
+///
+/// Files that cannot be read as text count, and match, only when no tags are given.
+pub fn summarize_in(scanner: &Scanner, tags: &[&str]) -> Result<Summary> {
+    let mut summary = Summary {
+        dirs: vec![Tally::default(); scanner.roots().count()],
+        tags: vec![Tally::default(); tags.len()],
+        ..Summary::default()
+    };
+    scanner.visit(|visit| {
+        let dir = visit.root.and_then(|root| summary.dirs.get_mut(root));
+        let Some(record) = visit.record else {
+            // Files that are not text only count when every file does
+            if tags.is_empty() {
+                summary.total.add(0, true);
+                if let Some(dir) = dir {
+                    dir.add(0, true);
                 }
+                summary.matched_files.push(visit.path.to_path_buf());
             }
+            return;
+        };
+        let matches = tags.is_empty() || record.has_any_tag(tags);
+        summary.total.add(record.words, matches);
+        if let Some(dir) = dir {
+            dir.add(record.words, matches);
         }
-    }
-
-    Ok(count)
+        for (tag, tally) in tags.iter().zip(&mut summary.tags) {
+            tally.add(record.words, record.has_any_tag(&[tag]));
+        }
+        let groups = &mut summary.priorities;
+        let index = match groups.iter().position(|g| g.priority == record.priority) {
+            Some(index) => index,
+            None => {
+                groups.push(PriorityGroup {
+                    priority: record.priority.clone(),
+                    ..PriorityGroup::default()
+                });
+                groups.len() - 1
+            }
+        };
+        let group = &mut groups[index];
+        group.total_words += record.words;
+        if matches {
+            group.files += 1;
+            group.words += record.words;
+            summary.matched_files.push(visit.path.to_path_buf());
+        }
+    })?;
+    summary
+        .priorities
+        .sort_by(|a, b| compare_priorities(a.priority.as_deref(), b.priority.as_deref()));
+    Ok(summary)
 }
 
-/// Count words in files matching tag criteria
-pub fn count_words(dirs: &[PathBuf], tags: &[&str], exclude: &[&str]) -> Result<usize> {
-    let mut total_words = 0;
+impl Tally {
+    /// Share of all words in tagged files, rounded to 2 decimal places.
+    #[inline]
+    #[must_use]
+    pub fn percentage(&self) -> f64 {
+        percentage(self.words, self.total_words)
+    }
 
-    for dir in dirs {
-        let absolute_dir = if dir.is_absolute() {
-            dir.clone()
-        } else {
-            std::env::current_dir()?.join(dir)
-        };
+    /// Count a file of `words` words, as a match or only towards the total.
+    fn add(&mut self, words: usize, matches: bool) {
+        self.total_words += words;
+        if matches {
+            self.files += 1;
+            self.words += words;
+        }
+    }
+}
 
-        let ignore_patterns = load_ignore_patterns(&absolute_dir)?;
+/// [`count_files`], [`count_words`] and [`calculate_percentage`] at once,
+/// reading every file only once.
+pub fn tally_in(scanner: &Scanner, tags: &[&str]) -> Result<Tally> {
+    Ok(summarize_in(scanner, tags)?.total)
+}
 
-        for entry in WalkDir::new(&absolute_dir)
-            .follow_links(true)
-            .into_iter()
-            .filter_entry(|e| !should_exclude(e, exclude, Some(&ignore_patterns)))
-        {
-            let entry = entry?;
-            if !entry.file_type().is_file() {
-                continue;
-            }
+impl PriorityGroup {
+    /// Share of the group's words in tagged files, rounded to 2 decimal places.
+    #[inline]
+    #[must_use]
+    pub fn percentage(&self) -> f64 {
+        percentage(self.words, self.total_words)
+    }
+}
 
-            // Skip files that can't be read (binary files, permission issues, etc.)
-            if let Ok(content) = std::fs::read_to_string(entry.path()) {
-                let body = strip_frontmatter(&content);
+/// Files and words carrying any of `tags` (or all files when `tags` is
+/// empty) per priority, highest priority first and notes without one last.
+///
+/// A group is listed whenever any scanned note has its priority, even if none
+/// of them carries the tags.
+pub fn group_by_priority(scanner: &Scanner, tags: &[&str]) -> Result<Vec<PriorityGroup>> {
+    Ok(summarize_in(scanner, tags)?.priorities)
+}
 
-                // If no tags specified, count all words
-                if tags.is_empty() {
-                    let words = body.split_whitespace().count();
-                    total_words += words;
-                    continue;
-                }
+/// Orders priorities from most to least urgent: numbers ascending (1 first),
+/// then `critical`, `high`, `medium`, `normal` and `low`, then any other value
+/// alphabetically, and finally no priority.
+fn compare_priorities(a: Option<&str>, b: Option<&str>) -> Ordering {
+    const NAMED: [&str; 5] = ["critical", "high", "medium", "normal", "low"];
 
-                // Check if file has any of the specified tags
-                if let Ok(frontmatter) = parse_frontmatter(&content) {
-                    if let Some(file_tags) = frontmatter.tags {
-                        if tags.iter().any(|tag| file_tags.iter().any(|ft| ft == tag)) {
-                            let words = body.split_whitespace().count();
-                            total_words += words;
-                        }
-                    }
-                }
-            }
+    fn rank(priority: Option<&str>) -> (u8, u64, usize, String) {
+        let Some(priority) = priority else {
+            return (3, 0, 0, String::new());
+        };
+        let lower = priority.to_lowercase();
+        if let Ok(number) = lower.parse::<u64>() {
+            (0, number, 0, lower)
+        } else if let Some(index) = NAMED.iter().position(|name| *name == lower) {
+            (1, 0, index, lower)
+        } else {
+            (2, 0, 0, lower)
         }
     }
 
-    Ok(total_words)
+    rank(a).cmp(&rank(b))
 }
 
-/// Calculate percentage of words in tagged files
-pub fn calculate_percentage(dirs: &[PathBuf], tags: &[&str], exclude: &[&str]) -> Result<f64> {
-    let tagged_words = count_words(dirs, tags, exclude)?;
-    let total_words = count_words(dirs, &[], exclude)?;
-
-    if total_words == 0 {
-        return Ok(0.0);
-    }
-
-    let percentage = (tagged_words as f64 / total_words as f64) * 100.0;
-    // Round to 2 decimal places
-    Ok((percentage * 100.0).round() / 100.0)
+fn scanner(dirs: &[PathBuf], exclude: &[&str]) -> Result<Scanner> {
+    Ok(ScanOptions::new(dirs).exclude(exclude).build()?)
 }
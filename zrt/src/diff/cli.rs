@@ -0,0 +1,167 @@
+use anyhow::Result;
+use clap::Args;
+use comfy_table::Cell;
+use std::path::PathBuf;
+
+use crate::core::history::{HISTORY_FILE, Snapshot, latest_snapshot};
+use crate::diff::{Change, diff_snapshots};
+use crate::init::ZrtConfig;
+use crate::output::{scan, table};
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser, Debug)]
+    struct TestArgs {
+        #[command(flatten)]
+        diff: DiffArgs,
+    }
+
+    #[test]
+    fn test_should_compare_history_with_fresh_scan_by_default() {
+        // REQ-DIFF-005
+
+        // Given / When
+        let args = TestArgs::parse_from(["program"]);
+
+        // Then
+        assert!(args.diff.old.is_none());
+        assert!(args.diff.new.is_none());
+    }
+
+    #[test]
+    fn test_should_accept_two_snapshot_files() {
+        // REQ-DIFF-006
+
+        // Given / When
+        let args = TestArgs::parse_from(["program", "before.jsonl", "after.jsonl"]);
+
+        // Then
+        assert_eq!(args.diff.old, Some(PathBuf::from("before.jsonl")));
+        assert_eq!(args.diff.new, Some(PathBuf::from("after.jsonl")));
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+#[derive(Args, Debug)]
+pub struct DiffArgs {
+    /// Snapshot file to compare from (latest entry; default: .zrt/history.jsonl)
+    pub old: Option<PathBuf>,
+
+    /// Snapshot file to compare to (latest entry; default: a fresh scan)
+    pub new: Option<PathBuf>,
+
+    /// Directories to scan when comparing against a fresh scan
+    #[arg(short = 'd', long = "dir", num_args = 0.., default_values = &["."])]
+    pub directories: Vec<PathBuf>,
+
+    /// Directories to exclude (space-separated)
+    #[arg(short, long, num_args = 0..)]
+    pub exclude: Vec<String>,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+pub fn run(args: DiffArgs, config: &ZrtConfig) -> Result<()> {
+    let old_path = args.old.unwrap_or_else(|| PathBuf::from(HISTORY_FILE));
+    let old = latest_snapshot(&old_path)?;
+
+    let new = if let Some(new_path) = &args.new {
+        latest_snapshot(new_path)?
+    } else {
+        let exclude_dirs: Vec<&str> = args.exclude.iter().map(String::as_str).collect();
+        Snapshot::capture_in(
+            &scan::scanner(&args.directories, &exclude_dirs)?,
+            &config.refactor.done_tag,
+            &config.refactor.todo_tag,
+        )?
+    };
+
+    let diff = diff_snapshots(&old, &new);
+
+    if table::is_tty() {
+        let mut out = table::table(["", "before", "after", "change"]);
+        out.add_row(vec![
+            Cell::new("percentage"),
+            table::percentage(diff.percentage.0),
+            table::percentage(diff.percentage.1),
+            table::change(
+                diff.percentage_delta(),
+                format!("{:+.2}", diff.percentage_delta()),
+                true,
+            ),
+        ]);
+        for (label, change, higher_is_better) in [
+            ("files", diff.files, true),
+            ("words", diff.words, true),
+            ("done files", diff.done_files, true),
+            ("todo files", diff.todo_files, false),
+        ] {
+            out.add_row(vec![
+                Cell::new(label),
+                table::number(change.from),
+                table::number(change.to),
+                table::change(
+                    change.delta() as f64,
+                    format!("{:+}", change.delta()),
+                    higher_is_better,
+                ),
+            ]);
+        }
+        println!("{out}");
+    } else {
+        println!(
+            "percentage: {:.2} -> {:.2} ({:+.2})",
+            diff.percentage.0,
+            diff.percentage.1,
+            diff.percentage_delta()
+        );
+        print_change("files", diff.files);
+        print_change("words", diff.words);
+        print_change("done files", diff.done_files);
+        print_change("todo files", diff.todo_files);
+    }
+
+    print_section("newly done", diff.newly_done.iter().map(String::as_str));
+    print_section(
+        "regressed to todo",
+        diff.regressed.iter().map(String::as_str),
+    );
+    if !diff.word_changes.is_empty() {
+        println!("word changes:");
+        for (path, delta) in &diff.word_changes {
+            println!("  {delta:+} {path}");
+        }
+    }
+
+    Ok(())
+}
+
+fn print_change(label: &str, change: Change) {
+    println!(
+        "{label}: {} -> {} ({:+})",
+        change.from,
+        change.to,
+        change.delta()
+    );
+}
+
+fn print_section<'a>(label: &str, paths: impl ExactSizeIterator<Item = &'a str>) {
+    if paths.len() == 0 {
+        return;
+    }
+    println!("{label}:");
+    for path in paths {
+        println!("  {path}");
+    }
+}
@@ -0,0 +1,207 @@
+pub mod cli;
+
+use crate::core::history::Snapshot;
+use crate::core::status::RefactorStatus;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::links::note_from_content;
+    use chrono::DateTime;
+    use std::path::Path;
+
+    fn snapshot(notes: &[(&str, &str)]) -> Snapshot {
+        let notes: Vec<_> = notes
+            .iter()
+            .map(|(path, content)| note_from_content(Path::new(path), content))
+            .collect();
+        Snapshot::from_notes(
+            DateTime::from_timestamp(0, 0).unwrap(),
+            &notes,
+            "refactored",
+            "to_refactor",
+        )
+    }
+
+    #[test]
+    fn test_should_report_newly_done_notes() {
+        // REQ-DIFF-001
+
+        // Given
+        let old = snapshot(&[
+            ("a.md", "---\ntags: [to_refactor]\n---\none two"),
+            ("b.md", "---\ntags: [refactored]\n---\none two"),
+        ]);
+        let new = snapshot(&[
+            ("a.md", "---\ntags: [refactored]\n---\none two"),
+            ("b.md", "---\ntags: [refactored]\n---\none two"),
+            ("c.md", "---\ntags: [refactored]\n---\none two"),
+        ]);
+
+        // When
+        let diff = diff_snapshots(&old, &new);
+
+        // Then
+        assert_eq!(diff.newly_done, vec!["a.md", "c.md"]);
+        assert!(diff.regressed.is_empty());
+    }
+
+    #[test]
+    fn test_should_report_notes_regressing_to_todo() {
+        // REQ-DIFF-002
+
+        // Given
+        let old = snapshot(&[("a.md", "---\ntags: [refactored]\n---\none")]);
+        let new = snapshot(&[("a.md", "---\ntags: [to_refactor]\n---\none")]);
+
+        // When
+        let diff = diff_snapshots(&old, &new);
+
+        // Then
+        assert_eq!(diff.regressed, vec!["a.md"]);
+        assert!(diff.newly_done.is_empty());
+    }
+
+    #[test]
+    fn test_should_report_word_count_changes() {
+        // REQ-DIFF-003
+
+        // Given
+        let old = snapshot(&[("a.md", "one two three"), ("b.md", "one"), ("c.md", "x")]);
+        let new = snapshot(&[("a.md", "one"), ("b.md", "one"), ("d.md", "one two")]);
+
+        // When
+        let diff = diff_snapshots(&old, &new);
+
+        // Then
+        assert_eq!(
+            diff.word_changes,
+            vec![
+                ("a.md".to_owned(), -2),
+                ("c.md".to_owned(), -1),
+                ("d.md".to_owned(), 2),
+            ]
+        );
+        assert_eq!(diff.words.delta(), -1);
+        assert_eq!(diff.files.delta(), 0);
+    }
+
+    #[test]
+    fn test_should_report_percentage_movement() {
+        // REQ-DIFF-004
+
+        // Given
+        let old = snapshot(&[
+            ("a.md", "---\ntags: [refactored]\n---\none"),
+            ("b.md", "one two three"),
+        ]);
+        let new = snapshot(&[
+            ("a.md", "---\ntags: [refactored]\n---\none"),
+            ("b.md", "---\ntags: [refactored]\n---\none two three"),
+        ]);
+
+        // When
+        let diff = diff_snapshots(&old, &new);
+
+        // Then
+        assert_eq!(diff.percentage.0, 25.0);
+        assert_eq!(diff.percentage.1, 100.0);
+        assert_eq!(diff.percentage_delta(), 75.0);
+        assert_eq!(diff.done_files.delta(), 1);
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// A count before and after.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Change {
+    pub from: usize,
+    pub to: usize,
+}
+
+/// Differences between two snapshots.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SnapshotDiff {
+    pub files: Change,
+    pub words: Change,
+    pub done_files: Change,
+    pub todo_files: Change,
+    /// Done percentage before and after
+    pub percentage: (f64, f64),
+    /// Notes that are done now but were not before, including new notes
+    pub newly_done: Vec<String>,
+    /// Notes that were done before and are marked to refactor again
+    pub regressed: Vec<String>,
+    /// Word count change per note; added and removed notes count from or to zero
+    pub word_changes: Vec<(String, i64)>,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+impl Change {
+    #[inline]
+    #[must_use]
+    pub const fn delta(self) -> i64 {
+        self.to as i64 - self.from as i64
+    }
+}
+
+impl SnapshotDiff {
+    /// Percentage point movement, rounded to 2 decimal places.
+    #[inline]
+    #[must_use]
+    pub fn percentage_delta(&self) -> f64 {
+        ((self.percentage.1 - self.percentage.0) * 100.0).round() / 100.0
+    }
+}
+
+/// Compare an older snapshot with a newer one, listing notes in path order.
+pub fn diff_snapshots(old: &Snapshot, new: &Snapshot) -> SnapshotDiff {
+    let change = |from: usize, to: usize| Change { from, to };
+
+    let mut newly_done = Vec::new();
+    let mut regressed = Vec::new();
+    let mut word_changes = Vec::new();
+
+    for (path, note) in &new.notes {
+        let before = old.notes.get(path);
+        let was = before.map(|n| n.status);
+        if note.status == RefactorStatus::Done && was != Some(RefactorStatus::Done) {
+            newly_done.push(path.clone());
+        }
+        if note.status == RefactorStatus::Todo && was == Some(RefactorStatus::Done) {
+            regressed.push(path.clone());
+        }
+
+        let old_words = before.map_or(0, |n| n.words);
+        if note.words != old_words || before.is_none() {
+            word_changes.push((path.clone(), note.words as i64 - old_words as i64));
+        }
+    }
+
+    for (path, note) in &old.notes {
+        if !new.notes.contains_key(path) {
+            word_changes.push((path.clone(), -(note.words as i64)));
+        }
+    }
+    word_changes.sort();
+
+    SnapshotDiff {
+        files: change(old.files, new.files),
+        words: change(old.words, new.words),
+        done_files: change(old.done_files, new.done_files),
+        todo_files: change(old.todo_files, new.todo_files),
+        percentage: (old.percentage, new.percentage),
+        newly_done,
+        regressed,
+        word_changes,
+    }
+}
@@ -0,0 +1,202 @@
+use anyhow::Result;
+use clap::Args;
+use std::path::PathBuf;
+
+use crate::core::scanner::ScanOptions;
+use crate::doctor::{Check, diagnose};
+use crate::init::{LintConfig, RuleLevel, ZrtConfig};
+use crate::lint::LintProblem;
+use crate::lint::check_rule_names;
+use crate::output::files_from::FilesFromArgs;
+use crate::output::number;
+use crate::output::scan;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser, Debug)]
+    struct TestArgs {
+        #[command(flatten)]
+        doctor: DoctorArgs,
+    }
+
+    fn found(check: Check, path: &str, message: &str) -> LintProblem {
+        LintProblem {
+            path: PathBuf::from(path),
+            rule: check.name(),
+            message: message.to_owned(),
+            fix: None,
+        }
+    }
+
+    #[test]
+    fn test_should_triage_problems_by_severity_with_examples() -> Result<()> {
+        // REQ-DOCTOR-003
+        let args = TestArgs::parse_from(["program", "--examples", "1"]).doctor;
+        let problems = vec![
+            found(
+                Check::BrokenLinks,
+                "a.md",
+                "link to x resolves to no note or file",
+            ),
+            found(
+                Check::BrokenLinks,
+                "b.md",
+                "link to y resolves to no note or file",
+            ),
+            found(Check::OrphanAssets, "pic.png", "not referenced by any note"),
+        ];
+
+        assert_eq!(
+            summary(&problems, &LintConfig::default(), args.examples),
+            vec![
+                "Errors:",
+                "  broken-links: 2",
+                "    a.md: link to x resolves to no note or file",
+                "    … and 1 more",
+                "Warnings:",
+                "  orphan-assets: 1",
+                "    pic.png: not referenced by any note",
+            ]
+        );
+
+        // REQ-DOCTOR-004
+        // Levels from the config move checks between sections or hide them
+        let config: LintConfig =
+            toml::from_str("[rules]\nbroken-links = \"warn\"\norphan-assets = \"ignore\"")?;
+        assert_eq!(
+            summary(&problems, &config, 1),
+            vec![
+                "Warnings:",
+                "  broken-links: 2",
+                "    a.md: link to x resolves to no note or file",
+                "    … and 1 more",
+            ]
+        );
+        assert_eq!(errors(&problems, &config), 0);
+        assert_eq!(errors(&problems, &LintConfig::default()), 2);
+        assert_eq!(
+            summary(&problems[2..], &config, 3),
+            vec!["No problems found"]
+        );
+        Ok(())
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+#[derive(Args, Debug)]
+pub struct DoctorArgs {
+    /// Directories to scan (space-separated, defaults to current directory)
+    #[arg(short = 'd', long = "dir", num_args = 0.., default_values = &["."])]
+    pub directories: Vec<PathBuf>,
+
+    /// Directories to exclude (space-separated)
+    #[arg(short, long, num_args = 0..)]
+    pub exclude: Vec<String>,
+
+    /// Number of example problems to show for each check
+    #[arg(long, default_value_t = 3)]
+    pub examples: usize,
+
+    #[command(flatten)]
+    pub files_from: FilesFromArgs,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+/// The level `config` gives the check a problem was found by.
+fn level(problem: &LintProblem, config: &LintConfig) -> RuleLevel {
+    let default = Check::from_name(problem.rule).map_or(RuleLevel::Error, Check::default_level);
+    config.level(problem.rule, default)
+}
+
+/// Problems counting as errors under `config`.
+fn errors(problems: &[LintProblem], config: &LintConfig) -> usize {
+    problems
+        .iter()
+        .filter(|p| level(p, config) == RuleLevel::Error)
+        .count()
+}
+
+/// Checks that found problems, errors before warnings, each with its count
+/// and up to `examples` of its problems. Checks `config` ignores are left out.
+fn summary(problems: &[LintProblem], config: &LintConfig, examples: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    for (wanted, heading) in [
+        (RuleLevel::Error, "Errors:"),
+        (RuleLevel::Warn, "Warnings:"),
+    ] {
+        let checks: Vec<(Check, Vec<&LintProblem>)> = Check::ALL
+            .into_iter()
+            .filter(|check| config.level(check.name(), check.default_level()) == wanted)
+            .map(|check| {
+                let found = problems.iter().filter(|p| p.rule == check.name()).collect();
+                (check, found)
+            })
+            .filter(|(_, found): &(Check, Vec<_>)| !found.is_empty())
+            .collect();
+        if checks.is_empty() {
+            continue;
+        }
+        lines.push(heading.to_owned());
+        for (check, found) in checks {
+            lines.push(format!(
+                "  {}: {}",
+                check.name(),
+                number::count(found.len())
+            ));
+            for problem in found.iter().take(examples) {
+                lines.push(format!(
+                    "    {}: {}",
+                    problem.path.display(),
+                    problem.message
+                ));
+            }
+            if found.len() > examples {
+                lines.push(format!(
+                    "    … and {} more",
+                    number::count(found.len() - examples)
+                ));
+            }
+        }
+    }
+    if lines.is_empty() {
+        lines.push("No problems found".to_owned());
+    }
+    lines
+}
+
+pub fn run(args: DoctorArgs, config: &ZrtConfig) -> Result<()> {
+    check_rule_names(&config.lint)?;
+    let exclude_dirs: Vec<&str> = args.exclude.iter().map(String::as_str).collect();
+
+    let options = args
+        .files_from
+        .apply(ScanOptions::new(&args.directories).exclude(&exclude_dirs))?;
+    let scanner = scan::attach(options).build()?;
+    let problems = diagnose(
+        &scanner,
+        &config.refactor.done_tag,
+        &config.refactor.todo_tag,
+    )?;
+
+    for line in summary(&problems, &config.lint, args.examples) {
+        println!("{line}");
+    }
+
+    let errors = errors(&problems, &config.lint);
+    if errors > 0 {
+        anyhow::bail!("{errors} error(s) found");
+    }
+    Ok(())
+}
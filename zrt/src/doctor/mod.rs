@@ -0,0 +1,313 @@
+pub mod cli;
+
+use anyhow::Result;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::core::frontmatter::zettel_id;
+use crate::core::links::{AssetFiles, LinkIndex, NoteNode, extract_assets, note_from_analysis};
+use crate::core::scanner::Scanner;
+use crate::empty::{Emptiness, classify};
+use crate::init::RuleLevel;
+use crate::lint::LintProblem;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::scanner::ScanOptions;
+    use crate::core::vfs::MemoryFs;
+
+    fn found(problems: &[LintProblem], check: Check) -> Vec<(&Path, &str)> {
+        problems
+            .iter()
+            .filter(|p| p.rule == check.name())
+            .map(|p| (p.path.as_path(), p.message.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn test_should_run_every_health_check_in_one_scan() -> Result<()> {
+        // REQ-DOCTOR-001
+        // Given a vault with one of each problem
+        let vfs = MemoryFs::new()
+            .with_file(
+                "/v/202401011200-a.md",
+                "---\ntitle: Alpha\n---\n[[202401011200-b]] [[gone]] ![[pic.png]]",
+            )
+            .with_file("/v/202401011200-b.md", "---\ntitle: alpha\n---\nbody")
+            .with_file("/v/plain.md", "no frontmatter")
+            .with_file("/v/both.md", "---\ntags: [refactored, to_refactor]\n---\nx")
+            .with_file("/v/broken.md", "---\ntags: [unclosed\n---\nx")
+            .with_file("/v/blank.md", "\n")
+            .with_file("/v/stub.md", "---\ntitle: Stub\n---\n")
+            .with_file("/v/pic.png", "")
+            .with_file("/v/unused.pdf", "");
+        let scanner = ScanOptions::new(["/v"]).vfs(vfs).build()?;
+
+        // When
+        let problems = diagnose(&scanner, "refactored", "to_refactor")?;
+
+        // Then
+        assert_eq!(
+            found(&problems, Check::BrokenLinks),
+            [(
+                Path::new("/v/202401011200-a.md"),
+                "link to gone resolves to no note or file"
+            )]
+        );
+        assert_eq!(
+            found(&problems, Check::MissingFrontmatter),
+            [(Path::new("/v/plain.md"), "no frontmatter")]
+        );
+        assert_eq!(
+            found(&problems, Check::DuplicateIds),
+            [(
+                Path::new("/v/202401011200-b.md"),
+                "ID 202401011200 also used by /v/202401011200-a.md"
+            )]
+        );
+        assert_eq!(
+            found(&problems, Check::DuplicateTitles),
+            [(
+                Path::new("/v/202401011200-b.md"),
+                "title alpha also used by /v/202401011200-a.md"
+            )]
+        );
+        assert_eq!(
+            found(&problems, Check::ConflictingTags),
+            [(
+                Path::new("/v/both.md"),
+                "tagged both refactored and to_refactor"
+            )]
+        );
+        assert_eq!(found(&problems, Check::InvalidFrontmatter).len(), 1);
+        assert_eq!(
+            found(&problems, Check::EmptyNote),
+            [
+                (Path::new("/v/blank.md"), "empty note (whitespace only)"),
+                (Path::new("/v/stub.md"), "empty note (frontmatter only)")
+            ]
+        );
+        assert_eq!(
+            found(&problems, Check::OrphanAssets),
+            [(Path::new("/v/unused.pdf"), "not referenced by any note")]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_name_checks_and_default_severities() {
+        // REQ-DOCTOR-002
+        assert_eq!(Check::ALL.len(), 8);
+        assert_eq!(Check::from_name("empty-note"), Some(Check::EmptyNote));
+        assert_eq!(Check::from_name("broken-links"), Some(Check::BrokenLinks));
+        assert_eq!(
+            Check::from_name("frontmatter"),
+            Some(Check::InvalidFrontmatter)
+        );
+        assert_eq!(Check::from_name("nope"), None);
+        assert_eq!(Check::BrokenLinks.default_level(), RuleLevel::Error);
+        assert_eq!(Check::OrphanAssets.default_level(), RuleLevel::Warn);
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// A vault health check run by `zrt doctor`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Check {
+    /// Frontmatter that fails to parse as YAML, hiding the note's tags
+    InvalidFrontmatter,
+    /// Links that resolve to no note and no file
+    BrokenLinks,
+    /// Zettel IDs (`YYYYMMDDHHMM` or `YYYYMMDDHHMMSS` file name prefixes) used by several notes
+    DuplicateIds,
+    /// Notes carrying both the done and the todo tag
+    ConflictingTags,
+    /// Titles, ignoring case, used by several notes
+    DuplicateTitles,
+    /// Notes without a frontmatter block
+    MissingFrontmatter,
+    /// Notes without body text
+    EmptyNote,
+    /// Files other than notes that no note references
+    OrphanAssets,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+impl Check {
+    pub const ALL: [Self; 8] = [
+        Self::InvalidFrontmatter,
+        Self::BrokenLinks,
+        Self::DuplicateIds,
+        Self::ConflictingTags,
+        Self::DuplicateTitles,
+        Self::MissingFrontmatter,
+        Self::EmptyNote,
+        Self::OrphanAssets,
+    ];
+
+    /// The name problems are reported under; `frontmatter` and `empty-note`
+    /// are shared with `zrt lint` and the pre-commit hook.
+    #[inline]
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::InvalidFrontmatter => "frontmatter",
+            Self::BrokenLinks => "broken-links",
+            Self::DuplicateIds => "duplicate-ids",
+            Self::ConflictingTags => "conflicting-tags",
+            Self::DuplicateTitles => "duplicate-titles",
+            Self::MissingFrontmatter => "missing-frontmatter",
+            Self::EmptyNote => "empty-note",
+            Self::OrphanAssets => "orphan-assets",
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|check| check.name() == name)
+    }
+
+    /// The level unless `[lint.rules]` sets one: problems that lose data
+    /// or break navigation are errors, untidiness is a warning.
+    #[inline]
+    #[must_use]
+    pub const fn default_level(self) -> RuleLevel {
+        match self {
+            Self::InvalidFrontmatter | Self::BrokenLinks | Self::DuplicateIds => RuleLevel::Error,
+            Self::ConflictingTags
+            | Self::DuplicateTitles
+            | Self::MissingFrontmatter
+            | Self::EmptyNote
+            | Self::OrphanAssets => RuleLevel::Warn,
+        }
+    }
+}
+
+/// Run every health check over the scanned files, reading each once.
+/// Problems come in walk order within each check, checks in [`Check::ALL`]
+/// order.
+///
+/// # Errors
+///
+/// Returns an error if the scan fails.
+pub fn diagnose(scanner: &Scanner, done_tag: &str, todo_tag: &str) -> Result<Vec<LintProblem>> {
+    let files = scanner.files()?;
+    let mut problems = Vec::new();
+    let mut notes = Vec::new();
+    let mut assets = Vec::new();
+
+    assets.extend(files.iter().filter(|path| !scanner.is_note(path)).cloned());
+    for note in scanner.notes() {
+        let note = note?;
+        if !scanner.is_note(&note.path) {
+            continue;
+        }
+        let path = &note.path;
+        let emptiness = classify(&note.content);
+        // A note with nothing in it is only reported as empty
+        let blank = matches!(
+            emptiness,
+            Some(Emptiness::ZeroBytes | Emptiness::Whitespace)
+        );
+        if let Some(error) = &note.frontmatter_error {
+            problems.push(problem(Check::InvalidFrontmatter, path, error.clone()));
+        } else if !blank && note.body().len() == note.content.len() {
+            problems.push(problem(Check::MissingFrontmatter, path, "no frontmatter"));
+        }
+        if let Some(emptiness) = emptiness {
+            let message = format!("empty note ({emptiness})");
+            problems.push(problem(Check::EmptyNote, path, message));
+        }
+        let tagged = |wanted: &str| note.tags.iter().any(|tag| tag == wanted);
+        if tagged(done_tag) && tagged(todo_tag) {
+            let message = format!("tagged both {done_tag} and {todo_tag}");
+            problems.push(problem(Check::ConflictingTags, path, message));
+        }
+        notes.push((note_from_analysis(&note), extract_assets(note.body())));
+    }
+
+    let (nodes, embeds): (Vec<_>, Vec<_>) = notes.into_iter().unzip();
+    let index = LinkIndex::from_notes(nodes);
+    let every_file = AssetFiles::new(files.iter().cloned());
+    let asset_files = AssetFiles::new(assets.iter().cloned());
+    let mut referenced = vec![false; assets.len()];
+
+    for (i, (note, embeds)) in index.notes.iter().zip(&embeds).enumerate() {
+        for link in &note.links {
+            if index.resolve(i, link).is_none() && !every_file.resolves(&note.path, link) {
+                let message = format!("link to {} resolves to no note or file", link.target);
+                problems.push(problem(Check::BrokenLinks, &note.path, message));
+            }
+        }
+        for link in note.links.iter().chain(embeds) {
+            for target in asset_files.targets(&note.path, link) {
+                referenced[target] = true;
+            }
+        }
+    }
+
+    problems.extend(duplicates(&index, Check::DuplicateIds, |note| {
+        zettel_id(&note.stem).map(|id| format!("ID {id}"))
+    }));
+    problems.extend(duplicates(&index, Check::DuplicateTitles, |note| {
+        (note.title != note.stem).then(|| format!("title {}", note.title.to_lowercase()))
+    }));
+    for (path, referenced) in assets.iter().zip(referenced) {
+        if !referenced {
+            problems.push(problem(
+                Check::OrphanAssets,
+                path,
+                "not referenced by any note",
+            ));
+        }
+    }
+
+    problems.sort_by_key(|problem| Check::from_name(problem.rule));
+    Ok(problems)
+}
+
+fn problem(check: Check, path: &Path, message: impl Into<String>) -> LintProblem {
+    LintProblem {
+        path: path.to_path_buf(),
+        rule: check.name(),
+        message: message.into(),
+        fix: None,
+    }
+}
+
+/// A problem for every note sharing its `key` with an earlier note, naming
+/// the first note with it.
+fn duplicates(
+    index: &LinkIndex,
+    check: Check,
+    key: impl Fn(&NoteNode) -> Option<String>,
+) -> Vec<LintProblem> {
+    let mut first: BTreeMap<String, &PathBuf> = BTreeMap::new();
+    let mut problems = Vec::new();
+    for note in &index.notes {
+        let Some(key) = key(note) else {
+            continue;
+        };
+        match first.get(&key) {
+            Some(original) => {
+                let message = format!("{key} also used by {}", original.display());
+                problems.push(problem(check, &note.path, message));
+            }
+            None => {
+                first.insert(key, &note.path);
+            }
+        }
+    }
+    problems
+}
@@ -0,0 +1,112 @@
+use anyhow::Result;
+use chrono::Local;
+use clap::Args;
+use std::path::PathBuf;
+
+use crate::core::scanner::ScanOptions;
+use crate::due::{collect_due, group_by_week};
+use crate::init::ZrtConfig;
+use crate::output::exec::ExecArgs;
+use crate::output::open::OpenArgs;
+use crate::output::scan;
+use crate::output::titles::TitleArgs;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser, Debug)]
+    struct TestArgs {
+        #[command(flatten)]
+        due: DueArgs,
+    }
+
+    #[test]
+    fn test_should_default_to_four_weeks() {
+        // REQ-DUE-003
+        let args = TestArgs::parse_from(["program"]);
+
+        assert_eq!(args.due.weeks, 4);
+        assert!(!args.due.overdue);
+    }
+
+    #[test]
+    fn test_should_accept_weeks_and_overdue() {
+        // REQ-DUE-003
+        let args = TestArgs::parse_from(["program", "-w", "2", "--overdue"]);
+
+        assert_eq!(args.due.weeks, 2);
+        assert!(args.due.overdue);
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+#[derive(Args, Debug)]
+pub struct DueArgs {
+    /// Directories to scan (space-separated, defaults to current directory)
+    #[arg(short = 'd', long = "dir", num_args = 0.., default_values = &["."])]
+    pub directories: Vec<PathBuf>,
+
+    /// Directories to exclude (space-separated)
+    #[arg(short, long, num_args = 0..)]
+    pub exclude: Vec<String>,
+
+    /// Number of weeks ahead to list, counting the current week
+    #[arg(short, long, default_value = "4")]
+    pub weeks: u64,
+
+    /// Only list overdue notes
+    #[arg(long)]
+    pub overdue: bool,
+
+    #[command(flatten)]
+    pub titles: TitleArgs,
+
+    #[command(flatten)]
+    pub open: OpenArgs,
+
+    #[command(flatten)]
+    pub exec: ExecArgs,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+pub fn run(args: DueArgs, config: &ZrtConfig) -> Result<()> {
+    let exclude_dirs: Vec<&str> = args.exclude.iter().map(String::as_str).collect();
+
+    let scanner =
+        scan::attach(ScanOptions::new(&args.directories).exclude(&exclude_dirs)).build()?;
+    let today = Local::now().date_naive();
+    let weeks = if args.overdue { 0 } else { args.weeks };
+    let groups = group_by_week(
+        collect_due(&scanner, &config.refactor.done_tag)?,
+        today,
+        weeks,
+    );
+
+    for (i, (group, notes)) in groups.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        println!("{group}");
+        for note in notes {
+            println!("  {} {}", note.due, args.titles.label(&note.path));
+        }
+    }
+
+    let paths: Vec<&PathBuf> = groups
+        .iter()
+        .flat_map(|(_, notes)| notes.iter().map(|note| &note.path))
+        .collect();
+    args.exec.run(&paths)?;
+    args.open.open(&paths)
+}
@@ -0,0 +1,188 @@
+pub mod cli;
+
+use anyhow::Result;
+use chrono::{Datelike as _, Days, NaiveDate};
+use std::fmt;
+use std::path::PathBuf;
+
+use crate::core::scanner::Scanner;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::scanner::ScanOptions;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap_or_default()
+    }
+
+    fn note(path: &str, due: NaiveDate) -> DueNote {
+        DueNote {
+            path: PathBuf::from(path),
+            due,
+        }
+    }
+
+    #[test]
+    fn test_should_collect_due_notes_by_date_skipping_done() -> Result<()> {
+        // REQ-DUE-001
+        // Given notes with due dates, a deadline, a done note and a note without a date
+        let dir = TempDir::new()?;
+        fs::write(dir.path().join("a.md"), "---\ndue: 2024-05-03\n---\nA")?;
+        fs::write(dir.path().join("b.md"), "---\ndeadline: 2024-05-01\n---\nB")?;
+        fs::write(
+            dir.path().join("c.md"),
+            "---\ndue: 2024-04-01\ntags: [refactored]\n---\nC",
+        )?;
+        fs::write(dir.path().join("d.md"), "---\ntags: [to_refactor]\n---\nD")?;
+        fs::write(dir.path().join("e.md"), "---\ndue: [unclosed\n---\nE")?;
+        let scanner = ScanOptions::new([dir.path()]).build()?;
+
+        // When collecting due notes
+        let notes = collect_due(&scanner, "refactored")?;
+
+        // Then open notes with a date are sorted by it
+        let found: Vec<_> = notes
+            .iter()
+            .map(|n| (n.path.file_name().map(PathBuf::from), n.due))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (Some(PathBuf::from("b.md")), date(2024, 5, 1)),
+                (Some(PathBuf::from("a.md")), date(2024, 5, 3)),
+            ]
+        );
+        // And the note with broken frontmatter is reported, not dropped silently
+        let skipped: Vec<_> = scanner.report().invalid_frontmatter.into_keys().collect();
+        assert_eq!(skipped, vec![dir.path().join("e.md")]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_group_overdue_then_upcoming_weeks() {
+        // REQ-DUE-002
+        // Given today is Wednesday 2024-05-08
+        let today = date(2024, 5, 8);
+        let notes = vec![
+            note("late.md", date(2024, 5, 7)),
+            note("today.md", date(2024, 5, 8)),
+            note("sunday.md", date(2024, 5, 12)),
+            note("next.md", date(2024, 5, 13)),
+            note("far.md", date(2024, 6, 30)),
+        ];
+
+        // When grouping with a two-week horizon
+        let groups = group_by_week(notes, today, 2);
+
+        // Then overdue notes come first, then one group per week starting Monday
+        let summary: Vec<_> = groups
+            .iter()
+            .map(|(group, notes)| (*group, notes.len()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (Group::Overdue, 1),
+                (Group::Week(date(2024, 5, 6)), 2),
+                (Group::Week(date(2024, 5, 13)), 1),
+            ]
+        );
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// A note with a `due` or `deadline` date.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DueNote {
+    pub path: PathBuf,
+    pub due: NaiveDate,
+}
+
+/// A heading in the due report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Group {
+    /// Due before today
+    Overdue,
+    /// Due in the week starting on this Monday
+    Week(NaiveDate),
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+impl fmt::Display for Group {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Overdue => f.write_str("Overdue"),
+            Self::Week(monday) => write!(f, "Week of {monday}"),
+        }
+    }
+}
+
+/// Markdown notes with a due date, sorted by date and then path. Notes tagged
+/// `done_tag` are skipped, as are files whose frontmatter cannot be parsed,
+/// which the scanner lists on its report.
+///
+/// # Errors
+///
+/// Returns an error if the scan fails.
+pub fn collect_due(scanner: &Scanner, done_tag: &str) -> Result<Vec<DueNote>> {
+    let mut notes = Vec::new();
+    for note in scanner.notes() {
+        let note = note?;
+        if !scanner.is_note(&note.path) {
+            continue;
+        }
+
+        let done = note.tags.iter().any(|t| t == done_tag);
+        if let (false, Some(due)) = (done, note.frontmatter.due()) {
+            notes.push(DueNote {
+                path: note.path,
+                due,
+            });
+        }
+    }
+    notes.sort_by(|a, b| a.due.cmp(&b.due).then_with(|| a.path.cmp(&b.path)));
+    Ok(notes)
+}
+
+/// Split date-sorted notes into overdue notes and the upcoming notes of each
+/// week, starting with the current one, for `weeks` weeks. Later notes are
+/// left out and empty groups are omitted.
+#[must_use]
+pub fn group_by_week(
+    notes: Vec<DueNote>,
+    today: NaiveDate,
+    weeks: u64,
+) -> Vec<(Group, Vec<DueNote>)> {
+    let this_week = today - Days::new(u64::from(today.weekday().num_days_from_monday()));
+    let horizon = this_week + Days::new(weeks * 7);
+
+    let mut groups: Vec<(Group, Vec<DueNote>)> = Vec::new();
+    for note in notes {
+        let group = if note.due < today {
+            Group::Overdue
+        } else if note.due < horizon {
+            let offset = note.due.weekday().num_days_from_monday();
+            Group::Week(note.due - Days::new(u64::from(offset)))
+        } else {
+            break;
+        };
+        match groups.last_mut() {
+            Some((last, notes)) if *last == group => notes.push(note),
+            _ => groups.push((group, vec![note])),
+        }
+    }
+    groups
+}
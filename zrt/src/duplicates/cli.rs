@@ -0,0 +1,114 @@
+use anyhow::Result;
+use clap::{Args, ValueEnum};
+use std::path::PathBuf;
+
+use crate::core::scanner::ScanOptions;
+use crate::duplicates::find_duplicates;
+use crate::output::files_from::FilesFromArgs;
+use crate::output::scan;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser, Debug)]
+    struct TestArgs {
+        #[command(flatten)]
+        duplicates: DuplicatesArgs,
+    }
+
+    #[test]
+    fn test_should_default_to_content_duplicates() {
+        // REQ-DUP-004
+        let args = TestArgs::parse_from(["program"]);
+
+        assert_eq!(args.duplicates.by, DuplicatesBy::Content);
+        assert!((args.duplicates.threshold - 0.8).abs() < f64::EPSILON);
+        assert_eq!(args.duplicates.shingle_size, 5);
+    }
+
+    #[test]
+    fn test_should_accept_by_threshold_and_shingle_size() {
+        // REQ-DUP-004
+        let args = TestArgs::parse_from([
+            "program",
+            "--by",
+            "content",
+            "--threshold",
+            "0.6",
+            "--shingle-size",
+            "3",
+        ]);
+
+        assert!((args.duplicates.threshold - 0.6).abs() < f64::EPSILON);
+        assert_eq!(args.duplicates.shingle_size, 3);
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// What two notes must share to count as duplicates.
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DuplicatesBy {
+    /// Near-identical body text
+    #[default]
+    Content,
+}
+
+#[derive(Args, Debug)]
+pub struct DuplicatesArgs {
+    /// Directories to scan (space-separated, defaults to current directory)
+    #[arg(short = 'd', long = "dir", num_args = 0.., default_values = &["."])]
+    pub directories: Vec<PathBuf>,
+
+    /// Directories to exclude (space-separated)
+    #[arg(short, long, num_args = 0..)]
+    pub exclude: Vec<String>,
+
+    /// What to compare notes by
+    #[arg(long, value_enum, default_value_t)]
+    pub by: DuplicatesBy,
+
+    /// Minimum share of word shingles two notes must have in common (0.0-1.0)
+    #[arg(long, default_value = "0.8")]
+    pub threshold: f64,
+
+    /// Number of consecutive words in each shingle
+    #[arg(long, default_value = "5")]
+    pub shingle_size: usize,
+
+    #[command(flatten)]
+    pub files_from: FilesFromArgs,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+pub fn run(args: DuplicatesArgs) -> Result<()> {
+    let exclude_dirs: Vec<&str> = args.exclude.iter().map(String::as_str).collect();
+
+    let options = args
+        .files_from
+        .apply(ScanOptions::new(&args.directories).exclude(&exclude_dirs))?;
+    let scanner = scan::attach(options).build()?;
+    let pairs = match args.by {
+        DuplicatesBy::Content => find_duplicates(&scanner, args.shingle_size, args.threshold)?,
+    };
+
+    for pair in &pairs {
+        println!(
+            "{:.2} {} {}",
+            pair.similarity,
+            pair.first.display(),
+            pair.second.display()
+        );
+    }
+    Ok(())
+}
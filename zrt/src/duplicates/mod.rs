@@ -0,0 +1,246 @@
+pub mod cli;
+
+use anyhow::Result;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::hash::{Hash as _, Hasher as _};
+use std::path::PathBuf;
+
+use crate::core::frontmatter::strip_frontmatter;
+use crate::core::scanner::Scanner;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::scanner::ScanOptions;
+    use std::fs;
+    use tempfile::TempDir;
+
+    const IMPORTED: &str = "Systems thinking looks at the relationships between parts \
+        rather than the parts themselves, and asks how feedback loops shape the \
+        behaviour of the whole system over time.";
+
+    /// Estimated Jaccard similarity: the share of signature positions that agree.
+    fn estimate(a: &[u64], b: &[u64]) -> f64 {
+        let agreeing = a.iter().zip(b).filter(|(x, y)| x == y).count();
+        agreeing as f64 / SIGNATURE_LEN as f64
+    }
+
+    #[test]
+    fn test_should_shingle_note_bodies() {
+        // REQ-DUP-001
+        let short = shingles("---\ntags: [idea]\n---\nOne, two!", 5);
+        let long = shingles("a b c d e f", 5);
+        let same = shingles("A B C D E F", 5);
+
+        assert_eq!(short.len(), 1);
+        assert_eq!(long.len(), 2);
+        assert_eq!(long, same);
+        assert!(shingles("---\ntags: [idea]\n---\n", 5).is_empty());
+    }
+
+    #[test]
+    fn test_should_estimate_similarity_from_signatures() {
+        // REQ-DUP-002
+        let a = shingles(IMPORTED, 5);
+        let b = shingles(&format!("{IMPORTED} One more closing sentence."), 5);
+        let unrelated = shingles(
+            "Completely different words about cooking pasta at home tonight",
+            5,
+        );
+
+        let estimated = estimate(&signature(&a), &signature(&b));
+
+        assert!((estimated - jaccard(&a, &b)).abs() < 0.15);
+        assert!(estimated > 0.7);
+        assert!(estimate(&signature(&a), &signature(&unrelated)) < 0.1);
+    }
+
+    #[test]
+    fn test_should_find_near_duplicate_pairs() -> Result<()> {
+        // REQ-DUP-003
+        // Given an imported note, a lightly edited copy, an unrelated note and an empty one
+        let dir = TempDir::new()?;
+        fs::write(
+            dir.path().join("a.md"),
+            format!("---\ntags: [import]\n---\n{IMPORTED}"),
+        )?;
+        fs::write(
+            dir.path().join("b.md"),
+            IMPORTED.replace("over time", "in time"),
+        )?;
+        fs::write(
+            dir.path().join("c.md"),
+            "Notes on sourdough starters and flour.",
+        )?;
+        fs::write(dir.path().join("d.md"), "")?;
+        fs::write(dir.path().join("e.txt"), IMPORTED)?;
+        let scanner = ScanOptions::new([dir.path()]).build()?;
+
+        // When looking for content duplicates
+        let pairs = find_duplicates(&scanner, 5, 0.5)?;
+
+        // Then only the edited copy pairs with the original
+        assert_eq!(pairs.len(), 1);
+        assert_eq!(pairs[0].first, dir.path().join("a.md"));
+        assert_eq!(pairs[0].second, dir.path().join("b.md"));
+        assert!(pairs[0].similarity >= 0.5 && pairs[0].similarity < 1.0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_sort_by_similarity_descending() -> Result<()> {
+        // REQ-DUP-003
+        let dir = TempDir::new()?;
+        fs::write(dir.path().join("a.md"), IMPORTED)?;
+        fs::write(dir.path().join("b.md"), IMPORTED)?;
+        fs::write(
+            dir.path().join("c.md"),
+            format!("{IMPORTED} Plus a short remark."),
+        )?;
+        let scanner = ScanOptions::new([dir.path()]).build()?;
+
+        let pairs = find_duplicates(&scanner, 5, 0.5)?;
+
+        assert_eq!(pairs.len(), 3);
+        assert!((pairs[0].similarity - 1.0).abs() < f64::EPSILON);
+        assert!(pairs.windows(2).all(|w| w[0].similarity >= w[1].similarity));
+        Ok(())
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// Number of hash functions in a minhash signature.
+const SIGNATURE_LEN: usize = 128;
+
+/// Signature rows per locality-sensitive hashing band; pairs sharing any band
+/// are compared exactly.
+const BAND_ROWS: usize = 4;
+
+/// Two notes whose bodies share most of their text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DuplicatePair {
+    pub first: PathBuf,
+    pub second: PathBuf,
+    /// Jaccard similarity of the notes' shingle sets (0.0-1.0)
+    pub similarity: f64,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+/// Hashes of every run of `size` consecutive words in a note's body.
+///
+/// Words are lowercase alphanumeric runs, so punctuation and case changes do
+/// not hide a copy. Bodies shorter than `size` words form a single shingle.
+#[must_use]
+pub fn shingles(content: &str, size: usize) -> HashSet<u64> {
+    let words: Vec<String> = strip_frontmatter(content)
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect();
+    if words.is_empty() {
+        return HashSet::new();
+    }
+
+    words
+        .windows(size.clamp(1, words.len()))
+        .map(|window| {
+            let mut hasher = DefaultHasher::new();
+            window.hash(&mut hasher);
+            hasher.finish()
+        })
+        .collect()
+}
+
+/// Minhash signature of a shingle set: the smallest value under each of the
+/// seeded hash functions.
+fn signature(shingles: &HashSet<u64>) -> Vec<u64> {
+    (0..SIGNATURE_LEN as u64)
+        .map(|seed| {
+            shingles
+                .iter()
+                .map(|&shingle| mix(shingle ^ seed.wrapping_mul(0x9E37_79B9_7F4A_7C15)))
+                .min()
+                .unwrap_or(u64::MAX)
+        })
+        .collect()
+}
+
+/// SplitMix64 finaliser, used to derive the signature's hash functions.
+const fn mix(mut x: u64) -> u64 {
+    x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    x ^ (x >> 31)
+}
+
+fn jaccard(a: &HashSet<u64>, b: &HashSet<u64>) -> f64 {
+    let shared = a.intersection(b).count();
+    let union = a.len() + b.len() - shared;
+    if union == 0 {
+        0.0
+    } else {
+        shared as f64 / union as f64
+    }
+}
+
+/// Pairs of markdown notes whose bodies are at least `threshold` similar,
+/// most similar first.
+///
+/// Notes are shingled into runs of `shingle_size` words and minhashed; only
+/// pairs that collide in a signature band are compared exactly, so large
+/// vaults avoid comparing every pair.
+///
+/// # Errors
+///
+/// Returns an error if the scan fails or a note cannot be read.
+pub fn find_duplicates(
+    scanner: &Scanner,
+    shingle_size: usize,
+    threshold: f64,
+) -> Result<Vec<DuplicatePair>> {
+    let mut notes = Vec::new();
+    for note in scanner.notes() {
+        let note = note?;
+        if !scanner.is_note(&note.path) {
+            continue;
+        }
+        let set = shingles(&note.content, shingle_size);
+        if !set.is_empty() {
+            notes.push((note.path, set));
+        }
+    }
+
+    let mut candidates = BTreeSet::new();
+    let mut buckets: HashMap<(usize, &[u64]), Vec<usize>> = HashMap::new();
+    let signatures: Vec<Vec<u64>> = notes.iter().map(|(_, set)| signature(set)).collect();
+    for (i, sig) in signatures.iter().enumerate() {
+        for (band, rows) in sig.chunks(BAND_ROWS).enumerate() {
+            let bucket = buckets.entry((band, rows)).or_default();
+            candidates.extend(bucket.iter().map(|&j| (j, i)));
+            bucket.push(i);
+        }
+    }
+
+    let mut pairs: Vec<DuplicatePair> = candidates
+        .into_iter()
+        .filter_map(|(i, j)| {
+            let similarity = jaccard(&notes[i].1, &notes[j].1);
+            (similarity >= threshold).then(|| DuplicatePair {
+                first: notes[i].0.clone(),
+                second: notes[j].0.clone(),
+                similarity,
+            })
+        })
+        .collect();
+    pairs.sort_by(|a, b| b.similarity.total_cmp(&a.similarity));
+    Ok(pairs)
+}
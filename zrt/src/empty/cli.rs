@@ -0,0 +1,87 @@
+use anyhow::Result;
+use clap::Args;
+use std::path::PathBuf;
+
+use crate::core::scanner::ScanOptions;
+use crate::empty::find_empty;
+use crate::output::exec::ExecArgs;
+use crate::output::files_from::FilesFromArgs;
+use crate::output::open::OpenArgs;
+use crate::output::scan;
+use crate::output::titles::TitleArgs;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser, Debug)]
+    struct TestArgs {
+        #[command(flatten)]
+        empty: EmptyArgs,
+    }
+
+    #[test]
+    fn test_should_accept_directories_and_exec() {
+        // REQ-EMPTY-003
+        let args = TestArgs::parse_from(["program", "-d", "notes", "inbox", "--exec", "rm"]);
+
+        assert_eq!(
+            args.empty.directories,
+            vec![PathBuf::from("notes"), PathBuf::from("inbox")]
+        );
+        assert_eq!(args.empty.exec.exec.as_deref(), Some("rm"));
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+#[derive(Args, Debug)]
+pub struct EmptyArgs {
+    /// Directories to scan (space-separated, defaults to current directory)
+    #[arg(short = 'd', long = "dir", num_args = 0.., default_values = &["."])]
+    pub directories: Vec<PathBuf>,
+
+    /// Directories to exclude (space-separated)
+    #[arg(short, long, num_args = 0..)]
+    pub exclude: Vec<String>,
+
+    #[command(flatten)]
+    pub files_from: FilesFromArgs,
+
+    #[command(flatten)]
+    pub titles: TitleArgs,
+
+    #[command(flatten)]
+    pub open: OpenArgs,
+
+    #[command(flatten)]
+    pub exec: ExecArgs,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+pub fn run(args: EmptyArgs) -> Result<()> {
+    let exclude_dirs: Vec<&str> = args.exclude.iter().map(String::as_str).collect();
+
+    let options = args
+        .files_from
+        .apply(ScanOptions::new(&args.directories).exclude(&exclude_dirs))?;
+    let scanner = scan::attach(options).build()?;
+    let empty = find_empty(&scanner)?;
+
+    for note in &empty {
+        println!("{} ({})", args.titles.label(&note.path), note.emptiness);
+    }
+
+    let paths: Vec<&PathBuf> = empty.iter().map(|note| &note.path).collect();
+    args.exec.run(&paths)?;
+    args.open.open(&paths)
+}
@@ -0,0 +1,142 @@
+pub mod cli;
+
+use anyhow::{Context as _, Result};
+use std::fmt;
+use std::path::PathBuf;
+
+use crate::core::frontmatter::strip_frontmatter;
+use crate::core::scanner::Scanner;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::scanner::ScanOptions;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_should_classify_empty_content() {
+        // REQ-EMPTY-001
+        assert_eq!(classify(""), Some(Emptiness::ZeroBytes));
+        assert_eq!(classify(" \n\t\n"), Some(Emptiness::Whitespace));
+        assert_eq!(
+            classify("---\ntags: [idea]\n---\n\n"),
+            Some(Emptiness::FrontmatterOnly)
+        );
+        assert_eq!(classify("---\ntags: [idea]\n---\nbody"), None);
+        assert_eq!(classify("just text"), None);
+    }
+
+    #[test]
+    fn test_should_find_empty_notes_in_walk_order() -> Result<()> {
+        // REQ-EMPTY-002
+        // Given a vault with empty notes of every kind, a real note and an empty non-note
+        let dir = TempDir::new()?;
+        fs::write(dir.path().join("a.md"), "")?;
+        fs::write(dir.path().join("b.md"), "---\ntags: [to_refactor]\n---\n")?;
+        fs::write(dir.path().join("c.md"), "a real note")?;
+        fs::write(dir.path().join("d.md"), "\n\n")?;
+        fs::write(dir.path().join("e.txt"), "")?;
+        let scanner = ScanOptions::new([dir.path()]).build()?;
+
+        // When looking for empty notes
+        let empty = find_empty(&scanner)?;
+
+        // Then each empty markdown note is reported with its kind
+        let found: Vec<_> = empty
+            .iter()
+            .map(|note| (note.path.file_name().map(PathBuf::from), note.emptiness))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (Some(PathBuf::from("a.md")), Emptiness::ZeroBytes),
+                (Some(PathBuf::from("b.md")), Emptiness::FrontmatterOnly),
+                (Some(PathBuf::from("d.md")), Emptiness::Whitespace),
+            ]
+        );
+        Ok(())
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// Why a note counts as empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Emptiness {
+    /// The file has no content at all
+    ZeroBytes,
+    /// The file holds only whitespace
+    Whitespace,
+    /// The file has frontmatter but no body text
+    FrontmatterOnly,
+}
+
+/// A note without any body text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmptyNote {
+    pub path: PathBuf,
+    pub emptiness: Emptiness,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+impl fmt::Display for Emptiness {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::ZeroBytes => "zero bytes",
+            Self::Whitespace => "whitespace only",
+            Self::FrontmatterOnly => "frontmatter only",
+        })
+    }
+}
+
+/// How a note's content is empty, or `None` if it has body text.
+#[must_use]
+pub fn classify(content: &str) -> Option<Emptiness> {
+    if content.is_empty() {
+        Some(Emptiness::ZeroBytes)
+    } else if content.trim().is_empty() {
+        Some(Emptiness::Whitespace)
+    } else if strip_frontmatter(content).trim().is_empty() {
+        Some(Emptiness::FrontmatterOnly)
+    } else {
+        None
+    }
+}
+
+/// Markdown notes without body text, in walk order.
+///
+/// # Errors
+///
+/// Returns an error if the scan fails or an empty note cannot be re-read.
+pub fn find_empty(scanner: &Scanner) -> Result<Vec<EmptyNote>> {
+    let mut empty = Vec::new();
+    for record in scanner.iter() {
+        let record = record?;
+        let is_note = scanner.is_note(&record.path);
+        // Only notes without a single body word need their content inspected
+        if !is_note || record.words > 0 {
+            continue;
+        }
+
+        let content = scanner
+            .read_to_string(&record.path)
+            .with_context(|| format!("Failed to read {}", record.path.display()))?;
+        if let Some(emptiness) = classify(&content) {
+            empty.push(EmptyNote {
+                path: record.path,
+                emptiness,
+            });
+        }
+    }
+    Ok(empty)
+}
@@ -0,0 +1,78 @@
+use anyhow::Result;
+use clap::Args;
+use std::path::PathBuf;
+
+use crate::core::links::LinkIndex;
+use crate::export::export_sqlite;
+use crate::init::ZrtConfig;
+use crate::output::scan;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser, Debug)]
+    struct TestArgs {
+        #[command(flatten)]
+        export: ExportArgs,
+    }
+
+    #[test]
+    fn test_should_require_an_output_database() {
+        // REQ-EXPORT-004
+        assert!(TestArgs::try_parse_from(["program"]).is_err());
+
+        let args = TestArgs::parse_from(["program", "--sqlite", "vault.db", "-e", "archive"]);
+
+        assert_eq!(args.export.sqlite, PathBuf::from("vault.db"));
+        assert_eq!(args.export.exclude, vec!["archive"]);
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+#[derive(Args, Debug)]
+pub struct ExportArgs {
+    /// Directories to scan (space-separated, defaults to current directory)
+    #[arg(short = 'd', long = "dir", num_args = 0.., default_values = &["."])]
+    pub directories: Vec<PathBuf>,
+
+    /// Directories to exclude (space-separated)
+    #[arg(short, long, num_args = 0..)]
+    pub exclude: Vec<String>,
+
+    /// SQLite database to write, replacing it if it exists
+    #[arg(long, value_name = "FILE")]
+    pub sqlite: PathBuf,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+pub fn run(args: ExportArgs, config: &ZrtConfig) -> Result<()> {
+    let exclude_dirs: Vec<&str> = args.exclude.iter().map(String::as_str).collect();
+
+    let index = LinkIndex::build_in(&scan::scanner(&args.directories, &exclude_dirs)?)?;
+    let summary = export_sqlite(
+        &index,
+        &args.sqlite,
+        &config.refactor.done_tag,
+        &config.refactor.todo_tag,
+    )?;
+
+    println!(
+        "Exported {} notes, {} tags and {} links to {}",
+        summary.notes,
+        summary.tags,
+        summary.links,
+        args.sqlite.display()
+    );
+    Ok(())
+}
@@ -0,0 +1,251 @@
+pub mod cli;
+
+use anyhow::{Context as _, Result};
+use rusqlite::{Connection, params};
+use std::path::Path;
+use std::time::UNIX_EPOCH;
+
+use crate::core::links::{LinkIndex, LinkKind, normalize_path};
+use crate::core::status::RefactorStatus;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn vault() -> Result<TempDir> {
+        let dir = TempDir::new()?;
+        fs::create_dir(dir.path().join("ideas"))?;
+        fs::write(
+            dir.path().join("ideas/a.md"),
+            "---\ntags: [to_refactor, idea]\ncreated: 2024-01-31\n---\none two three [[b]] [[missing]]",
+        )?;
+        fs::write(
+            dir.path().join("b.md"),
+            "---\ntags: [refactored]\n---\nfour [a](ideas/a.md)",
+        )?;
+        Ok(dir)
+    }
+
+    #[test]
+    fn test_should_export_notes_with_metadata() -> Result<()> {
+        // REQ-EXPORT-001
+        // Given a vault with two notes
+        let dir = vault()?;
+        let index = LinkIndex::build(&[dir.path().to_path_buf()], &[])?;
+        let db = dir.path().join("vault.db");
+
+        // When exporting it
+        let summary = export_sqlite(&index, &db, "refactored", "to_refactor")?;
+
+        // Then each note has a row with its words, size, mtime, creation date and folder
+        let conn = Connection::open(&db)?;
+        let (words, size, mtime, created, folder, status): (
+            i64,
+            i64,
+            Option<i64>,
+            Option<String>,
+            String,
+            String,
+        ) = conn.query_row(
+            "SELECT words, size, mtime, created, folder, status FROM notes WHERE stem = 'a'",
+            [],
+            |row| {
+                Ok((
+                    row.get(0)?,
+                    row.get(1)?,
+                    row.get(2)?,
+                    row.get(3)?,
+                    row.get(4)?,
+                    row.get(5)?,
+                ))
+            },
+        )?;
+        assert_eq!(summary.notes, 2);
+        assert_eq!(words, 5);
+        assert_eq!(
+            size,
+            i64::try_from(fs::metadata(dir.path().join("ideas/a.md"))?.len())?
+        );
+        assert!(mtime.is_some_and(|t| t > 0));
+        assert_eq!(created.as_deref(), Some("2024-01-31"));
+        assert!(folder.ends_with("ideas"));
+        assert_eq!(status, "todo");
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_export_tags_and_resolved_links() -> Result<()> {
+        // REQ-EXPORT-002
+        let dir = vault()?;
+        let index = LinkIndex::build(&[dir.path().to_path_buf()], &[])?;
+        let db = dir.path().join("vault.db");
+
+        let summary = export_sqlite(&index, &db, "refactored", "to_refactor")?;
+
+        let conn = Connection::open(&db)?;
+        let idea_notes: i64 =
+            conn.query_row("SELECT COUNT(*) FROM tags WHERE tag = 'idea'", [], |row| {
+                row.get(0)
+            })?;
+        let resolved: i64 = conn.query_row(
+            "SELECT COUNT(*) FROM links WHERE target_id IS NOT NULL",
+            [],
+            |row| row.get(0),
+        )?;
+        assert_eq!(idea_notes, 1);
+        assert_eq!(summary.tags, 3);
+        assert_eq!(summary.links, 3);
+        assert_eq!(resolved, 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_replace_existing_database() -> Result<()> {
+        // REQ-EXPORT-003
+        let dir = vault()?;
+        let index = LinkIndex::build(&[dir.path().to_path_buf()], &[])?;
+        let db = dir.path().join("vault.db");
+
+        export_sqlite(&index, &db, "refactored", "to_refactor")?;
+        export_sqlite(&index, &db, "refactored", "to_refactor")?;
+
+        let conn = Connection::open(&db)?;
+        let notes: i64 = conn.query_row("SELECT COUNT(*) FROM notes", [], |row| row.get(0))?;
+        assert_eq!(notes, 2);
+        Ok(())
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// Rows written by an export.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ExportSummary {
+    pub notes: usize,
+    pub tags: usize,
+    pub links: usize,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+const SCHEMA: &str = "
+CREATE TABLE notes (
+    id INTEGER PRIMARY KEY,
+    path TEXT NOT NULL UNIQUE,
+    folder TEXT NOT NULL,
+    stem TEXT NOT NULL,
+    title TEXT NOT NULL,
+    words INTEGER NOT NULL,
+    size INTEGER,
+    mtime INTEGER,
+    created TEXT,
+    status TEXT NOT NULL
+);
+CREATE TABLE tags (
+    note_id INTEGER NOT NULL REFERENCES notes(id),
+    tag TEXT NOT NULL
+);
+CREATE TABLE links (
+    source_id INTEGER NOT NULL REFERENCES notes(id),
+    target TEXT NOT NULL,
+    kind TEXT NOT NULL,
+    target_id INTEGER REFERENCES notes(id)
+);
+CREATE INDEX tags_tag ON tags(tag);
+CREATE INDEX links_target ON links(target_id);
+";
+
+/// Write every note, its tags and its links to a new SQLite database at `path`,
+/// replacing any existing file.
+///
+/// `notes.size` is in bytes and `notes.mtime` in seconds since the Unix epoch;
+/// both are `NULL` when the file can no longer be inspected. `notes.created` is
+/// a `YYYY-MM-DD` date, `NULL` when neither frontmatter nor file name gives one.
+/// `links.target_id`
+/// is `NULL` for links that do not resolve to a scanned note.
+///
+/// # Errors
+///
+/// Returns an error if the database cannot be created or written.
+pub fn export_sqlite(
+    index: &LinkIndex,
+    path: &Path,
+    done_tag: &str,
+    todo_tag: &str,
+) -> Result<ExportSummary> {
+    if path.exists() {
+        std::fs::remove_file(path)
+            .with_context(|| format!("Failed to replace {}", path.display()))?;
+    }
+    let mut conn = Connection::open(path)
+        .with_context(|| format!("Failed to create database {}", path.display()))?;
+    conn.execute_batch(SCHEMA)?;
+
+    let mut summary = ExportSummary::default();
+    let tx = conn.transaction()?;
+    {
+        let mut insert_note = tx.prepare(
+            "INSERT INTO notes (id, path, folder, stem, title, words, size, mtime, created, status)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+        )?;
+        let mut insert_tag = tx.prepare("INSERT INTO tags (note_id, tag) VALUES (?1, ?2)")?;
+        let mut insert_link = tx.prepare(
+            "INSERT INTO links (source_id, target, kind, target_id) VALUES (?1, ?2, ?3, ?4)",
+        )?;
+
+        for (id, note) in index.notes.iter().enumerate() {
+            let path = normalize_path(&note.path);
+            let folder = path.parent().unwrap_or_else(|| Path::new(""));
+            let metadata = std::fs::metadata(&note.path).ok();
+            let size = metadata.as_ref().map(std::fs::Metadata::len);
+            let mtime = metadata
+                .and_then(|m| m.modified().ok())
+                .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+                .map(|d| d.as_secs());
+            let status = RefactorStatus::of(&note.tags, done_tag, todo_tag);
+
+            insert_note.execute(params![
+                id,
+                path.to_string_lossy(),
+                folder.to_string_lossy(),
+                note.stem,
+                note.title,
+                note.words,
+                size,
+                mtime,
+                note.created.map(|date| date.to_string()),
+                status.as_str(),
+            ])?;
+            summary.notes += 1;
+        }
+
+        // Links may point forward, so they follow once every note has a row.
+        for (id, note) in index.notes.iter().enumerate() {
+            for tag in &note.tags {
+                insert_tag.execute(params![id, tag])?;
+                summary.tags += 1;
+            }
+
+            for link in &note.links {
+                let kind = match link.kind {
+                    LinkKind::Wiki => "wiki",
+                    LinkKind::Markdown => "markdown",
+                };
+                insert_link.execute(params![id, link.target, kind, index.resolve(id, link)])?;
+                summary.links += 1;
+            }
+        }
+    }
+    tx.commit()?;
+
+    Ok(summary)
+}
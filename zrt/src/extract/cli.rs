@@ -0,0 +1,111 @@
+use anyhow::Result;
+use clap::{Args, ValueEnum};
+use std::path::PathBuf;
+
+use crate::core::scanner::ScanOptions;
+use crate::extract::{Field, columns, extract};
+use crate::output::csv;
+use crate::output::files_from::FilesFromArgs;
+use crate::output::scan;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser, Debug)]
+    struct TestArgs {
+        #[command(flatten)]
+        extract: ExtractArgs,
+    }
+
+    #[test]
+    fn test_should_accept_comma_separated_fields() {
+        // REQ-EXTRACT-003
+        let args = TestArgs::parse_from([
+            "program",
+            "--fields",
+            "title,status,created,tags",
+            "--format",
+            "csv",
+        ]);
+
+        assert_eq!(
+            args.extract.fields,
+            vec!["title", "status", "created", "tags"]
+        );
+        assert_eq!(args.extract.format, ExtractFormat::Csv);
+    }
+
+    #[test]
+    fn test_should_default_to_title_and_tags_as_csv() {
+        // REQ-EXTRACT-003
+        let args = TestArgs::parse_from(["program"]);
+
+        assert_eq!(args.extract.fields, vec!["title", "tags"]);
+        assert_eq!(args.extract.format, ExtractFormat::Csv);
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// Output format of `zrt extract`.
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ExtractFormat {
+    /// Comma-separated values with a header row
+    #[default]
+    Csv,
+}
+
+#[derive(Args, Debug)]
+pub struct ExtractArgs {
+    /// Directories to scan (space-separated, defaults to current directory)
+    #[arg(short = 'd', long = "dir", num_args = 0.., default_values = &["."])]
+    pub directories: Vec<PathBuf>,
+
+    /// Directories to exclude (space-separated)
+    #[arg(short, long, num_args = 0..)]
+    pub exclude: Vec<String>,
+
+    /// Frontmatter fields to extract (comma-separated); `path` and `words`
+    /// are added unless listed
+    #[arg(long, value_delimiter = ',', default_value = "title,tags")]
+    pub fields: Vec<String>,
+
+    /// Output format
+    #[arg(long, value_enum, default_value_t)]
+    pub format: ExtractFormat,
+
+    #[command(flatten)]
+    pub files_from: FilesFromArgs,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+pub fn run(args: ExtractArgs) -> Result<()> {
+    let exclude_dirs: Vec<&str> = args.exclude.iter().map(String::as_str).collect();
+
+    let options = args
+        .files_from
+        .apply(ScanOptions::new(&args.directories).exclude(&exclude_dirs))?;
+    let scanner = scan::attach(options).build()?;
+    let fields = columns(&args.fields);
+    let rows = extract(&scanner, &fields)?;
+
+    match args.format {
+        ExtractFormat::Csv => {
+            println!("{}", csv::record(fields.iter().map(Field::name)));
+            for row in &rows {
+                println!("{}", csv::record(row));
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,193 @@
+pub mod cli;
+
+use anyhow::Result;
+
+use crate::core::note::NoteAnalysis;
+use crate::core::scanner::{NoteRecord, Scanner};
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::scanner::ScanOptions;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn fields(names: &[&str]) -> Vec<Field> {
+        columns(&names.iter().map(|&n| n.to_owned()).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn test_should_add_path_and_words_unless_placed() {
+        // REQ-EXTRACT-001
+        assert_eq!(
+            fields(&["title", "status"]),
+            vec![
+                Field::Path,
+                Field::Title,
+                Field::Property("status".to_owned()),
+                Field::Words,
+            ]
+        );
+        assert_eq!(fields(&["words", "path"]), vec![Field::Words, Field::Path]);
+        assert_eq!(fields(&[]), vec![Field::Path, Field::Words]);
+    }
+
+    #[test]
+    fn test_should_extract_requested_fields_per_note() -> Result<()> {
+        // REQ-EXTRACT-002
+        // Given notes with and without the requested properties
+        let dir = TempDir::new()?;
+        fs::write(
+            dir.path().join("202401311542-a.md"),
+            "---\ntitle: Alpha\ntags: [idea, draft]\nstatus: draft\nrelated: [b, c]\n---\none two three",
+        )?;
+        fs::write(dir.path().join("b.md"), "# Beta\nfour")?;
+        fs::write(dir.path().join("c.txt"), "not a note")?;
+        let scanner = ScanOptions::new([dir.path()]).build()?;
+        let names: Vec<String> = ["title", "status", "created", "tags", "related"]
+            .map(String::from)
+            .to_vec();
+
+        // When extracting them
+        let rows = extract(&scanner, &columns(&names))?;
+
+        // Then each note has a row, missing values left empty
+        let a = dir.path().join("202401311542-a.md").display().to_string();
+        let b = dir.path().join("b.md").display().to_string();
+        assert_eq!(
+            rows,
+            vec![
+                vec![
+                    a.as_str(),
+                    "Alpha",
+                    "draft",
+                    "2024-01-31",
+                    "idea;draft",
+                    "b;c",
+                    "3"
+                ],
+                vec![b.as_str(), "Beta", "", "", "", "", "3"],
+            ]
+        );
+        Ok(())
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// A column of the extracted table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Field {
+    /// The note's path
+    Path,
+    /// Words in the body, excluding frontmatter
+    Words,
+    /// Frontmatter title or first `# heading`
+    Title,
+    Tags,
+    Aliases,
+    /// Frontmatter `created` date or the zettel ID in the file name
+    Created,
+    /// The `due` (or `deadline`) date
+    Due,
+    /// Any other frontmatter property
+    Property(String),
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+impl Field {
+    #[inline]
+    #[must_use]
+    pub fn parse(name: &str) -> Self {
+        match name {
+            "path" => Self::Path,
+            "words" => Self::Words,
+            "title" => Self::Title,
+            "tags" => Self::Tags,
+            "aliases" => Self::Aliases,
+            "created" => Self::Created,
+            "due" => Self::Due,
+            other => Self::Property(other.to_owned()),
+        }
+    }
+
+    /// The column header.
+    #[inline]
+    #[must_use]
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Path => "path",
+            Self::Words => "words",
+            Self::Title => "title",
+            Self::Tags => "tags",
+            Self::Aliases => "aliases",
+            Self::Created => "created",
+            Self::Due => "due",
+            Self::Property(name) => name,
+        }
+    }
+}
+
+/// The requested fields as columns, led by `path` and followed by `words`
+/// unless the request places them itself.
+#[must_use]
+pub fn columns(requested: &[String]) -> Vec<Field> {
+    let mut fields: Vec<Field> = requested.iter().map(|name| Field::parse(name)).collect();
+    if !fields.contains(&Field::Path) {
+        fields.insert(0, Field::Path);
+    }
+    if !fields.contains(&Field::Words) {
+        fields.push(Field::Words);
+    }
+    fields
+}
+
+/// One row of `fields` per markdown note, in walk order.
+///
+/// Lists are joined with `;`, and missing values are empty. Notes whose
+/// frontmatter cannot be parsed get empty frontmatter fields and are listed
+/// on the scanner's report.
+///
+/// # Errors
+///
+/// Returns an error if the scan fails or a note cannot be read.
+pub fn extract(scanner: &Scanner, fields: &[Field]) -> Result<Vec<Vec<String>>> {
+    let mut rows = Vec::new();
+    for note in scanner.notes() {
+        let note = note?;
+        if !scanner.is_note(&note.path) {
+            continue;
+        }
+        rows.push(row(&note, fields));
+    }
+    Ok(rows)
+}
+
+fn row(note: &NoteAnalysis, fields: &[Field]) -> Vec<String> {
+    let record = NoteRecord::from_analysis(note);
+    let frontmatter = &note.frontmatter;
+    fields
+        .iter()
+        .map(|field| match field {
+            Field::Path => note.path.display().to_string(),
+            Field::Words => record.words.to_string(),
+            Field::Title => record.title.clone().unwrap_or_default(),
+            Field::Tags => record.tags.join(";"),
+            Field::Aliases => frontmatter.aliases.join(";"),
+            Field::Created => record.created.map(|d| d.to_string()).unwrap_or_default(),
+            Field::Due => frontmatter.due().map(|d| d.to_string()).unwrap_or_default(),
+            Field::Property(name) => frontmatter
+                .property_list(name)
+                .unwrap_or_default()
+                .join(";"),
+        })
+        .collect()
+}
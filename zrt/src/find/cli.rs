@@ -0,0 +1,101 @@
+use anyhow::Result;
+use clap::Args;
+use std::path::PathBuf;
+
+use crate::core::scanner::ScanOptions;
+use crate::find::find_notes;
+use crate::output::exec::ExecArgs;
+use crate::output::exit::NoMatches;
+use crate::output::files_from::FilesFromArgs;
+use crate::output::open::OpenArgs;
+use crate::output::scan;
+use crate::output::titles::TitleArgs;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser, Debug)]
+    struct TestArgs {
+        #[command(flatten)]
+        find: FindArgs,
+    }
+
+    #[test]
+    fn test_should_join_query_words_and_default_limit() {
+        // REQ-FIND-004
+        let args = TestArgs::parse_from(["program", "feedback", "lo", "--open"]);
+
+        assert_eq!(args.find.query.join(" "), "feedback lo");
+        assert_eq!(args.find.limit, 10);
+        assert_eq!(args.find.open.open, Some(1));
+        assert!(TestArgs::try_parse_from(["program"]).is_err());
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+#[derive(Args, Debug)]
+pub struct FindArgs {
+    /// Characters to look for, in order, in note titles, file names and aliases
+    #[arg(required = true, num_args = 1..)]
+    pub query: Vec<String>,
+
+    /// Directories to scan (space-separated, defaults to current directory)
+    #[arg(short = 'd', long = "dir", num_args = 0.., default_values = &["."])]
+    pub directories: Vec<PathBuf>,
+
+    /// Directories to exclude (space-separated)
+    #[arg(short, long, num_args = 0..)]
+    pub exclude: Vec<String>,
+
+    /// Number of matches to list, closest first
+    #[arg(short = 'n', long, default_value_t = 10)]
+    pub limit: usize,
+
+    #[command(flatten)]
+    pub files_from: FilesFromArgs,
+
+    #[command(flatten)]
+    pub titles: TitleArgs,
+
+    #[command(flatten)]
+    pub open: OpenArgs,
+
+    #[command(flatten)]
+    pub exec: ExecArgs,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+pub fn run(args: FindArgs) -> Result<()> {
+    let exclude_dirs: Vec<&str> = args.exclude.iter().map(String::as_str).collect();
+
+    let options = args
+        .files_from
+        .apply(ScanOptions::new(&args.directories).exclude(&exclude_dirs))?;
+    let scanner = scan::attach(options).build()?;
+    let mut matches = find_notes(&scanner, &args.query.join(" "))?;
+    matches.truncate(args.limit);
+
+    for found in &matches {
+        println!("{}", args.titles.label(&found.path));
+    }
+
+    let paths: Vec<&PathBuf> = matches.iter().map(|found| &found.path).collect();
+    args.exec.run(&paths)?;
+    args.open.open(&paths)?;
+
+    if matches.is_empty() {
+        return Err(NoMatches.into());
+    }
+    Ok(())
+}
@@ -0,0 +1,205 @@
+pub mod cli;
+
+use anyhow::Result;
+use std::cmp::Reverse;
+use std::path::PathBuf;
+
+use crate::core::scanner::Scanner;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::scanner::ScanOptions;
+    use crate::core::vfs::MemoryFs;
+    use std::path::Path;
+
+    #[test]
+    fn test_should_match_query_characters_in_order_ignoring_case() {
+        // REQ-FIND-001
+        assert!(fuzzy_score("fdbk", "Feedback loops").is_some());
+        assert!(fuzzy_score("FEED loop", "feedback-loops").is_some());
+        assert!(fuzzy_score("kbdf", "Feedback loops").is_none());
+        assert!(fuzzy_score("loopsx", "Feedback loops").is_none());
+        assert_eq!(fuzzy_score("", "anything"), Some(0));
+    }
+
+    #[test]
+    fn test_should_score_consecutive_and_word_start_matches_higher() {
+        // REQ-FIND-002
+        let score = |query, name| fuzzy_score(query, name).unwrap_or(i64::MIN);
+
+        assert!(score("loop", "feedback loops") > score("loop", "list of open points"));
+        assert!(score("fl", "feedback loops") > score("fl", "reflection"));
+        assert!(score("sys", "systems thinking") > score("sys", "ecosystems"));
+        assert!(score("tc", "TiddlyCards") > score("tc", "attic"));
+    }
+
+    #[test]
+    fn test_should_rank_notes_by_best_title_stem_or_alias() -> Result<()> {
+        // REQ-FIND-003
+        // Given notes matching through their title, an alias and only their stem
+        let vfs = MemoryFs::new()
+            .with_file("/vault/202401.md", "---\ntitle: Feedback loops\n---\nbody")
+            .with_file(
+                "/vault/stocks.md",
+                "---\naliases: [Feedback flows]\n---\nbody",
+            )
+            .with_file("/vault/feedback.md", "body")
+            .with_file("/vault/unrelated.md", "# Gardening\nbody")
+            .with_file("/vault/feedback.txt", "not a note");
+        let scanner = ScanOptions::new(["/vault"]).vfs(vfs).build()?;
+
+        // When
+        let matches = find_notes(&scanner, "feedback lo")?;
+
+        // Then the closest name wins, and notes that do not match are left out
+        let paths: Vec<&Path> = matches.iter().map(|m| m.path.as_path()).collect();
+        assert_eq!(
+            paths,
+            [Path::new("/vault/202401.md"), Path::new("/vault/stocks.md")]
+        );
+        assert_eq!(matches[0].name, "Feedback loops");
+        assert_eq!(matches[1].name, "Feedback flows");
+        assert!(find_notes(&scanner, "zzz")?.is_empty());
+        Ok(())
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// A note whose title, file name or alias fuzzily matches a query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzyMatch {
+    pub path: PathBuf,
+    /// The title, file name without extension or alias that matched best
+    pub name: String,
+    /// Higher is a closer match
+    pub score: i64,
+}
+
+/// Points for each matched character.
+const SCORE_MATCH: i64 = 16;
+/// Extra points for a character that starts a word.
+const BONUS_WORD_START: i64 = 8;
+/// Extra points for a character right after the previous match.
+const BONUS_CONSECUTIVE: i64 = 6;
+/// Points lost for each character skipped between two matches.
+const PENALTY_GAP: i64 = 1;
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+/// How closely `name` matches `query`, or `None` if it does not contain the
+/// query's characters in order. Case and whitespace in the query are
+/// ignored. Like fzf, matches are scored higher when they run together or
+/// start words, and lower the more characters lie between them.
+#[must_use]
+pub fn fuzzy_score(query: &str, name: &str) -> Option<i64> {
+    let query: Vec<char> = query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect();
+    let chars: Vec<char> = name.chars().collect();
+    let lower: Vec<char> = chars
+        .iter()
+        .map(|c| c.to_lowercase().next().unwrap_or(*c))
+        .collect();
+    let bonuses: Vec<i64> = (0..chars.len())
+        .map(|j| word_start_bonus(j.checked_sub(1).map(|k| chars[k]), chars[j]))
+        .collect();
+
+    // best[j]: the best score with the query so far matched and its last
+    // character at position j of the name.
+    let mut best: Vec<Option<i64>> = vec![Some(0); chars.len()];
+    let mut first = true;
+    for q in query {
+        let mut next = vec![None; chars.len()];
+        // The best score of an earlier match, less the gap since it.
+        let mut gapped: Option<i64> = None;
+        for j in 0..chars.len() {
+            let previous = if first {
+                Some(0)
+            } else {
+                let adjacent = j
+                    .checked_sub(1)
+                    .and_then(|k| best[k])
+                    .map(|score| score + BONUS_CONSECUTIVE);
+                adjacent.max(gapped)
+            };
+            if lower[j] == q {
+                next[j] = previous.map(|score| score + SCORE_MATCH + bonuses[j]);
+            }
+            if !first {
+                let before = j.checked_sub(1).and_then(|k| best[k]);
+                gapped = gapped.max(before).map(|score| score - PENALTY_GAP);
+            }
+        }
+        best = next;
+        first = false;
+    }
+    if first {
+        return Some(0);
+    }
+    best.into_iter().flatten().max()
+}
+
+/// Bonus for `c` when it starts a word: at the start of the name, after a
+/// separator, or as the capital in camelCase.
+fn word_start_bonus(previous: Option<char>, c: char) -> i64 {
+    match previous {
+        None => BONUS_WORD_START,
+        Some(p) if !p.is_alphanumeric() && c.is_alphanumeric() => BONUS_WORD_START,
+        Some(p) if p.is_lowercase() && c.is_uppercase() => BONUS_WORD_START,
+        _ => 0,
+    }
+}
+
+/// Markdown notes whose title, file name without extension or one of whose
+/// aliases matches `query`, closest first. Ties go to the shorter name, then
+/// the path.
+///
+/// # Errors
+///
+/// Returns an error if the scan fails.
+pub fn find_notes(scanner: &Scanner, query: &str) -> Result<Vec<FuzzyMatch>> {
+    let mut matches = Vec::new();
+    for note in scanner.notes() {
+        let note = note?;
+        if !scanner.is_note(&note.path) {
+            continue;
+        }
+        let stem = note
+            .path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned());
+        let names = note
+            .title()
+            .into_iter()
+            .chain(stem)
+            .chain(note.frontmatter.aliases.iter().cloned());
+        let best = names
+            .filter_map(|name| fuzzy_score(query, &name).map(|score| (score, name)))
+            .max_by_key(|(score, name)| (*score, Reverse(name.chars().count())));
+        if let Some((score, name)) = best {
+            matches.push(FuzzyMatch {
+                path: note.path,
+                name,
+                score,
+            });
+        }
+    }
+    matches.sort_by(|a, b| {
+        b.score
+            .cmp(&a.score)
+            .then_with(|| a.name.chars().count().cmp(&b.name.chars().count()))
+            .then_with(|| a.path.cmp(&b.path))
+    });
+    Ok(matches)
+}
@@ -0,0 +1,154 @@
+use anyhow::Result;
+use clap::Args;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use crate::core::links::LinkIndex;
+use crate::graph::{GraphFilter, build_graph, json_value, render_dot};
+use crate::init::ZrtConfig;
+use crate::output::format::JsonFormat;
+use crate::output::scan;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser, Debug)]
+    struct TestArgs {
+        #[command(flatten)]
+        graph: GraphArgs,
+    }
+
+    #[test]
+    fn test_should_default_to_dot_format() {
+        // REQ-GRAPH-006
+
+        // Given / When
+        let args = TestArgs::parse_from(["program"]);
+
+        // Then
+        assert!(matches!(args.graph.format, GraphFormat::Dot));
+    }
+
+    #[test]
+    fn test_should_accept_json_format() {
+        // REQ-GRAPH-102
+
+        // Given / When
+        let args = TestArgs::parse_from(["program", "--format", "json"]);
+
+        // Then
+        assert!(matches!(args.graph.format, GraphFormat::Json(_)));
+    }
+
+    #[test]
+    fn test_should_accept_pinned_json_version() {
+        // REQ-GRAPH-103
+
+        // Given / When
+        let args = TestArgs::parse_from(["program", "--format", "json@1"]);
+
+        // Then
+        assert_eq!(
+            args.graph.format,
+            GraphFormat::Json(JsonFormat { version: 1 })
+        );
+        assert!(TestArgs::try_parse_from(["program", "--format", "json@99"]).is_err());
+        assert!(TestArgs::try_parse_from(["program", "--format", "svg"]).is_err());
+    }
+
+    #[test]
+    fn test_should_accept_tag_and_folder_filters() {
+        // REQ-GRAPH-002, REQ-GRAPH-003
+
+        // Given / When
+        let args = TestArgs::parse_from(["program", "--tag", "writing", "--folder", "work"]);
+
+        // Then
+        assert_eq!(args.graph.tag, Some("writing".to_owned()));
+        assert_eq!(args.graph.folder, Some(PathBuf::from("work")));
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphFormat {
+    /// Graphviz DOT
+    Dot,
+    /// Nodes/edges JSON for D3, Gephi and similar viewers
+    Json(JsonFormat),
+}
+
+#[derive(Args, Debug)]
+pub struct GraphArgs {
+    /// Directories to scan (space-separated, defaults to current directory)
+    #[arg(short = 'd', long = "dir", num_args = 0.., default_values = &["."])]
+    pub directories: Vec<PathBuf>,
+
+    /// Directories to exclude (space-separated)
+    #[arg(short, long, num_args = 0..)]
+    pub exclude: Vec<String>,
+
+    /// Output format: dot, json, or json@VERSION to pin the JSON shape
+    #[arg(long, default_value = "dot")]
+    pub format: GraphFormat,
+
+    /// Only include notes with this tag
+    #[arg(long)]
+    pub tag: Option<String>,
+
+    /// Only include notes inside this folder
+    #[arg(long)]
+    pub folder: Option<PathBuf>,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+impl FromStr for GraphFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "dot" {
+            Ok(Self::Dot)
+        } else {
+            s.parse().map(Self::Json)
+        }
+    }
+}
+
+pub fn run(args: GraphArgs, config: &ZrtConfig) -> Result<()> {
+    let exclude_dirs: Vec<&str> = args.exclude.iter().map(String::as_str).collect();
+
+    let folder = match args.folder {
+        Some(folder) if folder.is_relative() => Some(std::env::current_dir()?.join(folder)),
+        other => other,
+    };
+    let filter = GraphFilter {
+        tag: args.tag,
+        folder,
+    };
+
+    let index = LinkIndex::build_in(&scan::scanner(&args.directories, &exclude_dirs)?)?;
+    let graph = build_graph(
+        &index,
+        &filter,
+        &config.refactor.done_tag,
+        &config.refactor.todo_tag,
+    );
+
+    match args.format {
+        GraphFormat::Dot => print!("{}", render_dot(&graph)),
+        GraphFormat::Json(format) => println!("{}", format.render("graph", &json_value(&graph)?)?),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,463 @@
+pub mod cli;
+
+use anyhow::Result;
+use serde::Serialize;
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::Write as _;
+use std::path::PathBuf;
+
+use crate::core::links::{LinkIndex, normalize_path};
+use crate::core::status::RefactorStatus;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::links::note_from_content;
+    use std::path::Path;
+
+    fn index(notes: &[(&str, &str)]) -> LinkIndex {
+        LinkIndex::from_notes(
+            notes
+                .iter()
+                .map(|(path, content)| note_from_content(Path::new(path), content))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_should_build_edges_from_resolved_links() {
+        // REQ-GRAPH-001
+
+        // Given
+        let index = index(&[
+            ("/v/a.md", "[[b]] [[b|again]] [[missing]]"),
+            ("/v/b.md", "[[a]]"),
+        ]);
+
+        // When
+        let graph = build_graph(&index, &GraphFilter::default(), "refactored", "to_refactor");
+
+        // Then
+        assert_eq!(graph.nodes.len(), 2);
+        assert_eq!(graph.edges, vec![(0, 1), (1, 0)]);
+    }
+
+    #[test]
+    fn test_should_filter_nodes_by_tag() {
+        // REQ-GRAPH-002
+
+        // Given
+        let index = index(&[
+            ("/v/a.md", "---\ntags: [writing]\n---\n[[b]] [[c]]"),
+            ("/v/b.md", "---\ntags: [writing]\n---\n"),
+            ("/v/c.md", "---\ntags: [ideas]\n---\n"),
+        ]);
+        let filter = GraphFilter {
+            tag: Some("writing".to_owned()),
+            folder: None,
+        };
+
+        // When
+        let graph = build_graph(&index, &filter, "refactored", "to_refactor");
+
+        // Then
+        assert_eq!(graph.nodes.len(), 2);
+        assert_eq!(graph.edges, vec![(0, 1)]);
+    }
+
+    #[test]
+    fn test_should_filter_nodes_by_folder() {
+        // REQ-GRAPH-003
+
+        // Given
+        let index = index(&[
+            ("/v/work/a.md", "[[b]]"),
+            ("/v/home/b.md", ""),
+            ("/v/work/sub/c.md", ""),
+        ]);
+        let filter = GraphFilter {
+            tag: None,
+            folder: Some(PathBuf::from("/v/work")),
+        };
+
+        // When
+        let graph = build_graph(&index, &filter, "refactored", "to_refactor");
+
+        // Then
+        let names: Vec<&str> = graph.nodes.iter().map(|n| n.name.as_str()).collect();
+        assert_eq!(names, vec!["a", "c"]);
+        assert!(graph.edges.is_empty());
+    }
+
+    #[test]
+    fn test_should_color_nodes_by_refactor_status() {
+        // REQ-GRAPH-004
+
+        // Given
+        let index = index(&[
+            ("/v/a.md", "---\ntags: [refactored]\n---\n[[b]]"),
+            ("/v/b.md", "---\ntags: [to_refactor]\n---\n"),
+        ]);
+        let graph = build_graph(&index, &GraphFilter::default(), "refactored", "to_refactor");
+
+        // When
+        let dot = render_dot(&graph);
+
+        // Then
+        assert!(dot.starts_with("digraph notes {"));
+        assert!(dot.contains("n0 [label=\"a\", fillcolor=\"palegreen\"]"));
+        assert!(dot.contains("n1 [label=\"b\", fillcolor=\"lightcoral\"]"));
+        assert!(dot.contains("n0 -> n1;"));
+    }
+
+    #[test]
+    fn test_should_render_json_nodes_and_edges() -> Result<()> {
+        // REQ-GRAPH-101
+
+        // Given
+        let index = index(&[
+            (
+                "/v/a.md",
+                "---\ntitle: Alpha\ntags: [refactored]\n---\none two [[b]]",
+            ),
+            ("/v/b.md", ""),
+        ]);
+        let graph = build_graph(&index, &GraphFilter::default(), "refactored", "to_refactor");
+
+        // When
+        let json: serde_json::Value = serde_json::from_str(&render_json(&graph)?)?;
+
+        // Then
+        let a = &json["nodes"][0];
+        assert_eq!(a["id"], "/v/a.md");
+        assert_eq!(a["title"], "Alpha");
+        assert_eq!(a["tags"][0], "refactored");
+        assert_eq!(a["words"], 3);
+        assert_eq!(a["status"], "done");
+        assert_eq!(a["links"][0], "/v/b.md");
+        assert_eq!(json["nodes"][1]["title"], "b");
+        assert_eq!(json["edges"][0]["source"], "/v/a.md");
+        assert_eq!(json["edges"][0]["target"], "/v/b.md");
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_find_weakly_connected_components() {
+        // REQ-GRAPH-201
+
+        // Given: a → b ← c form one island, d → e another, f is alone
+        let index = index(&[
+            ("/v/a.md", "[[b]]"),
+            ("/v/b.md", ""),
+            ("/v/c.md", "[[b]]"),
+            ("/v/d.md", "[[e]]"),
+            ("/v/e.md", ""),
+            ("/v/f.md", ""),
+        ]);
+        let graph = build_graph(&index, &GraphFilter::default(), "refactored", "to_refactor");
+
+        // When
+        let components = components(&graph);
+
+        // Then
+        assert_eq!(components, vec![vec![0, 1, 2], vec![3, 4], vec![5]]);
+    }
+
+    #[test]
+    fn test_should_rank_linked_notes_higher_with_pagerank() {
+        // REQ-GRAPH-301
+
+        // Given: a and c both link to b
+        let index = index(&[("/v/a.md", "[[b]]"), ("/v/b.md", ""), ("/v/c.md", "[[b]]")]);
+        let graph = build_graph(&index, &GraphFilter::default(), "refactored", "to_refactor");
+
+        // When
+        let ranks = pagerank(&graph, 0.85, 20);
+
+        // Then
+        assert!(ranks[1] > ranks[0]);
+        assert!((ranks[0] - ranks[2]).abs() < 1e-9);
+        assert!((ranks.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_should_escape_quotes_in_labels() {
+        // REQ-GRAPH-005
+        let index = index(&[("/v/say \"hi\".md", "")]);
+        let graph = build_graph(&index, &GraphFilter::default(), "refactored", "to_refactor");
+
+        let dot = render_dot(&graph);
+
+        assert!(dot.contains("label=\"say \\\"hi\\\"\""));
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// Restricts which notes become graph nodes.
+#[derive(Debug, Default)]
+pub struct GraphFilter {
+    /// Only include notes carrying this tag
+    pub tag: Option<String>,
+    /// Only include notes inside this folder
+    pub folder: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone)]
+pub struct GraphNode {
+    pub path: PathBuf,
+    pub name: String,
+    pub title: String,
+    pub tags: Vec<String>,
+    pub words: usize,
+    pub status: RefactorStatus,
+}
+
+#[derive(Serialize)]
+struct JsonGraph<'a> {
+    nodes: Vec<JsonNode<'a>>,
+    edges: Vec<JsonEdge>,
+}
+
+#[derive(Serialize)]
+struct JsonNode<'a> {
+    id: String,
+    title: &'a str,
+    tags: &'a [String],
+    words: usize,
+    status: &'static str,
+    links: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct JsonEdge {
+    source: String,
+    target: String,
+}
+
+/// A directed note graph. Edges are `(source, target)` positions in `nodes`.
+#[derive(Debug, Default)]
+pub struct Graph {
+    pub nodes: Vec<GraphNode>,
+    pub edges: Vec<(usize, usize)>,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+impl GraphFilter {
+    fn includes(&self, path: &std::path::Path, tags: &[String]) -> bool {
+        let tag_ok = self
+            .tag
+            .as_ref()
+            .is_none_or(|tag| tags.iter().any(|t| t == tag));
+        let folder_ok = self
+            .folder
+            .as_ref()
+            .is_none_or(|folder| normalize_path(path).starts_with(normalize_path(folder)));
+        tag_ok && folder_ok
+    }
+}
+
+/// Build the note graph from a link index, keeping only edges between included notes.
+/// Duplicate links and self-links collapse away.
+pub fn build_graph(
+    index: &LinkIndex,
+    filter: &GraphFilter,
+    done_tag: &str,
+    todo_tag: &str,
+) -> Graph {
+    let mut positions: HashMap<usize, usize> = HashMap::new();
+    let mut nodes = Vec::new();
+
+    for (i, note) in index.notes.iter().enumerate() {
+        if !filter.includes(&note.path, &note.tags) {
+            continue;
+        }
+        positions.insert(i, nodes.len());
+        nodes.push(GraphNode {
+            path: note.path.clone(),
+            name: note.stem.clone(),
+            title: note.title.clone(),
+            tags: note.tags.clone(),
+            words: note.words,
+            status: RefactorStatus::of(&note.tags, done_tag, todo_tag),
+        });
+    }
+
+    let mut edges = BTreeSet::new();
+    for (&source, &from) in &positions {
+        for link in &index.notes[source].links {
+            let target = index
+                .resolve(source, link)
+                .and_then(|t| positions.get(&t).copied());
+            if let Some(to) = target {
+                if to != from {
+                    edges.insert((from, to));
+                }
+            }
+        }
+    }
+
+    Graph {
+        nodes,
+        edges: edges.into_iter().collect(),
+    }
+}
+
+/// Render the graph in Graphviz DOT format, coloring nodes by refactor status.
+pub fn render_dot(graph: &Graph) -> String {
+    let mut out = String::from("digraph notes {\n");
+    out.push_str("    node [shape=box, style=filled];\n");
+
+    for (i, node) in graph.nodes.iter().enumerate() {
+        let _ = writeln!(
+            out,
+            "    n{i} [label=\"{}\", fillcolor=\"{}\"];",
+            escape_dot(&node.name),
+            status_color(node.status)
+        );
+    }
+    for (from, to) in &graph.edges {
+        let _ = writeln!(out, "    n{from} -> n{to};");
+    }
+
+    out.push_str("}\n");
+    out
+}
+
+/// Render the graph as a `{nodes, edges}` JSON document for D3, Gephi and similar tools.
+/// Node ids are note paths; each node also lists the ids it links to.
+///
+/// # Errors
+///
+/// Returns an error if serialization fails.
+pub fn render_json(graph: &Graph) -> Result<String> {
+    Ok(serde_json::to_string_pretty(&json_value(graph)?)?)
+}
+
+/// The `{nodes, edges}` document of [`render_json`] as a JSON value.
+///
+/// # Errors
+///
+/// Returns an error if serialization fails.
+pub fn json_value(graph: &Graph) -> Result<serde_json::Value> {
+    let ids: Vec<String> = graph
+        .nodes
+        .iter()
+        .map(|n| n.path.display().to_string())
+        .collect();
+
+    let nodes = graph
+        .nodes
+        .iter()
+        .enumerate()
+        .map(|(i, node)| JsonNode {
+            id: ids[i].clone(),
+            title: &node.title,
+            tags: &node.tags,
+            words: node.words,
+            status: node.status.as_str(),
+            links: graph
+                .edges
+                .iter()
+                .filter(|(from, _)| *from == i)
+                .map(|(_, to)| ids[*to].clone())
+                .collect(),
+        })
+        .collect();
+
+    let edges = graph
+        .edges
+        .iter()
+        .map(|(from, to)| JsonEdge {
+            source: ids[*from].clone(),
+            target: ids[*to].clone(),
+        })
+        .collect();
+
+    Ok(serde_json::to_value(JsonGraph { nodes, edges })?)
+}
+
+/// Group nodes into weakly connected components, ignoring edge direction.
+/// Components are sorted by size descending; members keep node order.
+pub fn components(graph: &Graph) -> Vec<Vec<usize>> {
+    let mut neighbours: Vec<Vec<usize>> = vec![Vec::new(); graph.nodes.len()];
+    for &(from, to) in &graph.edges {
+        neighbours[from].push(to);
+        neighbours[to].push(from);
+    }
+
+    let mut component_of: Vec<Option<usize>> = vec![None; graph.nodes.len()];
+    let mut components: Vec<Vec<usize>> = Vec::new();
+    for start in 0..graph.nodes.len() {
+        if component_of[start].is_some() {
+            continue;
+        }
+        let id = components.len();
+        let mut members = Vec::new();
+        let mut stack = vec![start];
+        component_of[start] = Some(id);
+        while let Some(node) = stack.pop() {
+            members.push(node);
+            for &next in &neighbours[node] {
+                if component_of[next].is_none() {
+                    component_of[next] = Some(id);
+                    stack.push(next);
+                }
+            }
+        }
+        members.sort_unstable();
+        components.push(members);
+    }
+
+    components.sort_by(|a, b| b.len().cmp(&a.len()).then(a[0].cmp(&b[0])));
+    components
+}
+
+/// Simplified PageRank: scores sum to 1 and links from notes without
+/// outgoing links are spread evenly across the graph.
+pub fn pagerank(graph: &Graph, damping: f64, iterations: usize) -> Vec<f64> {
+    let n = graph.nodes.len();
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let mut out_degree = vec![0_usize; n];
+    for &(from, _) in &graph.edges {
+        out_degree[from] += 1;
+    }
+
+    let base = (1.0 - damping) / n as f64;
+    let mut ranks = vec![1.0 / n as f64; n];
+    for _ in 0..iterations {
+        let dangling: f64 = (0..n)
+            .filter(|&i| out_degree[i] == 0)
+            .map(|i| ranks[i])
+            .sum();
+        let mut next = vec![base + damping * dangling / n as f64; n];
+        for &(from, to) in &graph.edges {
+            next[to] += damping * ranks[from] / out_degree[from] as f64;
+        }
+        ranks = next;
+    }
+    ranks
+}
+
+const fn status_color(status: RefactorStatus) -> &'static str {
+    match status {
+        RefactorStatus::Done => "palegreen",
+        RefactorStatus::Todo => "lightcoral",
+        RefactorStatus::Untracked => "lightgrey",
+    }
+}
+
+fn escape_dot(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
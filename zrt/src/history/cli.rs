@@ -0,0 +1,107 @@
+use anyhow::Result;
+use clap::Args;
+use std::path::PathBuf;
+
+use crate::core::history::{HISTORY_FILE, load_history};
+use crate::history::git_history;
+use crate::init::ZrtConfig;
+use crate::trend::cli::{print_trend, print_velocity};
+use crate::trend::{Period, points, velocity};
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser, Debug)]
+    struct TestArgs {
+        #[command(flatten)]
+        history: HistoryArgs,
+    }
+
+    #[test]
+    fn test_should_read_history_file_by_default() {
+        // REQ-HISTORY-004
+
+        // Given / When
+        let args = TestArgs::parse_from(["program"]);
+
+        // Then
+        assert!(!args.history.git);
+        assert!(args.history.by.is_none());
+        assert_eq!(args.history.history, PathBuf::from(".zrt/history.jsonl"));
+    }
+
+    #[test]
+    fn test_should_accept_git_flag_with_directory_and_grouping() {
+        // REQ-HISTORY-004
+
+        // Given / When
+        let args =
+            TestArgs::parse_from(["program", "--git", "-d", "notes", "--by", "week", "-e", "a"]);
+
+        // Then
+        assert!(args.history.git);
+        assert_eq!(args.history.directory, PathBuf::from("notes"));
+        assert_eq!(args.history.by, Some(Period::Week));
+        assert_eq!(args.history.exclude, vec!["a"]);
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+#[derive(Args, Debug)]
+pub struct HistoryArgs {
+    /// Reconstruct progress from the git history instead of recorded snapshots
+    #[arg(long)]
+    pub git: bool,
+
+    /// Vault directory to reconstruct with --git (defaults to current directory)
+    #[arg(short = 'd', long = "dir", default_value = ".")]
+    pub directory: PathBuf,
+
+    /// Directories to exclude (space-separated)
+    #[arg(short, long, num_args = 0..)]
+    pub exclude: Vec<String>,
+
+    /// Group by day or ISO week instead of showing every snapshot or commit
+    #[arg(long, value_enum)]
+    pub by: Option<Period>,
+
+    /// History file to read snapshots from
+    #[arg(long, default_value = HISTORY_FILE)]
+    pub history: PathBuf,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+pub fn run(args: HistoryArgs, config: &ZrtConfig) -> Result<()> {
+    let snapshots = if args.git {
+        let exclude_dirs: Vec<&str> = args.exclude.iter().map(String::as_str).collect();
+        git_history(
+            &args.directory,
+            &exclude_dirs,
+            &config.refactor.done_tag,
+            &config.refactor.todo_tag,
+        )?
+    } else {
+        load_history(&args.history)?
+    };
+
+    if snapshots.is_empty() {
+        anyhow::bail!("No history found");
+    }
+
+    let points = points(&snapshots, args.by);
+    print_trend(&points);
+    print_velocity(velocity(&snapshots).as_ref());
+
+    Ok(())
+}
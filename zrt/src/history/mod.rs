@@ -0,0 +1,315 @@
+pub mod cli;
+
+use anyhow::{Context, Result};
+use chrono::DateTime;
+use std::collections::HashMap;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use crate::core::history::Snapshot;
+use crate::core::ignore::load_ignore_patterns;
+use crate::core::links::{NoteNode, note_from_content};
+use crate::core::patterns::Patterns;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn git_in(dir: &Path, args: &[&str]) -> Result<()> {
+        let status = Command::new("git")
+            .arg("-C")
+            .arg(dir)
+            .args(["-c", "user.name=Test", "-c", "user.email=test@example.com"])
+            .args(args)
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()?;
+        anyhow::ensure!(status.success(), "git {args:?} failed");
+        Ok(())
+    }
+
+    fn commit(dir: &Path, date: &str) -> Result<()> {
+        git_in(dir, &["add", "-A"])?;
+        let status = Command::new("git")
+            .arg("-C")
+            .arg(dir)
+            .args(["-c", "user.name=Test", "-c", "user.email=test@example.com"])
+            .args(["commit", "-q", "-m", "update"])
+            .env("GIT_AUTHOR_DATE", date)
+            .env("GIT_COMMITTER_DATE", date)
+            .status()?;
+        anyhow::ensure!(status.success(), "git commit failed");
+        Ok(())
+    }
+
+    fn create_test_file(dir: &TempDir, name: &str, content: &str) -> Result<PathBuf> {
+        let path = dir.path().join(name);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, content)?;
+        Ok(path)
+    }
+
+    #[test]
+    fn test_should_reconstruct_progress_from_git_commits() -> Result<()> {
+        // REQ-HISTORY-001
+
+        // Given
+        let dir = TempDir::new()?;
+        git_in(dir.path(), &["init", "-q"])?;
+        create_test_file(&dir, "a.md", "---\ntags: [to_refactor]\n---\none two")?;
+        create_test_file(&dir, "b.md", "---\ntags: [to_refactor]\n---\none two")?;
+        commit(dir.path(), "2026-03-01T12:00:00Z")?;
+        create_test_file(&dir, "a.md", "---\ntags: [refactored]\n---\none two")?;
+        commit(dir.path(), "2026-03-02T12:00:00Z")?;
+
+        // When
+        let history = git_history(dir.path(), &[], "refactored", "to_refactor")?;
+
+        // Then
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].percentage, 0.0);
+        assert_eq!(history[0].todo_files, 2);
+        assert_eq!(history[1].percentage, 50.0);
+        assert_eq!(history[1].done_files, 1);
+        assert_eq!(
+            history[1].timestamp,
+            DateTime::parse_from_rfc3339("2026-03-02T12:00:00Z")?.to_utc()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_skip_excluded_hidden_and_non_markdown_files() -> Result<()> {
+        // REQ-HISTORY-002
+
+        // Given
+        let dir = TempDir::new()?;
+        git_in(dir.path(), &["init", "-q"])?;
+        create_test_file(&dir, "a.md", "one")?;
+        create_test_file(&dir, "archive/b.md", "two")?;
+        create_test_file(&dir, ".obsidian/c.md", "three")?;
+        create_test_file(&dir, "d.txt", "four")?;
+        commit(dir.path(), "2026-03-01T12:00:00Z")?;
+
+        // When
+        let history = git_history(dir.path(), &["archive"], "refactored", "to_refactor")?;
+
+        // Then
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].files, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_fail_outside_git_repository() -> Result<()> {
+        // REQ-HISTORY-003
+        let dir = TempDir::new()?;
+        create_test_file(&dir, "a.md", "one")?;
+
+        let result = git_history(dir.path(), &[], "refactored", "to_refactor");
+
+        assert!(result.is_err());
+        Ok(())
+    }
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+/// Reconstruct progress snapshots from the git history of a vault directory.
+///
+/// Every commit touching the directory yields one snapshot, oldest first,
+/// timestamped with the commit time. Files are filtered like a normal scan:
+/// hidden paths, excluded directories, `.zrtignore` matches and non-markdown
+/// files are skipped. Notes are only parsed again when their content changes.
+pub fn git_history(
+    dir: &Path,
+    exclude: &[&str],
+    done_tag: &str,
+    todo_tag: &str,
+) -> Result<Vec<Snapshot>> {
+    let absolute_dir = if dir.is_absolute() {
+        dir.to_path_buf()
+    } else {
+        std::env::current_dir()?.join(dir)
+    };
+    let ignore_patterns = load_ignore_patterns(&absolute_dir)?;
+
+    let log = git(
+        &absolute_dir,
+        &["log", "--reverse", "--format=%H %ct", "--", "."],
+    )?;
+
+    let mut parsed: HashMap<(String, String), NoteNode> = HashMap::new();
+    let mut snapshots = Vec::new();
+
+    for line in log.lines() {
+        let Some((commit, time)) = line.split_once(' ') else {
+            continue;
+        };
+        let timestamp = time
+            .parse()
+            .ok()
+            .and_then(|seconds| DateTime::from_timestamp(seconds, 0))
+            .with_context(|| format!("Invalid commit time for {commit}: {time}"))?;
+
+        let files: Vec<(String, String)> = list_files(&absolute_dir, commit)?
+            .into_iter()
+            .filter(|(_, path)| {
+                Path::new(path).extension().is_some_and(|ext| ext == "md")
+                    && !is_excluded(&absolute_dir, path, exclude, &ignore_patterns)
+            })
+            .collect();
+
+        let missing: Vec<&str> = files
+            .iter()
+            .filter(|key| !parsed.contains_key(*key))
+            .map(|(oid, _)| oid.as_str())
+            .collect();
+        let contents = read_blobs(&absolute_dir, &missing)?;
+
+        let mut notes = Vec::with_capacity(files.len());
+        for key in files {
+            if !parsed.contains_key(&key) {
+                let Some(content) = contents.get(&key.0) else {
+                    continue;
+                };
+                let note = note_from_content(&absolute_dir.join(&key.1), content);
+                parsed.insert(key.clone(), note);
+            }
+            notes.push(parsed[&key].clone());
+        }
+
+        snapshots.push(Snapshot::from_notes(timestamp, &notes, done_tag, todo_tag));
+    }
+
+    Ok(snapshots)
+}
+
+/// Run git in `dir` and return its standard output.
+pub(crate) fn git(dir: &Path, args: &[&str]) -> Result<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(args)
+        .output()
+        .context("Failed to run git; is it installed?")?;
+
+    if !output.status.success() {
+        anyhow::bail!(
+            "git {} failed in {}: {}",
+            args.first().unwrap_or(&""),
+            dir.display(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Blob ids and paths (relative to `dir`) of every file in `dir` at `commit`.
+fn list_files(dir: &Path, commit: &str) -> Result<Vec<(String, String)>> {
+    let listing = git(dir, &["ls-tree", "-r", "-z", commit, "--", "."])?;
+
+    Ok(listing
+        .split('\0')
+        .filter_map(|entry| {
+            let (info, path) = entry.split_once('\t')?;
+            let mut fields = info.split(' ');
+            let (_mode, kind, oid) = (fields.next()?, fields.next()?, fields.next()?);
+            (kind == "blob").then(|| (oid.to_owned(), path.to_owned()))
+        })
+        .collect())
+}
+
+/// Read several blobs with a single `git cat-file --batch`, skipping non-UTF-8 content.
+fn read_blobs(dir: &Path, oids: &[&str]) -> Result<HashMap<String, String>> {
+    let mut contents = HashMap::new();
+    if oids.is_empty() {
+        return Ok(contents);
+    }
+
+    let mut child = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(["cat-file", "--batch"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .context("Failed to run git; is it installed?")?;
+
+    let mut stdin = child.stdin.take().context("Failed to open git stdin")?;
+    let request: String = oids.iter().map(|oid| format!("{oid}\n")).collect();
+    let writer = std::thread::spawn(move || stdin.write_all(request.as_bytes()));
+
+    let mut output = Vec::new();
+    child
+        .stdout
+        .take()
+        .context("Failed to open git stdout")?
+        .read_to_end(&mut output)?;
+    writer
+        .join()
+        .map_err(|_| anyhow::anyhow!("Failed to write to git"))??;
+    child.wait()?;
+
+    // Each object is "<oid> <type> <size>\n<content>\n"
+    let mut rest = output.as_slice();
+    while let Some(end) = rest.iter().position(|&b| b == b'\n') {
+        let header = String::from_utf8_lossy(&rest[..end]).into_owned();
+        rest = &rest[end + 1..];
+        let mut fields = header.split(' ');
+        let (Some(oid), Some(_kind), Some(size)) = (fields.next(), fields.next(), fields.next())
+        else {
+            continue;
+        };
+        let Ok(size) = size.parse::<usize>() else {
+            continue;
+        };
+        let body = rest
+            .get(..size)
+            .context("Truncated output from git cat-file")?;
+        if let Ok(text) = std::str::from_utf8(body) {
+            contents.insert(oid.to_owned(), text.to_owned());
+        }
+        rest = rest.get(size + 1..).unwrap_or_default();
+    }
+
+    Ok(contents)
+}
+
+/// Apply the same rules as a directory walk to a path from the git tree.
+pub(crate) fn is_excluded(
+    root: &Path,
+    relative: &str,
+    exclude: &[&str],
+    patterns: &Patterns,
+) -> bool {
+    let components: Vec<&str> = relative.split('/').collect();
+    let mut prefix = PathBuf::from(root);
+
+    for (i, component) in components.iter().enumerate() {
+        prefix.push(component);
+        let is_dir = i + 1 < components.len();
+        if component.starts_with('.') && !component.starts_with(".tmp") {
+            return true;
+        }
+        if is_dir && exclude.contains(component) {
+            return true;
+        }
+        if patterns.matches(&prefix) {
+            return true;
+        }
+    }
+
+    false
+}
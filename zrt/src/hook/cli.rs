@@ -0,0 +1,111 @@
+use anyhow::Result;
+use clap::{Args, Subcommand};
+use std::path::Path;
+
+use crate::hook::{check_staged, install};
+use crate::init::{RuleLevel, ZrtConfig};
+use crate::lint::check_rule_names;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser, Debug)]
+    struct TestArgs {
+        #[command(flatten)]
+        hook: HookArgs,
+    }
+
+    #[test]
+    fn test_should_parse_pre_commit_with_excludes() {
+        // REQ-HOOK-004
+        let args = TestArgs::parse_from(["program", "pre-commit", "-e", "archive"]);
+
+        assert!(matches!(
+            args.hook.command,
+            HookCommand::PreCommit { ref exclude } if exclude == &["archive"]
+        ));
+    }
+
+    #[test]
+    fn test_should_parse_install_with_force() {
+        // REQ-HOOK-004
+        let args = TestArgs::parse_from(["program", "install", "--force"]);
+
+        assert!(matches!(
+            args.hook.command,
+            HookCommand::Install { force: true }
+        ));
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+#[derive(Args, Debug)]
+pub struct HookArgs {
+    #[command(subcommand)]
+    pub command: HookCommand,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum HookCommand {
+    /// Check staged notes and fail if any break a rule
+    PreCommit {
+        /// Directories to exclude (space-separated)
+        #[arg(short, long, num_args = 0..)]
+        exclude: Vec<String>,
+    },
+
+    /// Write a git pre-commit hook that runs `zrt hook pre-commit`
+    Install {
+        /// Replace an existing pre-commit hook
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+pub fn run(args: HookArgs, config: &ZrtConfig) -> Result<()> {
+    match args.command {
+        HookCommand::PreCommit { exclude } => {
+            check_rule_names(&config.lint)?;
+            let exclude_dirs: Vec<&str> = exclude.iter().map(String::as_str).collect();
+            let problems = check_staged(
+                Path::new("."),
+                &exclude_dirs,
+                &config.refactor.done_tag,
+                &config.refactor.todo_tag,
+                &config.lint,
+            )?;
+
+            for problem in &problems {
+                for (violation, level) in &problem.violations {
+                    if *level == RuleLevel::Error {
+                        eprintln!("{}: {violation}", problem.path);
+                    } else {
+                        eprintln!("warning: {}: {violation}", problem.path);
+                    }
+                }
+            }
+            let failing = problems.iter().filter(|p| p.has_errors()).count();
+            if failing > 0 {
+                anyhow::bail!("{failing} staged note(s) need fixing");
+            }
+            Ok(())
+        }
+        HookCommand::Install { force } => {
+            let hook = install(Path::new("."), force)?;
+            println!("Installed {}", hook.display());
+            Ok(())
+        }
+    }
+}
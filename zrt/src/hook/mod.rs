@@ -0,0 +1,392 @@
+pub mod cli;
+
+use anyhow::{Context as _, Result};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use crate::core::frontmatter::{parse_frontmatter, without_bom};
+use crate::core::ignore::load_ignore_patterns;
+use crate::empty::{Emptiness, classify};
+use crate::history::{git, is_excluded};
+use crate::init::{LintConfig, RuleLevel};
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::process::{Command, Stdio};
+    use tempfile::TempDir;
+
+    fn git_in(dir: &Path, args: &[&str]) -> Result<()> {
+        let status = Command::new("git")
+            .arg("-C")
+            .arg(dir)
+            .args(args)
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()?;
+        anyhow::ensure!(status.success(), "git {args:?} failed");
+        Ok(())
+    }
+
+    fn check(content: &str) -> Vec<Violation> {
+        check_note(content, "refactored", "to_refactor")
+    }
+
+    #[test]
+    fn test_should_accept_note_with_frontmatter() {
+        // REQ-HOOK-001
+        assert!(check("---\ntags: [to_refactor]\n---\nBody").is_empty());
+        assert!(check("\u{feff}---\r\ntags: [to_refactor]\r\n---\r\nBody").is_empty());
+    }
+
+    #[test]
+    fn test_should_flag_missing_frontmatter() {
+        // REQ-HOOK-001
+        assert_eq!(check("Just a body"), vec![Violation::MissingFrontmatter]);
+    }
+
+    #[test]
+    fn test_should_flag_invalid_frontmatter() {
+        // REQ-HOOK-001
+        let violations = check("---\ntags: [unclosed\n---\nBody");
+
+        assert!(matches!(
+            violations.as_slice(),
+            [Violation::InvalidFrontmatter(_)]
+        ));
+    }
+
+    #[test]
+    fn test_should_flag_both_done_and_todo_tags() {
+        // REQ-HOOK-001
+        let violations = check("---\ntags: [refactored, to_refactor]\n---\nBody");
+
+        assert_eq!(violations, vec![Violation::ConflictingTags]);
+    }
+
+    #[test]
+    fn test_should_flag_empty_notes() {
+        // REQ-HOOK-005
+        assert_eq!(check(""), vec![Violation::EmptyNote]);
+        assert_eq!(check("  \n"), vec![Violation::EmptyNote]);
+        assert_eq!(
+            check("---\ntags: [refactored, to_refactor]\n---\n"),
+            vec![Violation::ConflictingTags, Violation::EmptyNote]
+        );
+    }
+
+    #[test]
+    fn test_should_check_only_staged_markdown_content() -> Result<()> {
+        // REQ-HOOK-002
+        // Given a repository with a bad note staged and a bad note left unstaged
+        let dir = TempDir::new()?;
+        git_in(dir.path(), &["init", "-q"])?;
+        fs::write(dir.path().join("staged.md"), "no frontmatter")?;
+        fs::write(dir.path().join("fixed.md"), "no frontmatter")?;
+        fs::write(dir.path().join("notes.txt"), "not markdown")?;
+        fs::create_dir(dir.path().join("archive"))?;
+        fs::write(dir.path().join("archive/old.md"), "no frontmatter")?;
+        git_in(
+            dir.path(),
+            &[
+                "add",
+                "staged.md",
+                "fixed.md",
+                "notes.txt",
+                "archive/old.md",
+            ],
+        )?;
+        // And a working-tree fix that has not been staged yet
+        fs::write(dir.path().join("fixed.md"), "---\ntags: []\n---\nok")?;
+        fs::write(dir.path().join("unstaged.md"), "no frontmatter")?;
+
+        // When checking staged notes, excluding the archive
+        let lint = LintConfig::default();
+        let problems = check_staged(dir.path(), &["archive"], "refactored", "to_refactor", &lint)?;
+
+        // Then only staged markdown content is judged
+        let paths: Vec<&str> = problems.iter().map(|p| p.path.as_str()).collect();
+        assert_eq!(paths, vec!["fixed.md", "staged.md"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_apply_configured_rule_levels() -> Result<()> {
+        // REQ-HOOK-006
+        // Given staged notes breaking a downgraded and an ignored rule
+        let dir = TempDir::new()?;
+        git_in(dir.path(), &["init", "-q"])?;
+        fs::write(dir.path().join("plain.md"), "no frontmatter")?;
+        fs::write(dir.path().join("empty.md"), "")?;
+        git_in(dir.path(), &["add", "plain.md", "empty.md"])?;
+        let lint: LintConfig =
+            toml::from_str("[rules]\nmissing-frontmatter = \"warn\"\nempty-note = \"ignore\"")?;
+
+        // When checking them
+        let problems = check_staged(dir.path(), &[], "refactored", "to_refactor", &lint)?;
+
+        // Then the warning is reported without failing and the ignored rule is dropped
+        assert_eq!(
+            problems,
+            vec![Problem {
+                path: "plain.md".to_owned(),
+                violations: vec![(Violation::MissingFrontmatter, RuleLevel::Warn)],
+            }]
+        );
+        assert!(!problems[0].has_errors());
+        assert!(RULES.contains(&Violation::EmptyNote.rule()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_install_hook_script() -> Result<()> {
+        // REQ-HOOK-003
+        let dir = TempDir::new()?;
+        git_in(dir.path(), &["init", "-q"])?;
+
+        let path = install(dir.path(), false)?;
+
+        let script = fs::read_to_string(&path)?;
+        assert!(path.ends_with("hooks/pre-commit"));
+        assert!(script.contains("zrt hook pre-commit"));
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt as _;
+            assert_eq!(fs::metadata(&path)?.permissions().mode() & 0o111, 0o111);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_not_overwrite_foreign_hook_without_force() -> Result<()> {
+        // REQ-HOOK-003
+        let dir = TempDir::new()?;
+        git_in(dir.path(), &["init", "-q"])?;
+        let hooks = dir.path().join(".git/hooks");
+        fs::create_dir_all(&hooks)?;
+        fs::write(hooks.join("pre-commit"), "#!/bin/sh\nmake lint\n")?;
+
+        assert!(install(dir.path(), false).is_err());
+        install(dir.path(), true)?;
+
+        let script = fs::read_to_string(hooks.join("pre-commit"))?;
+        assert!(script.contains("zrt hook pre-commit"));
+        Ok(())
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// Rules checked by the pre-commit hook, by the names `[lint.rules]` sets
+/// their level with; they are shared with `zrt doctor`.
+pub const RULES: [&str; 4] = [
+    "missing-frontmatter",
+    "frontmatter",
+    "conflicting-tags",
+    "empty-note",
+];
+
+/// A rule a note breaks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Violation {
+    /// The note does not start with a `---` frontmatter block
+    MissingFrontmatter,
+    /// The frontmatter is not valid YAML
+    InvalidFrontmatter(String),
+    /// The note is tagged both done and todo
+    ConflictingTags,
+    /// The note has no body text
+    EmptyNote,
+}
+
+/// A staged note and the rules it breaks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Problem {
+    /// Path relative to the repository root
+    pub path: String,
+    /// Each broken rule with the level `[lint.rules]` gives it
+    pub violations: Vec<(Violation, RuleLevel)>,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+impl fmt::Display for Violation {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingFrontmatter => f.write_str("missing frontmatter"),
+            Self::InvalidFrontmatter(error) => write!(f, "invalid frontmatter: {error}"),
+            Self::ConflictingTags => f.write_str("tagged both done and todo"),
+            Self::EmptyNote => f.write_str("empty note"),
+        }
+    }
+}
+
+impl Violation {
+    /// The name of the rule, one of [`RULES`].
+    #[inline]
+    #[must_use]
+    pub const fn rule(&self) -> &'static str {
+        match self {
+            Self::MissingFrontmatter => "missing-frontmatter",
+            Self::InvalidFrontmatter(_) => "frontmatter",
+            Self::ConflictingTags => "conflicting-tags",
+            Self::EmptyNote => "empty-note",
+        }
+    }
+
+    /// The level `config` gives this rule; every rule fails the hook unless
+    /// configured otherwise.
+    #[inline]
+    #[must_use]
+    pub fn level(&self, config: &LintConfig) -> RuleLevel {
+        config.level(self.rule(), RuleLevel::Error)
+    }
+}
+
+impl Problem {
+    /// Whether any broken rule is at the error level, so the commit must fail.
+    #[inline]
+    #[must_use]
+    pub fn has_errors(&self) -> bool {
+        self.violations
+            .iter()
+            .any(|(_, level)| *level == RuleLevel::Error)
+    }
+}
+
+const HOOK_SCRIPT: &str = "#!/bin/sh\n# Installed by zrt\nexec zrt hook pre-commit\n";
+
+/// Rules broken by a note's content.
+#[must_use]
+pub fn check_note(content: &str, done_tag: &str, todo_tag: &str) -> Vec<Violation> {
+    let emptiness = classify(content);
+    // A note with nothing in it is only reported as empty
+    if matches!(
+        emptiness,
+        Some(Emptiness::ZeroBytes | Emptiness::Whitespace)
+    ) {
+        return vec![Violation::EmptyNote];
+    }
+    if without_bom(content).lines().next() != Some("---") {
+        return vec![Violation::MissingFrontmatter];
+    }
+
+    let mut violations = Vec::new();
+    match parse_frontmatter(content) {
+        Err(error) => violations.push(Violation::InvalidFrontmatter(error.to_string())),
+        Ok(frontmatter) => {
+            let tags = frontmatter.tags.unwrap_or_default();
+            let has = |tag: &str| tags.iter().any(|t| t == tag);
+            if has(done_tag) && has(todo_tag) {
+                violations.push(Violation::ConflictingTags);
+            }
+        }
+    }
+    if emptiness.is_some() {
+        violations.push(Violation::EmptyNote);
+    }
+    violations
+}
+
+/// Check the staged version of every added, copied, modified or renamed
+/// markdown file in the repository containing `dir`.
+///
+/// Files are filtered like a scan: hidden paths, excluded directories and
+/// `.zrtignore` matches are skipped. Rules `lint` ignores are not reported,
+/// and only notes with problems are returned, sorted by path.
+///
+/// # Errors
+///
+/// Returns an error if `dir` is not inside a git repository or git fails.
+pub fn check_staged(
+    dir: &Path,
+    exclude: &[&str],
+    done_tag: &str,
+    todo_tag: &str,
+    lint: &LintConfig,
+) -> Result<Vec<Problem>> {
+    let root = PathBuf::from(git(dir, &["rev-parse", "--show-toplevel"])?.trim());
+    let ignore_patterns = load_ignore_patterns(&root)?;
+
+    let staged = git(
+        &root,
+        &[
+            "diff",
+            "--cached",
+            "--name-only",
+            "-z",
+            "--diff-filter=ACMR",
+        ],
+    )?;
+    let mut paths: Vec<&str> = staged
+        .split('\0')
+        .filter(|path| Path::new(path).extension().is_some_and(|ext| ext == "md"))
+        .filter(|path| !is_excluded(&root, path, exclude, &ignore_patterns))
+        .collect();
+    paths.sort_unstable();
+
+    let mut problems = Vec::new();
+    for path in paths {
+        let content = git(&root, &["show", &format!(":{path}")])?;
+        let violations: Vec<_> = check_note(&content, done_tag, todo_tag)
+            .into_iter()
+            .map(|violation| {
+                let level = violation.level(lint);
+                (violation, level)
+            })
+            .filter(|(_, level)| *level != RuleLevel::Ignore)
+            .collect();
+        if !violations.is_empty() {
+            problems.push(Problem {
+                path: path.to_owned(),
+                violations,
+            });
+        }
+    }
+    Ok(problems)
+}
+
+/// Write a pre-commit hook running `zrt hook pre-commit` into the repository
+/// containing `dir`, returning its path.
+///
+/// # Errors
+///
+/// Returns an error if `dir` is not inside a git repository, a hook not
+/// written by zrt already exists and `force` is not set, or the file cannot
+/// be written.
+pub fn install(dir: &Path, force: bool) -> Result<PathBuf> {
+    let hook = dir.join(git(dir, &["rev-parse", "--git-path", "hooks/pre-commit"])?.trim());
+
+    if let Ok(existing) = std::fs::read_to_string(&hook) {
+        if existing != HOOK_SCRIPT && !force {
+            anyhow::bail!(
+                "{} already exists; use --force to replace it",
+                hook.display()
+            );
+        }
+    }
+
+    if let Some(parent) = hook.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    std::fs::write(&hook, HOOK_SCRIPT)
+        .with_context(|| format!("Failed to write {}", hook.display()))?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt as _;
+        std::fs::set_permissions(&hook, std::fs::Permissions::from_mode(0o755))?;
+    }
+
+    Ok(hook)
+}
@@ -0,0 +1,105 @@
+use anyhow::Result;
+use clap::Args;
+use std::path::PathBuf;
+
+use crate::core::links::LinkIndex;
+use crate::core::status::RefactorStatus;
+use crate::graph::{GraphFilter, build_graph};
+use crate::hubs::{Centrality, rank_hubs};
+use crate::init::ZrtConfig;
+use crate::output::scan;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser, Debug)]
+    struct TestArgs {
+        #[command(flatten)]
+        hubs: HubsArgs,
+    }
+
+    #[test]
+    fn test_should_default_to_pagerank_and_twenty_results() {
+        // REQ-HUBS-004
+
+        // Given / When
+        let args = TestArgs::parse_from(["program"]);
+
+        // Then
+        assert!(matches!(args.hubs.by, Centrality::Pagerank));
+        assert_eq!(args.hubs.limit, 20);
+        assert!(!args.hubs.all);
+    }
+
+    #[test]
+    fn test_should_accept_degree_centrality() {
+        // REQ-HUBS-004
+
+        // Given / When
+        let args = TestArgs::parse_from(["program", "--by", "degree", "--all"]);
+
+        // Then
+        assert!(matches!(args.hubs.by, Centrality::Degree));
+        assert!(args.hubs.all);
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+#[derive(Args, Debug)]
+pub struct HubsArgs {
+    /// Directories to scan (space-separated, defaults to current directory)
+    #[arg(short = 'd', long = "dir", num_args = 0.., default_values = &["."])]
+    pub directories: Vec<PathBuf>,
+
+    /// Directories to exclude (space-separated)
+    #[arg(short, long, num_args = 0..)]
+    pub exclude: Vec<String>,
+
+    /// Centrality measure used for ranking
+    #[arg(long, value_enum, default_value = "pagerank")]
+    pub by: Centrality,
+
+    /// Include notes of every refactor status, not only those still to refactor
+    #[arg(long)]
+    pub all: bool,
+
+    /// Number of results to show (default: 20)
+    #[arg(long, default_value = "20")]
+    pub limit: usize,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+pub fn run(args: HubsArgs, config: &ZrtConfig) -> Result<()> {
+    let exclude_dirs: Vec<&str> = args.exclude.iter().map(String::as_str).collect();
+
+    let index = LinkIndex::build_in(&scan::scanner(&args.directories, &exclude_dirs)?)?;
+    let graph = build_graph(
+        &index,
+        &GraphFilter::default(),
+        &config.refactor.done_tag,
+        &config.refactor.todo_tag,
+    );
+
+    let status = (!args.all).then_some(RefactorStatus::Todo);
+    for hub in rank_hubs(&graph, args.by, status).iter().take(args.limit) {
+        println!(
+            "{} {:.4} {}",
+            hub.status.as_str(),
+            hub.score,
+            hub.path.display()
+        );
+    }
+
+    Ok(())
+}
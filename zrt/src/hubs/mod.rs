@@ -0,0 +1,139 @@
+pub mod cli;
+
+use clap::ValueEnum;
+use std::path::PathBuf;
+
+use crate::core::status::RefactorStatus;
+use crate::graph::{Graph, pagerank};
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::links::{LinkIndex, note_from_content};
+    use crate::graph::{GraphFilter, build_graph};
+    use std::path::Path;
+
+    fn graph(notes: &[(&str, &str)]) -> Graph {
+        let index = LinkIndex::from_notes(
+            notes
+                .iter()
+                .map(|(path, content)| note_from_content(Path::new(path), content))
+                .collect(),
+        );
+        build_graph(&index, &GraphFilter::default(), "refactored", "to_refactor")
+    }
+
+    #[test]
+    fn test_should_rank_by_degree() {
+        // REQ-HUBS-001
+
+        // Given: b has three connections, a and c one each
+        let graph = graph(&[
+            ("/v/a.md", "[[b]]"),
+            ("/v/b.md", "[[c]]"),
+            ("/v/c.md", ""),
+            ("/v/d.md", "[[b]]"),
+        ]);
+
+        // When
+        let hubs = rank_hubs(&graph, Centrality::Degree, None);
+
+        // Then
+        assert_eq!(hubs[0].path, PathBuf::from("/v/b.md"));
+        assert!((hubs[0].score - 3.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_should_rank_by_pagerank() {
+        // REQ-HUBS-002
+
+        // Given
+        let graph = graph(&[("/v/a.md", "[[c]]"), ("/v/b.md", "[[c]]"), ("/v/c.md", "")]);
+
+        // When
+        let hubs = rank_hubs(&graph, Centrality::Pagerank, None);
+
+        // Then
+        assert_eq!(hubs[0].path, PathBuf::from("/v/c.md"));
+    }
+
+    #[test]
+    fn test_should_only_keep_notes_with_requested_status() {
+        // REQ-HUBS-003
+
+        // Given
+        let graph = graph(&[
+            ("/v/a.md", "---\ntags: [refactored]\n---\n[[b]] [[c]]"),
+            ("/v/b.md", "---\ntags: [to_refactor]\n---\n[[c]]"),
+            ("/v/c.md", "---\ntags: [to_refactor]\n---\n"),
+        ]);
+
+        // When
+        let hubs = rank_hubs(&graph, Centrality::Degree, Some(RefactorStatus::Todo));
+
+        // Then
+        assert_eq!(hubs.len(), 2);
+        assert!(hubs.iter().all(|h| h.status == RefactorStatus::Todo));
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum Centrality {
+    /// Number of incoming plus outgoing links
+    Degree,
+    /// PageRank over the link graph
+    Pagerank,
+}
+
+#[derive(Debug)]
+pub struct Hub {
+    pub path: PathBuf,
+    pub score: f64,
+    pub status: RefactorStatus,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+/// Rank notes by centrality, most central first.
+/// When `status` is given, only notes with that refactor status are returned.
+pub fn rank_hubs(
+    graph: &Graph,
+    centrality: Centrality,
+    status: Option<RefactorStatus>,
+) -> Vec<Hub> {
+    let scores = match centrality {
+        Centrality::Degree => {
+            let mut degree = vec![0.0; graph.nodes.len()];
+            for &(from, to) in &graph.edges {
+                degree[from] += 1.0;
+                degree[to] += 1.0;
+            }
+            degree
+        }
+        Centrality::Pagerank => pagerank(graph, 0.85, 50),
+    };
+
+    let mut hubs: Vec<Hub> = graph
+        .nodes
+        .iter()
+        .zip(scores)
+        .filter(|(node, _)| status.is_none_or(|s| node.status == s))
+        .map(|(node, score)| Hub {
+            path: node.path.clone(),
+            score,
+            status: node.status,
+        })
+        .collect();
+
+    hubs.sort_by(|a, b| b.score.total_cmp(&a.score).then(a.path.cmp(&b.path)));
+    hubs
+}
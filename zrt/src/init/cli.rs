@@ -1,6 +1,8 @@
 use anyhow::Result;
 use clap::Args;
 
+use crate::init::InitOutcome;
+
 // ============================================
 // TESTS
 // ============================================
@@ -37,5 +39,9 @@ pub struct InitArgs {
 // ============================================
 
 pub fn run(_args: InitArgs) -> Result<()> {
-    crate::init::run(None)
+    match crate::init::run(None)? {
+        InitOutcome::Created => println!("Initialized config directory at .zrt/"),
+        InitOutcome::AlreadyExists => println!("config directory already exists at .zrt/"),
+    }
+    Ok(())
 }
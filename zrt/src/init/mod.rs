@@ -1,9 +1,13 @@
+#[cfg(feature = "cli")]
 pub mod cli;
 
 use anyhow::{Context as _, Result};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 
+use crate::core::frontmatter::MetadataFormat;
+
 // ============================================
 // TESTS
 // ============================================
@@ -15,10 +19,11 @@ mod tests {
     #[test]
     fn test_should_create_zrt_directory() -> Result<()> {
         let temp_dir = TempDir::new()?;
-        run(Some(temp_dir.path()))?;
+        let result = run(Some(temp_dir.path()))?;
 
         let zrt_exists = temp_dir.path().join(".zrt").exists();
         assert!(zrt_exists);
+        assert_eq!(result, InitOutcome::Created);
         Ok(())
     }
 
@@ -37,8 +42,8 @@ mod tests {
         let temp_dir = TempDir::new()?;
         std::fs::create_dir_all(temp_dir.path().join(".zrt"))?;
 
-        let result = run(Some(temp_dir.path()));
-        assert!(result.is_ok());
+        let result = run(Some(temp_dir.path()))?;
+        assert_eq!(result, InitOutcome::AlreadyExists);
         Ok(())
     }
 
@@ -62,7 +67,263 @@ mod tests {
 
         assert_eq!(config.word_threshold, 300);
         assert_eq!(config.line_threshold, 60);
+        assert_eq!(config.heading_threshold, 5);
         assert!(matches!(config.sort_by, SortBy::Words));
+        assert_eq!(config.done_tag, "refactored");
+        assert_eq!(config.todo_tag, "to_refactor");
+    }
+
+    #[test]
+    fn test_should_fill_missing_fields_with_defaults() -> Result<()> {
+        // REQ-INIT-021
+        let temp_dir = TempDir::new()?;
+        let config_path = temp_dir.path().join("config.toml");
+        std::fs::write(&config_path, "[refactor]\nword_threshold = 500\n")?;
+
+        let config = ZrtConfig::load_from_file(&config_path)?;
+
+        assert_eq!(config.refactor.word_threshold, 500);
+        assert_eq!(config.refactor.line_threshold, 60);
+        assert_eq!(config.refactor.done_tag, "refactored");
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_load_next_weights() -> Result<()> {
+        // REQ-INIT-023
+        let temp_dir = TempDir::new()?;
+        let config_path = temp_dir.path().join("config.toml");
+        std::fs::write(&config_path, "[next]\nbacklinks = 3.0\n")?;
+
+        let config = ZrtConfig::load_from_file(&config_path)?;
+
+        assert_eq!(config.next.backlinks, 3.0);
+        assert_eq!(config.next.words, NextConfig::default().words);
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_load_lint_conventions() -> Result<()> {
+        // REQ-INIT-025
+        let temp_dir = TempDir::new()?;
+        let config_path = temp_dir.path().join("config.toml");
+        std::fs::write(&config_path, "[lint]\nfilenames = \"kebab\"\n")?;
+
+        let config = ZrtConfig::load_from_file(&config_path)?;
+
+        assert_eq!(config.lint.filenames.as_deref(), Some("kebab"));
+        assert_eq!(ZrtConfig::default().lint.filenames, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_load_metadata_formats() -> Result<()> {
+        // REQ-INIT-035
+        let temp_dir = TempDir::new()?;
+        let config_path = temp_dir.path().join("config.toml");
+        std::fs::write(
+            &config_path,
+            "[frontmatter]\nformats = [\"pandoc\", \"multimarkdown\"]\n",
+        )?;
+
+        let config = ZrtConfig::load_from_file(&config_path)?;
+
+        assert_eq!(
+            config.frontmatter.formats,
+            vec![MetadataFormat::Pandoc, MetadataFormat::MultiMarkdown]
+        );
+        assert!(ZrtConfig::default().frontmatter.formats.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_load_rule_levels() -> Result<()> {
+        // REQ-INIT-034
+        let temp_dir = TempDir::new()?;
+        let config_path = temp_dir.path().join("config.toml");
+        std::fs::write(
+            &config_path,
+            "[lint.rules]\norphan-assets = \"ignore\"\ntitles = \"warn\"\n",
+        )?;
+
+        let config = ZrtConfig::load_from_file(&config_path)?;
+
+        assert_eq!(
+            config.lint.level("orphan-assets", RuleLevel::Warn),
+            RuleLevel::Ignore
+        );
+        assert_eq!(
+            config.lint.level("titles", RuleLevel::Error),
+            RuleLevel::Warn
+        );
+        assert_eq!(
+            config.lint.level("frontmatter", RuleLevel::Error),
+            RuleLevel::Error
+        );
+        assert!(toml::from_str::<LintConfig>("[rules]\ntitles = \"loud\"").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_load_journal_location() -> Result<()> {
+        // REQ-INIT-027
+        let temp_dir = TempDir::new()?;
+        let config_path = temp_dir.path().join("config.toml");
+        std::fs::write(&config_path, "[journal]\nfolder = \"daily\"\n")?;
+
+        let config = ZrtConfig::load_from_file(&config_path)?;
+
+        assert_eq!(config.journal.folder.as_deref(), Some("daily"));
+        assert_eq!(config.journal.format, "%Y-%m-%d");
+        assert_eq!(ZrtConfig::default().journal.folder, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_load_rename_pattern() -> Result<()> {
+        // REQ-INIT-036
+        let temp_dir = TempDir::new()?;
+        let config_path = temp_dir.path().join("config.toml");
+        std::fs::write(
+            &config_path,
+            "[rename]\npattern = \"{created} {title}.md\"\n",
+        )?;
+
+        let config = ZrtConfig::load_from_file(&config_path)?;
+
+        assert_eq!(config.rename.pattern, "{created} {title}.md");
+        assert_eq!(ZrtConfig::default().rename.pattern, "{id}-{slug}.md");
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_load_notify_webhook() -> Result<()> {
+        // REQ-INIT-028
+        let temp_dir = TempDir::new()?;
+        let config_path = temp_dir.path().join("config.toml");
+        std::fs::write(
+            &config_path,
+            "[notify]\nwebhook = \"https://hooks.example.com/zrt\"\n",
+        )?;
+
+        let config = ZrtConfig::load_from_file(&config_path)?;
+
+        assert_eq!(
+            config.notify.webhook.as_deref(),
+            Some("https://hooks.example.com/zrt")
+        );
+        assert_eq!(config.notify.milestones, vec![25.0, 50.0, 75.0, 100.0]);
+        assert_eq!(ZrtConfig::default().notify.webhook, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_load_plugins() -> Result<()> {
+        // REQ-INIT-029
+        let temp_dir = TempDir::new()?;
+        let config_path = temp_dir.path().join("config.toml");
+        std::fs::write(
+            &config_path,
+            "[[plugins]]\nname = \"links\"\nwasm = \"links.wasm\"\n\n\
+             [[plugins]]\nname = \"words\"\ncommand = [\"python3\", \"words.py\"]\ntimeout = 2\n",
+        )?;
+
+        let config = ZrtConfig::load_from_file(&config_path)?;
+
+        assert_eq!(
+            config.plugins,
+            vec![
+                PluginConfig {
+                    name: "links".to_owned(),
+                    wasm: Some(PathBuf::from("links.wasm")),
+                    ..PluginConfig::default()
+                },
+                PluginConfig {
+                    name: "words".to_owned(),
+                    command: vec!["python3".to_owned(), "words.py".to_owned()],
+                    timeout: Some(2),
+                    ..PluginConfig::default()
+                }
+            ]
+        );
+        assert!(ZrtConfig::default().plugins.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_load_scan_hooks() -> Result<()> {
+        // REQ-INIT-030
+        let temp_dir = TempDir::new()?;
+        let config_path = temp_dir.path().join("config.toml");
+        std::fs::write(&config_path, "[hooks]\npre_snapshot = [\"git pull\"]\n")?;
+
+        let config = ZrtConfig::load_from_file(&config_path)?;
+
+        assert_eq!(config.hooks.pre_snapshot, vec!["git pull"]);
+        assert!(config.hooks.post_snapshot.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_apply_profile_overrides() -> Result<()> {
+        // REQ-INIT-031
+        let temp_dir = TempDir::new()?;
+        let config_path = temp_dir.path().join("config.toml");
+        std::fs::write(
+            &config_path,
+            "[refactor]\nword_threshold = 500\n\n\
+             [profiles.strict]\nword_threshold = 200\ndone_tag = \"evergreen\"\n\
+             dirs = [\"work\"]\n",
+        )?;
+        let config = ZrtConfig::load_from_file(&config_path)?;
+
+        assert_eq!(config.profile("strict")?.dirs, vec!["work"]);
+        let strict = config.clone().with_profile("strict")?;
+
+        assert_eq!(strict.refactor.word_threshold, 200);
+        assert_eq!(strict.refactor.done_tag, "evergreen");
+        // Settings the profile leaves out keep the base value
+        assert_eq!(strict.refactor.todo_tag, "to_refactor");
+        assert_eq!(strict.refactor.line_threshold, 60);
+        assert_eq!(
+            config.with_profile("lax").err().map(|e| e.to_string()),
+            Some("No profile named lax in .zrt/config.toml".to_owned())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_load_named_queries() -> Result<()> {
+        // REQ-INIT-033
+        let temp_dir = TempDir::new()?;
+        let config_path = temp_dir.path().join("config.toml");
+        std::fs::write(
+            &config_path,
+            "[queries]\nbacklog = \"FROM #to_refactor AND -#draft\"\n",
+        )?;
+        let config = ZrtConfig::load_from_file(&config_path)?;
+
+        assert_eq!(config.query("backlog")?, "FROM #to_refactor AND -#draft");
+        assert_eq!(
+            config.query("inbox").err().map(|e| e.to_string()),
+            Some("No query named inbox in .zrt/config.toml".to_owned())
+        );
+        assert!(ZrtConfig::default().queries.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_load_workflow_states() -> Result<()> {
+        // REQ-INIT-024
+        let temp_dir = TempDir::new()?;
+        let config_path = temp_dir.path().join("config.toml");
+        std::fs::write(&config_path, "[workflow]\nstates = [\"seed\", \"tree\"]\n")?;
+
+        let config = ZrtConfig::load_from_file(&config_path)?;
+
+        assert_eq!(config.workflow.states, vec!["seed", "tree"]);
+        assert_eq!(config.workflow.field, "status");
+        Ok(())
     }
 
     #[test]
@@ -94,6 +355,8 @@ mod tests {
     fn test_should_load_or_default_config() {
         let config = ZrtConfig::load_or_default();
         assert_eq!(config.refactor.word_threshold, 300);
+        let config = ZrtConfig::load_or_default_with(Some("no-such-profile"));
+        assert_eq!(config.refactor.word_threshold, 300);
     }
 
     #[test]
@@ -121,21 +384,206 @@ mod tests {
 // ============================================
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ZrtConfig {
+    #[serde(default)]
     pub refactor: RefactorConfig,
+    #[serde(default)]
+    pub next: NextConfig,
+    #[serde(default)]
+    pub workflow: WorkflowConfig,
+    #[serde(default)]
+    pub lint: LintConfig,
+    #[serde(default)]
+    pub frontmatter: FrontmatterConfig,
+    #[serde(default)]
+    pub journal: JournalConfig,
+    #[serde(default)]
+    pub rename: RenameConfig,
+    #[serde(default)]
+    pub notify: NotifyConfig,
+    #[serde(default)]
+    pub hooks: HooksConfig,
+    /// Analyzers run by `zrt plugins` and `zrt overview`, from `[[plugins]]` tables
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub plugins: Vec<PluginConfig>,
+    /// Named overrides selected with `zrt --profile NAME`, from
+    /// `[profiles.NAME]` tables
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub profiles: BTreeMap<String, ProfileConfig>,
+    /// Queries run with `zrt query --name NAME`, from the `[queries]` table
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub queries: BTreeMap<String, String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct RefactorConfig {
     pub word_threshold: usize,
     pub line_threshold: usize,
+    /// Top-level sections at which `zrt suggest-splits` reports a note
+    pub heading_threshold: usize,
     pub sort_by: SortBy,
+    /// Tag marking notes that have been refactored
+    pub done_tag: String,
+    /// Tag marking notes that still need refactoring
+    pub todo_tag: String,
+}
+
+/// Weights `zrt next` gives each factor when scoring notes to refactor
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NextConfig {
+    /// Weight of the note's word count
+    pub words: f64,
+    /// Weight of the number of notes linking to it
+    pub backlinks: f64,
+    /// Weight of the days since it was last modified
+    pub staleness: f64,
+}
+
+/// Ordered note states tracked in a frontmatter property, for `zrt workflow`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WorkflowConfig {
+    /// Frontmatter property holding the state
+    pub field: String,
+    /// States from first to last
+    pub states: Vec<String>,
+}
+
+/// Conventions checked by `zrt lint`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LintConfig {
+    /// Filename convention: a preset name (`zettel`, `date`, `kebab`,
+    /// `snake`) or a regular expression matched against the file name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filenames: Option<String>,
+    /// Level of `zrt lint` rules and `zrt doctor` checks by name, from the
+    /// `[lint.rules]` table; unlisted ones keep their default
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub rules: BTreeMap<String, RuleLevel>,
+}
+
+/// How note metadata is read
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FrontmatterConfig {
+    /// Header formats read from notes without YAML frontmatter: `pandoc`
+    /// title blocks and `multimarkdown` metadata
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub formats: Vec<MetadataFormat>,
+}
+
+/// Where `zrt journal` finds daily notes
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JournalConfig {
+    /// Folder holding the daily notes; unset to look everywhere
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub folder: Option<String>,
+    /// Date format of daily note file names, without the extension
+    pub format: String,
+}
+
+/// File names `zrt rename` gives notes
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RenameConfig {
+    /// File name with `{id}`, `{title}`, `{slug}`, `{stem}` and `{created}`
+    /// filled in from each note
+    pub pattern: String,
+}
+
+/// Webhook messages sent when refactoring passes a milestone
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NotifyConfig {
+    /// URL receiving a JSON POST per milestone, e.g. a Slack or Discord
+    /// incoming webhook; unset to send nothing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webhook: Option<String>,
+    /// Done percentages announced when a scan first reaches them
+    pub milestones: Vec<f64>,
+}
+
+/// Shell commands run around `zrt snapshot`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HooksConfig {
+    /// Run in order before the scan, e.g. `git pull`
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub pre_snapshot: Vec<String>,
+    /// Run in order after the scan, each receiving the JSON report on stdin
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub post_snapshot: Vec<String>,
+}
+
+/// An analyzer plugin: a WebAssembly module run inside zrt, or a program
+/// reading one JSON note per line on stdin and answering each with a JSON
+/// object of metrics on stdout
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PluginConfig {
+    /// Name shown next to the plugin's metrics
+    pub name: String,
+    /// Program and arguments, e.g. `["python3", "links.py"]`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub command: Vec<String>,
+    /// WebAssembly module to run instead of a command, e.g. `"links.wasm"`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wasm: Option<PathBuf>,
+    /// Seconds the plugin may take per note (default: 10)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<u64>,
+}
+
+/// Settings a profile overrides; unset fields keep the base configuration
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProfileConfig {
+    /// Directories scanned by commands given no `-d`
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub dirs: Vec<String>,
+    /// Directories excluded by commands given no `-e`
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub exclude: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub done_tag: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub todo_tag: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub word_threshold: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_threshold: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub heading_threshold: Option<usize>,
+}
+
+/// What `run` did to the configuration directory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InitOutcome {
+    Created,
+    AlreadyExists,
+}
+
+/// How a lint rule or doctor check's problems count
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleLevel {
+    /// Reported, and fails the run
+    Error,
+    /// Reported without failing the run
+    Warn,
+    /// Not reported
+    Ignore,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, clap::ValueEnum)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
 #[serde(rename_all = "lowercase")]
 pub enum SortBy {
     Words,
     Lines,
+    Size,
 }
 
 // ============================================
@@ -146,6 +594,17 @@ impl Default for ZrtConfig {
     fn default() -> Self {
         Self {
             refactor: RefactorConfig::default(),
+            next: NextConfig::default(),
+            workflow: WorkflowConfig::default(),
+            lint: LintConfig::default(),
+            frontmatter: FrontmatterConfig::default(),
+            journal: JournalConfig::default(),
+            rename: RenameConfig::default(),
+            notify: NotifyConfig::default(),
+            hooks: HooksConfig::default(),
+            plugins: Vec::new(),
+            profiles: BTreeMap::new(),
+            queries: BTreeMap::new(),
         }
     }
 }
@@ -156,11 +615,75 @@ impl Default for RefactorConfig {
         Self {
             word_threshold: 300,
             line_threshold: 60,
+            heading_threshold: 5,
             sort_by: SortBy::Words,
+            done_tag: "refactored".to_owned(),
+            todo_tag: "to_refactor".to_owned(),
         }
     }
 }
 
+impl Default for NextConfig {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            words: 1.0,
+            backlinks: 1.0,
+            staleness: 0.5,
+        }
+    }
+}
+
+impl Default for WorkflowConfig {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            field: "status".to_owned(),
+            states: ["inbox", "draft", "refactored", "evergreen"]
+                .map(str::to_owned)
+                .to_vec(),
+        }
+    }
+}
+
+impl Default for JournalConfig {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            folder: None,
+            format: "%Y-%m-%d".to_owned(),
+        }
+    }
+}
+
+impl Default for RenameConfig {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            pattern: "{id}-{slug}.md".to_owned(),
+        }
+    }
+}
+
+impl Default for NotifyConfig {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            webhook: None,
+            milestones: vec![25.0, 50.0, 75.0, 100.0],
+        }
+    }
+}
+
+impl LintConfig {
+    /// The configured level of `rule`, else `default`.
+    #[inline]
+    #[must_use]
+    pub fn level(&self, rule: &str, default: RuleLevel) -> RuleLevel {
+        self.rules.get(rule).copied().unwrap_or(default)
+    }
+}
+
 impl Default for SortBy {
     #[inline]
     fn default() -> Self {
@@ -200,6 +723,7 @@ impl ZrtConfig {
             .with_context(|| format!("Failed to write config file: {}", path.display()))
     }
 
+    /// Loads `.zrt/config.toml`, or the defaults if it is missing or invalid
     #[inline]
     pub fn load_or_default() -> Self {
         let config_path = PathBuf::from(".zrt/config.toml");
@@ -212,6 +736,65 @@ impl ZrtConfig {
             Self::default()
         }
     }
+
+    /// [`ZrtConfig::load_or_default`] with the profile called `profile`
+    /// applied, or the base configuration if there is no such profile
+    #[inline]
+    pub fn load_or_default_with(profile: Option<&str>) -> Self {
+        let config = Self::load_or_default();
+        match profile {
+            Some(name) => config.clone().with_profile(name).unwrap_or_else(|e| {
+                eprintln!("Warning: {e}, using the base configuration");
+                config
+            }),
+            None => config,
+        }
+    }
+
+    /// The profile called `name`
+    ///
+    /// # Errors
+    /// Returns an error if no profile has that name
+    #[inline]
+    pub fn profile(&self, name: &str) -> Result<&ProfileConfig> {
+        self.profiles
+            .get(name)
+            .with_context(|| format!("No profile named {name} in .zrt/config.toml"))
+    }
+
+    /// The text of the saved query called `name`
+    ///
+    /// # Errors
+    /// Returns an error if no query has that name
+    #[inline]
+    pub fn query(&self, name: &str) -> Result<&str> {
+        self.queries
+            .get(name)
+            .map(String::as_str)
+            .with_context(|| format!("No query named {name} in .zrt/config.toml"))
+    }
+
+    /// This configuration with the overrides of the profile called `name`
+    ///
+    /// # Errors
+    /// Returns an error if no profile has that name
+    #[inline]
+    pub fn with_profile(mut self, name: &str) -> Result<Self> {
+        let profile = self.profile(name)?.clone();
+        let refactor = &mut self.refactor;
+        if let Some(tag) = profile.done_tag {
+            refactor.done_tag = tag;
+        }
+        if let Some(tag) = profile.todo_tag {
+            refactor.todo_tag = tag;
+        }
+        refactor.word_threshold = profile.word_threshold.unwrap_or(refactor.word_threshold);
+        refactor.line_threshold = profile.line_threshold.unwrap_or(refactor.line_threshold);
+        refactor.heading_threshold = profile
+            .heading_threshold
+            .unwrap_or(refactor.heading_threshold);
+        Ok(self)
+    }
 }
 
 // ============================================
@@ -227,19 +810,19 @@ impl ZrtConfig {
 ///
 /// # Returns
 ///
-/// * `Ok(())` if initialization succeeds
+/// * `Ok(InitOutcome::Created)` if the directory and config were created
+/// * `Ok(InitOutcome::AlreadyExists)` if `.zrt/` was already present
 ///
 /// # Errors
 ///
 /// Returns an error if directory creation or file writing fails.
-pub fn run(base_path: Option<&Path>) -> Result<()> {
+pub fn run(base_path: Option<&Path>) -> Result<InitOutcome> {
     let zrt_dir = base_path
         .map(|p| p.join(".zrt"))
         .unwrap_or_else(|| PathBuf::from(".zrt"));
 
     if zrt_dir.exists() {
-        println!("config directory already exists at .zrt/");
-        return Ok(());
+        return Ok(InitOutcome::AlreadyExists);
     }
 
     std::fs::create_dir_all(&zrt_dir)?;
@@ -247,7 +830,5 @@ pub fn run(base_path: Option<&Path>) -> Result<()> {
     let config = ZrtConfig::default();
     config.save_to_file(&zrt_dir.join("config.toml"))?;
 
-    println!("Initialized config directory at .zrt/");
-
-    Ok(())
+    Ok(InitOutcome::Created)
 }
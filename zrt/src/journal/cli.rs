@@ -0,0 +1,129 @@
+use anyhow::Result;
+use chrono::{Local, Utc};
+use clap::Args;
+use std::path::PathBuf;
+
+use crate::core::scanner::ScanOptions;
+use crate::init::ZrtConfig;
+use crate::journal::{JournalReport, daily_notes, journal_report};
+use crate::output::{number, scan};
+use crate::summary::parse_since;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser, Debug)]
+    struct TestArgs {
+        #[command(flatten)]
+        journal: JournalArgs,
+    }
+
+    #[test]
+    fn test_should_default_to_last_30_days() {
+        // REQ-JOURNAL-004
+        let args = TestArgs::parse_from(["program"]);
+
+        assert_eq!(args.journal.since, "30d");
+        assert!(!args.journal.all);
+        assert_eq!(args.journal.folder, None);
+        assert_eq!(args.journal.format, None);
+    }
+
+    #[test]
+    fn test_should_accept_folder_format_and_all() {
+        // REQ-JOURNAL-004
+        let args = TestArgs::parse_from([
+            "program", "--all", "--folder", "daily", "--format", "%Y%m%d",
+        ]);
+
+        assert!(args.journal.all);
+        assert_eq!(args.journal.folder, Some(PathBuf::from("daily")));
+        assert_eq!(args.journal.format.as_deref(), Some("%Y%m%d"));
+        assert!(TestArgs::try_parse_from(["program", "--all", "--since", "7d"]).is_err());
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+#[derive(Args, Debug)]
+pub struct JournalArgs {
+    /// Directories to scan (space-separated, defaults to current directory)
+    #[arg(short = 'd', long = "dir", num_args = 0.., default_values = &["."])]
+    pub directories: Vec<PathBuf>,
+
+    /// Directories to exclude (space-separated)
+    #[arg(short, long, num_args = 0..)]
+    pub exclude: Vec<String>,
+
+    /// Start of the period: days (30d), weeks (4w) or a date (YYYY-MM-DD)
+    #[arg(long, default_value = "30d")]
+    pub since: String,
+
+    /// Report on the whole journal, from its first daily note
+    #[arg(long, conflicts_with = "since")]
+    pub all: bool,
+
+    /// Folder holding the daily notes (default: `journal.folder`, or anywhere)
+    #[arg(long)]
+    pub folder: Option<PathBuf>,
+
+    /// Date format of daily note file names (default: `journal.format`)
+    #[arg(long)]
+    pub format: Option<String>,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+pub fn run(args: JournalArgs, config: &ZrtConfig) -> Result<()> {
+    let exclude_dirs: Vec<&str> = args.exclude.iter().map(String::as_str).collect();
+    let folder = args
+        .folder
+        .or_else(|| config.journal.folder.as_ref().map(PathBuf::from));
+    let format = args.format.unwrap_or_else(|| config.journal.format.clone());
+
+    let scanner =
+        scan::attach(ScanOptions::new(&args.directories).exclude(&exclude_dirs)).build()?;
+    let notes = daily_notes(&scanner, folder.as_deref(), &format)?;
+
+    let today = Local::now().date_naive();
+    let from = if args.all {
+        notes.first().map_or(today, |note| note.date)
+    } else {
+        parse_since(&args.since, Utc::now())?.date_naive()
+    };
+
+    print_report(&journal_report(&notes, from, today));
+    Ok(())
+}
+
+fn print_report(report: &JournalReport) {
+    println!(
+        "Daily notes: {} of {} days ({} to {})",
+        number::count(report.written),
+        number::count(report.days),
+        report.from,
+        report.to
+    );
+    println!("Current streak: {} days", report.current_streak);
+    println!("Longest streak: {} days", report.longest_streak);
+    println!("Average words: {:.1} per day", report.average_words);
+    if report.missing.is_empty() {
+        println!("Missing days: none");
+    } else {
+        let missing: Vec<String> = report.missing.iter().map(ToString::to_string).collect();
+        println!(
+            "Missing days ({}): {}",
+            report.missing.len(),
+            missing.join(", ")
+        );
+    }
+}
@@ -0,0 +1,254 @@
+pub mod cli;
+
+use anyhow::Result;
+use chrono::{NaiveDate, TimeDelta};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::core::scanner::Scanner;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::scanner::ScanOptions;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn date(text: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(text, "%Y-%m-%d").unwrap_or_default()
+    }
+
+    fn daily(text: &str, words: usize) -> DailyNote {
+        DailyNote {
+            date: date(text),
+            path: PathBuf::from(format!("{text}.md")),
+            words,
+        }
+    }
+
+    #[test]
+    fn test_should_date_notes_named_by_format() {
+        // REQ-JOURNAL-001
+        let daily = |path: &str, folder: Option<&str>| {
+            journal_date(Path::new(path), folder.map(Path::new), "%Y-%m-%d")
+        };
+
+        assert_eq!(daily("v/2024-01-31.md", None), Some(date("2024-01-31")));
+        assert_eq!(daily("v/2024-01-31-meeting.md", None), None);
+        assert_eq!(daily("v/ideas.md", None), None);
+        assert_eq!(
+            daily("v/daily/2024/2024-01-31.md", Some("daily")),
+            Some(date("2024-01-31"))
+        );
+        assert_eq!(daily("v/notes/2024-01-31.md", Some("daily")), None);
+        assert_eq!(
+            journal_date(Path::new("20240131.md"), None, "%Y%m%d"),
+            Some(date("2024-01-31"))
+        );
+    }
+
+    #[test]
+    fn test_should_collect_daily_notes_by_date() -> Result<()> {
+        // REQ-JOURNAL-002
+        // Given two daily notes and an ordinary note
+        let dir = TempDir::new()?;
+        fs::write(dir.path().join("2024-01-31.md"), "one two three")?;
+        fs::write(
+            dir.path().join("2024-01-30.md"),
+            "---\ntags: [day]\n---\nfour",
+        )?;
+        fs::write(dir.path().join("topic.md"), "five six")?;
+        let scanner = ScanOptions::new([dir.path()]).build()?;
+
+        // When collecting daily notes
+        let notes = daily_notes(&scanner, None, "%Y-%m-%d")?;
+
+        // Then they come oldest first with their body word counts
+        let summary: Vec<(NaiveDate, usize)> = notes.iter().map(|n| (n.date, n.words)).collect();
+        assert_eq!(
+            summary,
+            vec![(date("2024-01-30"), 1), (date("2024-01-31"), 3)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_report_streaks_words_and_missing_days() {
+        // REQ-JOURNAL-003
+        // Given notes on 5 of the 7 days before today, the last 2 in a row
+        let notes = [
+            daily("2024-01-24", 100),
+            daily("2024-01-25", 200),
+            daily("2024-01-26", 300),
+            daily("2024-01-29", 50),
+            daily("2024-01-30", 350),
+        ];
+
+        // When reporting the week up to today
+        let report = journal_report(&notes, date("2024-01-24"), date("2024-01-31"));
+
+        // Then today is not yet missing and yesterday keeps the streak alive
+        assert_eq!(report.written, 5);
+        assert_eq!(report.days, 8);
+        assert_eq!(report.current_streak, 2);
+        assert_eq!(report.longest_streak, 3);
+        assert!((report.average_words - 200.0).abs() < f64::EPSILON);
+        assert_eq!(report.missing, vec![date("2024-01-27"), date("2024-01-28")]);
+    }
+
+    #[test]
+    fn test_should_break_current_streak_after_a_missed_day() {
+        // REQ-JOURNAL-003
+        let notes = [daily("2024-01-28", 10), daily("2024-01-29", 10)];
+
+        let report = journal_report(&notes, date("2024-01-28"), date("2024-01-31"));
+
+        assert_eq!(report.current_streak, 0);
+        assert_eq!(report.longest_streak, 2);
+        assert_eq!(report.missing, vec![date("2024-01-30")]);
+    }
+
+    #[test]
+    fn test_should_count_today_and_combine_notes_of_a_day() {
+        // REQ-JOURNAL-003
+        let notes = [
+            daily("2024-01-30", 10),
+            daily("2024-01-31", 10),
+            daily("2024-01-31", 30),
+            daily("2024-01-01", 500),
+        ];
+
+        let report = journal_report(&notes, date("2024-01-30"), date("2024-01-31"));
+
+        assert_eq!(report.written, 2);
+        assert_eq!(report.days, 2);
+        assert_eq!(report.current_streak, 2);
+        assert!((report.average_words - 25.0).abs() < f64::EPSILON);
+        assert!(report.missing.is_empty());
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// A note for one day of the journal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DailyNote {
+    pub date: NaiveDate,
+    pub path: PathBuf,
+    pub words: usize,
+}
+
+/// Journaling health over a period ending today.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JournalReport {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    /// Days in the period
+    pub days: usize,
+    /// Days in the period with a daily note
+    pub written: usize,
+    /// Consecutive days with a note ending today, or yesterday if today
+    /// has no note yet
+    pub current_streak: usize,
+    pub longest_streak: usize,
+    /// Mean words per day written
+    pub average_words: f64,
+    /// Days before today without a note, oldest first
+    pub missing: Vec<NaiveDate>,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+/// The day a note is for, when its file name is a date in `format` and it
+/// lies inside `folder` (at any depth) if one is given.
+#[must_use]
+pub fn journal_date(path: &Path, folder: Option<&Path>, format: &str) -> Option<NaiveDate> {
+    if folder.is_some_and(|folder| !path.ancestors().skip(1).any(|dir| dir.ends_with(folder))) {
+        return None;
+    }
+    let stem = path.file_stem()?.to_str()?;
+    NaiveDate::parse_from_str(stem, format).ok()
+}
+
+/// The daily notes `scanner` reaches, oldest first.
+///
+/// # Errors
+///
+/// Returns an error if the scan fails.
+pub fn daily_notes(
+    scanner: &Scanner,
+    folder: Option<&Path>,
+    format: &str,
+) -> Result<Vec<DailyNote>> {
+    let mut notes = Vec::new();
+    for record in scanner.iter() {
+        let record = record?;
+        if !scanner.is_note(&record.path) {
+            continue;
+        }
+        if let Some(date) = journal_date(&record.path, folder, format) {
+            notes.push(DailyNote {
+                date,
+                path: record.path,
+                words: record.words,
+            });
+        }
+    }
+    notes.sort_by(|a, b| a.date.cmp(&b.date).then_with(|| a.path.cmp(&b.path)));
+    Ok(notes)
+}
+
+/// Report on the daily notes dated from `from` to `today`, both included.
+/// Several notes for one day count as one day with their words combined.
+#[must_use]
+pub fn journal_report(notes: &[DailyNote], from: NaiveDate, today: NaiveDate) -> JournalReport {
+    let mut words_by_day: BTreeMap<NaiveDate, usize> = BTreeMap::new();
+    for note in notes.iter().filter(|n| (from..=today).contains(&n.date)) {
+        *words_by_day.entry(note.date).or_insert(0) += note.words;
+    }
+
+    let days: Vec<NaiveDate> = from.iter_days().take_while(|day| *day <= today).collect();
+    let mut longest_streak = 0;
+    let mut run = 0;
+    for day in &days {
+        if words_by_day.contains_key(day) {
+            run += 1;
+            longest_streak = longest_streak.max(run);
+        } else if *day != today {
+            run = 0;
+        }
+    }
+    let written_yesterday = words_by_day.contains_key(&(today - TimeDelta::days(1)));
+    let current_streak = if words_by_day.contains_key(&today) || written_yesterday {
+        run
+    } else {
+        0
+    };
+
+    let average_words = if words_by_day.is_empty() {
+        0.0
+    } else {
+        words_by_day.values().sum::<usize>() as f64 / words_by_day.len() as f64
+    };
+
+    JournalReport {
+        from,
+        to: today,
+        days: days.len(),
+        written: words_by_day.len(),
+        current_streak,
+        longest_streak,
+        average_words,
+        missing: days
+            .into_iter()
+            .filter(|day| *day != today && !words_by_day.contains_key(day))
+            .collect(),
+    }
+}
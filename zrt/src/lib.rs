@@ -3,21 +3,126 @@
 //! A tool for analyzing and managing refactoring tasks in a Zettelkasten note system.
 //! Provides functionality for scanning directories, counting files and words,
 //! and tracking refactoring progress through front matter tags.
+//!
+//! The command-line tool lives behind the default `cli` feature. Building with
+//! `--no-default-features` leaves the parsing and statistics core (`core`,
+//! configuration and word counting), which also compiles to
+//! `wasm32-unknown-unknown` for in-browser analysis over [`MemoryFs`] or a
+//! custom [`Vfs`].
 
+#[cfg(feature = "cli")]
+pub mod archive;
+#[cfg(feature = "cli")]
+pub mod assets;
+#[cfg(feature = "cli")]
+pub mod backlinks;
+#[cfg(feature = "cli")]
+pub mod burndown;
+#[cfg(feature = "cli")]
+pub mod check;
+#[cfg(feature = "cli")]
 pub mod cli;
+#[cfg(feature = "cli")]
+pub mod clusters;
+#[cfg(feature = "cli")]
 pub mod connected;
 pub mod core;
+#[cfg(feature = "cli")]
 pub mod count;
+#[cfg(feature = "cli")]
+pub mod diff;
+#[cfg(feature = "cli")]
+pub mod doctor;
+#[cfg(feature = "cli")]
+pub mod due;
+#[cfg(feature = "cli")]
+pub mod duplicates;
+#[cfg(feature = "cli")]
+pub mod empty;
+#[cfg(feature = "cli")]
+pub mod export;
+#[cfg(feature = "cli")]
+pub mod extract;
+#[cfg(feature = "cli")]
+pub mod find;
+#[cfg(feature = "cli")]
+pub mod graph;
+#[cfg(feature = "cli")]
+pub mod history;
+#[cfg(feature = "cli")]
+pub mod hook;
+#[cfg(feature = "cli")]
+pub mod hubs;
 pub mod init;
+#[cfg(feature = "cli")]
+pub mod journal;
+#[cfg(feature = "cli")]
+pub mod links;
+#[cfg(feature = "cli")]
+pub mod lint;
+#[cfg(feature = "cli")]
+pub mod move_notes;
+#[cfg(feature = "cli")]
+pub mod next;
+#[cfg(feature = "cli")]
+pub mod outline;
+#[cfg(feature = "cli")]
+pub mod output;
+#[cfg(feature = "cli")]
+pub mod overview;
+#[cfg(feature = "cli")]
+pub mod plugins;
+#[cfg(feature = "cli")]
+pub mod progress;
+#[cfg(feature = "cli")]
+pub mod query;
+#[cfg(feature = "cli")]
+pub mod queue;
+#[cfg(feature = "cli")]
+pub mod random;
+#[cfg(feature = "cli")]
+pub mod rename;
+#[cfg(feature = "cli")]
+pub mod schema;
+#[cfg(feature = "cli")]
 pub mod search;
+#[cfg(feature = "cli")]
+pub mod serve;
+#[cfg(feature = "cli")]
 pub mod similar;
+#[cfg(feature = "cli")]
+pub mod snapshot;
+#[cfg(feature = "cli")]
+pub mod stale;
+#[cfg(feature = "cli")]
+pub mod streak;
+#[cfg(feature = "cli")]
+pub mod suggest_splits;
+#[cfg(feature = "cli")]
+pub mod summary;
+#[cfg(feature = "cli")]
 pub mod tags;
+#[cfg(feature = "cli")]
+pub mod tasks;
+#[cfg(feature = "cli")]
+pub mod trend;
+#[cfg(feature = "cli")]
+pub mod watch;
 pub mod wordcount;
+#[cfg(feature = "cli")]
+pub mod workflow;
 
+pub use core::error::ZrtError;
 pub use core::filter::utils::is_hidden;
-pub use core::frontmatter::{Frontmatter, parse_frontmatter};
-pub use core::ignore::load_ignore_patterns;
+pub use core::frontmatter::{Frontmatter, note_title, parse_frontmatter};
+pub use core::history::Snapshot;
+pub use core::ignore::{load_ignore_patterns, load_ignore_patterns_in};
+pub use core::links::{Link, LinkIndex, LinkKind, NoteNode, extract_assets, extract_links};
 pub use core::patterns::Patterns;
+pub use core::scanner::{
+    ComparisonStats, NoteRecord, ScanOptions, Scanner, SinglePatternStats, Visit,
+};
+pub use core::vfs::{EntryKind, MemoryFs, RealFs, Vfs};
 pub use init::{RefactorConfig, SortBy, ZrtConfig};
 pub use wordcount::models::{FileMetrics, FileWordCount};
-pub use wordcount::{count_file_metrics, count_words, print_file_metrics, print_top_files};
+pub use wordcount::{count_file_metrics, count_words, sort_file_metrics};
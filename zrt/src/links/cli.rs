@@ -0,0 +1,123 @@
+use anyhow::Result;
+use clap::Args;
+use std::path::PathBuf;
+
+use crate::core::links::LinkIndex;
+use crate::graph::{GraphFilter, build_graph};
+use crate::init::ZrtConfig;
+use crate::links::{LinkStats, link_stats, link_stats_by_tag};
+use crate::output::{number, scan};
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser, Debug)]
+    struct TestArgs {
+        #[command(flatten)]
+        links: LinksArgs,
+    }
+
+    #[test]
+    fn test_should_accept_stats_flag() {
+        // REQ-LINKSTAT-006
+
+        // Given / When
+        let args = TestArgs::parse_from(["program", "--stats"]);
+
+        // Then
+        assert!(args.links.stats);
+    }
+
+    #[test]
+    fn test_should_default_top_to_ten() {
+        // REQ-LINKSTAT-007
+
+        // Given / When
+        let args = TestArgs::parse_from(["program", "--stats"]);
+
+        // Then
+        assert_eq!(args.links.top, 10);
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+#[derive(Args, Debug)]
+pub struct LinksArgs {
+    /// Directories to scan (space-separated, defaults to current directory)
+    #[arg(short = 'd', long = "dir", num_args = 0.., default_values = &["."])]
+    pub directories: Vec<PathBuf>,
+
+    /// Directories to exclude (space-separated)
+    #[arg(short, long, num_args = 0..)]
+    pub exclude: Vec<String>,
+
+    /// Report degree statistics instead of listing links
+    #[arg(long)]
+    pub stats: bool,
+
+    /// Number of most-linked notes to show in statistics
+    #[arg(short = 'n', long = "top", default_value = "10")]
+    pub top: usize,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+pub fn run(args: LinksArgs, config: &ZrtConfig) -> Result<()> {
+    let exclude_dirs: Vec<&str> = args.exclude.iter().map(String::as_str).collect();
+
+    let index = LinkIndex::build_in(&scan::scanner(&args.directories, &exclude_dirs)?)?;
+    let graph = build_graph(
+        &index,
+        &GraphFilter::default(),
+        &config.refactor.done_tag,
+        &config.refactor.todo_tag,
+    );
+
+    if !args.stats {
+        for (from, to) in &graph.edges {
+            println!(
+                "{} {}",
+                graph.nodes[*from].path.display(),
+                graph.nodes[*to].path.display()
+            );
+        }
+        return Ok(());
+    }
+
+    print_stats(&link_stats(&graph, None, args.top), "");
+    for (tag, stats) in link_stats_by_tag(&graph, args.top) {
+        println!();
+        println!("Tag: {tag}");
+        print_stats(&stats, "  ");
+    }
+
+    Ok(())
+}
+
+fn print_stats(stats: &LinkStats, indent: &str) {
+    println!("{indent}Notes: {}", number::count(stats.notes));
+    println!("{indent}Links: {}", number::count(stats.links));
+    println!("{indent}Average links per note: {:.2}", stats.average_links);
+    println!("{indent}In-degree distribution:");
+    for (degree, count) in &stats.in_degrees {
+        println!("{indent}  {degree}: {count}");
+    }
+    println!("{indent}Out-degree distribution:");
+    for (degree, count) in &stats.out_degrees {
+        println!("{indent}  {degree}: {count}");
+    }
+    println!("{indent}Most linked:");
+    for (path, degree) in &stats.most_linked {
+        println!("{indent}  {degree} {}", path.display());
+    }
+}
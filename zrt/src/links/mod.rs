@@ -0,0 +1,189 @@
+pub mod cli;
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use crate::graph::Graph;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::links::{LinkIndex, note_from_content};
+    use crate::graph::{GraphFilter, build_graph};
+    use std::path::Path;
+
+    fn graph(notes: &[(&str, &str)]) -> Graph {
+        let index = LinkIndex::from_notes(
+            notes
+                .iter()
+                .map(|(path, content)| note_from_content(Path::new(path), content))
+                .collect(),
+        );
+        build_graph(&index, &GraphFilter::default(), "refactored", "to_refactor")
+    }
+
+    #[test]
+    fn test_should_compute_degree_distributions() {
+        // REQ-LINKSTAT-001
+
+        // Given: a → b, a → c, b → c
+        let graph = graph(&[
+            ("/v/a.md", "[[b]] [[c]]"),
+            ("/v/b.md", "[[c]]"),
+            ("/v/c.md", ""),
+        ]);
+
+        // When
+        let stats = link_stats(&graph, None, 10);
+
+        // Then
+        assert_eq!(stats.notes, 3);
+        assert_eq!(stats.links, 3);
+        assert_eq!(stats.in_degrees, BTreeMap::from([(0, 1), (1, 1), (2, 1)]));
+        assert_eq!(stats.out_degrees, BTreeMap::from([(0, 1), (1, 1), (2, 1)]));
+    }
+
+    #[test]
+    fn test_should_compute_average_links_per_note() {
+        // REQ-LINKSTAT-002
+        let graph = graph(&[("/v/a.md", "[[b]] [[c]]"), ("/v/b.md", ""), ("/v/c.md", "")]);
+
+        let stats = link_stats(&graph, None, 10);
+
+        assert!((stats.average_links - 2.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_should_rank_most_linked_notes() {
+        // REQ-LINKSTAT-003
+
+        // Given
+        let graph = graph(&[
+            ("/v/a.md", "[[c]]"),
+            ("/v/b.md", "[[c]] [[a]]"),
+            ("/v/c.md", ""),
+        ]);
+
+        // When
+        let stats = link_stats(&graph, None, 1);
+
+        // Then
+        assert_eq!(stats.most_linked, vec![(PathBuf::from("/v/c.md"), 2)]);
+    }
+
+    #[test]
+    fn test_should_aggregate_per_tag() {
+        // REQ-LINKSTAT-004
+
+        // Given
+        let graph = graph(&[
+            ("/v/a.md", "---\ntags: [hub]\n---\n[[b]] [[c]]"),
+            ("/v/b.md", "---\ntags: [leaf]\n---\n"),
+            ("/v/c.md", "---\ntags: [leaf]\n---\n"),
+        ]);
+
+        // When
+        let by_tag = link_stats_by_tag(&graph, 10);
+
+        // Then
+        assert_eq!(by_tag["hub"].notes, 1);
+        assert_eq!(by_tag["hub"].links, 2);
+        assert_eq!(by_tag["leaf"].notes, 2);
+        assert_eq!(by_tag["leaf"].links, 0);
+        assert_eq!(by_tag["leaf"].most_linked.len(), 2);
+    }
+
+    #[test]
+    fn test_should_handle_empty_graph() {
+        // REQ-LINKSTAT-005
+        let stats = link_stats(&Graph::default(), None, 10);
+        assert_eq!(stats.notes, 0);
+        assert!(stats.average_links.abs() < f64::EPSILON);
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// Link degree statistics for a set of notes.
+///
+/// Degrees are always measured against the whole graph, so a note's
+/// in-degree counts links from notes outside the set too.
+#[derive(Debug, Default)]
+pub struct LinkStats {
+    pub notes: usize,
+    /// Total outgoing links from the notes in the set
+    pub links: usize,
+    pub average_links: f64,
+    /// Number of notes per in-degree
+    pub in_degrees: BTreeMap<usize, usize>,
+    /// Number of notes per out-degree
+    pub out_degrees: BTreeMap<usize, usize>,
+    /// Notes with the highest in-degree, descending
+    pub most_linked: Vec<(PathBuf, usize)>,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+/// Compute link statistics over the notes carrying `tag`, or all notes when `tag` is `None`.
+/// `top` limits how many of the most linked notes are kept.
+pub fn link_stats(graph: &Graph, tag: Option<&str>, top: usize) -> LinkStats {
+    let (in_degree, out_degree) = degrees(graph);
+    let members: Vec<usize> = (0..graph.nodes.len())
+        .filter(|&i| tag.is_none_or(|tag| graph.nodes[i].tags.iter().any(|t| t == tag)))
+        .collect();
+
+    let mut stats = LinkStats {
+        notes: members.len(),
+        ..LinkStats::default()
+    };
+    for &i in &members {
+        stats.links += out_degree[i];
+        *stats.in_degrees.entry(in_degree[i]).or_insert(0) += 1;
+        *stats.out_degrees.entry(out_degree[i]).or_insert(0) += 1;
+    }
+    if stats.notes > 0 {
+        stats.average_links = stats.links as f64 / stats.notes as f64;
+    }
+
+    let mut ranked: Vec<(PathBuf, usize)> = members
+        .iter()
+        .map(|&i| (graph.nodes[i].path.clone(), in_degree[i]))
+        .collect();
+    ranked.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    ranked.truncate(top);
+    stats.most_linked = ranked;
+
+    stats
+}
+
+/// Compute [`link_stats`] for every tag present in the graph.
+pub fn link_stats_by_tag(graph: &Graph, top: usize) -> BTreeMap<String, LinkStats> {
+    let mut tags: Vec<&str> = graph
+        .nodes
+        .iter()
+        .flat_map(|n| n.tags.iter().map(String::as_str))
+        .collect();
+    tags.sort_unstable();
+    tags.dedup();
+
+    tags.into_iter()
+        .map(|tag| (tag.to_owned(), link_stats(graph, Some(tag), top)))
+        .collect()
+}
+
+fn degrees(graph: &Graph) -> (Vec<usize>, Vec<usize>) {
+    let mut in_degree = vec![0; graph.nodes.len()];
+    let mut out_degree = vec![0; graph.nodes.len()];
+    for &(from, to) in &graph.edges {
+        out_degree[from] += 1;
+        in_degree[to] += 1;
+    }
+    (in_degree, out_degree)
+}
@@ -0,0 +1,299 @@
+use anyhow::Result;
+use clap::Args;
+use std::path::PathBuf;
+
+use crate::core::scanner::{ScanOptions, Scanner};
+use crate::empty::find_empty;
+use crate::init::{RuleLevel, ZrtConfig};
+use crate::lint::{FilenameRule, LintProblem, check_rule_names};
+use crate::output::files_from::FilesFromArgs;
+use crate::output::scan;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser, Debug)]
+    struct TestArgs {
+        #[command(flatten)]
+        lint: LintArgs,
+    }
+
+    #[test]
+    fn test_should_accept_filenames_with_convention_and_fix() {
+        // REQ-LINT-004
+        let args =
+            TestArgs::parse_from(["program", "--filenames", "--convention", "kebab", "--fix"]);
+
+        assert!(args.lint.filenames);
+        assert_eq!(args.lint.convention.as_deref(), Some("kebab"));
+        assert!(args.lint.fix);
+    }
+
+    #[test]
+    fn test_should_lint_markdown_files_only() -> Result<()> {
+        // REQ-LINT-004
+        let dir = tempfile::TempDir::new()?;
+        std::fs::write(dir.path().join("good-name.md"), "x")?;
+        std::fs::write(dir.path().join("Bad Name.md"), "x")?;
+        std::fs::write(dir.path().join("Image File.png"), "x")?;
+        let scanner = ScanOptions::new([dir.path()]).build()?;
+
+        let problems = lint_filenames(&scanner, &FilenameRule::new("kebab")?)?;
+
+        let paths: Vec<_> = problems.iter().map(|p| p.path.clone()).collect();
+        assert_eq!(paths, vec![dir.path().join("Bad Name.md")]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_flag_notes_without_a_title() -> Result<()> {
+        // REQ-LINT-005
+        // Given notes titled by frontmatter, by heading and not at all
+        let dir = tempfile::TempDir::new()?;
+        std::fs::write(dir.path().join("a.md"), "---\ntitle: Alpha\n---\nbody")?;
+        std::fs::write(dir.path().join("b.md"), "intro\n# Beta\nbody")?;
+        std::fs::write(
+            dir.path().join("c.md"),
+            "---\ntags: [idea]\n---\n## Sub\nbody",
+        )?;
+        std::fs::write(dir.path().join("d.png"), "x")?;
+        let scanner = ScanOptions::new([dir.path()]).build()?;
+
+        // When linting titles
+        let problems = lint_titles(&scanner)?;
+
+        // Then only the untitled note is reported
+        let found: Vec<_> = problems.iter().map(|p| (p.path.clone(), p.rule)).collect();
+        assert_eq!(found, vec![(dir.path().join("c.md"), "titles")]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_report_unparseable_frontmatter_with_yaml_error() -> Result<()> {
+        // REQ-LINT-006
+        let dir = tempfile::TempDir::new()?;
+        std::fs::write(dir.path().join("a.md"), "---\ntags: [ok]\n---\nbody")?;
+        std::fs::write(dir.path().join("b.md"), "---\ntags: [broken\n---\nbody")?;
+        std::fs::write(dir.path().join("c.md"), "no frontmatter")?;
+        std::fs::write(dir.path().join("latin.md"), b"caf\xe9")?;
+        let scanner = ScanOptions::new([dir.path()]).build()?;
+
+        let problems = lint_frontmatter(&scanner)?;
+
+        // The Latin-1 note is skipped and listed, like in every other scan
+        assert_eq!(
+            scanner.report().not_utf8.into_iter().collect::<Vec<_>>(),
+            vec![dir.path().join("latin.md")]
+        );
+        assert_eq!(problems.len(), 1);
+        assert_eq!(problems[0].path, dir.path().join("b.md"));
+        assert_eq!(problems[0].rule, "frontmatter");
+        assert!(
+            problems[0]
+                .message
+                .starts_with("Failed to parse front matter")
+        );
+        assert!(
+            TestArgs::parse_from(["program", "--frontmatter"])
+                .lint
+                .frontmatter
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_flag_empty_notes() -> Result<()> {
+        // REQ-LINT-008
+        let dir = tempfile::TempDir::new()?;
+        std::fs::write(dir.path().join("a.md"), "---\ntitle: A\n---\nbody")?;
+        std::fs::write(dir.path().join("b.md"), "---\ntitle: B\n---\n")?;
+        let scanner = ScanOptions::new([dir.path()]).build()?;
+
+        let problems = lint_empty(&scanner)?;
+
+        let found: Vec<_> = problems
+            .iter()
+            .map(|p| (p.path.clone(), p.rule, p.message.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![(
+                dir.path().join("b.md"),
+                "empty-note",
+                "empty note (frontmatter only)"
+            )]
+        );
+        assert!(TestArgs::parse_from(["program", "--empty"]).lint.empty);
+        Ok(())
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+#[derive(Args, Debug)]
+pub struct LintArgs {
+    /// Directories to scan (space-separated, defaults to current directory)
+    #[arg(short = 'd', long = "dir", num_args = 0.., default_values = &["."])]
+    pub directories: Vec<PathBuf>,
+
+    /// Directories to exclude (space-separated)
+    #[arg(short, long, num_args = 0..)]
+    pub exclude: Vec<String>,
+
+    /// Check file names against the filename convention
+    #[arg(long)]
+    pub filenames: bool,
+
+    /// Check that every note has a frontmatter title or `# heading`
+    #[arg(long)]
+    pub titles: bool,
+
+    /// Check that every note's frontmatter parses as YAML
+    #[arg(long)]
+    pub frontmatter: bool,
+
+    /// Check that every note has body text
+    #[arg(long)]
+    pub empty: bool,
+
+    /// Filename convention: zettel, date, kebab, snake or a regular
+    /// expression (default: lint.filenames from config)
+    #[arg(long, value_name = "PRESET|REGEX")]
+    pub convention: Option<String>,
+
+    /// Propose a conforming name for every file that breaks a convention
+    #[arg(long)]
+    pub fix: bool,
+
+    #[command(flatten)]
+    pub files_from: FilesFromArgs,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+pub fn run(args: LintArgs, config: &ZrtConfig) -> Result<()> {
+    check_rule_names(&config.lint)?;
+    let exclude_dirs: Vec<&str> = args.exclude.iter().map(String::as_str).collect();
+    let level = |rule| config.lint.level(rule, RuleLevel::Error);
+    // Without rule flags, every configured rule runs; ignored rules never do
+    let all_rules = !args.filenames && !args.titles && !args.frontmatter && !args.empty;
+    let enabled = |rule, flag| (all_rules || flag) && level(rule) != RuleLevel::Ignore;
+
+    let convention = args.convention.or_else(|| config.lint.filenames.clone());
+    let filename_rule = match (convention, args.filenames) {
+        (Some(spec), _) => Some(FilenameRule::new(&spec)?),
+        (None, true) => anyhow::bail!(
+            "No filename convention; pass --convention or set lint.filenames in the config"
+        ),
+        (None, false) => None,
+    };
+    let options = args
+        .files_from
+        .apply(ScanOptions::new(&args.directories).exclude(&exclude_dirs))?;
+    let scanner = scan::attach(options).build()?;
+    let mut problems = Vec::new();
+    if let Some(rule) = filename_rule.filter(|_| enabled("filenames", true)) {
+        problems.extend(lint_filenames(&scanner, &rule)?);
+    }
+    if enabled("frontmatter", args.frontmatter) {
+        problems.extend(lint_frontmatter(&scanner)?);
+    }
+    if enabled("titles", args.titles) {
+        problems.extend(lint_titles(&scanner)?);
+    }
+    if enabled("empty-note", args.empty) {
+        problems.extend(lint_empty(&scanner)?);
+    }
+
+    let mut errors = 0;
+    for problem in &problems {
+        if level(problem.rule) == RuleLevel::Error {
+            errors += 1;
+            println!("{problem}");
+        } else {
+            println!("warning: {problem}");
+        }
+        if let (true, Some(fix)) = (args.fix, &problem.fix) {
+            println!("  -> {}", fix.display());
+        }
+    }
+
+    if errors > 0 {
+        anyhow::bail!("{errors} lint error(s) found");
+    }
+    Ok(())
+}
+
+/// Markdown files whose names break `rule`, in walk order.
+fn lint_filenames(scanner: &Scanner, rule: &FilenameRule) -> Result<Vec<LintProblem>> {
+    let mut problems = Vec::new();
+    for path in scanner.files()? {
+        if !scanner.is_note(&path) {
+            continue;
+        }
+        let modified = std::fs::metadata(&path).and_then(|m| m.modified()).ok();
+        problems.extend(rule.check(&path, modified));
+    }
+    Ok(problems)
+}
+
+/// Markdown notes whose frontmatter fails to parse, in walk order. Their
+/// tags are otherwise silently ignored.
+fn lint_frontmatter(scanner: &Scanner) -> Result<Vec<LintProblem>> {
+    let mut problems = Vec::new();
+    for note in scanner.note_headers() {
+        let note = note?;
+        if !scanner.is_note(&note.path) {
+            continue;
+        }
+        if let Some(message) = note.frontmatter_error {
+            problems.push(LintProblem {
+                path: note.path,
+                rule: "frontmatter",
+                message,
+                fix: None,
+            });
+        }
+    }
+    Ok(problems)
+}
+
+/// Markdown notes with neither a frontmatter title nor a `# heading`, in walk order.
+fn lint_titles(scanner: &Scanner) -> Result<Vec<LintProblem>> {
+    let mut problems = Vec::new();
+    for record in scanner.iter() {
+        let record = record?;
+        if !scanner.is_note(&record.path) || record.title.is_some() {
+            continue;
+        }
+        problems.push(LintProblem {
+            path: record.path,
+            rule: "titles",
+            message: "no title or # heading".to_owned(),
+            fix: None,
+        });
+    }
+    Ok(problems)
+}
+
+/// Markdown notes without body text, in walk order.
+fn lint_empty(scanner: &Scanner) -> Result<Vec<LintProblem>> {
+    Ok(find_empty(scanner)?
+        .into_iter()
+        .map(|note| LintProblem {
+            path: note.path,
+            rule: "empty-note",
+            message: format!("empty note ({})", note.emptiness),
+            fix: None,
+        })
+        .collect())
+}
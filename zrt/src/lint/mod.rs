@@ -0,0 +1,284 @@
+pub mod cli;
+
+use anyhow::{Context as _, Result};
+use chrono::{DateTime, Local};
+use regex::Regex;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use crate::doctor::Check;
+use crate::hook;
+use crate::init::LintConfig;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(spec: &str, name: &str) -> Result<Option<LintProblem>> {
+        Ok(FilenameRule::new(spec)?.check(Path::new(name), None))
+    }
+
+    #[test]
+    fn test_should_accept_names_matching_presets() -> Result<()> {
+        // REQ-LINT-001
+        assert!(check("zettel", "202401311542-systems-thinking.md")?.is_none());
+        assert!(check("date", "2024-01-31 meeting notes.md")?.is_none());
+        assert!(check("kebab", "systems-thinking.md")?.is_none());
+        assert!(check("snake", "systems_thinking.md")?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_flag_names_breaking_presets() -> Result<()> {
+        // REQ-LINT-001
+        assert!(check("zettel", "systems-thinking.md")?.is_some());
+        assert!(check("date", "meeting.md")?.is_some());
+        assert!(check("kebab", "Systems Thinking.md")?.is_some());
+        assert!(check("snake", "systems-thinking.md")?.is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_use_custom_regex() -> Result<()> {
+        // REQ-LINT-002
+        assert!(check(r"^[A-Z][a-z]+\.md$", "Index.md")?.is_none());
+        assert!(check(r"^[A-Z][a-z]+\.md$", "index.md")?.is_some());
+        assert!(FilenameRule::new("(unclosed").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_propose_conforming_renames() -> Result<()> {
+        // REQ-LINT-003
+        let problem = check("kebab", "notes/Systems  Thinking (v2).md")?;
+        assert_eq!(
+            problem.and_then(|p| p.fix),
+            Some(PathBuf::from("notes/systems-thinking-v2.md"))
+        );
+
+        let problem = check("snake", "Systems Thinking.md")?;
+        assert_eq!(
+            problem.and_then(|p| p.fix),
+            Some(PathBuf::from("systems_thinking.md"))
+        );
+
+        // A timestamp is only available from the file's modification time
+        let modified = DateTime::parse_from_rfc3339("2024-01-31T12:00:00Z")?.into();
+        let rule = FilenameRule::new("zettel")?;
+        let problem = rule.check(Path::new("Systems Thinking.md"), Some(modified));
+        let fix = problem.and_then(|p| p.fix).map(|f| f.display().to_string());
+        assert!(
+            fix.is_some_and(|f| f.starts_with("20240131") && f.ends_with("-systems-thinking.md"))
+        );
+
+        // Custom patterns cannot be satisfied automatically
+        assert_eq!(check(r"^x\.md$", "y.md")?.and_then(|p| p.fix), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_reject_levels_for_unknown_rules() -> Result<()> {
+        // REQ-LINT-007
+        let mut config: LintConfig = toml::from_str(
+            "[rules]\ntitles = \"warn\"\norphan-assets = \"ignore\"\nempty-note = \"warn\"",
+        )?;
+        check_rule_names(&config)?;
+
+        config
+            .rules
+            .extend(toml::from_str::<LintConfig>("[rules]\norphans = \"warn\"")?.rules);
+        let error = check_rule_names(&config).map_err(|e| e.to_string());
+        assert!(error.is_err_and(|e| e.starts_with("Unknown rule orphans in [lint.rules]")));
+        Ok(())
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// Rules checked by `zrt lint`, by name.
+pub const RULES: [&str; 4] = ["filenames", "titles", "frontmatter", "empty-note"];
+
+/// A file breaking a lint rule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintProblem {
+    pub path: PathBuf,
+    /// Name of the rule, as used by its command-line flag
+    pub rule: &'static str,
+    pub message: String,
+    /// A path that would satisfy the rule, if one can be derived
+    pub fix: Option<PathBuf>,
+}
+
+/// Filename conventions with a known shape, so renames can be proposed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Preset {
+    /// `YYYYMMDDHHMM-title.md`
+    Zettel,
+    /// `YYYY-MM-DD` followed by anything
+    Date,
+    /// `lower-case-words.md`
+    Kebab,
+    /// `lower_case_words.md`
+    Snake,
+}
+
+/// The filename convention every note must follow.
+#[derive(Debug, Clone)]
+pub struct FilenameRule {
+    /// The preset name or regex as configured, for messages
+    spec: String,
+    pattern: Regex,
+    preset: Option<Preset>,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+impl fmt::Display for LintProblem {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} ({})",
+            self.path.display(),
+            self.message,
+            self.rule
+        )
+    }
+}
+
+impl Preset {
+    fn parse(name: &str) -> Option<Self> {
+        match name {
+            "zettel" => Some(Self::Zettel),
+            "date" => Some(Self::Date),
+            "kebab" => Some(Self::Kebab),
+            "snake" => Some(Self::Snake),
+            _ => None,
+        }
+    }
+
+    const fn pattern(self) -> &'static str {
+        match self {
+            Self::Zettel => r"^\d{12}-[a-z0-9]+(-[a-z0-9]+)*\.md$",
+            Self::Date => r"^\d{4}-\d{2}-\d{2}\b.*\.md$",
+            Self::Kebab => r"^[a-z0-9]+(-[a-z0-9]+)*\.md$",
+            Self::Snake => r"^[a-z0-9]+(_[a-z0-9]+)*\.md$",
+        }
+    }
+
+    /// A conforming file name for a note called `stem`, using its
+    /// modification time where the convention needs a timestamp.
+    fn rename(self, stem: &str, modified: Option<SystemTime>) -> Option<String> {
+        let local = |format: &str| {
+            modified.map(|time| DateTime::<Local>::from(time).format(format).to_string())
+        };
+        let words = || slug_words(stem).filter(|words| !words.is_empty());
+        match self {
+            Self::Zettel => {
+                // Drop a timestamp-like prefix so it is not repeated in the title
+                let title = stem.trim_start_matches(|c: char| c.is_ascii_digit() || c == '-');
+                let words = slug_words(title).filter(|words| !words.is_empty())?;
+                Some(format!("{}-{}.md", local("%Y%m%d%H%M")?, words.join("-")))
+            }
+            Self::Date => Some(format!("{} {stem}.md", local("%Y-%m-%d")?)),
+            Self::Kebab => Some(format!("{}.md", words()?.join("-"))),
+            Self::Snake => Some(format!("{}.md", words()?.join("_"))),
+        }
+    }
+}
+
+impl FilenameRule {
+    /// A rule from a preset name or, failing that, a regular expression.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `spec` is neither a preset nor a valid regex.
+    #[inline]
+    pub fn new(spec: &str) -> Result<Self> {
+        let preset = Preset::parse(spec);
+        let pattern = Regex::new(preset.map_or(spec, |preset| preset.pattern()))
+            .with_context(|| format!("Invalid filename convention: {spec}"))?;
+        Ok(Self {
+            spec: spec.to_owned(),
+            pattern,
+            preset,
+        })
+    }
+
+    /// The problem with `path`'s file name, or `None` if it conforms.
+    #[inline]
+    #[must_use]
+    pub fn check(&self, path: &Path, modified: Option<SystemTime>) -> Option<LintProblem> {
+        let name = path.file_name()?.to_string_lossy();
+        if self.pattern.is_match(&name) {
+            return None;
+        }
+
+        let stem = path
+            .file_stem()
+            .map(|s| s.to_string_lossy())
+            .unwrap_or_default();
+        let fix = self
+            .preset
+            .and_then(|preset| preset.rename(&stem, modified))
+            .map(|name| path.with_file_name(name));
+        Some(LintProblem {
+            path: path.to_path_buf(),
+            rule: "filenames",
+            message: format!("file name does not match {}", self.spec),
+            fix,
+        })
+    }
+}
+
+/// Fail on a `[lint.rules]` entry naming no lint rule, doctor check or
+/// pre-commit hook rule, so a misspelt name does not silently leave its rule
+/// at the default.
+///
+/// # Errors
+///
+/// Returns an error naming the first unknown rule.
+pub fn check_rule_names(config: &LintConfig) -> Result<()> {
+    let known = || {
+        RULES
+            .into_iter()
+            .chain(Check::ALL.map(Check::name))
+            .chain(hook::RULES)
+    };
+    if let Some(name) = config
+        .rules
+        .keys()
+        .find(|name| !known().any(|rule| rule == *name))
+    {
+        let mut known: Vec<_> = known().collect();
+        known.sort_unstable();
+        known.dedup();
+        anyhow::bail!(
+            "Unknown rule {name} in [lint.rules]; known rules: {}",
+            known.join(", ")
+        );
+    }
+    Ok(())
+}
+
+/// Lower-case ASCII words of `text`, split on anything that is not a letter
+/// or digit, or `None` if it has non-ASCII letters that cannot be kept.
+fn slug_words(text: &str) -> Option<Vec<String>> {
+    if text.chars().any(|c| c.is_alphanumeric() && !c.is_ascii()) {
+        return None;
+    }
+    Some(
+        text.split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .map(str::to_ascii_lowercase)
+            .collect(),
+    )
+}
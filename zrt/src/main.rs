@@ -1,17 +1,12 @@
-mod cli;
-mod connected;
-mod core;
-mod count;
-mod init;
-mod search;
-mod similar;
-mod tags;
-mod wordcount;
+use std::process::ExitCode;
+use zrt::cli;
+use zrt::output::exit;
 
-use anyhow::Result;
-use clap::Parser as _;
-
-fn main() -> Result<()> {
-    let args = cli::Args::parse();
-    cli::run(args)
+fn main() -> ExitCode {
+    let args = cli::parse();
+    let result = cli::run(args);
+    if let Some(error) = exit::reported(&result) {
+        eprintln!("Error: {error:?}");
+    }
+    ExitCode::from(exit::status(&result))
 }
@@ -0,0 +1,95 @@
+use anyhow::Result;
+use clap::Args;
+use std::path::{Path, PathBuf};
+
+use crate::core::links::{apply_relocation, relocate};
+use crate::core::scanner::ScanOptions;
+use crate::move_notes::{destinations, tagged};
+use crate::output::{moves, scan};
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser, Debug)]
+    struct TestArgs {
+        #[command(flatten)]
+        move_notes: MoveArgs,
+    }
+
+    #[test]
+    fn test_should_require_tags_and_a_folder() {
+        // REQ-MOVE-003
+        let args =
+            TestArgs::parse_from(["program", "--tag", "draft", "wip", "--to", "INBOX/"]).move_notes;
+
+        assert_eq!(args.tag, vec!["draft", "wip"]);
+        assert_eq!(args.to, PathBuf::from("INBOX/"));
+        assert!(!args.keep_folders && !args.dry_run);
+        assert!(TestArgs::try_parse_from(["program", "--to", "INBOX"]).is_err());
+        assert!(TestArgs::try_parse_from(["program", "-t", "draft"]).is_err());
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+#[derive(Args, Debug)]
+pub struct MoveArgs {
+    /// Move notes with any of these tags
+    #[arg(short, long, num_args = 1.., required = true)]
+    pub tag: Vec<String>,
+
+    /// Folder to move the notes into, relative to the first scanned directory
+    #[arg(long)]
+    pub to: PathBuf,
+
+    /// Keep each note's subfolders below the target folder instead of moving
+    /// it straight into it
+    #[arg(long)]
+    pub keep_folders: bool,
+
+    /// Show what would move without changing any file
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Directories to scan (space-separated, defaults to current directory)
+    #[arg(short = 'd', long = "dir", num_args = 0.., default_values = &["."])]
+    pub directories: Vec<PathBuf>,
+
+    /// Directories to exclude (space-separated)
+    #[arg(short, long, num_args = 0..)]
+    pub exclude: Vec<String>,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+pub fn run(args: MoveArgs) -> Result<()> {
+    let tag_refs: Vec<&str> = args.tag.iter().map(String::as_str).collect();
+    let exclude_dirs: Vec<&str> = args.exclude.iter().map(String::as_str).collect();
+    let options = ScanOptions::new(&args.directories).exclude(&exclude_dirs);
+    let scanner = scan::attach(options).build()?;
+
+    let roots: Vec<PathBuf> = scanner.roots().map(Path::to_path_buf).collect();
+    let folder = roots
+        .first()
+        .map_or_else(|| args.to.clone(), |root| root.join(&args.to));
+    let paths = tagged(&scanner, &tag_refs)?;
+    let planned = destinations(&paths, &roots, &folder, args.keep_folders);
+    let relocated = relocate(&scanner, &planned)?;
+
+    for line in moves::report(&relocated, args.dry_run) {
+        println!("{line}");
+    }
+    if !args.dry_run {
+        apply_relocation(&relocated)?;
+    }
+    Ok(())
+}
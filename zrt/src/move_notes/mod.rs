@@ -0,0 +1,100 @@
+pub mod cli;
+
+use anyhow::Result;
+use std::path::{Path, PathBuf};
+
+use crate::archive::archive_moves;
+use crate::core::scanner::Scanner;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::scanner::ScanOptions;
+    use crate::core::vfs::MemoryFs;
+
+    #[test]
+    fn test_should_find_notes_with_any_tag() -> Result<()> {
+        // REQ-MOVE-001
+        let vfs = MemoryFs::new()
+            .with_file("/v/a.md", "---\ntags: [draft]\n---\n")
+            .with_file("/v/sub/b.md", "---\ntags: [idea, wip]\n---\n")
+            .with_file("/v/c.md", "---\ntags: [idea]\n---\n");
+        let scanner = ScanOptions::new(["/v"]).vfs(vfs).build()?;
+
+        assert_eq!(
+            tagged(&scanner, &["draft", "wip"])?,
+            vec![PathBuf::from("/v/a.md"), PathBuf::from("/v/sub/b.md")]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_move_into_the_folder_flat_or_keeping_subfolders() {
+        // REQ-MOVE-002
+        let roots = [PathBuf::from("/v")];
+        let paths = [
+            PathBuf::from("/v/a.md"),
+            PathBuf::from("/v/sub/b.md"),
+            PathBuf::from("/v/INBOX/c.md"),
+        ];
+        let folder = Path::new("/v/INBOX");
+
+        assert_eq!(
+            destinations(&paths, &roots, folder, false),
+            vec![
+                (PathBuf::from("/v/a.md"), PathBuf::from("/v/INBOX/a.md")),
+                (PathBuf::from("/v/sub/b.md"), PathBuf::from("/v/INBOX/b.md")),
+            ]
+        );
+        assert_eq!(
+            destinations(&paths, &roots, folder, true)[1],
+            (
+                PathBuf::from("/v/sub/b.md"),
+                PathBuf::from("/v/INBOX/sub/b.md")
+            )
+        );
+    }
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+/// Markdown notes carrying any of `tags`, in walk order.
+///
+/// # Errors
+///
+/// Returns an error if the scan fails.
+pub fn tagged(scanner: &Scanner, tags: &[&str]) -> Result<Vec<PathBuf>> {
+    let mut paths = Vec::new();
+    for record in scanner.iter() {
+        let record = record?;
+        if scanner.is_note(&record.path) && record.has_any_tag(tags) {
+            paths.push(record.path);
+        }
+    }
+    Ok(paths)
+}
+
+/// Where each note goes in `folder`: straight into it, or with
+/// `keep_folders` under its path relative to the scanned directory it is in.
+/// Notes already in the folder stay where they are.
+#[must_use]
+pub fn destinations(
+    paths: &[PathBuf],
+    roots: &[PathBuf],
+    folder: &Path,
+    keep_folders: bool,
+) -> Vec<(PathBuf, PathBuf)> {
+    if keep_folders {
+        return archive_moves(paths, roots, folder);
+    }
+    paths
+        .iter()
+        .filter(|path| !path.starts_with(folder))
+        .filter_map(|path| Some((path.clone(), folder.join(path.file_name()?))))
+        .collect()
+}
@@ -0,0 +1,112 @@
+use anyhow::Result;
+use clap::Args;
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use crate::core::links::LinkIndex;
+use crate::graph::{GraphFilter, build_graph};
+use crate::init::ZrtConfig;
+use crate::next::{candidates, rank};
+use crate::output::exec::ExecArgs;
+use crate::output::open::OpenArgs;
+use crate::output::scan;
+use crate::output::titles::TitleArgs;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser, Debug)]
+    struct TestArgs {
+        #[command(flatten)]
+        next: NextArgs,
+    }
+
+    #[test]
+    fn test_should_default_to_a_single_suggestion() {
+        // REQ-NEXT-004
+        let args = TestArgs::parse_from(["program"]);
+
+        assert_eq!(args.next.limit, 1);
+        assert_eq!(args.next.directories, vec![PathBuf::from(".")]);
+    }
+
+    #[test]
+    fn test_should_accept_limit_and_open() {
+        // REQ-NEXT-004
+        let args = TestArgs::parse_from(["program", "-n", "5", "--open"]);
+
+        assert_eq!(args.next.limit, 5);
+        assert_eq!(args.next.open.open, Some(1));
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+#[derive(Args, Debug)]
+pub struct NextArgs {
+    /// Directories to scan (space-separated, defaults to current directory)
+    #[arg(short = 'd', long = "dir", num_args = 0.., default_values = &["."])]
+    pub directories: Vec<PathBuf>,
+
+    /// Directories to exclude (space-separated)
+    #[arg(short, long, num_args = 0..)]
+    pub exclude: Vec<String>,
+
+    /// Number of suggestions to show (default: 1)
+    #[arg(short = 'n', long, default_value = "1")]
+    pub limit: usize,
+
+    #[command(flatten)]
+    pub titles: TitleArgs,
+
+    #[command(flatten)]
+    pub open: OpenArgs,
+
+    #[command(flatten)]
+    pub exec: ExecArgs,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+pub fn run(args: NextArgs, config: &ZrtConfig) -> Result<()> {
+    let exclude_dirs: Vec<&str> = args.exclude.iter().map(String::as_str).collect();
+
+    let index = LinkIndex::build_in(&scan::scanner(&args.directories, &exclude_dirs)?)?;
+    let graph = build_graph(
+        &index,
+        &GraphFilter::default(),
+        &config.refactor.done_tag,
+        &config.refactor.todo_tag,
+    );
+
+    let ranked = rank(candidates(&graph, SystemTime::now()), &config.next);
+    if ranked.is_empty() {
+        anyhow::bail!("No notes tagged {}", config.refactor.todo_tag);
+    }
+
+    let top = &ranked[..args.limit.min(ranked.len())];
+    for suggestion in top {
+        let note = &suggestion.note;
+        println!(
+            "{:.2} {} ({} words, {} backlinks, {:.0} days stale)",
+            suggestion.score,
+            args.titles.label(&note.path),
+            note.words,
+            note.backlinks,
+            note.days_stale
+        );
+    }
+
+    let paths: Vec<&PathBuf> = top.iter().map(|s| &s.note.path).collect();
+    args.exec.run(&paths)?;
+    args.open.open(&paths)
+}
@@ -0,0 +1,224 @@
+pub mod cli;
+
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use crate::core::status::RefactorStatus;
+use crate::graph::Graph;
+use crate::init::NextConfig;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::links::{LinkIndex, note_from_content};
+    use crate::graph::{GraphFilter, build_graph};
+    use std::time::Duration;
+
+    fn candidate(path: &str, words: usize, backlinks: usize, days_stale: f64) -> Candidate {
+        Candidate {
+            path: PathBuf::from(path),
+            words,
+            backlinks,
+            days_stale,
+        }
+    }
+
+    fn weights(words: f64, backlinks: f64, staleness: f64) -> NextConfig {
+        NextConfig {
+            words,
+            backlinks,
+            staleness,
+        }
+    }
+
+    #[test]
+    fn test_should_only_consider_notes_to_refactor() {
+        // REQ-NEXT-001
+        // Given a graph where a done note and a todo note both link to another todo note
+        let index = LinkIndex::from_notes(vec![
+            note_from_content(
+                Path::new("/v/hub.md"),
+                "---\ntags: [to_refactor]\n---\none two",
+            ),
+            note_from_content(
+                Path::new("/v/a.md"),
+                "---\ntags: [to_refactor]\n---\n[[hub]]",
+            ),
+            note_from_content(
+                Path::new("/v/b.md"),
+                "---\ntags: [refactored]\n---\n[[hub]]",
+            ),
+        ]);
+        let graph = build_graph(&index, &GraphFilter::default(), "refactored", "to_refactor");
+
+        // When collecting candidates
+        let found = candidates(&graph, SystemTime::now());
+
+        // Then only todo notes remain, with backlinks counted from every note
+        let summary: Vec<_> = found
+            .iter()
+            .map(|c| (c.path.to_string_lossy().into_owned(), c.backlinks))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![("/v/hub.md".to_owned(), 2), ("/v/a.md".to_owned(), 0)]
+        );
+    }
+
+    #[test]
+    fn test_should_rank_by_weighted_factors() {
+        // REQ-NEXT-002
+        // Given a long note and a heavily linked one
+        let notes = vec![
+            candidate("long.md", 1000, 1, 0.0),
+            candidate("linked.md", 100, 10, 0.0),
+        ];
+
+        // When ranking with backlinks weighted higher than words
+        let ranked = rank(notes.clone(), &weights(1.0, 2.0, 0.0));
+
+        // Then the linked note comes first
+        assert_eq!(ranked[0].note.path, Path::new("linked.md"));
+
+        // And weighting words higher flips the order
+        let ranked = rank(notes, &weights(2.0, 1.0, 0.0));
+        assert_eq!(ranked[0].note.path, Path::new("long.md"));
+    }
+
+    #[test]
+    fn test_should_normalize_each_factor() {
+        // REQ-NEXT-002
+        // Given the maximum of every factor on a single note
+        let notes = vec![
+            candidate("top.md", 500, 4, 30.0),
+            candidate("half.md", 250, 2, 15.0),
+        ];
+
+        // When ranking with unit weights
+        let ranked = rank(notes, &weights(1.0, 1.0, 1.0));
+
+        // Then scores are sums of factors scaled to 0..=1
+        assert!((ranked[0].score - 3.0).abs() < 1e-9);
+        assert!((ranked[1].score - 1.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_should_score_zero_when_factors_are_all_zero() {
+        // REQ-NEXT-002
+        let ranked = rank(
+            vec![candidate("b.md", 0, 0, 0.0), candidate("a.md", 0, 0, 0.0)],
+            &weights(1.0, 1.0, 1.0),
+        );
+
+        // Ties fall back to path order
+        assert_eq!(ranked[0].note.path, Path::new("a.md"));
+        assert!(ranked.iter().all(|s| s.score == 0.0));
+    }
+
+    #[test]
+    fn test_should_measure_staleness_in_days() {
+        // REQ-NEXT-003
+        let modified = SystemTime::UNIX_EPOCH;
+        let now = modified + Duration::from_secs(3 * 86_400 + 43_200);
+
+        assert!((days_between(modified, now) - 3.5).abs() < 1e-9);
+        assert!(days_between(now, modified).abs() < f64::EPSILON);
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// A note still to refactor, with the raw factors it is scored on.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Candidate {
+    pub path: PathBuf,
+    pub words: usize,
+    /// Number of distinct notes linking here
+    pub backlinks: usize,
+    /// Days since the file was last modified
+    pub days_stale: f64,
+}
+
+/// A ranked candidate.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Suggestion {
+    pub note: Candidate,
+    pub score: f64,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+/// Notes in `graph` with the todo refactor status, in graph order.
+///
+/// Backlinks count edges from every note in the graph, refactored or not.
+/// Staleness is measured from each file's modification time to `now`, and is
+/// zero when that time cannot be read.
+#[must_use]
+pub fn candidates(graph: &Graph, now: SystemTime) -> Vec<Candidate> {
+    let mut backlinks = vec![0; graph.nodes.len()];
+    for &(_, to) in &graph.edges {
+        backlinks[to] += 1;
+    }
+
+    graph
+        .nodes
+        .iter()
+        .zip(backlinks)
+        .filter(|(node, _)| node.status == RefactorStatus::Todo)
+        .map(|(node, backlinks)| Candidate {
+            path: node.path.clone(),
+            words: node.words,
+            backlinks,
+            days_stale: modified(&node.path).map_or(0.0, |time| days_between(time, now)),
+        })
+        .collect()
+}
+
+/// Score candidates and sort them best first.
+///
+/// Each factor is scaled by its largest value among the candidates, so it
+/// contributes between 0 and its weight; ties keep path order.
+#[must_use]
+pub fn rank(candidates: Vec<Candidate>, weights: &NextConfig) -> Vec<Suggestion> {
+    let max_words = candidates.iter().map(|c| c.words).max().unwrap_or(0);
+    let max_backlinks = candidates.iter().map(|c| c.backlinks).max().unwrap_or(0);
+    let max_days = candidates.iter().map(|c| c.days_stale).fold(0.0, f64::max);
+
+    let mut suggestions: Vec<Suggestion> = candidates
+        .into_iter()
+        .map(|note| {
+            let score = weights.words * ratio(note.words as f64, max_words as f64)
+                + weights.backlinks * ratio(note.backlinks as f64, max_backlinks as f64)
+                + weights.staleness * ratio(note.days_stale, max_days);
+            Suggestion { note, score }
+        })
+        .collect();
+
+    suggestions.sort_by(|a, b| {
+        b.score
+            .total_cmp(&a.score)
+            .then_with(|| a.note.path.cmp(&b.note.path))
+    });
+    suggestions
+}
+
+fn ratio(value: f64, max: f64) -> f64 {
+    if max > 0.0 { value / max } else { 0.0 }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).ok()?.modified().ok()
+}
+
+fn days_between(earlier: SystemTime, later: SystemTime) -> f64 {
+    later
+        .duration_since(earlier)
+        .map_or(0.0, |elapsed| elapsed.as_secs_f64() / 86_400.0)
+}
@@ -0,0 +1,120 @@
+use anyhow::Result;
+use clap::Args;
+use std::path::PathBuf;
+
+use crate::core::scanner::ScanOptions;
+use crate::outline::{Limits, check, outline_notes};
+use crate::output::files_from::FilesFromArgs;
+use crate::output::scan;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser, Debug)]
+    struct TestArgs {
+        #[command(flatten)]
+        outline: OutlineArgs,
+    }
+
+    #[test]
+    fn test_should_default_limits() {
+        // REQ-OUTLINE-004
+        let args = TestArgs::parse_from(["program"]);
+
+        assert!(!args.outline.check);
+        assert_eq!(args.outline.max_depth, 4);
+        assert_eq!(args.outline.max_sections, 12);
+    }
+
+    #[test]
+    fn test_should_accept_check_and_limits() {
+        // REQ-OUTLINE-004
+        let args = TestArgs::parse_from([
+            "program",
+            "--check",
+            "--max-depth",
+            "3",
+            "--max-sections",
+            "8",
+        ]);
+
+        assert!(args.outline.check);
+        assert_eq!(args.outline.max_depth, 3);
+        assert_eq!(args.outline.max_sections, 8);
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+#[derive(Args, Debug)]
+pub struct OutlineArgs {
+    /// Directories to scan (space-separated, defaults to current directory)
+    #[arg(short = 'd', long = "dir", num_args = 0.., default_values = &["."])]
+    pub directories: Vec<PathBuf>,
+
+    /// Directories to exclude (space-separated)
+    #[arg(short, long, num_args = 0..)]
+    pub exclude: Vec<String>,
+
+    /// Report structural issues instead of printing outlines
+    #[arg(long)]
+    pub check: bool,
+
+    /// Deepest heading level allowed before a note is reported
+    #[arg(long, default_value = "4")]
+    pub max_depth: usize,
+
+    /// Most sections (headings below the H1) allowed before a note is reported
+    #[arg(long, default_value = "12")]
+    pub max_sections: usize,
+
+    #[command(flatten)]
+    pub files_from: FilesFromArgs,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+pub fn run(args: OutlineArgs) -> Result<()> {
+    let exclude_dirs: Vec<&str> = args.exclude.iter().map(String::as_str).collect();
+
+    let options = args
+        .files_from
+        .apply(ScanOptions::new(&args.directories).exclude(&exclude_dirs))?;
+    let scanner = scan::attach(options).build()?;
+    let outlines = outline_notes(&scanner)?;
+
+    if args.check {
+        let limits = Limits {
+            max_depth: args.max_depth,
+            max_sections: args.max_sections,
+        };
+        for note in &outlines {
+            for issue in check(&note.headings, limits) {
+                println!("{}: {issue}", note.path.display());
+            }
+        }
+        return Ok(());
+    }
+
+    for note in outlines.iter().filter(|note| !note.headings.is_empty()) {
+        println!("{}", note.path.display());
+        for heading in &note.headings {
+            println!(
+                "{}{} {}",
+                "  ".repeat(heading.level),
+                "#".repeat(heading.level),
+                heading.text
+            );
+        }
+    }
+    Ok(())
+}
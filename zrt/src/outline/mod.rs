@@ -0,0 +1,241 @@
+pub mod cli;
+
+use anyhow::Result;
+use std::fmt;
+use std::path::PathBuf;
+
+use crate::core::scanner::Scanner;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::scanner::ScanOptions;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn h(level: usize, text: &str) -> Heading {
+        Heading {
+            level,
+            text: text.to_owned(),
+        }
+    }
+
+    #[test]
+    fn test_should_parse_atx_headings_outside_code() {
+        // REQ-OUTLINE-001
+        let body = "# Title #\ntext\n## Part\n```\n# not a heading\n```\n#hashtag\n####### seven\n   ### Deep\n";
+
+        assert_eq!(
+            headings(body),
+            vec![h(1, "Title"), h(2, "Part"), h(3, "Deep")]
+        );
+    }
+
+    #[test]
+    fn test_should_report_structural_issues() {
+        // REQ-OUTLINE-002
+        let limits = Limits {
+            max_depth: 3,
+            max_sections: 3,
+        };
+        let outline = vec![h(2, "Intro"), h(4, "Detail"), h(2, "More"), h(3, "Sub")];
+
+        let issues = check(&outline, limits);
+
+        assert_eq!(
+            issues,
+            vec![
+                Issue::MissingH1,
+                Issue::SkippedLevel {
+                    heading: "Detail".to_owned(),
+                    from: 2,
+                    to: 4,
+                },
+                Issue::TooDeep {
+                    heading: "Detail".to_owned(),
+                    level: 4,
+                },
+                Issue::TooManySections { count: 4 },
+            ]
+        );
+        assert_eq!(check(&[h(1, "Title"), h(2, "Part")], limits), vec![]);
+    }
+
+    #[test]
+    fn test_should_outline_every_note() -> Result<()> {
+        // REQ-OUTLINE-003
+        let dir = TempDir::new()?;
+        fs::write(dir.path().join("a.md"), "---\ntitle: A\n---\n# A\n## One")?;
+        fs::write(dir.path().join("b.md"), "no headings")?;
+        fs::write(dir.path().join("c.txt"), "# ignored")?;
+        let scanner = ScanOptions::new([dir.path()]).build()?;
+
+        let outlines = outline_notes(&scanner)?;
+
+        let found: Vec<_> = outlines
+            .iter()
+            .map(|note| (note.path.clone(), note.headings.len()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![(dir.path().join("a.md"), 2), (dir.path().join("b.md"), 0)]
+        );
+        Ok(())
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// An ATX heading (`## Text`) in a note body.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Heading {
+    /// 1 for `#` through 6 for `######`
+    pub level: usize,
+    pub text: String,
+}
+
+/// A note's headings in order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteOutline {
+    pub path: PathBuf,
+    pub headings: Vec<Heading>,
+}
+
+/// How deep and how long an outline may get before it is reported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    /// Deepest heading level allowed
+    pub max_depth: usize,
+    /// Most headings allowed below the H1
+    pub max_sections: usize,
+}
+
+/// A structural problem that suggests a note needs splitting or reshaping.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Issue {
+    /// No level-one heading
+    MissingH1,
+    /// A heading more than one level below the one before it
+    SkippedLevel {
+        heading: String,
+        from: usize,
+        to: usize,
+    },
+    /// A heading deeper than the maximum depth
+    TooDeep { heading: String, level: usize },
+    /// More sections than the maximum
+    TooManySections { count: usize },
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+impl fmt::Display for Issue {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingH1 => f.write_str("no level-one heading"),
+            Self::SkippedLevel { heading, from, to } => {
+                write!(f, "\"{heading}\" skips from H{from} to H{to}")
+            }
+            Self::TooDeep { heading, level } => write!(f, "\"{heading}\" is nested at H{level}"),
+            Self::TooManySections { count } => write!(f, "{count} sections"),
+        }
+    }
+}
+
+/// ATX headings in `body`, skipping fenced code blocks.
+#[must_use]
+pub fn headings(body: &str) -> Vec<Heading> {
+    let mut found = Vec::new();
+    let mut in_fence = false;
+    for line in body.lines() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence {
+            continue;
+        }
+        found.extend(heading(line));
+    }
+    found
+}
+
+/// The ATX heading on `line`, if it is one.
+///
+/// A heading needs a space after its 1 to 6 `#`s, so `#hashtags` are not
+/// headings; closing `#`s are dropped. Fenced code is not tracked here.
+#[must_use]
+pub fn heading(line: &str) -> Option<Heading> {
+    let trimmed = line.trim_start();
+    let level = trimmed.chars().take_while(|&c| c == '#').count();
+    let text = trimmed[level..].strip_prefix(' ')?;
+    let text = text.trim().trim_end_matches('#').trim_end();
+    ((1..=6).contains(&level) && !text.is_empty()).then(|| Heading {
+        level,
+        text: text.to_owned(),
+    })
+}
+
+/// Structural issues in an outline, in the order they are found, with the
+/// section count last.
+#[must_use]
+pub fn check(outline: &[Heading], limits: Limits) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    if !outline.iter().any(|heading| heading.level == 1) {
+        issues.push(Issue::MissingH1);
+    }
+
+    // A note starts at the H1, whether or not it is written out
+    let mut previous = 1;
+    for heading in outline {
+        if heading.level > previous + 1 {
+            issues.push(Issue::SkippedLevel {
+                heading: heading.text.clone(),
+                from: previous,
+                to: heading.level,
+            });
+        }
+        if heading.level > limits.max_depth {
+            issues.push(Issue::TooDeep {
+                heading: heading.text.clone(),
+                level: heading.level,
+            });
+        }
+        previous = heading.level;
+    }
+
+    let sections = outline.iter().filter(|heading| heading.level > 1).count();
+    if sections > limits.max_sections {
+        issues.push(Issue::TooManySections { count: sections });
+    }
+    issues
+}
+
+/// The outline of every markdown note, in walk order.
+///
+/// # Errors
+///
+/// Returns an error if the scan fails or a note cannot be read.
+pub fn outline_notes(scanner: &Scanner) -> Result<Vec<NoteOutline>> {
+    let mut outlines = Vec::new();
+    for note in scanner.notes() {
+        let note = note?;
+        if !scanner.is_note(&note.path) {
+            continue;
+        }
+        outlines.push(NoteOutline {
+            headings: headings(note.body()),
+            path: note.path,
+        });
+    }
+    Ok(outlines)
+}
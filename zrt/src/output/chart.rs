@@ -0,0 +1,162 @@
+use crate::output::number;
+use crate::output::table::{HIGH_PERCENTAGE, LOW_PERCENTAGE};
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows(values: &[(&str, usize)]) -> Vec<(String, usize)> {
+        values
+            .iter()
+            .map(|(label, value)| ((*label).to_owned(), *value))
+            .collect()
+    }
+
+    #[test]
+    fn test_should_scale_bars_to_width() {
+        // REQ-OUT-015
+        // Given labels of 5 characters and values up to 3 digits, 30 columns
+        // leave 20 for the longest bar
+        let chart = bar_chart(&rows(&[("ideas", 100), ("draft", 50), ("x", 0)]), 30);
+
+        assert_eq!(
+            chart,
+            vec![
+                format!("ideas {} 100", "█".repeat(20)),
+                format!("draft {}{} 50", "█".repeat(10), " ".repeat(10)),
+                format!("x     {} 0", " ".repeat(20)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_should_draw_partial_blocks() {
+        // REQ-OUT-015
+        assert_eq!(bar(3, 8, 1), "▍");
+        assert_eq!(bar(1, 32, 1), " ");
+        assert_eq!(bar(6, 8, 2), "█▌");
+        assert_eq!(bar(0, 0, 3), "   ");
+    }
+
+    #[test]
+    fn test_should_fill_gauge_by_percentage() {
+        // REQ-OUT-016
+        assert_eq!(gauge(62.0, 14, false), "█████████░░░░░ 62.00%");
+        assert_eq!(gauge(0.0, 4, false), "░░░░ 0.00%");
+        assert_eq!(gauge(100.0, 4, false), "████ 100.00%");
+        assert_eq!(gauge(130.0, 4, false), "████ 130.00%");
+    }
+
+    #[test]
+    fn test_should_color_gauge_by_threshold() {
+        // REQ-OUT-016
+        assert_eq!(gauge(10.0, 2, true), "\x1b[31m░░\x1b[0m 10.00%");
+        assert_eq!(gauge(50.0, 2, true), "\x1b[33m█░\x1b[0m 50.00%");
+        assert_eq!(gauge(80.0, 2, true), "\x1b[32m██\x1b[0m 80.00%");
+    }
+
+    #[test]
+    fn test_should_truncate_long_labels() {
+        // REQ-OUT-015
+        let chart = bar_chart(&rows(&[(&"t".repeat(50), 1)]), 40);
+
+        assert!(chart[0].starts_with(&format!("{}… ", "t".repeat(12))));
+        assert_eq!(chart[0].chars().count(), 40);
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// Partial blocks for eighths of a bar cell, from empty to full.
+const EIGHTHS: [char; 9] = [' ', '▏', '▎', '▍', '▌', '▋', '▊', '▉', '█'];
+
+/// The narrowest bar drawn, however little room the labels leave.
+const MIN_BAR: usize = 10;
+
+/// Cells in a percentage gauge.
+pub const GAUGE_WIDTH: usize = 20;
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+/// A horizontal bar chart with one `<label> <bar> <value>` line per row,
+/// fitted to `width` columns.
+///
+/// Bars are scaled so the largest value fills the room left by the labels
+/// and values; labels longer than a third of the width are truncated.
+#[must_use]
+pub fn bar_chart(rows: &[(String, usize)], width: usize) -> Vec<String> {
+    let max = rows.iter().map(|(_, value)| *value).max().unwrap_or(0);
+    let values: Vec<String> = rows.iter().map(|(_, value)| number::count(value)).collect();
+    let value_width = values.iter().map(|v| v.chars().count()).max().unwrap_or(0);
+    let label_width = rows
+        .iter()
+        .map(|(label, _)| label.chars().count())
+        .max()
+        .unwrap_or(0)
+        .min(width / 3);
+    let bar_width = width
+        .saturating_sub(label_width + value_width + 2)
+        .max(MIN_BAR);
+
+    rows.iter()
+        .zip(&values)
+        .map(|((label, value), shown)| {
+            format!(
+                "{:<label_width$} {} {shown}",
+                truncate(label, label_width),
+                bar(*value, max, bar_width)
+            )
+        })
+        .collect()
+}
+
+/// A bar of `width` cells filled in proportion to `value / max`, to the
+/// nearest eighth of a cell.
+fn bar(value: usize, max: usize, width: usize) -> String {
+    let eighths = (value * width * 8 + max / 2).checked_div(max).unwrap_or(0);
+    let full = eighths / 8;
+    let mut bar = "█".repeat(full);
+    if full < width {
+        bar.push(EIGHTHS[eighths % 8]);
+        bar.push_str(&" ".repeat(width - full - 1));
+    }
+    bar
+}
+
+/// A `██████░░░░ 62.00%` gauge of `width` cells, colored red, yellow or
+/// green like table percentages when `colored` is set.
+#[must_use]
+pub fn gauge(percentage: f64, width: usize, colored: bool) -> String {
+    let filled =
+        ((percentage.clamp(0.0, 100.0) / 100.0 * width as f64).round() as usize).min(width);
+    let bar = format!("{}{}", "█".repeat(filled), "░".repeat(width - filled));
+    let bar = if colored {
+        let color = if percentage >= HIGH_PERCENTAGE {
+            32
+        } else if percentage >= LOW_PERCENTAGE {
+            33
+        } else {
+            31
+        };
+        format!("\x1b[{color}m{bar}\x1b[0m")
+    } else {
+        bar
+    };
+    format!("{bar} {percentage:.2}%")
+}
+
+fn truncate(label: &str, width: usize) -> String {
+    if label.chars().count() <= width {
+        label.to_owned()
+    } else {
+        let kept: String = label.chars().take(width.saturating_sub(1)).collect();
+        format!("{kept}…")
+    }
+}
@@ -0,0 +1,44 @@
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_should_quote_fields_only_when_needed() {
+        // REQ-OUT-012
+        assert_eq!(record(["a", "b c", ""]), "a,b c,");
+        assert_eq!(
+            record(["x,y", "say \"hi\"", "two\nlines"]),
+            "\"x,y\",\"say \"\"hi\"\"\",\"two\nlines\""
+        );
+    }
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+/// One CSV record (RFC 4180) without the line ending.
+///
+/// Fields holding a comma, quote or line break are quoted, with quotes doubled.
+#[must_use]
+pub fn record<I, S>(fields: I) -> String
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    fields
+        .into_iter()
+        .map(|field| {
+            let field = field.as_ref();
+            if field.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field.to_owned()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
@@ -0,0 +1,174 @@
+use anyhow::{Context as _, Result};
+use clap::Args;
+use std::path::Path;
+use std::process::Command;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser, Debug)]
+    struct TestArgs {
+        #[command(flatten)]
+        exec: ExecArgs,
+    }
+
+    #[test]
+    fn test_should_accept_exec_template() {
+        // REQ-OUT-008
+        assert_eq!(TestArgs::parse_from(["program"]).exec.exec, None);
+        assert_eq!(
+            TestArgs::parse_from(["program", "--exec", "mv {} done/"])
+                .exec
+                .exec
+                .as_deref(),
+            Some("mv {} done/")
+        );
+    }
+
+    #[test]
+    fn test_should_substitute_path_into_template() {
+        // REQ-OUT-008
+        let argv = command_argv("mv {} 'done notes/'", Path::new("a b.md"));
+
+        assert_eq!(argv, vec!["mv", "a b.md", "done notes/"]);
+    }
+
+    #[test]
+    fn test_should_append_path_without_placeholder() {
+        // REQ-OUT-008
+        let argv = command_argv("wc -w", Path::new("note.md"));
+
+        assert_eq!(argv, vec!["wc", "-w", "note.md"]);
+    }
+
+    #[test]
+    fn test_should_keep_quoted_words_together() {
+        // REQ-OUT-008
+        assert_eq!(
+            split_words(r#"grep -l "to do" '{}'"#),
+            vec!["grep", "-l", "to do", "{}"]
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_should_run_once_per_file_and_report_failures() -> Result<()> {
+        // REQ-OUT-009
+        let dir = tempfile::TempDir::new()?;
+        let present = dir.path().join("present.md");
+        std::fs::write(&present, "x")?;
+        let missing = dir.path().join("missing.md");
+        let args = ExecArgs {
+            exec: Some("test -f {}".to_owned()),
+        };
+
+        assert!(args.run(&[&present]).is_ok());
+        let error = args.run(&[&present, &missing]).err().map(|e| e.to_string());
+        assert_eq!(error.as_deref(), Some("1 of 2 commands failed"));
+        Ok(())
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// Flag for running a command on each listed file, shared by the listing commands.
+#[derive(Args, Debug, Default)]
+pub struct ExecArgs {
+    /// Run CMD once per listed file; `{}` is replaced by the path, which is
+    /// appended when CMD has no `{}`
+    #[arg(long, value_name = "CMD")]
+    pub exec: Option<String>,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+impl ExecArgs {
+    /// Run the `--exec` command for every path in order, if it was given.
+    ///
+    /// Every path is processed even when some commands fail.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the command is empty or cannot be started, or
+    /// after the last path if any command exited unsuccessfully.
+    #[inline]
+    pub fn run<P: AsRef<Path>>(&self, paths: &[P]) -> Result<()> {
+        let Some(template) = &self.exec else {
+            return Ok(());
+        };
+
+        let mut failed = 0;
+        for path in paths {
+            let argv = command_argv(template, path.as_ref());
+            let (program, args) = argv.split_first().context("--exec needs a command")?;
+            let status = Command::new(program)
+                .args(args)
+                .status()
+                .with_context(|| format!("Failed to run {program}"))?;
+            if !status.success() {
+                failed += 1;
+            }
+        }
+
+        if failed > 0 {
+            anyhow::bail!("{failed} of {} commands failed", paths.len());
+        }
+        Ok(())
+    }
+}
+
+/// The argument list for running `template` on `path`.
+fn command_argv(template: &str, path: &Path) -> Vec<String> {
+    let path = path.to_string_lossy();
+    let mut argv = split_words(template);
+    if argv.iter().any(|word| word.contains("{}")) {
+        for word in &mut argv {
+            *word = word.replace("{}", &path);
+        }
+    } else {
+        argv.push(path.into_owned());
+    }
+    argv
+}
+
+/// Split a command line on whitespace, keeping single- or double-quoted text together.
+fn split_words(line: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut in_word = false;
+    let mut quote = None;
+
+    for c in line.chars() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => current.push(c),
+            None if c == '\'' || c == '"' => {
+                quote = Some(c);
+                in_word = true;
+            }
+            None if c.is_whitespace() => {
+                if in_word {
+                    words.push(std::mem::take(&mut current));
+                    in_word = false;
+                }
+            }
+            None => {
+                current.push(c);
+                in_word = true;
+            }
+        }
+    }
+    if in_word {
+        words.push(current);
+    }
+    words
+}
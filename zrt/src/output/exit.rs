@@ -0,0 +1,70 @@
+use thiserror::Error;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_should_map_results_to_grep_style_statuses() {
+        // REQ-OUT-019
+        assert_eq!(status(&Ok(())), SUCCESS);
+        assert_eq!(status(&Err(NoMatches.into())), NO_MATCH);
+        assert_eq!(status(&Err(anyhow::anyhow!("Directory not found"))), ERROR);
+    }
+
+    #[test]
+    fn test_should_not_report_no_matches_as_an_error() {
+        // REQ-OUT-019
+        assert!(reported(&Ok(())).is_none());
+        assert!(reported(&Err(NoMatches.into())).is_none());
+        assert!(reported(&Err(anyhow::anyhow!("Directory not found"))).is_some());
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// Exit status when the command succeeded and a query found something.
+pub const SUCCESS: u8 = 0;
+
+/// Exit status when a query ran fine but nothing matched, like `grep`.
+pub const NO_MATCH: u8 = 1;
+
+/// Exit status for every error.
+pub const ERROR: u8 = 2;
+
+/// Returned by query commands that matched no files, after printing their
+/// (empty or zero) result, so the process exits with [`NO_MATCH`].
+#[derive(Debug, Error)]
+#[error("no matching files")]
+pub struct NoMatches;
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+/// The exit status for a command's result.
+#[inline]
+#[must_use]
+pub fn status(result: &anyhow::Result<()>) -> u8 {
+    match result {
+        Ok(()) => SUCCESS,
+        Err(error) if error.is::<NoMatches>() => NO_MATCH,
+        Err(_) => ERROR,
+    }
+}
+
+/// The error to print for a command's result; [`NoMatches`] only sets the
+/// exit status.
+#[inline]
+#[must_use]
+pub fn reported(result: &anyhow::Result<()>) -> Option<&anyhow::Error> {
+    result
+        .as_ref()
+        .err()
+        .filter(|error| !error.is::<NoMatches>())
+}
@@ -0,0 +1,121 @@
+use anyhow::{Context as _, Result};
+use clap::Args;
+use std::io::Read as _;
+use std::path::PathBuf;
+
+use crate::core::scanner::ScanOptions;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser, Debug)]
+    struct TestArgs {
+        #[command(flatten)]
+        files_from: FilesFromArgs,
+    }
+
+    #[test]
+    fn test_should_accept_files_from() {
+        // REQ-OUT-010
+        assert_eq!(
+            TestArgs::parse_from(["program"]).files_from.files_from,
+            None
+        );
+        assert_eq!(
+            TestArgs::parse_from(["program", "--files-from", "-"])
+                .files_from
+                .files_from,
+            Some(PathBuf::from("-"))
+        );
+    }
+
+    #[test]
+    fn test_should_parse_one_path_per_line() {
+        // REQ-OUT-010
+        let paths = parse_list("notes/a.md\r\n\n  \nnotes/b c.md\n");
+
+        assert_eq!(
+            paths,
+            vec![PathBuf::from("notes/a.md"), PathBuf::from("notes/b c.md")]
+        );
+    }
+
+    #[test]
+    fn test_should_leave_options_alone_without_flag() -> Result<()> {
+        // REQ-OUT-010
+        let dir = tempfile::TempDir::new()?;
+        std::fs::write(dir.path().join("a.md"), "one")?;
+        std::fs::write(dir.path().join("b.md"), "two")?;
+        let list = dir.path().join("list.txt");
+        std::fs::write(&list, format!("{}\n", dir.path().join("b.md").display()))?;
+        let options = ScanOptions::new([dir.path()]);
+
+        let walked = FilesFromArgs::default().apply(options.clone())?;
+        let listed = FilesFromArgs {
+            files_from: Some(list),
+        }
+        .apply(options)?;
+
+        assert_eq!(walked.count_files(&[])?, 3);
+        assert_eq!(listed.files()?, vec![dir.path().join("b.md")]);
+        Ok(())
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// Flag for scanning a list of files instead of walking directories, shared by
+/// the commands built on the scanner.
+#[derive(Args, Debug, Default)]
+pub struct FilesFromArgs {
+    /// Scan the files listed in FILE, one path per line, instead of walking
+    /// directories; `-` reads the list from stdin
+    #[arg(long, value_name = "FILE")]
+    pub files_from: Option<PathBuf>,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+impl FilesFromArgs {
+    /// Restrict `options` to the listed files, if `--files-from` was given.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the list cannot be read.
+    #[inline]
+    pub fn apply(&self, options: ScanOptions) -> Result<ScanOptions> {
+        let Some(source) = &self.files_from else {
+            return Ok(options);
+        };
+
+        let text = if source.as_os_str() == "-" {
+            let mut text = String::new();
+            std::io::stdin()
+                .read_to_string(&mut text)
+                .context("Failed to read file list from stdin")?;
+            text
+        } else {
+            std::fs::read_to_string(source)
+                .with_context(|| format!("Failed to read file list {}", source.display()))?
+        };
+        Ok(options.paths(parse_list(&text)))
+    }
+}
+
+/// The non-blank lines of a file list, trimmed of surrounding whitespace.
+fn parse_list(text: &str) -> Vec<PathBuf> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(PathBuf::from)
+        .collect()
+}
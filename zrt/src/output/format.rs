@@ -0,0 +1,103 @@
+use anyhow::{Context as _, Result};
+use serde_json::Value;
+use std::str::FromStr;
+
+use crate::schema::{SCHEMA_VERSION, project, versioned};
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_should_parse_pinned_and_latest_versions() {
+        // REQ-OUT-013
+        assert_eq!(
+            "json".parse(),
+            Ok(JsonFormat {
+                version: SCHEMA_VERSION
+            })
+        );
+        assert_eq!("json@1".parse(), Ok(JsonFormat { version: 1 }));
+        assert!("json@0".parse::<JsonFormat>().is_err());
+        assert!(
+            format!("json@{}", SCHEMA_VERSION + 1)
+                .parse::<JsonFormat>()
+                .is_err()
+        );
+        assert!("json@x".parse::<JsonFormat>().is_err());
+        assert!("yaml".parse::<JsonFormat>().is_err());
+    }
+
+    #[test]
+    fn test_should_render_in_pinned_shape() -> Result<()> {
+        // REQ-OUT-013
+        // Given stats carrying a field the version 1 schema doesn't declare
+        let value = json!({"files": 2, "words": 10, "chars": 60});
+
+        // When rendering it as json@1
+        let rendered = JsonFormat { version: 1 }.render("stats", &value)?;
+
+        // Then the extra field is dropped
+        let parsed: Value = serde_json::from_str(&rendered)?;
+        assert_eq!(parsed, json!({"files": 2, "words": 10}));
+        Ok(())
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// JSON output pinned to a schema version, written `json` (the current
+/// version) or `json@N`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JsonFormat {
+    pub version: u32,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+impl FromStr for JsonFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let version = match s.split_once('@') {
+            None if s == "json" => SCHEMA_VERSION,
+            Some(("json", version)) => version
+                .parse()
+                .map_err(|_| format!("invalid format version '{version}'"))?,
+            _ => return Err(format!("unknown format '{s}'")),
+        };
+        if (1..=SCHEMA_VERSION).contains(&version) {
+            Ok(Self { version })
+        } else {
+            Err(format!(
+                "unsupported format version {version} (supported: 1 to {SCHEMA_VERSION})"
+            ))
+        }
+    }
+}
+
+impl JsonFormat {
+    /// Pretty-print `value`, the current shape of the output called `name`,
+    /// in the shape of the pinned version.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `name` has no schema at this version or
+    /// serialization fails.
+    pub fn render(self, name: &str, value: &Value) -> Result<String> {
+        let schema = versioned(name, self.version)
+            .with_context(|| format!("No {name} schema at version {}", self.version))?;
+        Ok(serde_json::to_string_pretty(&project(
+            value,
+            &schema.schema,
+        ))?)
+    }
+}
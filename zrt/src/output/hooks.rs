@@ -0,0 +1,138 @@
+use anyhow::{Context as _, Result, bail};
+use serde_json::Value;
+use std::io::Write as _;
+use std::process::{Command, Stdio};
+
+use crate::init::HooksConfig;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tempfile::TempDir;
+
+    fn hooks(pre_snapshot: &[&str], post_snapshot: &[&str]) -> HooksConfig {
+        HooksConfig {
+            pre_snapshot: pre_snapshot.iter().map(|c| (*c).to_owned()).collect(),
+            post_snapshot: post_snapshot.iter().map(|c| (*c).to_owned()).collect(),
+        }
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_should_run_pre_snapshot_commands_in_order() -> Result<()> {
+        // REQ-OUT-018
+        let dir = TempDir::new()?;
+        let log = dir.path().join("log");
+        let first = format!("echo first >> {}", log.display());
+        let second = format!("echo second >> {}", log.display());
+
+        pre_snapshot(&hooks(&[&first, &second], &[]))?;
+
+        assert_eq!(std::fs::read_to_string(&log)?, "first\nsecond\n");
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_should_stop_at_failing_hook() -> Result<()> {
+        // REQ-OUT-018
+        let dir = TempDir::new()?;
+        let log = dir.path().join("log");
+        let after = format!("echo after >> {}", log.display());
+
+        let error = pre_snapshot(&hooks(&["exit 3", &after], &[]))
+            .err()
+            .map(|e| e.to_string());
+
+        assert_eq!(
+            error.as_deref(),
+            Some("pre_snapshot hook `exit 3` failed with exit status: 3")
+        );
+        assert!(!log.exists());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_should_pipe_report_to_post_snapshot_commands() -> Result<()> {
+        // REQ-OUT-018
+        let dir = TempDir::new()?;
+        let report = dir.path().join("report.json");
+        let command = format!("cat > {}", report.display());
+
+        post_snapshot(&hooks(&[], &[&command]), &json!({"files": 3}))?;
+
+        assert_eq!(std::fs::read_to_string(&report)?, "{\"files\":3}\n");
+        Ok(())
+    }
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+/// Run the `hooks.pre_snapshot` commands in order before `zrt snapshot`
+/// scans, e.g. to `git pull` the vault.
+///
+/// # Errors
+///
+/// Returns an error, without running later hooks, if a command cannot be
+/// started or exits unsuccessfully.
+pub fn pre_snapshot(hooks: &HooksConfig) -> Result<()> {
+    for command in &hooks.pre_snapshot {
+        run_hook("pre_snapshot", command, None)?;
+    }
+    Ok(())
+}
+
+/// Run the `hooks.post_snapshot` commands in order after `zrt snapshot`
+/// records, each receiving `report` as one line of JSON on stdin.
+///
+/// # Errors
+///
+/// Returns an error, without running later hooks, if a command cannot be
+/// started or exits unsuccessfully.
+pub fn post_snapshot(hooks: &HooksConfig, report: &Value) -> Result<()> {
+    let input = format!("{report}\n");
+    for command in &hooks.post_snapshot {
+        run_hook("post_snapshot", command, Some(input.as_bytes()))?;
+    }
+    Ok(())
+}
+
+/// Run `command` with the platform shell, feeding it `input` if given.
+fn run_hook(stage: &str, command: &str, input: Option<&[u8]>) -> Result<()> {
+    let mut shell = if cfg!(windows) {
+        let mut shell = Command::new("cmd");
+        shell.arg("/C");
+        shell
+    } else {
+        let mut shell = Command::new("sh");
+        shell.arg("-c");
+        shell
+    };
+    let mut child = shell
+        .arg(command)
+        .stdin(if input.is_some() {
+            Stdio::piped()
+        } else {
+            Stdio::null()
+        })
+        .spawn()
+        .with_context(|| format!("Failed to start {stage} hook `{command}`"))?;
+    if let (Some(input), Some(mut stdin)) = (input, child.stdin.take()) {
+        // A hook may exit without reading its input; its status decides.
+        let _ = stdin.write_all(input);
+    }
+    let status = child
+        .wait()
+        .with_context(|| format!("Failed to wait for {stage} hook `{command}`"))?;
+    if !status.success() {
+        bail!("{stage} hook `{command}` failed with {status}");
+    }
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use std::io::IsTerminal as _;
+use tracing_subscriber::EnvFilter;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_should_raise_level_with_verbosity() {
+        // REQ-OUT-005
+        assert_eq!(default_directive(0), "warn");
+        assert_eq!(default_directive(1), "warn,zrt=debug");
+        assert_eq!(default_directive(2), "warn,zrt=trace");
+        assert_eq!(default_directive(5), "warn,zrt=trace");
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// Environment variable holding a `tracing` filter that overrides `-v`.
+pub const LOG_ENV: &str = "ZRT_LOG";
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+/// Send diagnostics to stderr: warnings by default, debug with `-v`, trace
+/// with `-vv`, or whatever filter `ZRT_LOG` holds when it is set.
+#[inline]
+pub fn init(verbosity: u8) {
+    let filter = EnvFilter::try_from_env(LOG_ENV)
+        .unwrap_or_else(|_| EnvFilter::new(default_directive(verbosity)));
+    // A subscriber may already be installed, e.g. by an embedding program.
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(std::io::stderr)
+        .with_ansi(std::io::stderr().is_terminal())
+        .without_time()
+        .try_init();
+}
+
+const fn default_directive(verbosity: u8) -> &'static str {
+    match verbosity {
+        0 => "warn",
+        1 => "warn,zrt=debug",
+        _ => "warn,zrt=trace",
+    }
+}
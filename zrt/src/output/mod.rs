@@ -0,0 +1,17 @@
+pub mod chart;
+pub mod csv;
+pub mod exec;
+pub mod exit;
+pub mod files_from;
+pub mod format;
+pub mod hooks;
+pub mod logging;
+pub mod moves;
+pub mod number;
+pub mod open;
+pub mod progress;
+pub mod scan;
+pub mod snippet;
+pub mod table;
+pub mod titles;
+pub mod webhook;
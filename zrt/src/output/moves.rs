@@ -0,0 +1,68 @@
+use crate::core::links::Relocated;
+use crate::output::number;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_should_list_moves_then_count_relinked_notes() {
+        // REQ-OUT-021
+        let relocated = [
+            Relocated {
+                from: PathBuf::from("a.md"),
+                to: PathBuf::from("archive/a.md"),
+                content: None,
+            },
+            Relocated {
+                from: PathBuf::from("b.md"),
+                to: PathBuf::from("b.md"),
+                content: Some("[a](archive/a.md)".to_owned()),
+            },
+        ];
+
+        assert_eq!(
+            report(&relocated, true),
+            vec![
+                "a.md -> archive/a.md",
+                "Would move 1 note(s) and update links in 1 other note(s)",
+            ]
+        );
+        assert_eq!(
+            report(&relocated, false)[1],
+            "Moved 1 note(s) and updated links in 1 other note(s)"
+        );
+        assert_eq!(report(&[], false), vec!["No notes to move"]);
+    }
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+/// Every move as `from -> to`, then how many notes moved and how many others
+/// had links fixed, for commands that move notes.
+#[must_use]
+pub fn report(relocated: &[Relocated], dry_run: bool) -> Vec<String> {
+    let mut lines: Vec<String> = relocated
+        .iter()
+        .filter(|note| note.moves())
+        .map(|note| format!("{} -> {}", note.from.display(), note.to.display()))
+        .collect();
+    let moved = lines.len();
+    if moved == 0 {
+        return vec!["No notes to move".to_owned()];
+    }
+    let relinked = number::count(relocated.len() - moved);
+    let moved = number::count(moved);
+    lines.push(if dry_run {
+        format!("Would move {moved} note(s) and update links in {relinked} other note(s)")
+    } else {
+        format!("Moved {moved} note(s) and updated links in {relinked} other note(s)")
+    });
+    lines
+}
@@ -0,0 +1,118 @@
+use std::fmt::Display;
+use std::sync::OnceLock;
+
+use crate::output::table;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_should_group_digits_in_threes() {
+        // REQ-OUT-014
+        assert_eq!(group(1_204_931, ','), "1,204,931");
+        assert_eq!(group(999, ','), "999");
+        assert_eq!(group(1000, '.'), "1.000");
+        assert_eq!(group(0, ','), "0");
+        assert_eq!(group(-12_345, ','), "-12,345");
+        assert_eq!(group("+4096", ','), "+4,096");
+        assert_eq!(group("12345.67", ','), "12,345.67");
+    }
+
+    #[test]
+    fn test_should_pick_separator_from_locale() {
+        // REQ-OUT-014
+        assert_eq!(separator_for("en_US.UTF-8"), ',');
+        assert_eq!(separator_for("de_DE.UTF-8"), '.');
+        assert_eq!(separator_for("fr_FR"), '\u{202f}');
+        assert_eq!(separator_for("de_CH.UTF-8"), '\'');
+        assert_eq!(separator_for("C"), ',');
+        assert_eq!(separator_for(""), ',');
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// Languages that group thousands with a period.
+const PERIOD: [&str; 11] = [
+    "da", "de", "el", "es", "id", "it", "nl", "pt", "ro", "sl", "tr",
+];
+
+/// Languages that group thousands with a narrow no-break space.
+const SPACE: [&str; 12] = [
+    "cs", "fi", "fr", "hu", "lt", "lv", "nb", "no", "pl", "ru", "sk", "uk",
+];
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+/// A count for terminal output: digits grouped in threes with the locale's
+/// separator when stdout is a terminal, and left raw when it is piped.
+#[must_use]
+pub fn count(value: impl Display) -> String {
+    if table::is_tty() {
+        group(value, separator())
+    } else {
+        value.to_string()
+    }
+}
+
+/// `value` with the digits before any decimal point grouped in threes.
+#[must_use]
+pub fn group(value: impl Display, separator: char) -> String {
+    let text = value.to_string();
+    let digits_start = text.find(|c: char| c.is_ascii_digit()).unwrap_or(0);
+    let digits_end = text[digits_start..]
+        .find(|c: char| !c.is_ascii_digit())
+        .map_or(text.len(), |end| digits_start + end);
+    let digits = &text[digits_start..digits_end];
+
+    let mut grouped = String::with_capacity(text.len() + digits.len() / 3);
+    grouped.push_str(&text[..digits_start]);
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push(separator);
+        }
+        grouped.push(digit);
+    }
+    grouped.push_str(&text[digits_end..]);
+    grouped
+}
+
+/// The thousands separator of the user's locale, from `LC_ALL`,
+/// `LC_NUMERIC` or `LANG`.
+#[must_use]
+pub fn separator() -> char {
+    static SEPARATOR: OnceLock<char> = OnceLock::new();
+    *SEPARATOR.get_or_init(|| {
+        let locale = ["LC_ALL", "LC_NUMERIC", "LANG"]
+            .into_iter()
+            .filter_map(|name| std::env::var(name).ok())
+            .find(|value| !value.is_empty())
+            .unwrap_or_default();
+        separator_for(&locale)
+    })
+}
+
+/// The thousands separator for a locale name like `de_DE.UTF-8`, defaulting
+/// to a comma.
+#[must_use]
+pub fn separator_for(locale: &str) -> char {
+    let name = locale.split(['.', '@']).next().unwrap_or_default();
+    let (language, region) = name.split_once(['_', '-']).unwrap_or((name, ""));
+    if region == "CH" && matches!(language, "de" | "it" | "fr") {
+        '\''
+    } else if PERIOD.contains(&language) {
+        '.'
+    } else if SPACE.contains(&language) {
+        '\u{202f}'
+    } else {
+        ','
+    }
+}
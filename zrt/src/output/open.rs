@@ -0,0 +1,204 @@
+use anyhow::{Context as _, Result};
+use clap::Args;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser, Debug)]
+    struct TestArgs {
+        #[command(flatten)]
+        open: OpenArgs,
+    }
+
+    #[test]
+    fn test_should_open_one_file_by_default() {
+        // REQ-OUT-006
+        assert_eq!(TestArgs::parse_from(["program"]).open.open, None);
+        assert_eq!(
+            TestArgs::parse_from(["program", "--open"]).open.open,
+            Some(1)
+        );
+        assert_eq!(
+            TestArgs::parse_from(["program", "--open=3"]).open.open,
+            Some(3)
+        );
+    }
+
+    #[test]
+    fn test_should_not_take_the_next_argument_as_count() {
+        // REQ-OUT-006
+        #[derive(Parser, Debug)]
+        struct WithTags {
+            #[command(flatten)]
+            open: OpenArgs,
+            tags: Vec<String>,
+        }
+
+        let args = WithTags::parse_from(["program", "--open", "to_refactor"]);
+
+        assert_eq!(args.open.open, Some(1));
+        assert_eq!(args.tags, vec!["to_refactor"]);
+    }
+
+    #[test]
+    fn test_should_require_open_for_obsidian() {
+        // REQ-OUT-006
+        assert!(TestArgs::try_parse_from(["program", "--obsidian"]).is_err());
+        assert!(
+            TestArgs::parse_from(["program", "--open", "--obsidian"])
+                .open
+                .obsidian
+        );
+    }
+
+    #[test]
+    fn test_should_build_obsidian_uri() {
+        // REQ-OUT-007
+        let uri = obsidian_uri(Path::new("/vault/My Note (draft).md"));
+
+        assert_eq!(
+            uri,
+            "obsidian://open?path=%2Fvault%2FMy%20Note%20%28draft%29.md"
+        );
+    }
+
+    #[test]
+    fn test_should_split_editor_command() {
+        // REQ-OUT-007
+        assert_eq!(
+            editor_argv("code --wait"),
+            Some(("code".to_owned(), vec!["--wait".to_owned()]))
+        );
+        assert_eq!(editor_argv("  "), None);
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// Flags for opening listed files, shared by the listing commands.
+#[derive(Args, Debug, Default)]
+pub struct OpenArgs {
+    /// Open the first N listed files (default: 1) in $VISUAL or $EDITOR;
+    /// give a count as --open=N
+    #[arg(
+        long,
+        value_name = "N",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "1"
+    )]
+    pub open: Option<usize>,
+
+    /// With --open, open the files in Obsidian instead of an editor
+    #[arg(long, requires = "open")]
+    pub obsidian: bool,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+impl OpenArgs {
+    /// Open the first files of `paths` if `--open` was given.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no editor is configured or it cannot be started.
+    #[inline]
+    pub fn open<P: AsRef<Path>>(&self, paths: &[P]) -> Result<()> {
+        let Some(count) = self.open else {
+            return Ok(());
+        };
+        let paths: Vec<PathBuf> = paths
+            .iter()
+            .take(count)
+            .map(|p| p.as_ref().to_path_buf())
+            .collect();
+        if paths.is_empty() {
+            return Ok(());
+        }
+
+        if self.obsidian {
+            paths
+                .iter()
+                .try_for_each(|path| launch_uri(&obsidian_uri(path)))
+        } else {
+            open_in_editor(&paths)
+        }
+    }
+}
+
+fn open_in_editor(paths: &[PathBuf]) -> Result<()> {
+    let (program, args) = ["VISUAL", "EDITOR"]
+        .into_iter()
+        .find_map(|var| editor_argv(&std::env::var(var).ok()?))
+        .context("Set $VISUAL or $EDITOR to use --open, or add --obsidian")?;
+
+    let status = Command::new(&program)
+        .args(args)
+        .args(paths)
+        .status()
+        .with_context(|| format!("Failed to start editor {program}"))?;
+    if !status.success() {
+        anyhow::bail!("Editor {program} exited with {status}");
+    }
+    Ok(())
+}
+
+/// Split an editor setting such as `code --wait` into program and arguments.
+fn editor_argv(editor: &str) -> Option<(String, Vec<String>)> {
+    let mut parts = editor.split_whitespace().map(str::to_owned);
+    let program = parts.next()?;
+    Some((program, parts.collect()))
+}
+
+/// An `obsidian://` URI opening the note at `path`.
+#[must_use]
+pub fn obsidian_uri(path: &Path) -> String {
+    let absolute = std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf());
+    format!(
+        "obsidian://open?path={}",
+        percent_encode(&absolute.to_string_lossy())
+    )
+}
+
+fn percent_encode(text: &str) -> String {
+    let mut encoded = String::with_capacity(text.len());
+    for byte in text.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.' | b'~') {
+            encoded.push(char::from(byte));
+        } else {
+            encoded.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    encoded
+}
+
+fn launch_uri(uri: &str) -> Result<()> {
+    let mut command = if cfg!(target_os = "macos") {
+        Command::new("open")
+    } else if cfg!(windows) {
+        let mut command = Command::new("cmd");
+        command.args(["/C", "start", ""]);
+        command
+    } else {
+        Command::new("xdg-open")
+    };
+    let status = command
+        .arg(uri)
+        .status()
+        .with_context(|| format!("Failed to open {uri}"))?;
+    if !status.success() {
+        anyhow::bail!("Opening {uri} failed with {status}");
+    }
+    Ok(())
+}
@@ -0,0 +1,114 @@
+use indicatif::{ProgressBar, ProgressDrawTarget, ProgressStyle};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
+
+use crate::core::scanner::ScanOptions;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_should_stay_hidden_for_quick_scans() {
+        // REQ-OUT-001
+        let reporter = ScanProgress::with_delay(Duration::from_secs(3600));
+
+        reporter.update(1, Path::new("note.md"));
+
+        assert!(reporter.bar.get().is_none());
+    }
+
+    #[test]
+    fn test_should_show_once_the_delay_has_passed() {
+        // REQ-OUT-001
+        let reporter = ScanProgress::with_delay(Duration::ZERO);
+
+        reporter.update(2, Path::new("note.md"));
+
+        assert_eq!(reporter.bar.get().map(ProgressBar::position), Some(2));
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// Whether progress bars may be drawn; cleared by `--no-progress`.
+static ENABLED: AtomicBool = AtomicBool::new(true);
+
+/// How long a scan runs before its progress bar appears.
+const SHOW_AFTER: Duration = Duration::from_millis(300);
+
+/// A progress bar on stderr for a directory scan, only drawn once the scan
+/// has taken longer than a short delay.
+#[derive(Debug)]
+pub struct ScanProgress {
+    started: Instant,
+    delay: Duration,
+    bar: OnceLock<ProgressBar>,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+/// Enable or disable progress bars for the rest of the process.
+#[inline]
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Attach a progress bar to `options` unless progress bars are disabled.
+/// The bar is cleared when the scanner built from `options` is dropped.
+#[inline]
+#[must_use]
+pub fn attach(options: ScanOptions) -> ScanOptions {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return options;
+    }
+    let progress = Arc::new(ScanProgress::with_delay(SHOW_AFTER));
+    options.on_progress(move |files, path| progress.update(files, path))
+}
+
+impl ScanProgress {
+    fn with_delay(delay: Duration) -> Self {
+        Self {
+            started: Instant::now(),
+            delay,
+            bar: OnceLock::new(),
+        }
+    }
+
+    fn update(&self, files: usize, path: &Path) {
+        let bar = match self.bar.get() {
+            Some(bar) => bar,
+            None if self.started.elapsed() >= self.delay => self.bar.get_or_init(new_bar),
+            None => return,
+        };
+        bar.set_position(files as u64);
+        bar.set_message(path.display().to_string());
+    }
+}
+
+impl Drop for ScanProgress {
+    #[inline]
+    fn drop(&mut self) {
+        if let Some(bar) = self.bar.get() {
+            bar.finish_and_clear();
+        }
+    }
+}
+
+fn new_bar() -> ProgressBar {
+    let bar = ProgressBar::with_draw_target(None, ProgressDrawTarget::stderr());
+    if let Ok(style) = ProgressStyle::with_template("{spinner} {pos} files scanned {wide_msg}") {
+        bar.set_style(style);
+    }
+    bar.enable_steady_tick(Duration::from_millis(100));
+    bar
+}
@@ -0,0 +1,147 @@
+use std::path::Path;
+use std::sync::{Arc, LazyLock, Mutex, RwLock};
+
+use crate::core::error::Result;
+use crate::core::frontmatter::MetadataFormat;
+use crate::core::scanner::{ScanOptions, ScanReport, Scanner};
+use crate::core::timings::{TimingReport, Timings};
+use crate::output::progress;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::vfs::MemoryFs;
+
+    #[test]
+    fn test_should_apply_global_flags_to_scans() -> anyhow::Result<()> {
+        // REQ-OUT-022
+        let settings = ScanSettings {
+            extensions: vec!["txt".to_owned()],
+            ..ScanSettings::default()
+        };
+        let vfs = MemoryFs::new()
+            .with_file("/vault/a.md", "one")
+            .with_file("/vault/b.txt", "two");
+
+        let options = settings.apply(ScanOptions::new(["/vault"]).vfs(vfs));
+
+        assert_eq!(
+            options.files()?,
+            vec![std::path::PathBuf::from("/vault/b.txt")]
+        );
+        Ok(())
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// How the global flags configure every scan a command runs.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScanSettings {
+    /// Extensions given with `--ext`; empty scans every file
+    pub extensions: Vec<String>,
+    /// Fail on the first unreadable entry, set with `--strict`
+    pub strict: bool,
+    /// Read invalid UTF-8 with replacement characters, set with `--lossy`
+    pub lossy: bool,
+    /// Header formats from `[frontmatter] formats`
+    pub formats: Vec<MetadataFormat>,
+    /// Time the scan stages for [`take_timings`], set with `--timings`
+    pub timings: bool,
+}
+
+/// The settings of the current run, replaced by each `cli::run`.
+static SETTINGS: RwLock<ScanSettings> = RwLock::new(ScanSettings {
+    extensions: Vec::new(),
+    strict: false,
+    lossy: false,
+    formats: Vec::new(),
+    timings: false,
+});
+
+/// What the scans of the current run skipped, summarized by `cli::run`.
+static REPORT: LazyLock<Arc<Mutex<ScanReport>>> = LazyLock::new(Arc::default);
+
+/// Where the scans of the current run are timed when `--timings` is given.
+static TIMINGS: LazyLock<Arc<Timings>> = LazyLock::new(Arc::default);
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+impl ScanSettings {
+    /// Configure `options` with these settings.
+    #[inline]
+    #[must_use]
+    pub fn apply(&self, options: ScanOptions) -> ScanOptions {
+        let options = options
+            .strict(self.strict)
+            .lossy(self.lossy)
+            .formats(&self.formats);
+        if self.extensions.is_empty() {
+            options
+        } else {
+            options.extensions(&self.extensions)
+        }
+    }
+}
+
+/// Use `settings` for the scans of the rest of the run.
+#[inline]
+pub fn configure(settings: ScanSettings) {
+    if let Ok(mut current) = SETTINGS.write() {
+        *current = settings;
+    }
+}
+
+/// Configure `options` from the global flags, record what it skips for
+/// [`take_report`], time it for [`take_timings`] with `--timings` and attach
+/// a progress bar, see [`progress::attach`].
+#[inline]
+#[must_use]
+pub fn attach(options: ScanOptions) -> ScanOptions {
+    let (options, timed) = match SETTINGS.read() {
+        Ok(settings) => (settings.apply(options), settings.timings),
+        Err(_) => (options, false),
+    };
+    let options = options.report_to(Arc::clone(&REPORT));
+    progress::attach(if timed {
+        options.timings_to(Arc::clone(&TIMINGS))
+    } else {
+        options
+    })
+}
+
+/// What the scans skipped since the last call.
+#[inline]
+#[must_use]
+pub fn take_report() -> ScanReport {
+    REPORT
+        .lock()
+        .map(|mut report| std::mem::take(&mut *report))
+        .unwrap_or_default()
+}
+
+/// Where the scans spent their time since the last call.
+#[inline]
+#[must_use]
+pub fn take_timings() -> TimingReport {
+    TIMINGS.take()
+}
+
+/// A scanner over `dirs` without the `exclude` directories, configured from
+/// the global flags, for commands that only take directories.
+///
+/// # Errors
+///
+/// Returns an error if a directory does not exist or its ignore patterns
+/// cannot be loaded.
+#[inline]
+pub fn scanner<P: AsRef<Path>>(dirs: &[P], exclude: &[&str]) -> Result<Scanner> {
+    attach(ScanOptions::new(dirs).exclude(exclude)).build()
+}
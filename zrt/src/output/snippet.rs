@@ -0,0 +1,125 @@
+use crate::core::frontmatter::strip_frontmatter;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_should_excerpt_the_body_around_the_first_match() {
+        // REQ-OUT-020
+        let content = "---\ntags: [refactor]\n---\nIntro words here.\n\n\
+                       Much later the Refactor plan starts and goes on for a while.";
+
+        assert_eq!(snippet(content, &["refactor"], 24), "…the Refactor plan…");
+        assert_eq!(snippet(content, &["missing"], 14), "Intro words…");
+        assert_eq!(
+            snippet("---\ntags: []\n---\nShort  note", &[], 80),
+            "Short note"
+        );
+    }
+
+    #[test]
+    fn test_should_highlight_terms_only_when_colored() {
+        // REQ-OUT-020
+        assert_eq!(
+            highlight("a Refactor plan", &["refactor"], true),
+            "a \x1b[1;33mRefactor\x1b[0m plan"
+        );
+        assert_eq!(
+            highlight("a Refactor plan", &["refactor"], false),
+            "a Refactor plan"
+        );
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// Characters of note text shown in a snippet.
+pub const SNIPPET_WIDTH: usize = 120;
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+/// About `width` characters of a note's body on one line, centered on the
+/// first occurrence of any of `terms` (ignoring case), or from the start
+/// when none occurs. Cut ends are marked with `…`.
+#[must_use]
+pub fn snippet(content: &str, terms: &[&str], width: usize) -> String {
+    let text: Vec<char> = strip_frontmatter(content)
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .chars()
+        .collect();
+    if text.len() <= width {
+        return text.into_iter().collect();
+    }
+    let start = find_term(&text, terms, 0)
+        .map_or(0, |(at, _)| at.saturating_sub(width / 3))
+        .min(text.len() - width);
+    let end = start + width;
+    let window: String = text[start..end].iter().collect();
+    let mut excerpt = window.as_str();
+    // Drop words cut in half at either end.
+    if start > 0 && text[start - 1] != ' ' {
+        excerpt = excerpt.split_once(' ').map_or(excerpt, |(_, rest)| rest);
+    }
+    if end < text.len() && text[end] != ' ' {
+        excerpt = excerpt.rsplit_once(' ').map_or(excerpt, |(kept, _)| kept);
+    }
+    let mut excerpt = excerpt.trim().to_owned();
+    if start > 0 {
+        excerpt.insert(0, '…');
+    }
+    if end < text.len() {
+        excerpt.push('…');
+    }
+    excerpt
+}
+
+/// `text` with every occurrence of `terms` (ignoring case) in bold yellow
+/// when `colored` is set, for terminals.
+#[must_use]
+pub fn highlight(text: &str, terms: &[&str], colored: bool) -> String {
+    if !colored {
+        return text.to_owned();
+    }
+    let chars: Vec<char> = text.chars().collect();
+    let mut highlighted = String::with_capacity(text.len());
+    let mut from = 0;
+    while let Some((at, len)) = find_term(&chars, terms, from) {
+        highlighted.extend(&chars[from..at]);
+        highlighted.push_str("\x1b[1;33m");
+        highlighted.extend(&chars[at..at + len]);
+        highlighted.push_str("\x1b[0m");
+        from = at + len;
+    }
+    highlighted.extend(&chars[from..]);
+    highlighted
+}
+
+/// Position and length of the first occurrence at or after `from` of any
+/// non-empty term, ignoring case.
+fn find_term(text: &[char], terms: &[&str], from: usize) -> Option<(usize, usize)> {
+    let fold = |c: &char| c.to_lowercase().next().unwrap_or(*c);
+    let terms: Vec<Vec<char>> = terms
+        .iter()
+        .map(|term| term.chars().map(|c| fold(&c)).collect::<Vec<_>>())
+        .filter(|term| !term.is_empty())
+        .collect();
+    (from..text.len()).find_map(|at| {
+        terms
+            .iter()
+            .find(|term| {
+                text.get(at..at + term.len())
+                    .is_some_and(|window| window.iter().map(fold).eq(term.iter().copied()))
+            })
+            .map(|term| (at, term.len()))
+    })
+}
@@ -0,0 +1,136 @@
+use comfy_table::presets::UTF8_FULL_CONDENSED;
+use comfy_table::{Cell, CellAlignment, Color, Table};
+use std::io::IsTerminal as _;
+
+use crate::output::number;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_should_color_percentages_by_threshold() {
+        // REQ-OUT-003
+        assert_eq!(percentage_color(0.0), Color::Red);
+        assert_eq!(percentage_color(LOW_PERCENTAGE), Color::Yellow);
+        assert_eq!(percentage_color(HIGH_PERCENTAGE - 0.01), Color::Yellow);
+        assert_eq!(percentage_color(HIGH_PERCENTAGE), Color::Green);
+    }
+
+    #[test]
+    fn test_should_right_align_number_columns() {
+        // REQ-OUT-003
+        let mut table = table(["name", "files"]);
+        table.add_row(vec![Cell::new("a"), number(7)]);
+        table.add_row(vec![Cell::new("b"), number(123)]);
+
+        let rendered = table.to_string();
+
+        assert!(rendered.contains("┆     7 │"));
+        assert!(rendered.contains("┆   123 │"));
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// Percentages below this are shown in red.
+pub const LOW_PERCENTAGE: f64 = 25.0;
+
+/// Percentages at or above this are shown in green.
+pub const HIGH_PERCENTAGE: f64 = 75.0;
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+/// Whether stdout is a terminal; commands fall back to plain, pipeable
+/// lines when it is not.
+#[inline]
+#[must_use]
+pub fn is_tty() -> bool {
+    std::io::stdout().is_terminal()
+}
+
+/// Width of the terminal stdout is drawn to, falling back to `$COLUMNS` and
+/// then 80 columns when it is not a terminal or reports no size.
+#[must_use]
+pub fn width() -> usize {
+    Table::new()
+        .width()
+        .map(usize::from)
+        .filter(|&width| width > 0)
+        .or_else(|| std::env::var("COLUMNS").ok()?.parse().ok())
+        .unwrap_or(80)
+}
+
+/// A table with the given header; every column after the first is right-aligned.
+#[inline]
+#[must_use]
+pub fn table<const N: usize>(header: [&str; N]) -> Table {
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL_CONDENSED).set_header(header);
+    for index in 1..N {
+        if let Some(column) = table.column_mut(index) {
+            column.set_cell_alignment(CellAlignment::Right);
+        }
+    }
+    table
+}
+
+/// A count with its digits grouped in the locale's style.
+#[inline]
+#[must_use]
+pub fn number(value: impl std::fmt::Display) -> Cell {
+    Cell::new(number::group(value, number::separator()))
+}
+
+/// A percentage colored by how far along it is.
+#[inline]
+#[must_use]
+pub fn percentage(value: f64) -> Cell {
+    Cell::new(format!("{value:.2}%")).fg(percentage_color(value))
+}
+
+/// A count of done notes, in green.
+#[inline]
+#[must_use]
+pub fn done(value: usize) -> Cell {
+    number(value).fg(Color::Green)
+}
+
+/// A count of notes still to refactor, in red.
+#[inline]
+#[must_use]
+pub fn todo(value: usize) -> Cell {
+    number(value).fg(Color::Red)
+}
+
+/// A signed change, green when it is an improvement and red when it is not.
+#[inline]
+#[must_use]
+pub fn change(delta: f64, text: String, higher_is_better: bool) -> Cell {
+    let cell = Cell::new(text);
+    if delta == 0.0 {
+        cell
+    } else if (delta > 0.0) == higher_is_better {
+        cell.fg(Color::Green)
+    } else {
+        cell.fg(Color::Red)
+    }
+}
+
+#[must_use]
+fn percentage_color(value: f64) -> Color {
+    if value >= HIGH_PERCENTAGE {
+        Color::Green
+    } else if value >= LOW_PERCENTAGE {
+        Color::Yellow
+    } else {
+        Color::Red
+    }
+}
@@ -0,0 +1,69 @@
+use clap::Args;
+use std::path::Path;
+
+use crate::core::frontmatter::note_title;
+use crate::core::vfs::read_text;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+    use clap::Parser;
+
+    #[derive(Parser, Debug)]
+    struct TestArgs {
+        #[command(flatten)]
+        titles: TitleArgs,
+    }
+
+    #[test]
+    fn test_should_label_notes_by_title_when_asked() -> Result<()> {
+        // REQ-OUT-011
+        let dir = tempfile::TempDir::new()?;
+        let titled = dir.path().join("a.md");
+        let untitled = dir.path().join("b.md");
+        std::fs::write(&titled, "# Systems Thinking\nBody")?;
+        std::fs::write(&untitled, "Body")?;
+
+        let paths = TestArgs::parse_from(["program"]).titles;
+        let titles = TestArgs::parse_from(["program", "--titles"]).titles;
+
+        assert_eq!(paths.label(&titled), titled.display().to_string());
+        assert_eq!(titles.label(&titled), "Systems Thinking");
+        assert_eq!(titles.label(&untitled), untitled.display().to_string());
+        Ok(())
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// Flag for showing note titles instead of paths, shared by the listing commands.
+#[derive(Args, Debug, Default)]
+pub struct TitleArgs {
+    /// Show each note's title (frontmatter `title` or first `# heading`)
+    /// instead of its path; untitled notes still show their path
+    #[arg(long)]
+    pub titles: bool,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+impl TitleArgs {
+    /// How to show `path` in a listing.
+    #[inline]
+    #[must_use]
+    pub fn label(&self, path: &Path) -> String {
+        self.titles
+            .then(|| read_text(path).ok())
+            .flatten()
+            .and_then(|content| note_title(&content))
+            .unwrap_or_else(|| path.display().to_string())
+    }
+}
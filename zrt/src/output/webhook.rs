@@ -0,0 +1,208 @@
+use anyhow::{Context as _, Result};
+use serde_json::{Value, json};
+use std::time::Duration;
+use tracing::warn;
+
+use crate::core::history::Snapshot;
+use crate::init::NotifyConfig;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    fn snapshot(percentage: f64) -> Snapshot {
+        Snapshot {
+            timestamp: chrono::DateTime::default(),
+            files: 120,
+            words: 0,
+            done_files: 90,
+            todo_files: 0,
+            done_words: 0,
+            todo_words: 0,
+            percentage,
+            tags: BTreeMap::new(),
+            notes: BTreeMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_should_find_milestones_passed_between_scans() {
+        // REQ-OUT-017
+        let milestones = [75.0, 25.0, 50.0, 100.0];
+
+        assert_eq!(
+            crossed_milestones(20.0, 76.5, &milestones),
+            vec![25.0, 50.0, 75.0]
+        );
+        assert_eq!(crossed_milestones(74.0, 75.0, &milestones), vec![75.0]);
+        assert!(crossed_milestones(75.0, 80.0, &milestones).is_empty());
+        // Falling back below a milestone is not a milestone
+        assert!(crossed_milestones(80.0, 60.0, &milestones).is_empty());
+    }
+
+    #[test]
+    fn test_should_build_chat_payload() {
+        // REQ-OUT-017
+        let payload = milestone_payload(75.0, &snapshot(76.5));
+
+        let text = "zrt: refactoring passed 75% done (76.50%, 90 of 120 notes done)";
+        assert_eq!(payload["text"], text);
+        assert_eq!(payload["content"], text);
+        assert_eq!(payload["milestone"], 75.0);
+        assert_eq!(payload["percentage"], 76.5);
+        assert_eq!(payload["done_files"], 90);
+        assert_eq!(payload["files"], 120);
+    }
+
+    #[test]
+    fn test_should_post_payload_as_json() -> Result<()> {
+        // REQ-OUT-017
+        let server = tiny_http::Server::http("127.0.0.1:0").map_err(|e| anyhow::anyhow!(e))?;
+        let url = format!("http://{}/hook", server.server_addr());
+        let received = std::thread::spawn(move || -> Result<(String, String, String)> {
+            let mut request = server.recv()?;
+            let mut body = String::new();
+            request.as_reader().read_to_string(&mut body)?;
+            let content_type = request
+                .headers()
+                .iter()
+                .find(|header| header.field.equiv("Content-Type"))
+                .map(|header| header.value.to_string())
+                .unwrap_or_default();
+            let url = request.url().to_owned();
+            request.respond(tiny_http::Response::empty(204))?;
+            Ok((url, content_type, body))
+        });
+
+        post_json_with(ureq::AgentBuilder::new(), &url, &json!({"text": "hello"}))?;
+
+        let (path, content_type, body) = received
+            .join()
+            .map_err(|_| anyhow::anyhow!("server panicked"))??;
+        assert_eq!(path, "/hook");
+        assert_eq!(content_type, "application/json");
+        assert_eq!(body, r#"{"text":"hello"}"#);
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_report_failed_posts() -> Result<()> {
+        // REQ-OUT-017
+        let server = tiny_http::Server::http("127.0.0.1:0").map_err(|e| anyhow::anyhow!(e))?;
+        let url = format!("http://{}/hook", server.server_addr());
+        let responder = std::thread::spawn(move || {
+            if let Ok(request) = server.recv() {
+                let _ = request.respond(tiny_http::Response::empty(500));
+            }
+        });
+
+        let error = post_json_with(ureq::AgentBuilder::new(), &url, &json!({}))
+            .err()
+            .map(|e| e.to_string());
+
+        let _ = responder.join();
+        assert_eq!(error.as_deref(), Some("Posting to the webhook failed"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_not_notify_without_webhook() -> Result<()> {
+        // REQ-OUT-017
+        let config = NotifyConfig::default();
+
+        assert!(notify_milestones(&config, 0.0, &snapshot(100.0))?.is_empty());
+        Ok(())
+    }
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+/// The milestones passed going from `before` to `after` percent done,
+/// lowest first. A milestone is passed when `before` is below it and
+/// `after` reaches it.
+#[must_use]
+pub fn crossed_milestones(before: f64, after: f64, milestones: &[f64]) -> Vec<f64> {
+    let mut crossed: Vec<f64> = milestones
+        .iter()
+        .copied()
+        .filter(|milestone| before < *milestone && after >= *milestone)
+        .collect();
+    crossed.sort_by(f64::total_cmp);
+    crossed
+}
+
+/// The JSON posted when a scan passes `milestone`. The message is in both
+/// `text` (Slack) and `content` (Discord), next to the raw numbers.
+#[must_use]
+pub fn milestone_payload(milestone: f64, snapshot: &Snapshot) -> Value {
+    let text = format!(
+        "zrt: refactoring passed {milestone}% done ({:.2}%, {} of {} notes done)",
+        snapshot.percentage, snapshot.done_files, snapshot.files
+    );
+    json!({
+        "text": text,
+        "content": text,
+        "milestone": milestone,
+        "percentage": snapshot.percentage,
+        "done_files": snapshot.done_files,
+        "files": snapshot.files,
+    })
+}
+
+/// Post a message to the configured webhook for every milestone passed
+/// since a scan at `before` percent done, returning those milestones.
+///
+/// # Errors
+///
+/// Returns an error if a message cannot be delivered.
+pub fn notify_milestones(config: &NotifyConfig, before: f64, after: &Snapshot) -> Result<Vec<f64>> {
+    let Some(url) = &config.webhook else {
+        return Ok(Vec::new());
+    };
+    let crossed = crossed_milestones(before, after.percentage, &config.milestones);
+    for milestone in &crossed {
+        post_json(url, &milestone_payload(*milestone, after))?;
+    }
+    Ok(crossed)
+}
+
+/// Like [`notify_milestones`], printing each milestone passed. A failed
+/// post only warns, so it never fails the scan that triggered it.
+pub fn announce_milestones(config: &NotifyConfig, before: f64, after: &Snapshot) {
+    match notify_milestones(config, before, after) {
+        Ok(milestones) => {
+            for milestone in milestones {
+                println!("Milestone reached: {milestone}% done");
+            }
+        }
+        Err(error) => warn!("failed to post milestone: {error:#}"),
+    }
+}
+
+/// POST `payload` to `url` over HTTP or HTTPS, through the proxy named by
+/// `ALL_PROXY`, `HTTPS_PROXY` or `HTTP_PROXY` if one is set.
+fn post_json(url: &str, payload: &Value) -> Result<()> {
+    post_json_with(
+        ureq::AgentBuilder::new().try_proxy_from_env(true),
+        url,
+        payload,
+    )
+}
+
+/// [`post_json`] with the given client, giving up after 10 seconds.
+fn post_json_with(agent: ureq::AgentBuilder, url: &str, payload: &Value) -> Result<()> {
+    agent
+        .timeout(Duration::from_secs(10))
+        .build()
+        .post(url)
+        .set("Content-Type", "application/json")
+        .send_string(&payload.to_string())
+        .context("Posting to the webhook failed")?;
+    Ok(())
+}
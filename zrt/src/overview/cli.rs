@@ -0,0 +1,217 @@
+use anyhow::Result;
+use clap::Args;
+use std::path::PathBuf;
+
+use crate::core::analyzer::{AnalysisReport, analyze_notes};
+use crate::core::scanner::ScanOptions;
+use crate::init::ZrtConfig;
+use crate::output::files_from::FilesFromArgs;
+use crate::output::number;
+use crate::output::scan;
+use crate::output::titles::TitleArgs;
+use crate::overview::{Overview, overview};
+use crate::plugins::cli::summary_lines;
+use crate::plugins::load_plugins;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::analyzer::MetricSummary;
+    use crate::tags::TagTotal;
+    use clap::Parser;
+
+    #[derive(Parser, Debug)]
+    struct TestArgs {
+        #[command(flatten)]
+        overview: OverviewArgs,
+    }
+
+    #[test]
+    fn test_should_print_each_section() {
+        // REQ-OVERVIEW-002
+        let args = TestArgs::parse_from(["program", "--tags", "1"]).overview;
+        let overview = Overview {
+            files: 3,
+            words: 60,
+            untagged: 1,
+            done_files: 1,
+            todo_files: 1,
+            percentage: 25.0,
+            largest_todo: vec![(PathBuf::from("a.md"), 45)],
+            tags: vec![
+                TagTotal {
+                    tag: "to_refactor".to_owned(),
+                    files: 1,
+                    words: 45,
+                },
+                TagTotal {
+                    tag: "refactored".to_owned(),
+                    files: 1,
+                    words: 15,
+                },
+            ],
+        };
+
+        assert_eq!(args.top, 5);
+        assert!(!args.no_plugins);
+        assert_eq!(
+            args.lines(&overview, &AnalysisReport::default()),
+            vec![
+                "Files: 3",
+                "Words: 60",
+                "Untagged: 1",
+                "Done: 25.00% of words (1 done, 1 to refactor)",
+                "Largest to refactor:",
+                "  45 a.md",
+                "Tags:",
+                "  to_refactor: 1 notes, 45 words",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_should_end_with_plugin_metrics() {
+        // REQ-OVERVIEW-003
+        let args = TestArgs::parse_from(["program", "--tags", "0"]).overview;
+        let overview = Overview {
+            files: 0,
+            words: 0,
+            untagged: 0,
+            done_files: 0,
+            todo_files: 0,
+            percentage: 0.0,
+            largest_todo: Vec::new(),
+            tags: Vec::new(),
+        };
+        let plugins = AnalysisReport {
+            notes: Vec::new(),
+            summaries: vec![MetricSummary {
+                analyzer: "links".to_owned(),
+                metric: "external".to_owned(),
+                notes: 2,
+                total: 5.0,
+                min: 2.0,
+                max: 3.0,
+            }],
+        };
+
+        let lines = args.lines(&overview, &plugins);
+
+        assert_eq!(
+            lines[lines.len() - 2..],
+            [
+                "Plugins:",
+                "  links.external notes=2 total=5 mean=2.5 min=2 max=3"
+            ]
+        );
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+#[derive(Args, Debug)]
+pub struct OverviewArgs {
+    /// Directories to scan (space-separated, defaults to current directory)
+    #[arg(short = 'd', long = "dir", num_args = 0.., default_values = &["."])]
+    pub directories: Vec<PathBuf>,
+
+    /// Directories to exclude (space-separated)
+    #[arg(short, long, num_args = 0..)]
+    pub exclude: Vec<String>,
+
+    /// Number of the largest notes still to refactor to list
+    #[arg(short = 'n', long, default_value_t = 5)]
+    pub top: usize,
+
+    /// Number of the most used tags to list
+    #[arg(long, default_value_t = 10)]
+    pub tags: usize,
+
+    #[command(flatten)]
+    pub files_from: FilesFromArgs,
+
+    /// Skip the metrics of the plugins configured in .zrt/config.toml
+    #[arg(long)]
+    pub no_plugins: bool,
+
+    #[command(flatten)]
+    pub titles: TitleArgs,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+impl OverviewArgs {
+    /// The report, one section after another, ending with the metrics of
+    /// any plugins.
+    fn lines(&self, overview: &Overview, plugins: &AnalysisReport) -> Vec<String> {
+        let mut lines = vec![
+            format!("Files: {}", number::count(overview.files)),
+            format!("Words: {}", number::count(overview.words)),
+            format!("Untagged: {}", number::count(overview.untagged)),
+            format!(
+                "Done: {:.2}% of words ({} done, {} to refactor)",
+                overview.percentage,
+                number::count(overview.done_files),
+                number::count(overview.todo_files)
+            ),
+            "Largest to refactor:".to_owned(),
+        ];
+        for (path, words) in &overview.largest_todo {
+            lines.push(format!(
+                "  {} {}",
+                number::count(words),
+                self.titles.label(path)
+            ));
+        }
+        lines.push("Tags:".to_owned());
+        for tag in overview.tags.iter().take(self.tags) {
+            lines.push(format!(
+                "  {}: {} notes, {} words",
+                tag.tag,
+                number::count(tag.files),
+                number::count(tag.words)
+            ));
+        }
+        if !plugins.summaries.is_empty() {
+            lines.push("Plugins:".to_owned());
+            lines.extend(
+                summary_lines(plugins)
+                    .iter()
+                    .map(|line| format!("  {line}")),
+            );
+        }
+        lines
+    }
+}
+
+pub fn run(args: OverviewArgs, config: &ZrtConfig) -> Result<()> {
+    let exclude_dirs: Vec<&str> = args.exclude.iter().map(String::as_str).collect();
+
+    let options = args
+        .files_from
+        .apply(ScanOptions::new(&args.directories).exclude(&exclude_dirs))?;
+    let scanner = scan::attach(options).build()?;
+    let overview = overview(
+        &scanner,
+        &config.refactor.done_tag,
+        &config.refactor.todo_tag,
+        args.top,
+    )?;
+    let plugins = if args.no_plugins || config.plugins.is_empty() {
+        AnalysisReport::default()
+    } else {
+        analyze_notes(&scanner, &mut load_plugins(&config.plugins)?)?
+    };
+
+    for line in args.lines(&overview, &plugins) {
+        println!("{line}");
+    }
+    Ok(())
+}
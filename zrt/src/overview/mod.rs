@@ -0,0 +1,152 @@
+pub mod cli;
+
+use anyhow::Result;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use crate::core::history::percentage;
+use crate::core::scanner::Scanner;
+use crate::core::status::RefactorStatus;
+use crate::tags::TagTotal;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::scanner::ScanOptions;
+    use crate::core::vfs::MemoryFs;
+
+    #[test]
+    fn test_should_summarize_the_vault_in_one_pass() -> Result<()> {
+        // REQ-OVERVIEW-001
+        // Given done, todo and untagged notes
+        let vfs = MemoryFs::new()
+            .with_file(
+                "/vault/done.md",
+                "---\ntags: [refactored, idea]\n---\none two",
+            )
+            .with_file(
+                "/vault/long.md",
+                "---\ntags: [to_refactor]\n---\none two three",
+            )
+            .with_file(
+                "/vault/short.md",
+                "---\ntags: [to_refactor, idea]\n---\none",
+            )
+            .with_file("/vault/tiny.md", "---\ntags: [to_refactor]\n---\none")
+            .with_file("/vault/plain.md", "one two");
+        let scanner = ScanOptions::new(["/vault"]).vfs(vfs).build()?;
+
+        // When
+        let overview = overview(&scanner, "refactored", "to_refactor", 2)?;
+
+        // Then
+        assert_eq!((overview.files, overview.words), (5, 9));
+        assert_eq!(overview.untagged, 1);
+        assert_eq!((overview.done_files, overview.todo_files), (1, 3));
+        assert_eq!(overview.percentage, 22.22);
+        assert_eq!(
+            overview.largest_todo,
+            vec![
+                (PathBuf::from("/vault/long.md"), 3),
+                (PathBuf::from("/vault/short.md"), 1)
+            ]
+        );
+        let tags: Vec<_> = overview
+            .tags
+            .iter()
+            .map(|t| (t.tag.as_str(), t.files, t.words))
+            .collect();
+        assert_eq!(
+            tags,
+            vec![("to_refactor", 3, 5), ("idea", 2, 3), ("refactored", 1, 2)]
+        );
+        Ok(())
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// The state of a vault at a glance, gathered in a single scan.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Overview {
+    pub files: usize,
+    pub words: usize,
+    /// Notes without any tags
+    pub untagged: usize,
+    pub done_files: usize,
+    pub todo_files: usize,
+    /// Percentage of words in done notes, rounded to 2 decimal places
+    pub percentage: f64,
+    /// The longest notes still to refactor with their word counts, longest first
+    pub largest_todo: Vec<(PathBuf, usize)>,
+    /// Every tag, most used first
+    pub tags: Vec<TagTotal>,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+/// Totals, untagged notes, refactor progress, the `top` longest notes still
+/// to refactor and per-tag totals, from one traversal of `scanner`.
+///
+/// # Errors
+///
+/// Returns an error if the scan fails.
+pub fn overview(scanner: &Scanner, done_tag: &str, todo_tag: &str, top: usize) -> Result<Overview> {
+    let mut files = 0;
+    let mut words = 0;
+    let mut untagged = 0;
+    let mut done_files = 0;
+    let mut done_words = 0;
+    let mut todo = Vec::new();
+    let mut tags: HashMap<String, (usize, usize)> = HashMap::new();
+
+    for record in scanner.iter() {
+        let record = record?;
+        files += 1;
+        words += record.words;
+        if record.tags.is_empty() {
+            untagged += 1;
+        }
+        match RefactorStatus::of(&record.tags, done_tag, todo_tag) {
+            RefactorStatus::Done => {
+                done_files += 1;
+                done_words += record.words;
+            }
+            RefactorStatus::Todo => todo.push((record.path.clone(), record.words)),
+            RefactorStatus::Untracked => {}
+        }
+        for tag in record.tags {
+            let total = tags.entry(tag).or_insert((0, 0));
+            total.0 += 1;
+            total.1 += record.words;
+        }
+    }
+
+    let todo_files = todo.len();
+    todo.sort_by_key(|(path, words)| (Reverse(*words), path.clone()));
+    todo.truncate(top);
+    let mut tags: Vec<TagTotal> = tags
+        .into_iter()
+        .map(|(tag, (files, words))| TagTotal { tag, files, words })
+        .collect();
+    tags.sort_by(|a, b| b.files.cmp(&a.files).then(a.tag.cmp(&b.tag)));
+
+    Ok(Overview {
+        files,
+        words,
+        untagged,
+        done_files,
+        todo_files,
+        percentage: percentage(done_words, words),
+        largest_todo: todo,
+        tags,
+    })
+}
@@ -0,0 +1,187 @@
+use anyhow::Result;
+use clap::Args;
+use comfy_table::Cell;
+use std::path::PathBuf;
+
+use crate::core::analyzer::{AnalysisReport, MetricSummary, analyze_notes};
+use crate::core::scanner::ScanOptions;
+use crate::init::ZrtConfig;
+use crate::output::{scan, table};
+use crate::plugins::load_plugins;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::analyzer::NoteMetrics;
+    use clap::Parser;
+
+    #[derive(Parser, Debug)]
+    struct TestArgs {
+        #[command(flatten)]
+        plugins: PluginsArgs,
+    }
+
+    #[test]
+    fn test_should_accept_plugin_selection_and_notes() {
+        // REQ-PLUGIN-003
+        let args = TestArgs::parse_from(["program"]);
+        assert!(args.plugins.only.is_empty());
+        assert!(!args.plugins.notes);
+
+        let args = TestArgs::parse_from(["program", "--only", "links", "todos", "--notes"]);
+        assert_eq!(args.plugins.only, vec!["links", "todos"]);
+        assert!(args.plugins.notes);
+    }
+
+    #[test]
+    fn test_should_print_summaries_and_note_metrics_as_lines() {
+        // REQ-PLUGIN-003
+        let report = AnalysisReport {
+            notes: vec![NoteMetrics {
+                path: PathBuf::from("a.md"),
+                analyzer: "links".to_owned(),
+                metrics: [("external".to_owned(), 3.0), ("broken".to_owned(), 0.5)].into(),
+            }],
+            summaries: vec![MetricSummary {
+                analyzer: "links".to_owned(),
+                metric: "external".to_owned(),
+                notes: 2,
+                total: 5.0,
+                min: 2.0,
+                max: 3.0,
+            }],
+        };
+
+        assert_eq!(
+            summary_lines(&report),
+            vec!["links.external notes=2 total=5 mean=2.5 min=2 max=3"]
+        );
+        assert_eq!(
+            note_lines(&report),
+            vec!["a.md links.broken=0.5 links.external=3"]
+        );
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+#[derive(Args, Debug)]
+pub struct PluginsArgs {
+    /// Directories to scan (space-separated, defaults to current directory)
+    #[arg(short = 'd', long = "dir", num_args = 0.., default_values = &["."])]
+    pub directories: Vec<PathBuf>,
+
+    /// Directories to exclude (space-separated)
+    #[arg(short, long, num_args = 0..)]
+    pub exclude: Vec<String>,
+
+    /// Run only the named plugins (space-separated)
+    #[arg(long, num_args = 1..)]
+    pub only: Vec<String>,
+
+    /// Also list every note's metrics
+    #[arg(long)]
+    pub notes: bool,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+pub fn run(args: PluginsArgs, config: &ZrtConfig) -> Result<()> {
+    let plugins: Vec<_> = config
+        .plugins
+        .iter()
+        .filter(|plugin| args.only.is_empty() || args.only.contains(&plugin.name))
+        .cloned()
+        .collect();
+    if let Some(unknown) = args
+        .only
+        .iter()
+        .find(|name| !plugins.iter().any(|plugin| &plugin.name == *name))
+    {
+        anyhow::bail!("No plugin named {unknown} in .zrt/config.toml");
+    }
+    if plugins.is_empty() {
+        anyhow::bail!("No plugins configured; add [[plugins]] tables to .zrt/config.toml");
+    }
+    let exclude_dirs: Vec<&str> = args.exclude.iter().map(String::as_str).collect();
+
+    let scanner =
+        scan::attach(ScanOptions::new(&args.directories).exclude(&exclude_dirs)).build()?;
+    let report = analyze_notes(&scanner, &mut load_plugins(&plugins)?)?;
+
+    if args.notes {
+        for line in note_lines(&report) {
+            println!("{line}");
+        }
+    }
+    if table::is_tty() {
+        let mut out = table::table(["plugin", "metric", "notes", "total", "mean", "min", "max"]);
+        for summary in &report.summaries {
+            out.add_row(vec![
+                Cell::new(&summary.analyzer),
+                Cell::new(&summary.metric),
+                table::number(summary.notes),
+                table::number(summary.total),
+                table::number(round(summary.mean())),
+                table::number(summary.min),
+                table::number(summary.max),
+            ]);
+        }
+        println!("{out}");
+    } else {
+        for line in summary_lines(&report) {
+            println!("{line}");
+        }
+    }
+    Ok(())
+}
+
+/// One `plugin.metric notes=N total=T mean=M min=A max=B` line per metric.
+pub(crate) fn summary_lines(report: &AnalysisReport) -> Vec<String> {
+    report
+        .summaries
+        .iter()
+        .map(|summary: &MetricSummary| {
+            format!(
+                "{}.{} notes={} total={} mean={} min={} max={}",
+                summary.analyzer,
+                summary.metric,
+                summary.notes,
+                summary.total,
+                round(summary.mean()),
+                summary.min,
+                summary.max
+            )
+        })
+        .collect()
+}
+
+/// One `path plugin.metric=value ...` line per note and plugin reporting
+/// any metric.
+fn note_lines(report: &AnalysisReport) -> Vec<String> {
+    report
+        .notes
+        .iter()
+        .filter(|note| !note.metrics.is_empty())
+        .map(|note| {
+            let metrics: Vec<String> = note
+                .metrics
+                .iter()
+                .map(|(metric, value)| format!("{}.{metric}={value}", note.analyzer))
+                .collect();
+            format!("{} {}", note.path.display(), metrics.join(" "))
+        })
+        .collect()
+}
+
+/// `value` to at most 2 decimal places, so means stay readable.
+fn round(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
+}
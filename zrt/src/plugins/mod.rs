@@ -0,0 +1,361 @@
+pub mod cli;
+mod wasm;
+
+use anyhow::{Context as _, Result, bail};
+use std::io::{BufRead as _, BufReader, Write as _};
+use std::process::{Child, ChildStdin, Command, Stdio};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::core::analyzer::{Analyzer, Metrics, NoteInput};
+use crate::init::PluginConfig;
+
+pub use wasm::WasmAnalyzer;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::analyzer::analyze_notes;
+    use crate::core::scanner::ScanOptions;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn plugin(script: &str) -> CommandAnalyzer {
+        CommandAnalyzer::new(PluginConfig {
+            name: "script".to_owned(),
+            command: ["sh", "-c", script].map(str::to_owned).to_vec(),
+            timeout: Some(1),
+            ..PluginConfig::default()
+        })
+    }
+
+    fn note() -> NoteInput<'static> {
+        NoteInput {
+            path: std::path::Path::new("a.md"),
+            frontmatter: serde_json::Value::Null,
+            body: "",
+        }
+    }
+
+    fn vault() -> Result<TempDir> {
+        let dir = TempDir::new()?;
+        fs::write(dir.path().join("a.md"), "---\nrating: 4\n---\none")?;
+        fs::write(dir.path().join("b.md"), "two")?;
+        Ok(dir)
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_should_exchange_one_json_line_per_note() -> Result<()> {
+        // REQ-PLUGIN-001
+        // Given a plugin answering every note with a constant and echoing
+        // whether it saw the rating
+        let dir = vault()?;
+        let scanner = ScanOptions::new([dir.path()]).build()?;
+        let script = r#"while read -r note; do
+            case "$note" in
+                *'"rating":4'*) echo '{"notes": 1, "rated": 1}' ;;
+                *) echo '{"notes": 1}' ;;
+            esac
+        done"#;
+        let mut analyzers: Vec<Box<dyn Analyzer>> = vec![Box::new(plugin(script))];
+
+        // When analyzing the vault with it
+        let report = analyze_notes(&scanner, &mut analyzers)?;
+
+        // Then it received each note and its metrics were summarized
+        let totals: Vec<(&str, usize)> = report
+            .summaries
+            .iter()
+            .map(|s| (s.metric.as_str(), s.notes))
+            .collect();
+        assert_eq!(totals, vec![("notes", 2), ("rated", 1)]);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_should_fail_on_invalid_reply_or_early_exit() -> Result<()> {
+        // REQ-PLUGIN-002
+        let dir = vault()?;
+        let scanner = ScanOptions::new([dir.path()]).build()?;
+        let error = |script: &str| {
+            let mut analyzers: Vec<Box<dyn Analyzer>> = vec![Box::new(plugin(script))];
+            analyze_notes(&scanner, &mut analyzers)
+                .err()
+                .map(|e| format!("{e:#}"))
+                .unwrap_or_default()
+        };
+
+        assert!(
+            error("read -r note; echo 'not json'").contains("Invalid reply from plugin script")
+        );
+        assert!(error("exit 0").contains("Plugin script exited without replying"));
+        assert!(error("read -r note; echo '{\"n\": \"many\"}'").contains("Invalid reply"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_report_missing_program() {
+        // REQ-PLUGIN-002
+        let mut analyzer = CommandAnalyzer::new(PluginConfig {
+            name: "missing".to_owned(),
+            command: vec!["zrt-no-such-plugin".to_owned()],
+            ..PluginConfig::default()
+        });
+
+        let error = analyzer.analyze(&note()).err().map(|e| e.to_string());
+
+        assert_eq!(
+            error.as_deref(),
+            Some("Failed to start plugin missing: zrt-no-such-plugin")
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_should_stop_plugins_that_hang() {
+        // REQ-PLUGIN-005
+        // Given a plugin that never replies, and one that ignores the end of
+        // its input
+        let mut silent = plugin("sleep 30");
+        let stubborn = plugin("read -r note; echo '{}'; exec sleep 30");
+        let started = Instant::now();
+
+        // When analyzing with them
+        let error = silent.analyze(&note()).err().map(|e| e.to_string());
+        drop(silent);
+        let mut stubborn = stubborn;
+        assert!(stubborn.analyze(&note()).is_ok());
+        let dropped = Instant::now();
+        drop(stubborn);
+
+        // Then the silent one is killed after its timeout, and the stubborn
+        // one as soon as it is dropped
+        assert_eq!(
+            error.as_deref(),
+            Some("Plugin script did not reply within 1s")
+        );
+        assert!(dropped.elapsed() < Duration::from_secs(1));
+        assert!(started.elapsed() < Duration::from_secs(10));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_should_let_plugins_finish_after_the_last_note() -> Result<()> {
+        // REQ-PLUGIN-005
+        // Given a plugin that writes a file once its input ends
+        let dir = TempDir::new()?;
+        let done = dir.path().join("done");
+        let mut polite = plugin(&format!(
+            "read -r note; echo '{{}}'; cat >/dev/null; touch '{}'",
+            done.display()
+        ));
+
+        // When it is dropped after a note
+        assert!(polite.analyze(&note()).is_ok());
+        drop(polite);
+
+        // Then it had time to finish
+        assert!(done.exists());
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_load_commands_and_wasm_modules() {
+        // REQ-PLUGIN-004
+        let config = |command: &[&str], wasm: Option<&str>| PluginConfig {
+            name: "p".to_owned(),
+            command: command.iter().map(|arg| (*arg).to_owned()).collect(),
+            wasm: wasm.map(Into::into),
+            timeout: None,
+        };
+        let error = |plugins: &[PluginConfig]| load_plugins(plugins).err().map(|e| e.to_string());
+
+        assert!(load_plugins(&[config(&["sh"], None)]).is_ok());
+        assert_eq!(
+            error(&[config(&[], None)]),
+            Some("Plugin p needs either a command or a wasm module".to_owned())
+        );
+        assert_eq!(
+            error(&[config(&["sh"], Some("p.wasm"))]),
+            Some("Plugin p needs either a command or a wasm module".to_owned())
+        );
+        assert_eq!(
+            error(&[config(&[], Some("zrt-no-such-plugin.wasm"))]),
+            Some("Failed to read plugin p: zrt-no-such-plugin.wasm".to_owned())
+        );
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// Seconds a plugin may take per note unless its config says otherwise.
+pub const DEFAULT_TIMEOUT: u64 = 10;
+
+/// How long a program may take to exit after its stdin is closed before it
+/// is killed.
+const EXIT_GRACE: Duration = Duration::from_millis(200);
+
+/// An [`Analyzer`] backed by an external program, which lets analyzers be
+/// written in any language.
+///
+/// The program is started on the first note and kept running. It receives
+/// one [`NoteInput`] as JSON per line on stdin and answers each with one
+/// line holding a JSON object of numeric metrics. Stdin is closed after the
+/// last note. A program that does not reply within the plugin's timeout is
+/// killed, and so is one still running when the analyzer is dropped.
+#[derive(Debug)]
+pub struct CommandAnalyzer {
+    config: PluginConfig,
+    process: Option<Process>,
+}
+
+#[derive(Debug)]
+struct Process {
+    child: Child,
+    stdin: ChildStdin,
+    /// Lines read from stdout by a background thread, so waiting for a
+    /// reply can time out
+    replies: Receiver<String>,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+impl CommandAnalyzer {
+    #[must_use]
+    pub const fn new(config: PluginConfig) -> Self {
+        Self {
+            config,
+            process: None,
+        }
+    }
+
+    fn process(&mut self) -> Result<&mut Process> {
+        if let Some(ref mut process) = self.process {
+            return Ok(process);
+        }
+        let (program, args) = self
+            .config
+            .command
+            .split_first()
+            .with_context(|| format!("Plugin {} has an empty command", self.config.name))?;
+        let mut child = Command::new(program)
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .with_context(|| format!("Failed to start plugin {}: {program}", self.config.name))?;
+        let (Some(stdin), Some(stdout)) = (child.stdin.take(), child.stdout.take()) else {
+            bail!("Failed to connect to plugin {}", self.config.name);
+        };
+        let (sender, replies) = mpsc::channel();
+        thread::spawn(move || {
+            for line in BufReader::new(stdout).lines() {
+                let Ok(line) = line else { break };
+                if sender.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+        Ok(self.process.insert(Process {
+            child,
+            stdin,
+            replies,
+        }))
+    }
+
+    fn timeout(&self) -> Duration {
+        Duration::from_secs(self.config.timeout.unwrap_or(DEFAULT_TIMEOUT))
+    }
+}
+
+impl Process {
+    /// Close stdin to tell the plugin there are no more notes, and kill it
+    /// unless it exits within [`EXIT_GRACE`].
+    fn stop(self) {
+        let Self {
+            mut child, stdin, ..
+        } = self;
+        drop(stdin);
+        let deadline = Instant::now() + EXIT_GRACE;
+        while matches!(child.try_wait(), Ok(None)) {
+            if Instant::now() >= deadline {
+                let _ = child.kill();
+                break;
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+        let _ = child.wait();
+    }
+}
+
+impl Analyzer for CommandAnalyzer {
+    fn name(&self) -> &str {
+        &self.config.name
+    }
+
+    fn analyze(&mut self, note: &NoteInput<'_>) -> Result<Metrics> {
+        let name = self.config.name.clone();
+        let timeout = self.timeout();
+        let process = self.process()?;
+        let request = serde_json::to_string(note).context("Failed to serialize note")?;
+        let sent = writeln!(process.stdin, "{request}").and_then(|()| process.stdin.flush());
+        let reply = match sent.map(|()| process.replies.recv_timeout(timeout)) {
+            Ok(Ok(reply)) => reply,
+            Ok(Err(RecvTimeoutError::Timeout)) => {
+                if let Some(process) = self.process.take() {
+                    process.stop();
+                }
+                bail!("Plugin {name} did not reply within {}s", timeout.as_secs());
+            }
+            Ok(Err(RecvTimeoutError::Disconnected)) | Err(_) => {
+                bail!("Plugin {name} exited without replying")
+            }
+        };
+        serde_json::from_str(&reply)
+            .with_context(|| format!("Invalid reply from plugin {name}: {}", reply.trim_end()))
+    }
+}
+
+impl Drop for CommandAnalyzer {
+    fn drop(&mut self) {
+        if let Some(process) = self.process.take() {
+            process.stop();
+        }
+    }
+}
+
+/// An analyzer for every configured plugin: a [`WasmAnalyzer`] for plugins
+/// with a `wasm` module, else a [`CommandAnalyzer`].
+///
+/// # Errors
+///
+/// Returns an error if a plugin has both or neither of a command and a wasm
+/// module, or its module cannot be loaded.
+pub fn load_plugins(plugins: &[PluginConfig]) -> Result<Vec<Box<dyn Analyzer>>> {
+    plugins
+        .iter()
+        .map(|config| -> Result<Box<dyn Analyzer>> {
+            match (&config.wasm, config.command.is_empty()) {
+                (Some(path), true) => {
+                    let timeout = Duration::from_secs(config.timeout.unwrap_or(DEFAULT_TIMEOUT));
+                    Ok(Box::new(WasmAnalyzer::load(&config.name, path, timeout)?))
+                }
+                (None, false) => Ok(Box::new(CommandAnalyzer::new(config.clone()))),
+                _ => bail!(
+                    "Plugin {} needs either a command or a wasm module",
+                    config.name
+                ),
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,271 @@
+use anyhow::{Context as _, Result, anyhow, bail};
+use std::path::Path;
+use std::time::Duration;
+use wasmi::core::TrapCode;
+use wasmi::{Config, Engine, Linker, Memory, Module, Store, TypedFunc};
+
+use crate::core::analyzer::{Analyzer, Metrics, NoteInput};
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::analyzer::analyze_notes;
+    use crate::core::scanner::ScanOptions;
+    use crate::core::vfs::MemoryFs;
+
+    /// Replies `{"long":1}` to notes whose JSON is over 60 bytes, else
+    /// `{"short":1}`.
+    const LENGTHS: &str = r#"(module
+        (memory (export "memory") 1)
+        (data (i32.const 0) "{\"long\":1}{\"short\":1}")
+        (func (export "alloc") (param i32) (result i32) (i32.const 1024))
+        (func (export "analyze") (param $ptr i32) (param $len i32) (result i64)
+            (select (i64.const 10) (i64.const 0x0000000a0000000b)
+                (i32.gt_u (local.get $len) (i32.const 60)))))"#;
+
+    fn plugin(wat: &str, fuel: u64) -> Result<WasmAnalyzer> {
+        WasmAnalyzer::with_fuel("lengths", &wat::parse_str(wat)?, fuel, TIMEOUT)
+    }
+
+    const TIMEOUT: Duration = Duration::from_secs(2);
+
+    #[test]
+    fn test_should_run_modules_on_each_note() -> Result<()> {
+        // REQ-PLUGIN-004
+        let vfs = MemoryFs::new()
+            .with_file("/v/a.md", "---\nrating: 4\n---\nA body long enough")
+            .with_file("/v/b.md", "b");
+        let scanner = ScanOptions::new(["/v"]).vfs(vfs).build()?;
+        let mut analyzers: Vec<Box<dyn Analyzer>> = vec![Box::new(plugin(LENGTHS, 100_000)?)];
+
+        let report = analyze_notes(&scanner, &mut analyzers)?;
+
+        let metrics: Vec<(&Path, Vec<&String>)> = report
+            .notes
+            .iter()
+            .map(|note| (note.path.as_path(), note.metrics.keys().collect()))
+            .collect();
+        assert_eq!(
+            metrics,
+            vec![
+                (Path::new("/v/a.md"), vec![&"long".to_owned()]),
+                (Path::new("/v/b.md"), vec![&"short".to_owned()]),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_stop_modules_that_run_too_long() -> Result<()> {
+        // REQ-PLUGIN-005
+        let spinning = r#"(module
+            (memory (export "memory") 1)
+            (func (export "alloc") (param i32) (result i32) (i32.const 0))
+            (func (export "analyze") (param i32 i32) (result i64) (loop (br 0)) (i64.const 0)))"#;
+        let note = NoteInput {
+            path: Path::new("a.md"),
+            frontmatter: serde_json::Value::Null,
+            body: "",
+        };
+
+        let error = plugin(spinning, 10_000)?
+            .analyze(&note)
+            .err()
+            .map(|e| e.to_string());
+
+        assert_eq!(
+            error.as_deref(),
+            Some("Plugin lengths did not reply within 2s")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_reject_replies_out_of_bounds() -> Result<()> {
+        // REQ-PLUGIN-002
+        let huge = r#"(module
+            (memory (export "memory") 1)
+            (func (export "alloc") (param i32) (result i32) (i32.const 0))
+            (func (export "analyze") (param i32 i32) (result i64) (i64.const 0xffffffff)))"#;
+        let note = NoteInput {
+            path: Path::new("a.md"),
+            frontmatter: serde_json::Value::Null,
+            body: "",
+        };
+
+        let error = plugin(huge, 10_000)?
+            .analyze(&note)
+            .err()
+            .map(|e| format!("{e:#}"));
+
+        assert_eq!(
+            error.as_deref(),
+            Some("Plugin lengths failed: Plugin lengths replied out of bounds")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_require_the_plugin_exports() {
+        // REQ-PLUGIN-002
+        let error = plugin("(module (memory (export \"memory\") 1))", 1)
+            .err()
+            .map(|e| e.to_string());
+
+        assert_eq!(
+            error.as_deref(),
+            Some("Plugin lengths does not export alloc(i32) -> i32")
+        );
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// Fuel, roughly one unit per instruction, a module gets per second of its
+/// plugin's timeout.
+const FUEL_PER_SECOND: u64 = 100_000_000;
+
+/// An [`Analyzer`] compiled to WebAssembly and run inside zrt.
+///
+/// The module exports its `memory`, `alloc(len: i32) -> i32`, which returns
+/// where to write a note of `len` bytes, and `analyze(ptr: i32, len: i32) ->
+/// i64`, which reads the [`NoteInput`] JSON written there and returns where
+/// its reply, a JSON object of numeric metrics, starts (high 32 bits) and
+/// how long it is (low 32 bits). Modules cannot import anything, and a
+/// module still running a note after about its timeout's worth of
+/// instructions is stopped.
+#[derive(Debug)]
+pub struct WasmAnalyzer {
+    name: String,
+    store: Store<()>,
+    memory: Memory,
+    alloc: TypedFunc<i32, i32>,
+    analyze: TypedFunc<(i32, i32), i64>,
+    /// Instructions a note may take, from the timeout
+    fuel: u64,
+    timeout: Duration,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+impl WasmAnalyzer {
+    /// Load the module at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or is not a valid plugin.
+    pub fn load(name: &str, path: &Path, timeout: Duration) -> Result<Self> {
+        let wasm = std::fs::read(path)
+            .with_context(|| format!("Failed to read plugin {name}: {}", path.display()))?;
+        Self::new(name, &wasm, timeout)
+    }
+
+    /// Instantiate a module from its binary, giving it `timeout` per note.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the module is invalid, imports anything or lacks
+    /// one of the plugin exports.
+    pub fn new(name: &str, wasm: &[u8], timeout: Duration) -> Result<Self> {
+        let fuel = timeout.as_secs().saturating_mul(FUEL_PER_SECOND);
+        Self::with_fuel(name, wasm, fuel, timeout)
+    }
+
+    fn with_fuel(name: &str, wasm: &[u8], fuel: u64, timeout: Duration) -> Result<Self> {
+        let mut config = Config::default();
+        config.consume_fuel(true);
+        let engine = Engine::new(&config);
+        let module =
+            Module::new(&engine, wasm).with_context(|| format!("Invalid plugin module {name}"))?;
+        let mut store = Store::new(&engine, ());
+        store
+            .set_fuel(fuel)
+            .map_err(|_| anyhow!("Fuel metering is off"))?;
+        let instance = Linker::<()>::new(&engine)
+            .instantiate(&mut store, &module)
+            .and_then(|pre| pre.start(&mut store))
+            .with_context(|| format!("Failed to start plugin {name}"))?;
+
+        let memory = instance
+            .get_memory(&store, "memory")
+            .with_context(|| format!("Plugin {name} does not export its memory"))?;
+        let alloc = instance
+            .get_typed_func(&store, "alloc")
+            .with_context(|| format!("Plugin {name} does not export alloc(i32) -> i32"))?;
+        let analyze = instance
+            .get_typed_func(&store, "analyze")
+            .with_context(|| format!("Plugin {name} does not export analyze(i32, i32) -> i64"))?;
+        Ok(Self {
+            name: name.to_owned(),
+            store,
+            memory,
+            alloc,
+            analyze,
+            fuel,
+            timeout,
+        })
+    }
+
+    fn call(&mut self, request: &[u8]) -> Result<Vec<u8>> {
+        let name = &self.name;
+        self.store
+            .set_fuel(self.fuel)
+            .map_err(|_| anyhow!("Fuel metering is off"))?;
+        let len = i32::try_from(request.len()).context("Note too large for a plugin")?;
+        let ptr = self.alloc.call(&mut self.store, len)?;
+        self.memory
+            .write(&mut self.store, usize::try_from(ptr)?, request)
+            .map_err(|_| anyhow!("Plugin {name} allocated memory out of bounds"))?;
+        let reply = self.analyze.call(&mut self.store, (ptr, len))?;
+
+        // The start in the high and the length in the low 32 bits
+        let reply = reply as u64;
+        let (start, len) = ((reply >> 32) as usize, (reply & 0xffff_ffff) as usize);
+        // Checked before copying, so a bogus length cannot allocate much
+        let reply = start
+            .checked_add(len)
+            .and_then(|end| self.memory.data(&self.store).get(start..end))
+            .ok_or_else(|| anyhow!("Plugin {name} replied out of bounds"))?;
+        Ok(reply.to_vec())
+    }
+}
+
+impl Analyzer for WasmAnalyzer {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn analyze(&mut self, note: &NoteInput<'_>) -> Result<Metrics> {
+        let request = serde_json::to_vec(note).context("Failed to serialize note")?;
+        let reply = match self.call(&request) {
+            Ok(reply) => reply,
+            Err(error)
+                if error
+                    .downcast_ref::<wasmi::Error>()
+                    .and_then(wasmi::Error::as_trap_code)
+                    == Some(TrapCode::OutOfFuel) =>
+            {
+                bail!(
+                    "Plugin {} did not reply within {}s",
+                    self.name,
+                    self.timeout.as_secs()
+                )
+            }
+            Err(error) => return Err(error.context(format!("Plugin {} failed", self.name))),
+        };
+        serde_json::from_slice(&reply).with_context(|| {
+            format!(
+                "Invalid reply from plugin {}: {}",
+                self.name,
+                String::from_utf8_lossy(&reply).trim_end()
+            )
+        })
+    }
+}
@@ -0,0 +1,239 @@
+use anyhow::Result;
+use clap::{Args, ValueEnum};
+use comfy_table::Cell;
+use std::path::PathBuf;
+
+use crate::core::links::LinkIndex;
+use crate::init::ZrtConfig;
+use crate::output::{scan, table};
+use crate::progress::{FolderProgress, progress_tree};
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser, Debug)]
+    struct TestArgs {
+        #[command(flatten)]
+        progress: ProgressArgs,
+    }
+
+    #[test]
+    fn test_should_default_to_summary() {
+        // REQ-PROG-003
+
+        // Given / When
+        let args = TestArgs::parse_from(["program"]);
+
+        // Then
+        assert!(!args.progress.tree);
+        assert!(args.progress.depth.is_none());
+        assert_eq!(args.progress.by, ProgressBy::Tags);
+    }
+
+    #[test]
+    fn test_should_accept_tasks_mode_scoped_to_a_tag() {
+        // REQ-PROG-005, REQ-PROG-006
+
+        // Given / When
+        let args = TestArgs::parse_from(["program", "--by", "tasks", "--tag", "to_refactor"]);
+
+        // Then
+        assert_eq!(args.progress.by, ProgressBy::Tasks);
+        assert_eq!(args.progress.tag.as_deref(), Some("to_refactor"));
+    }
+
+    #[test]
+    fn test_should_summarize_tasks() {
+        // REQ-PROG-005
+
+        // Given
+        let folder = FolderProgress {
+            files: 2,
+            tasks: crate::core::tasks::TaskCounts { open: 1, closed: 3 },
+            ..FolderProgress::default()
+        };
+
+        // When / Then
+        assert_eq!(
+            summary(&folder, ProgressBy::Tasks),
+            "75.00% (3 closed, 1 open tasks, 2 files)"
+        );
+    }
+
+    #[test]
+    fn test_should_accept_tree_with_depth() {
+        // REQ-PROG-003
+
+        // Given / When
+        let args = TestArgs::parse_from(["program", "--tree", "--depth", "2"]);
+
+        // Then
+        assert!(args.progress.tree);
+        assert_eq!(args.progress.depth, Some(2));
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+#[derive(Args, Debug)]
+pub struct ProgressArgs {
+    /// Directories to scan (space-separated, defaults to current directory)
+    #[arg(short = 'd', long = "dir", num_args = 0.., default_values = &["."])]
+    pub directories: Vec<PathBuf>,
+
+    /// Directories to exclude (space-separated)
+    #[arg(short, long, num_args = 0..)]
+    pub exclude: Vec<String>,
+
+    /// Show the folder tree annotated with each folder's progress
+    #[arg(long)]
+    pub tree: bool,
+
+    /// Maximum folder depth shown with --tree
+    #[arg(long)]
+    pub depth: Option<usize>,
+
+    /// Measure progress by refactor tags or by checked task checkboxes
+    #[arg(long, value_enum, default_value_t = ProgressBy::Tags)]
+    pub by: ProgressBy,
+
+    /// Only count notes carrying this tag
+    #[arg(long)]
+    pub tag: Option<String>,
+}
+
+/// What the done percentage is computed from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ProgressBy {
+    /// Words in notes carrying the done tag
+    Tags,
+    /// Checked `- [x]` task items against all task items
+    Tasks,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+pub fn run(args: ProgressArgs, config: &ZrtConfig) -> Result<()> {
+    let exclude_dirs: Vec<&str> = args.exclude.iter().map(String::as_str).collect();
+
+    for dir in &args.directories {
+        let absolute_dir = if dir.is_absolute() {
+            dir.clone()
+        } else {
+            std::env::current_dir()?.join(dir)
+        };
+
+        let mut index = LinkIndex::build_in(&scan::scanner(
+            std::slice::from_ref(&absolute_dir),
+            &exclude_dirs,
+        )?)?;
+        if let Some(tag) = &args.tag {
+            index.notes.retain(|note| note.tags.contains(tag));
+        }
+        let tree = progress_tree(
+            &absolute_dir,
+            &index.notes,
+            &config.refactor.done_tag,
+            &config.refactor.todo_tag,
+        );
+
+        let mut rows = vec![(format!("{}/", dir.display()), &tree)];
+        if args.tree {
+            tree_rows(&tree, "", 1, args.depth, &mut rows);
+        }
+
+        if table::is_tty() {
+            println!("{}", render_table(&rows, args.by));
+        } else {
+            for (label, folder) in rows {
+                println!("{label} {}", summary(folder, args.by));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn render_table(rows: &[(String, &FolderProgress)], by: ProgressBy) -> comfy_table::Table {
+    let mut out = match by {
+        ProgressBy::Tags => table::table(["folder", "done%", "done", "todo", "files"]),
+        ProgressBy::Tasks => table::table(["folder", "done%", "closed", "open", "files"]),
+    };
+    for (label, folder) in rows {
+        out.add_row(match by {
+            ProgressBy::Tags => vec![
+                Cell::new(label),
+                table::percentage(folder.percentage()),
+                table::done(folder.done_files),
+                table::todo(folder.todo_files),
+                table::number(folder.files),
+            ],
+            ProgressBy::Tasks => vec![
+                Cell::new(label),
+                table::percentage(folder.task_percentage()),
+                table::done(folder.tasks.closed),
+                table::todo(folder.tasks.open),
+                table::number(folder.files),
+            ],
+        });
+    }
+    out
+}
+
+fn summary(folder: &FolderProgress, by: ProgressBy) -> String {
+    match by {
+        ProgressBy::Tags => format!(
+            "{:.2}% ({} done, {} todo, {} files)",
+            folder.percentage(),
+            folder.done_files,
+            folder.todo_files,
+            folder.files
+        ),
+        ProgressBy::Tasks => format!(
+            "{:.2}% ({} closed, {} open tasks, {} files)",
+            folder.task_percentage(),
+            folder.tasks.closed,
+            folder.tasks.open,
+            folder.files
+        ),
+    }
+}
+
+fn tree_rows<'a>(
+    folder: &'a FolderProgress,
+    prefix: &str,
+    depth: usize,
+    max_depth: Option<usize>,
+    rows: &mut Vec<(String, &'a FolderProgress)>,
+) {
+    if max_depth.is_some_and(|max| depth > max) {
+        return;
+    }
+
+    let count = folder.children.len();
+    for (i, (name, child)) in folder.children.iter().enumerate() {
+        let last = i + 1 == count;
+        let (branch, indent) = if last {
+            ("└── ", "    ")
+        } else {
+            ("├── ", "│   ")
+        };
+        rows.push((format!("{prefix}{branch}{name}/"), child));
+        tree_rows(
+            child,
+            &format!("{prefix}{indent}"),
+            depth + 1,
+            max_depth,
+            rows,
+        );
+    }
+}
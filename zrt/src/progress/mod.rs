@@ -0,0 +1,178 @@
+pub mod cli;
+
+use std::collections::BTreeMap;
+use std::path::{Component, Path};
+
+use crate::core::history::percentage;
+use crate::core::links::NoteNode;
+use crate::core::status::RefactorStatus;
+use crate::core::tasks::TaskCounts;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::links::note_from_content;
+
+    fn notes(notes: &[(&str, &str)]) -> Vec<NoteNode> {
+        notes
+            .iter()
+            .map(|(path, content)| note_from_content(Path::new(path), content))
+            .collect()
+    }
+
+    #[test]
+    fn test_should_count_progress_for_whole_vault() {
+        // REQ-PROG-001
+
+        // Given
+        let notes = notes(&[
+            ("/v/a.md", "---\ntags: [refactored]\n---\none two three"),
+            ("/v/b.md", "---\ntags: [to_refactor]\n---\none"),
+        ]);
+
+        // When
+        let tree = progress_tree(Path::new("/v"), &notes, "refactored", "to_refactor");
+
+        // Then
+        assert_eq!(tree.files, 2);
+        assert_eq!(tree.done_files, 1);
+        assert_eq!(tree.todo_files, 1);
+        assert_eq!(tree.percentage(), 75.0);
+        assert!(tree.children.is_empty());
+    }
+
+    #[test]
+    fn test_should_aggregate_progress_per_folder() {
+        // REQ-PROG-002
+
+        // Given
+        let notes = notes(&[
+            ("/v/top.md", "one"),
+            ("/v/rust/a.md", "---\ntags: [refactored]\n---\none"),
+            ("/v/rust/async/b.md", "---\ntags: [to_refactor]\n---\none"),
+            ("/v/writing/c.md", "---\ntags: [refactored]\n---\none"),
+        ]);
+
+        // When
+        let tree = progress_tree(Path::new("/v"), &notes, "refactored", "to_refactor");
+
+        // Then
+        assert_eq!(tree.files, 4);
+        let names: Vec<&String> = tree.children.keys().collect();
+        assert_eq!(names, vec!["rust", "writing"]);
+        let rust = &tree.children["rust"];
+        assert_eq!(rust.files, 2);
+        assert_eq!(rust.done_files, 1);
+        assert_eq!(rust.percentage(), 50.0);
+        assert_eq!(rust.children["async"].todo_files, 1);
+        assert_eq!(tree.children["writing"].percentage(), 100.0);
+    }
+
+    #[test]
+    fn test_should_aggregate_tasks_per_folder() {
+        // REQ-PROG-005
+
+        // Given notes tracking their work as checkboxes
+        let notes = notes(&[
+            ("/v/a.md", "- [x] one\n- [ ] two"),
+            ("/v/rust/b.md", "- [x] three\n- [x] four\n- [ ] five"),
+            ("/v/rust/c.md", "no tasks"),
+        ]);
+
+        // When
+        let tree = progress_tree(Path::new("/v"), &notes, "refactored", "to_refactor");
+
+        // Then the checked share is computed from the tasks alone
+        assert_eq!(tree.tasks, TaskCounts { open: 2, closed: 3 });
+        assert_eq!(tree.task_percentage(), 60.0);
+        assert_eq!(tree.children["rust"].task_percentage(), 66.67);
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// Refactoring progress of a folder, including everything below it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FolderProgress {
+    pub files: usize,
+    pub words: usize,
+    pub done_files: usize,
+    pub done_words: usize,
+    pub todo_files: usize,
+    /// Task checkboxes in every note, whatever its tags
+    pub tasks: TaskCounts,
+    /// Subfolders containing notes, by name
+    pub children: BTreeMap<String, FolderProgress>,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+impl FolderProgress {
+    /// Percentage of words in done notes, rounded to 2 decimal places.
+    #[inline]
+    #[must_use]
+    pub fn percentage(&self) -> f64 {
+        percentage(self.done_words, self.words)
+    }
+
+    /// Percentage of task checkboxes that are checked, rounded to 2 decimal places.
+    #[inline]
+    #[must_use]
+    pub fn task_percentage(&self) -> f64 {
+        self.tasks.percentage()
+    }
+
+    fn add(&mut self, note: &NoteNode, status: RefactorStatus) {
+        self.files += 1;
+        self.words += note.words;
+        self.tasks += note.tasks;
+        match status {
+            RefactorStatus::Done => {
+                self.done_files += 1;
+                self.done_words += note.words;
+            }
+            RefactorStatus::Todo => self.todo_files += 1,
+            RefactorStatus::Untracked => {}
+        }
+    }
+}
+
+/// Build the folder tree under `root` with progress counts for every folder.
+/// Notes outside `root` only count towards the root itself.
+pub fn progress_tree(
+    root: &Path,
+    notes: &[NoteNode],
+    done_tag: &str,
+    todo_tag: &str,
+) -> FolderProgress {
+    let mut tree = FolderProgress::default();
+
+    for note in notes {
+        let status = RefactorStatus::of(&note.tags, done_tag, todo_tag);
+        tree.add(note, status);
+
+        let Some(folder) = note.path.strip_prefix(root).ok().and_then(Path::parent) else {
+            continue;
+        };
+
+        let mut node = &mut tree;
+        for component in folder.components() {
+            if let Component::Normal(name) = component {
+                node = node
+                    .children
+                    .entry(name.to_string_lossy().into_owned())
+                    .or_default();
+                node.add(note, status);
+            }
+        }
+    }
+
+    tree
+}
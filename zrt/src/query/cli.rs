@@ -0,0 +1,289 @@
+use anyhow::Result;
+use clap::Args;
+use serde_json::{Map, Value as Json};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use crate::core::scanner::ScanOptions;
+use crate::init::ZrtConfig;
+use crate::output::csv;
+use crate::output::exec::ExecArgs;
+use crate::output::exit::NoMatches;
+use crate::output::files_from::FilesFromArgs;
+use crate::output::format::JsonFormat;
+use crate::output::open::OpenArgs;
+use crate::output::scan;
+use crate::output::titles::TitleArgs;
+use crate::query::{Field, Query, QueryRow, run_query};
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::Value;
+    use clap::Parser;
+    use serde_json::json;
+
+    #[derive(Parser, Debug)]
+    struct TestArgs {
+        #[command(flatten)]
+        query: QueryArgs,
+    }
+
+    #[test]
+    fn test_should_parse_query_argument() -> Result<()> {
+        // REQ-QUERY-001
+        let args = TestArgs::parse_from(["program", "FROM #idea LIMIT 5", "--format", "csv"]);
+        let words = TestArgs::parse_from(["program", "FROM", "#idea", "WHERE", "words", ">", "1"]);
+        let invalid = TestArgs::parse_from(["program", "LIMIT", "five"]);
+
+        let limit = args.query.query().transpose()?.map(|query| query.limit);
+        assert_eq!(limit, Some(Some(5)));
+        assert_eq!(args.query.format, Some(QueryFormat::Csv));
+        assert_eq!(
+            words.query.query().transpose()?,
+            Some(
+                "FROM #idea WHERE words > 1"
+                    .parse()
+                    .map_err(anyhow::Error::msg)?
+            )
+        );
+        assert_eq!(
+            invalid
+                .query
+                .query()
+                .map(|query| query.err().map(|e| e.to_string())),
+            Some(Some(
+                "Invalid query: expected a number, found 'five'".to_owned()
+            ))
+        );
+        assert!(TestArgs::try_parse_from(["program", "", "--format", "yaml"]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_run_saved_queries_by_name() -> Result<()> {
+        // REQ-QUERY-008
+        let args = TestArgs::parse_from(["program", "--name", "backlog"]);
+        let mut config = ZrtConfig::default();
+        config.queries.insert(
+            "backlog".to_owned(),
+            "FROM #to_refactor AND -#draft".to_owned(),
+        );
+        config
+            .queries
+            .insert("broken".to_owned(), "WHERE words >".to_owned());
+
+        assert_eq!(args.query.name.as_deref(), Some("backlog"));
+        assert!(saved_query(&config, "backlog")?.from.is_some());
+        assert_eq!(
+            saved_query(&config, "broken").err().map(|e| e.to_string()),
+            Some(
+                "Invalid query broken in .zrt/config.toml: expected a value, found the end of the query"
+                    .to_owned()
+            )
+        );
+        assert!(saved_query(&config, "missing").is_err());
+        assert!(TestArgs::try_parse_from(["program"]).is_err());
+        assert!(TestArgs::try_parse_from(["program", "LIMIT 1", "--name", "backlog"]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_run_saved_queries_with_bare_tags() -> Result<()> {
+        // REQ-QUERY-009
+        let dir = tempfile::TempDir::new()?;
+        let config_path = dir.path().join("config.toml");
+        std::fs::write(
+            &config_path,
+            "[queries]\nbacklog = \"to_refactor AND NOT draft\"\n",
+        )?;
+        std::fs::write(dir.path().join("a.md"), "---\ntags: [to_refactor]\n---\n")?;
+        std::fs::write(
+            dir.path().join("b.md"),
+            "---\ntags: [to_refactor, draft]\n---\n",
+        )?;
+        std::fs::write(dir.path().join("c.md"), "---\ntags: [draft]\n---\n")?;
+        let config = ZrtConfig::load_from_file(&config_path)?;
+
+        let query = saved_query(&config, "backlog")?;
+        let rows = run_query(&ScanOptions::new([dir.path()]).build()?, &query)?;
+
+        assert_eq!(
+            rows.iter().map(|row| row.path.clone()).collect::<Vec<_>>(),
+            vec![dir.path().join("a.md")]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_render_rows_as_json_objects() {
+        // REQ-QUERY-007
+        let columns = [Field::Path, Field::Words, Field::Tags, Field::Due];
+        let rows = [QueryRow {
+            path: PathBuf::from("a.md"),
+            values: vec![
+                Value::Text("a.md".to_owned()),
+                Value::Number(12.0),
+                Value::List(vec!["idea".to_owned()]),
+                Value::Missing,
+            ],
+        }];
+
+        assert_eq!(
+            json_rows(&columns, &rows),
+            json!([{"path": "a.md", "words": 12, "tags": ["idea"], "due": null}])
+        );
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// Output format of `zrt query`, which lists paths without one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueryFormat {
+    /// Comma-separated values with a header row
+    Csv,
+    /// An array of objects keyed by column, see `zrt schema query`
+    Json(JsonFormat),
+}
+
+#[derive(Args, Debug)]
+pub struct QueryArgs {
+    /// The query: [FROM #tag|"folder"] [WHERE condition] [SORT field [ASC|DESC], ...] [LIMIT n],
+    /// e.g. "FROM #to_refactor WHERE words > 800 SORT modified DESC LIMIT 20". Several words
+    /// are joined with spaces; quote the query when it uses `#`, `-`, `<`, `>` or parentheses
+    #[arg(required_unless_present = "name", conflicts_with = "name", num_args = 1..)]
+    pub query: Vec<String>,
+
+    /// Run the query saved under NAME in the `[queries]` table of .zrt/config.toml
+    #[arg(long, value_name = "NAME")]
+    pub name: Option<String>,
+
+    /// Directories to scan (space-separated, defaults to current directory)
+    #[arg(short = 'd', long = "dir", num_args = 0.., default_values = &["."])]
+    pub directories: Vec<PathBuf>,
+
+    /// Directories to exclude (space-separated)
+    #[arg(short, long, num_args = 0..)]
+    pub exclude: Vec<String>,
+
+    /// Print the path and every field the query uses: csv, json, or
+    /// json@VERSION to pin the JSON shape
+    #[arg(long)]
+    pub format: Option<QueryFormat>,
+
+    #[command(flatten)]
+    pub files_from: FilesFromArgs,
+
+    #[command(flatten)]
+    pub titles: TitleArgs,
+
+    #[command(flatten)]
+    pub open: OpenArgs,
+
+    #[command(flatten)]
+    pub exec: ExecArgs,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+impl FromStr for QueryFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "csv" {
+            Ok(Self::Csv)
+        } else {
+            s.parse().map(Self::Json)
+        }
+    }
+}
+
+impl QueryArgs {
+    /// The query given on the command line, its words joined with spaces.
+    fn query(&self) -> Option<Result<Query>> {
+        (!self.query.is_empty()).then(|| {
+            self.query
+                .join(" ")
+                .parse()
+                .map_err(|error| anyhow::anyhow!("Invalid query: {error}"))
+        })
+    }
+}
+
+pub fn run(args: QueryArgs, config: &ZrtConfig) -> Result<()> {
+    let exclude_dirs: Vec<&str> = args.exclude.iter().map(String::as_str).collect();
+
+    let options = args
+        .files_from
+        .apply(ScanOptions::new(&args.directories).exclude(&exclude_dirs))?;
+    let query = match (args.query(), &args.name) {
+        (Some(query), _) => query?,
+        (None, Some(name)) => saved_query(config, name)?,
+        (None, None) => anyhow::bail!("Give a query or the --name of a saved one"),
+    };
+    let scanner = scan::attach(options).build()?;
+    let rows = run_query(&scanner, &query)?;
+    let columns = query.columns();
+
+    match args.format {
+        None => {
+            for row in &rows {
+                println!("{}", args.titles.label(&row.path));
+            }
+        }
+        Some(QueryFormat::Csv) => {
+            println!("{}", csv::record(columns.iter().map(Field::name)));
+            for row in &rows {
+                println!(
+                    "{}",
+                    csv::record(row.values.iter().map(ToString::to_string))
+                );
+            }
+        }
+        Some(QueryFormat::Json(format)) => {
+            println!("{}", format.render("query", &json_rows(&columns, &rows))?);
+        }
+    }
+
+    let paths: Vec<&PathBuf> = rows.iter().map(|row| &row.path).collect();
+    args.exec.run(&paths)?;
+    args.open.open(&paths)?;
+
+    if rows.is_empty() {
+        return Err(NoMatches.into());
+    }
+    Ok(())
+}
+
+/// The query saved under `name`, parsed.
+fn saved_query(config: &ZrtConfig, name: &str) -> Result<Query> {
+    config
+        .query(name)?
+        .parse()
+        .map_err(|error| anyhow::anyhow!("Invalid query {name} in .zrt/config.toml: {error}"))
+}
+
+/// Each row as an object from column name to value.
+fn json_rows(columns: &[Field], rows: &[QueryRow]) -> Json {
+    Json::Array(
+        rows.iter()
+            .map(|row| {
+                Json::Object(
+                    columns
+                        .iter()
+                        .zip(&row.values)
+                        .map(|(field, value)| (field.name().to_owned(), value.to_json()))
+                        .collect::<Map<_, _>>(),
+                )
+            })
+            .collect(),
+    )
+}
@@ -0,0 +1,962 @@
+pub mod cli;
+
+use anyhow::Result;
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime};
+use std::cmp::Ordering;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::core::note::NoteAnalysis;
+use crate::core::scanner::Scanner;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::scanner::ScanOptions;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn parse(query: &str) -> Query {
+        query.parse().unwrap_or_default()
+    }
+
+    fn note(path: &str, content: &str) -> QueryNote {
+        QueryNote::new(
+            NoteAnalysis::from_content(Path::new(path), content.to_owned()),
+            &[PathBuf::from("vault")],
+            None,
+        )
+    }
+
+    fn matches(query: &str, note: &QueryNote) -> bool {
+        parse(query).matches(note)
+    }
+
+    #[test]
+    fn test_should_parse_clauses() {
+        // REQ-QUERY-001
+        assert_eq!(
+            parse("from #to_refactor where words > 800 sort modified desc, title limit 20"),
+            Query {
+                from: Some(Expr::Tag("to_refactor".to_owned())),
+                filter: Some(Expr::Compare(Field::Words, Op::Gt, Literal::Number(800.0))),
+                sort: vec![
+                    SortKey {
+                        field: Field::Modified,
+                        descending: true,
+                    },
+                    SortKey {
+                        field: Field::Title,
+                        descending: false,
+                    },
+                ],
+                limit: Some(20),
+            }
+        );
+        assert_eq!(
+            parse("FROM -#done AND (\"inbox\" OR #idea)").from,
+            Some(Expr::And(
+                Box::new(Expr::Not(Box::new(Expr::Tag("done".to_owned())))),
+                Box::new(Expr::Or(
+                    Box::new(Expr::Folder(PathBuf::from("inbox"))),
+                    Box::new(Expr::Tag("idea".to_owned())),
+                )),
+            ))
+        );
+        assert_eq!(
+            parse("WHERE status != 'done' AND NOT due").filter,
+            Some(Expr::And(
+                Box::new(Expr::Compare(
+                    Field::Property("status".to_owned()),
+                    Op::Ne,
+                    Literal::Text("done".to_owned()),
+                )),
+                Box::new(Expr::Not(Box::new(Expr::Present(Field::Due)))),
+            ))
+        );
+        assert_eq!(
+            parse("to_refactor AND NOT draft OR -idea LIMIT 3"),
+            Query {
+                from: Some(Expr::Or(
+                    Box::new(Expr::And(
+                        Box::new(Expr::Tag("to_refactor".to_owned())),
+                        Box::new(Expr::Not(Box::new(Expr::Tag("draft".to_owned())))),
+                    )),
+                    Box::new(Expr::Not(Box::new(Expr::Tag("idea".to_owned())))),
+                )),
+                limit: Some(3),
+                ..Query::default()
+            }
+        );
+        assert_eq!(parse("FROM #idea"), parse("idea"));
+        assert_eq!(parse(""), Query::default());
+    }
+
+    #[test]
+    fn test_should_reject_malformed_queries() {
+        // REQ-QUERY-002
+        let error = |query: &str| query.parse::<Query>().err().unwrap_or_default();
+
+        assert_eq!(
+            error("FROM WHERE words > 1"),
+            "expected a tag or \"folder\", found 'WHERE'"
+        );
+        assert_eq!(
+            error("to_refactor AND"),
+            "expected a tag or \"folder\", found the end of the query"
+        );
+        assert_eq!(
+            error("WHERE words >"),
+            "expected a value, found the end of the query"
+        );
+        assert_eq!(
+            error("WHERE (words > 1"),
+            "expected ')', found the end of the query"
+        );
+        assert_eq!(error("LIMIT ten"), "expected a number, found 'ten'");
+        assert_eq!(error("SORT words LIMIT 5 FROM #a"), "unexpected 'FROM'");
+        assert_eq!(error("WHERE title = 'open"), "unterminated string 'open");
+    }
+
+    #[test]
+    fn test_should_match_tags_and_folders() {
+        // REQ-QUERY-003
+        let nested = note("vault/inbox/a.md", "---\ntags: [project/zrt]\n---\n");
+        let other = note("vault/archive/b.md", "---\ntags: [projects]\n---\n");
+
+        assert!(matches("FROM #project", &nested));
+        assert!(!matches("FROM #project", &other));
+        assert!(matches("FROM \"inbox\"", &nested));
+        assert!(!matches("FROM \"inbox/\" OR #project/zrt", &other));
+        assert!(matches("FROM -\"inbox\" AND #projects", &other));
+        assert!(matches("", &other));
+    }
+
+    #[test]
+    fn test_should_compare_fields_with_values() {
+        // REQ-QUERY-004
+        let a = note(
+            "vault/a.md",
+            "---\ntitle: Alpha\ntags: [idea, draft]\nstatus: draft\nrating: '4'\ncreated: 2024-01-31\n---\none two three",
+        );
+
+        assert!(matches("WHERE words >= 3 AND words < 4", &a));
+        assert!(matches("WHERE rating > 3.5", &a));
+        assert!(matches(
+            "WHERE created > 2024-01-01 AND created <= '2024-01-31'",
+            &a
+        ));
+        assert!(matches("WHERE tags = draft AND tags != done", &a));
+        assert!(matches("WHERE tags CONTAINS #idea", &a));
+        assert!(matches("WHERE title contains alp AND name = a", &a));
+        assert!(matches("WHERE status = draft OR status = done", &a));
+        assert!(!matches("WHERE status > 3", &a));
+        assert!(matches(
+            "WHERE due != 2024-01-01 AND NOT due AND aliases != x",
+            &a
+        ));
+        assert!(!matches("WHERE due < 2030-01-01", &a));
+        assert!(matches("WHERE title AND status", &a));
+    }
+
+    #[test]
+    fn test_should_sort_limit_and_list_columns() {
+        // REQ-QUERY-005
+        let query = parse("FROM #a WHERE status SORT rating DESC, words LIMIT 3");
+        let row = |path: &str, rating: Value, words: f64| QueryRow {
+            path: PathBuf::from(path),
+            values: vec![
+                Value::Text(path.to_owned()),
+                Value::Text("todo".to_owned()),
+                rating,
+                Value::Number(words),
+            ],
+        };
+        let mut rows = vec![
+            row("a.md", Value::Missing, 1.0),
+            row("b.md", Value::Text("2".to_owned()), 5.0),
+            row("c.md", Value::Text("10".to_owned()), 9.0),
+            row("d.md", Value::Text("2".to_owned()), 3.0),
+        ];
+
+        query.arrange(&mut rows);
+
+        assert_eq!(
+            query.columns(),
+            vec![
+                Field::Path,
+                Field::Property("status".to_owned()),
+                Field::Property("rating".to_owned()),
+                Field::Words,
+            ]
+        );
+        let paths: Vec<&Path> = rows.iter().map(|row| row.path.as_path()).collect();
+        assert_eq!(paths, ["c.md", "d.md", "b.md"].map(Path::new));
+    }
+
+    #[test]
+    fn test_should_run_query_in_one_scan() -> Result<()> {
+        // REQ-QUERY-006
+        let dir = TempDir::new()?;
+        let inbox = dir.path().join("inbox");
+        fs::create_dir(&inbox)?;
+        fs::write(
+            inbox.join("long.md"),
+            "---\ntags: [to_refactor]\ntitle: Long\n---\none two three four",
+        )?;
+        fs::write(inbox.join("short.md"), "---\ntags: [to_refactor]\n---\none")?;
+        fs::write(
+            dir.path().join("elsewhere.md"),
+            "---\ntags: [to_refactor]\n---\none",
+        )?;
+        fs::write(
+            dir.path().join("done.md"),
+            "---\ntags: [done]\n---\none two three",
+        )?;
+        fs::write(dir.path().join("notes.txt"), "not a note")?;
+        let scanner = ScanOptions::new([dir.path()]).build()?;
+        let query = parse("FROM #to_refactor AND \"inbox\" WHERE words > 0 SORT words DESC");
+
+        let rows = run_query(&scanner, &query)?;
+
+        assert_eq!(
+            rows.iter()
+                .map(|row| row.values.clone())
+                .collect::<Vec<_>>(),
+            vec![
+                vec![
+                    Value::Text(inbox.join("long.md").display().to_string()),
+                    Value::Number(4.0),
+                ],
+                vec![
+                    Value::Text(inbox.join("short.md").display().to_string()),
+                    Value::Number(1.0),
+                ],
+            ]
+        );
+        Ok(())
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// A parsed `zrt query`:
+/// `[[FROM] source] [WHERE condition] [SORT field [ASC|DESC], ...] [LIMIT n]`,
+/// with keywords in any case and the clauses in that order. `FROM` may be
+/// left out when the query starts with its source.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Query {
+    /// Which notes to look at: `#tag` (or a bare `tag`) and `"folder"` joined
+    /// by `AND`, `OR` and `-` (or `NOT`); every note when missing
+    pub from: Option<Expr>,
+    /// Condition the notes must meet
+    pub filter: Option<Expr>,
+    /// Order of the results, by path when empty or tied
+    pub sort: Vec<SortKey>,
+    pub limit: Option<usize>,
+}
+
+/// A condition on a note.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    /// The note has the tag or one nested under it, e.g. `#project` matches
+    /// `project/zrt`
+    Tag(String),
+    /// The note is inside the folder, relative to a scanned directory
+    Folder(PathBuf),
+    /// `field OP value`
+    Compare(Field, Op, Literal),
+    /// The field is set and not empty
+    Present(Field),
+    Not(Box<Self>),
+    And(Box<Self>, Box<Self>),
+    Or(Box<Self>, Box<Self>),
+}
+
+/// A value a query can compare and sort by.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Field {
+    /// The note's path
+    Path,
+    /// The file name without its extension
+    Name,
+    /// Words in the body, excluding frontmatter
+    Words,
+    /// Frontmatter title or first `# heading`
+    Title,
+    Tags,
+    Aliases,
+    /// Frontmatter `created` date or the zettel ID in the file name
+    Created,
+    /// When the file was last modified
+    Modified,
+    /// The `due` (or `deadline`) date
+    Due,
+    /// Any other frontmatter property
+    Property(String),
+}
+
+/// A comparison operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    /// Case-insensitive substring of a text, or item of a list
+    Contains,
+}
+
+/// A value written in a query.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Literal {
+    Number(f64),
+    /// A bare `YYYY-MM-DD`
+    Date(NaiveDate),
+    /// A quoted string, `#tag` or any other bare word
+    Text(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SortKey {
+    pub field: Field,
+    pub descending: bool,
+}
+
+/// A field's value in one note.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Missing,
+    Number(f64),
+    Text(String),
+    Date(NaiveDate),
+    Time(NaiveDateTime),
+    List(Vec<String>),
+}
+
+/// A note as a query sees it.
+#[derive(Debug)]
+pub struct QueryNote {
+    note: NoteAnalysis,
+    /// The path inside whichever scanned directory holds the note
+    relative: PathBuf,
+    modified: Option<NaiveDateTime>,
+}
+
+/// A note matching a query, with the values of [`Query::columns`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryRow {
+    pub path: PathBuf,
+    pub values: Vec<Value>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(String),
+    Quoted(String),
+    Tag(String),
+    Op(Op),
+    Minus,
+    Open,
+    Close,
+    Comma,
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+impl FromStr for Query {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser {
+            tokens: tokenize(s)?,
+            position: 0,
+        };
+        let mut query = Self::default();
+        if parser.keyword("FROM") || parser.peek().is_some_and(|token| !is_clause(token)) {
+            query.from = Some(parser.or(Parser::source)?);
+        }
+        if parser.keyword("WHERE") {
+            query.filter = Some(parser.or(Parser::condition)?);
+        }
+        if parser.keyword("SORT") {
+            loop {
+                let field = parser.field()?;
+                let descending = parser.keyword("DESC");
+                if !descending {
+                    parser.keyword("ASC");
+                }
+                query.sort.push(SortKey { field, descending });
+                if !parser.eat(&Token::Comma) {
+                    break;
+                }
+            }
+        }
+        if parser.keyword("LIMIT") {
+            let token = parser.next();
+            let limit = match &token {
+                Some(Token::Word(word)) => word.parse().ok(),
+                _ => None,
+            };
+            query.limit = Some(limit.ok_or_else(|| expected("a number", token.as_ref()))?);
+        }
+        match parser.next() {
+            Some(token) => Err(format!("unexpected '{token}'")),
+            None => Ok(query),
+        }
+    }
+}
+
+impl Query {
+    /// Whether `note` is in the query's source and meets its condition.
+    #[inline]
+    #[must_use]
+    pub fn matches(&self, note: &QueryNote) -> bool {
+        [&self.from, &self.filter]
+            .into_iter()
+            .flatten()
+            .all(|expr| expr.matches(note))
+    }
+
+    /// The note's path followed by every field the condition and sort
+    /// order use, in the order they appear.
+    #[must_use]
+    pub fn columns(&self) -> Vec<Field> {
+        let mut fields = vec![Field::Path];
+        if let Some(filter) = &self.filter {
+            filter.fields(&mut fields);
+        }
+        for key in &self.sort {
+            if !fields.contains(&key.field) {
+                fields.push(key.field.clone());
+            }
+        }
+        fields
+    }
+
+    /// Sort `rows`, whose values are [`Query::columns`], and keep the first
+    /// `limit`. Missing values sort last either way; ties go by path.
+    pub fn arrange(&self, rows: &mut Vec<QueryRow>) {
+        let columns = self.columns();
+        let keys: Vec<(usize, bool)> = self
+            .sort
+            .iter()
+            .filter_map(|key| {
+                let column = columns.iter().position(|field| *field == key.field)?;
+                Some((column, key.descending))
+            })
+            .collect();
+        rows.sort_by(|a, b| {
+            keys.iter()
+                .map(|&(column, descending)| {
+                    sort_order(&a.values[column], &b.values[column], descending)
+                })
+                .find(|order| order.is_ne())
+                .unwrap_or_else(|| a.path.cmp(&b.path))
+        });
+        rows.truncate(self.limit.unwrap_or(usize::MAX));
+    }
+}
+
+impl Expr {
+    fn matches(&self, note: &QueryNote) -> bool {
+        match self {
+            Self::Tag(tag) => note.note.tags.iter().any(|t| {
+                t.strip_prefix(tag.as_str())
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+            }),
+            Self::Folder(folder) => note.relative.starts_with(folder),
+            Self::Compare(field, op, literal) => compare(&note.value(field), *op, literal),
+            Self::Present(field) => match note.value(field) {
+                Value::Missing => false,
+                Value::Text(text) => !text.trim().is_empty(),
+                Value::List(items) => !items.is_empty(),
+                _ => true,
+            },
+            Self::Not(expr) => !expr.matches(note),
+            Self::And(a, b) => a.matches(note) && b.matches(note),
+            Self::Or(a, b) => a.matches(note) || b.matches(note),
+        }
+    }
+
+    fn fields(&self, fields: &mut Vec<Field>) {
+        match self {
+            Self::Compare(field, ..) | Self::Present(field) => {
+                if !fields.contains(field) {
+                    fields.push(field.clone());
+                }
+            }
+            Self::Not(expr) => expr.fields(fields),
+            Self::And(a, b) | Self::Or(a, b) => {
+                a.fields(fields);
+                b.fields(fields);
+            }
+            Self::Tag(_) | Self::Folder(_) => {}
+        }
+    }
+}
+
+impl Field {
+    #[inline]
+    #[must_use]
+    pub fn parse(name: &str) -> Self {
+        match name {
+            "path" => Self::Path,
+            "name" => Self::Name,
+            "words" => Self::Words,
+            "title" => Self::Title,
+            "tags" => Self::Tags,
+            "aliases" => Self::Aliases,
+            "created" => Self::Created,
+            "modified" => Self::Modified,
+            "due" => Self::Due,
+            other => Self::Property(other.to_owned()),
+        }
+    }
+
+    /// The column header.
+    #[inline]
+    #[must_use]
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Path => "path",
+            Self::Name => "name",
+            Self::Words => "words",
+            Self::Title => "title",
+            Self::Tags => "tags",
+            Self::Aliases => "aliases",
+            Self::Created => "created",
+            Self::Modified => "modified",
+            Self::Due => "due",
+            Self::Property(name) => name,
+        }
+    }
+}
+
+impl Value {
+    /// The value as JSON: lists as arrays and missing values as `null`.
+    #[must_use]
+    pub fn to_json(&self) -> serde_json::Value {
+        match self {
+            Self::Missing => serde_json::Value::Null,
+            Self::Number(number) if number.fract() == 0.0 && *number >= 0.0 => {
+                serde_json::json!(*number as u64)
+            }
+            Self::Number(number) => serde_json::json!(number),
+            Self::List(items) => serde_json::json!(items),
+            other => serde_json::Value::String(other.to_string()),
+        }
+    }
+
+    /// Sort rank of the kinds of values, for comparing mixed kinds.
+    const fn rank(&self) -> u8 {
+        match self {
+            Self::Number(_) => 0,
+            Self::Date(_) | Self::Time(_) => 1,
+            Self::Text(_) => 2,
+            Self::List(_) => 3,
+            Self::Missing => 4,
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    /// Lists are joined with `;` and missing values are empty, as in `zrt extract`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing => Ok(()),
+            Self::Number(number) => write!(f, "{number}"),
+            Self::Text(text) => f.write_str(text),
+            Self::Date(date) => write!(f, "{date}"),
+            Self::Time(time) => write!(f, "{}", time.format("%Y-%m-%d %H:%M:%S")),
+            Self::List(items) => f.write_str(&items.join(";")),
+        }
+    }
+}
+
+impl QueryNote {
+    /// Wrap an analyzed note, looking up its modification time through
+    /// `scanner` only if one is given, since no other field needs the
+    /// filesystem.
+    #[must_use]
+    pub fn new(note: NoteAnalysis, roots: &[PathBuf], scanner: Option<&Scanner>) -> Self {
+        let relative = roots
+            .iter()
+            .find_map(|root| note.path.strip_prefix(root).ok())
+            .unwrap_or(&note.path)
+            .to_path_buf();
+        let modified = scanner
+            .and_then(|scanner| scanner.metadata(&note.path).ok()?.modified)
+            .map(|time| DateTime::<Local>::from(time).naive_local());
+        Self {
+            note,
+            relative,
+            modified,
+        }
+    }
+
+    /// The note's value of `field`.
+    #[must_use]
+    pub fn value(&self, field: &Field) -> Value {
+        let text = |text: Option<String>| text.map_or(Value::Missing, Value::Text);
+        let frontmatter = &self.note.frontmatter;
+        match field {
+            Field::Path => Value::Text(self.note.path.display().to_string()),
+            Field::Name => text(
+                self.note
+                    .path
+                    .file_stem()
+                    .map(|stem| stem.to_string_lossy().into_owned()),
+            ),
+            Field::Words => Value::Number(self.note.words as f64),
+            Field::Title => text(self.note.title()),
+            Field::Tags => Value::List(self.note.tags.clone()),
+            Field::Aliases => Value::List(frontmatter.aliases.clone()),
+            Field::Created => self.note.created().map_or(Value::Missing, Value::Date),
+            Field::Modified => self.modified.map_or(Value::Missing, Value::Time),
+            Field::Due => frontmatter.due().map_or(Value::Missing, Value::Date),
+            Field::Property(name) => frontmatter
+                .property(name)
+                .map(Value::Text)
+                .or_else(|| frontmatter.property_list(name).map(Value::List))
+                .unwrap_or(Value::Missing),
+        }
+    }
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn eat(&mut self, token: &Token) -> bool {
+        let found = self.peek() == Some(token);
+        if found {
+            self.position += 1;
+        }
+        found
+    }
+
+    fn keyword(&mut self, keyword: &str) -> bool {
+        let found =
+            matches!(self.peek(), Some(Token::Word(word)) if word.eq_ignore_ascii_case(keyword));
+        if found {
+            self.position += 1;
+        }
+        found
+    }
+
+    /// `and (OR and)*`, with `atom` parsing what the operators join.
+    fn or(&mut self, atom: fn(&mut Self) -> Result<Expr, String>) -> Result<Expr, String> {
+        let mut expr = self.and(atom)?;
+        while self.keyword("OR") {
+            expr = Expr::Or(Box::new(expr), Box::new(self.and(atom)?));
+        }
+        Ok(expr)
+    }
+
+    fn and(&mut self, atom: fn(&mut Self) -> Result<Expr, String>) -> Result<Expr, String> {
+        let mut expr = self.unary(atom)?;
+        while self.keyword("AND") {
+            expr = Expr::And(Box::new(expr), Box::new(self.unary(atom)?));
+        }
+        Ok(expr)
+    }
+
+    fn unary(&mut self, atom: fn(&mut Self) -> Result<Expr, String>) -> Result<Expr, String> {
+        if self.keyword("NOT") || self.eat(&Token::Minus) {
+            return Ok(Expr::Not(Box::new(self.unary(atom)?)));
+        }
+        if self.eat(&Token::Open) {
+            let expr = self.or(atom)?;
+            return match self.next() {
+                Some(Token::Close) => Ok(expr),
+                other => Err(expected("')'", other.as_ref())),
+            };
+        }
+        atom(self)
+    }
+
+    fn source(&mut self) -> Result<Expr, String> {
+        match self.next() {
+            Some(Token::Tag(tag)) => Ok(Expr::Tag(tag)),
+            Some(Token::Word(word)) if !KEYWORDS.iter().any(|k| word.eq_ignore_ascii_case(k)) => {
+                Ok(match word.strip_prefix('-') {
+                    Some(tag) if !tag.is_empty() => Expr::Not(Box::new(Expr::Tag(tag.to_owned()))),
+                    _ => Expr::Tag(word),
+                })
+            }
+            Some(Token::Quoted(folder)) => Ok(Expr::Folder(PathBuf::from(
+                folder.trim_start_matches("./").trim_matches('/'),
+            ))),
+            other => Err(expected("a tag or \"folder\"", other.as_ref())),
+        }
+    }
+
+    fn condition(&mut self) -> Result<Expr, String> {
+        if let Some(Token::Tag(tag)) = self.peek() {
+            let tag = tag.clone();
+            self.position += 1;
+            return Ok(Expr::Tag(tag));
+        }
+        let field = self.field()?;
+        let op = match self.peek() {
+            Some(Token::Op(op)) => *op,
+            Some(Token::Word(word)) if word.eq_ignore_ascii_case("CONTAINS") => Op::Contains,
+            _ => return Ok(Expr::Present(field)),
+        };
+        self.position += 1;
+        let literal = match self.next() {
+            Some(Token::Word(word)) => literal(&word),
+            Some(Token::Quoted(text) | Token::Tag(text)) => Literal::Text(text),
+            other => return Err(expected("a value", other.as_ref())),
+        };
+        Ok(Expr::Compare(field, op, literal))
+    }
+
+    fn field(&mut self) -> Result<Field, String> {
+        match self.next() {
+            Some(Token::Word(word)) => Ok(Field::parse(&word)),
+            other => Err(expected("a field", other.as_ref())),
+        }
+    }
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Word(word) => f.write_str(word),
+            Self::Quoted(text) => write!(f, "\"{text}\""),
+            Self::Tag(tag) => write!(f, "#{tag}"),
+            Self::Op(op) => f.write_str(match op {
+                Op::Eq => "=",
+                Op::Ne => "!=",
+                Op::Lt => "<",
+                Op::Le => "<=",
+                Op::Gt => ">",
+                Op::Ge => ">=",
+                Op::Contains => "CONTAINS",
+            }),
+            Self::Minus => f.write_str("-"),
+            Self::Open => f.write_str("("),
+            Self::Close => f.write_str(")"),
+            Self::Comma => f.write_str(","),
+        }
+    }
+}
+
+/// Words that cannot be bare tags.
+const KEYWORDS: [&str; 7] = ["FROM", "WHERE", "SORT", "LIMIT", "AND", "OR", "NOT"];
+
+/// Whether `token` opens the `WHERE`, `SORT` or `LIMIT` clause.
+fn is_clause(token: &Token) -> bool {
+    let Token::Word(word) = token else {
+        return false;
+    };
+    ["WHERE", "SORT", "LIMIT"]
+        .iter()
+        .any(|clause| word.eq_ignore_ascii_case(clause))
+}
+
+fn expected(what: &str, found: Option<&Token>) -> String {
+    match found {
+        Some(token) => format!("expected {what}, found '{token}'"),
+        None => format!("expected {what}, found the end of the query"),
+    }
+}
+
+/// A bare word as a number, a `YYYY-MM-DD` date, or else text.
+fn literal(word: &str) -> Literal {
+    if let Ok(number) = word.parse() {
+        Literal::Number(number)
+    } else if let Ok(date) = NaiveDate::parse_from_str(word, "%Y-%m-%d") {
+        Literal::Date(date)
+    } else {
+        Literal::Text(word.to_owned())
+    }
+}
+
+fn tokenize(query: &str) -> Result<Vec<Token>, String> {
+    const SPECIAL: &[char] = &['(', ')', ',', '=', '!', '<', '>', '"', '\''];
+    let mut tokens = Vec::new();
+    let mut rest = query.trim_start();
+    while let Some(c) = rest.chars().next() {
+        let (token, len) = match c {
+            '(' => (Token::Open, 1),
+            ')' => (Token::Close, 1),
+            ',' => (Token::Comma, 1),
+            '-' if rest[1..].starts_with(['#', '"', '\'', '(']) => (Token::Minus, 1),
+            '"' | '\'' => {
+                let end = rest[1..]
+                    .find(c)
+                    .ok_or_else(|| format!("unterminated string {rest}"))?;
+                (Token::Quoted(rest[1..=end].to_owned()), end + 2)
+            }
+            '!' | '<' | '>' | '=' => {
+                let two = rest.get(..2).unwrap_or_default();
+                match two {
+                    "!=" => (Token::Op(Op::Ne), 2),
+                    "<=" => (Token::Op(Op::Le), 2),
+                    ">=" => (Token::Op(Op::Ge), 2),
+                    "==" => (Token::Op(Op::Eq), 2),
+                    _ => match c {
+                        '<' => (Token::Op(Op::Lt), 1),
+                        '>' => (Token::Op(Op::Gt), 1),
+                        '=' => (Token::Op(Op::Eq), 1),
+                        _ => return Err("expected '=' after '!'".to_owned()),
+                    },
+                }
+            }
+            _ => {
+                let len = rest
+                    .find(|c: char| c.is_whitespace() || SPECIAL.contains(&c))
+                    .unwrap_or(rest.len())
+                    .max(c.len_utf8());
+                let word = &rest[..len];
+                let token = match word.strip_prefix('#') {
+                    Some(tag) if !tag.is_empty() => Token::Tag(tag.to_owned()),
+                    _ => Token::Word(word.to_owned()),
+                };
+                (token, len)
+            }
+        };
+        tokens.push(token);
+        rest = rest[len..].trim_start();
+    }
+    Ok(tokens)
+}
+
+/// Whether `value` meets `op literal`. A list does when any item does, or
+/// for `!=` when none equals the literal; a missing value only meets `!=`.
+fn compare(value: &Value, op: Op, literal: &Literal) -> bool {
+    match (value, op) {
+        (Value::Missing, _) => op == Op::Ne,
+        (Value::List(items), Op::Ne) => !items
+            .iter()
+            .any(|item| order(&Value::Text(item.clone()), literal).is_some_and(Ordering::is_eq)),
+        (Value::List(items), Op::Contains) => {
+            let literal = literal_text(literal);
+            items.contains(&literal)
+        }
+        (Value::List(items), _) => items
+            .iter()
+            .any(|item| compare(&Value::Text(item.clone()), op, literal)),
+        (_, Op::Contains) => value
+            .to_string()
+            .to_lowercase()
+            .contains(&literal_text(literal).to_lowercase()),
+        _ => order(value, literal).is_some_and(|order| match op {
+            Op::Eq => order.is_eq(),
+            Op::Ne => order.is_ne(),
+            Op::Lt => order.is_lt(),
+            Op::Le => order.is_le(),
+            Op::Gt => order.is_gt(),
+            Op::Ge | Op::Contains => order.is_ge(),
+        }),
+    }
+}
+
+/// How a scalar `value` orders against `literal`, converting text to a
+/// number or date to compare it with one; `None` when they cannot be compared.
+fn order(value: &Value, literal: &Literal) -> Option<Ordering> {
+    match (value, literal) {
+        (Value::Number(a), Literal::Number(b)) => a.partial_cmp(b),
+        (Value::Number(a), Literal::Text(b)) => a.partial_cmp(&b.trim().parse().ok()?),
+        (Value::Text(a), Literal::Number(b)) => a.trim().parse::<f64>().ok()?.partial_cmp(b),
+        (Value::Text(a), Literal::Date(b)) => Some(leading_date(a)?.cmp(b)),
+        (Value::Text(a), Literal::Text(b)) => Some(a.as_str().cmp(b)),
+        (Value::Date(a), Literal::Date(b)) => Some(a.cmp(b)),
+        (Value::Date(a), Literal::Text(b)) => Some(a.cmp(&leading_date(b)?)),
+        // Times compare by day, so `modified > 2024-01-31` means after that day
+        (Value::Time(a), Literal::Date(b)) => Some(a.date().cmp(b)),
+        (Value::Time(a), Literal::Text(b)) => Some(a.date().cmp(&leading_date(b)?)),
+        _ => None,
+    }
+}
+
+fn literal_text(literal: &Literal) -> String {
+    match literal {
+        Literal::Number(number) => number.to_string(),
+        Literal::Date(date) => date.to_string(),
+        Literal::Text(text) => text.clone(),
+    }
+}
+
+/// The `YYYY-MM-DD` a text starts with.
+fn leading_date(text: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(text.trim().get(..10)?, "%Y-%m-%d").ok()
+}
+
+/// Order of two values of a column, missing ones last in either direction.
+fn sort_order(a: &Value, b: &Value, descending: bool) -> Ordering {
+    let order = match (a, b) {
+        (Value::Missing, Value::Missing) => return Ordering::Equal,
+        (Value::Missing, _) => return Ordering::Greater,
+        (_, Value::Missing) => return Ordering::Less,
+        (Value::Number(a), Value::Number(b)) => a.total_cmp(b),
+        (Value::Text(a), Value::Text(b)) => match (a.trim().parse::<f64>(), b.trim().parse()) {
+            (Ok(a), Ok(b)) => a.total_cmp(&b),
+            _ => a.to_lowercase().cmp(&b.to_lowercase()),
+        },
+        (Value::Date(a), Value::Date(b)) => a.cmp(b),
+        (Value::Time(a), Value::Time(b)) => a.cmp(b),
+        (Value::Date(a), Value::Time(b)) => a.cmp(&b.date()),
+        (Value::Time(a), Value::Date(b)) => a.date().cmp(b),
+        (Value::List(a), Value::List(b)) => a.cmp(b),
+        _ => a.rank().cmp(&b.rank()),
+    };
+    if descending { order.reverse() } else { order }
+}
+
+/// Evaluate `query` over every markdown note of one scan, with `"folder"`
+/// sources relative to the scanned directories, or to the current directory
+/// when scanning listed files.
+///
+/// # Errors
+///
+/// Returns an error if the scan fails.
+pub fn run_query(scanner: &Scanner, query: &Query) -> Result<Vec<QueryRow>> {
+    let mut roots: Vec<PathBuf> = scanner.roots().map(Path::to_path_buf).collect();
+    if roots.is_empty() {
+        roots.extend(std::env::current_dir().ok());
+    }
+    let columns = query.columns();
+    let modified = columns.contains(&Field::Modified).then_some(scanner);
+    let mut rows = Vec::new();
+    for note in scanner.notes() {
+        let note = note?;
+        if !scanner.is_note(&note.path) {
+            continue;
+        }
+        let note = QueryNote::new(note, &roots, modified);
+        if query.matches(&note) {
+            rows.push(QueryRow {
+                path: note.note.path.clone(),
+                values: columns.iter().map(|field| note.value(field)).collect(),
+            });
+        }
+    }
+    query.arrange(&mut rows);
+    Ok(rows)
+}
@@ -0,0 +1,147 @@
+use anyhow::Result;
+use clap::{Args, Subcommand};
+use std::path::PathBuf;
+
+use crate::init::ZrtConfig;
+use crate::queue::{QUEUE_FILE, Queue, mark_done};
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser, Debug)]
+    struct TestArgs {
+        #[command(flatten)]
+        queue: QueueArgs,
+    }
+
+    #[test]
+    fn test_should_parse_queue_subcommands() {
+        // REQ-QUEUE-004
+        let args = TestArgs::parse_from(["program", "add", "a.md", "b.md", "--first"]).queue;
+        assert_eq!(args.file, PathBuf::from(".zrt/queue.txt"));
+        assert!(matches!(
+            args.command,
+            QueueCommand::Add { ref notes, first: true } if notes.len() == 2
+        ));
+
+        let args = TestArgs::parse_from(["program", "--file", "q.txt", "done"]).queue;
+        assert_eq!(args.file, PathBuf::from("q.txt"));
+        assert!(matches!(args.command, QueueCommand::Done { note: None }));
+        assert!(matches!(
+            TestArgs::parse_from(["program", "pop"]).queue.command,
+            QueueCommand::Pop
+        ));
+        assert!(TestArgs::try_parse_from(["program", "add"]).is_err());
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+#[derive(Args, Debug)]
+pub struct QueueArgs {
+    /// File holding the queue, one note path per line
+    #[arg(long, global = true, default_value = QUEUE_FILE)]
+    pub file: PathBuf,
+
+    #[command(subcommand)]
+    pub command: QueueCommand,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum QueueCommand {
+    /// Queue notes to refactor, last unless --first
+    Add {
+        /// Notes to queue
+        #[arg(required = true)]
+        notes: Vec<PathBuf>,
+
+        /// Put the notes at the front of the queue
+        #[arg(long)]
+        first: bool,
+    },
+
+    /// Print the queued notes in order
+    List,
+
+    /// Take the first note off the queue and print it
+    Pop,
+
+    /// Take a note (default: the first) off the queue and tag it as refactored
+    Done {
+        /// The note to finish
+        note: Option<PathBuf>,
+    },
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+pub fn run(args: QueueArgs, config: &ZrtConfig) -> Result<()> {
+    let mut queue = Queue::load(&args.file)?;
+    match args.command {
+        QueueCommand::Add { notes, first } => {
+            if let Some(missing) = notes.iter().find(|note| !note.is_file()) {
+                anyhow::bail!("No such note: {}", missing.display());
+            }
+            // Added in reverse at the front so they keep the order given
+            let ordered: Vec<&PathBuf> = if first {
+                notes.iter().rev().collect()
+            } else {
+                notes.iter().collect()
+            };
+            for note in ordered {
+                let added = if first {
+                    queue.add_first(note)
+                } else {
+                    queue.add(note)
+                };
+                if !added {
+                    eprintln!("Already queued: {}", note.display());
+                }
+            }
+            queue.save(&args.file)?;
+        }
+        QueueCommand::List => {
+            for note in &queue.notes {
+                println!("{}", note.display());
+            }
+        }
+        QueueCommand::Pop => {
+            let note = queue
+                .pop()
+                .ok_or_else(|| anyhow::anyhow!("The queue is empty"))?;
+            queue.save(&args.file)?;
+            println!("{}", note.display());
+        }
+        QueueCommand::Done { note } => {
+            let note = match note {
+                Some(note) => {
+                    if !queue.remove(&note) {
+                        anyhow::bail!("Not queued: {}", note.display());
+                    }
+                    note
+                }
+                None => queue
+                    .pop()
+                    .ok_or_else(|| anyhow::anyhow!("The queue is empty"))?,
+            };
+            mark_done(&note, &config.refactor.done_tag, &config.refactor.todo_tag)?;
+            queue.save(&args.file)?;
+            println!(
+                "Tagged {} {}; {} note(s) left",
+                note.display(),
+                config.refactor.done_tag,
+                queue.notes.len()
+            );
+        }
+    }
+    Ok(())
+}
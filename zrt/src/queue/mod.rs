@@ -0,0 +1,191 @@
+pub mod cli;
+
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+
+use crate::core::frontmatter;
+use crate::core::links::normalize_path;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_should_keep_notes_in_order_without_duplicates() {
+        // REQ-QUEUE-001
+        let mut queue = Queue::default();
+
+        assert!(queue.add(Path::new("./b.md")));
+        assert!(queue.add(Path::new("a.md")));
+        assert!(!queue.add(Path::new("b.md")));
+        assert!(queue.add_first(Path::new("c.md")));
+        assert!(!queue.add_first(Path::new("a.md")));
+        assert_eq!(
+            queue.notes,
+            vec![
+                PathBuf::from("c.md"),
+                PathBuf::from("b.md"),
+                PathBuf::from("a.md")
+            ]
+        );
+
+        assert_eq!(queue.pop(), Some(PathBuf::from("c.md")));
+        assert!(queue.remove(Path::new("./a.md")));
+        assert!(!queue.remove(Path::new("a.md")));
+        assert_eq!(queue.notes, vec![PathBuf::from("b.md")]);
+    }
+
+    #[test]
+    fn test_should_store_one_path_per_line() -> Result<()> {
+        // REQ-QUEUE-002
+        let dir = TempDir::new()?;
+        let file = dir.path().join(".zrt/queue.txt");
+        assert_eq!(Queue::load(&file)?, Queue::default());
+
+        let mut queue = Queue::default();
+        queue.add(Path::new("notes/a.md"));
+        queue.add(Path::new("b.md"));
+        queue.save(&file)?;
+
+        assert_eq!(fs::read_to_string(&file)?, "notes/a.md\nb.md\n");
+        fs::write(&file, "notes/a.md\n\n  b.md  \n")?;
+        assert_eq!(Queue::load(&file)?, queue);
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_mark_notes_done() -> Result<()> {
+        // REQ-QUEUE-003
+        let dir = TempDir::new()?;
+        let note = dir.path().join("a.md");
+        fs::write(&note, "---\ntitle: A\ntags: [idea, to_refactor]\n---\nBody")?;
+
+        mark_done(&note, "refactored", "to_refactor")?;
+
+        assert_eq!(
+            fs::read_to_string(&note)?,
+            "---\ntitle: A\ntags: [idea, refactored]\n---\nBody"
+        );
+        Ok(())
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// Where `zrt queue` keeps the queue unless told otherwise.
+pub const QUEUE_FILE: &str = ".zrt/queue.txt";
+
+/// Notes to refactor next, in the order they should be done.
+///
+/// Stored as one path per line, so the file also works with `--files-from`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Queue {
+    pub notes: Vec<PathBuf>,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+impl Queue {
+    /// Read the queue from `path`; a missing file is an empty queue.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file exists but cannot be read.
+    #[inline]
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read queue file: {}", path.display()))?;
+        Ok(Self {
+            notes: content
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .map(PathBuf::from)
+                .collect(),
+        })
+    }
+
+    /// Write the queue to `path`, creating its folder if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the folder or file cannot be written.
+    #[inline]
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+        }
+        let content: String = self
+            .notes
+            .iter()
+            .map(|note| format!("{}\n", note.display()))
+            .collect();
+        std::fs::write(path, content)
+            .with_context(|| format!("Failed to write queue file: {}", path.display()))
+    }
+
+    /// Queue `note` last; `false` if it is already queued.
+    #[inline]
+    pub fn add(&mut self, note: &Path) -> bool {
+        let note = normalize_path(note);
+        if self.notes.contains(&note) {
+            return false;
+        }
+        self.notes.push(note);
+        true
+    }
+
+    /// Queue `note` first; `false` if it is already queued.
+    #[inline]
+    pub fn add_first(&mut self, note: &Path) -> bool {
+        let note = normalize_path(note);
+        if self.notes.contains(&note) {
+            return false;
+        }
+        self.notes.insert(0, note);
+        true
+    }
+
+    /// Take `note` off the queue; `false` if it was not queued.
+    #[inline]
+    pub fn remove(&mut self, note: &Path) -> bool {
+        let note = normalize_path(note);
+        let before = self.notes.len();
+        self.notes.retain(|queued| *queued != note);
+        self.notes.len() < before
+    }
+
+    /// Take the first note off the queue.
+    #[inline]
+    pub fn pop(&mut self) -> Option<PathBuf> {
+        (!self.notes.is_empty()).then(|| self.notes.remove(0))
+    }
+}
+
+/// Tag the note at `path` as refactored: add `done_tag` and drop `todo_tag`,
+/// leaving the rest of its frontmatter as written.
+///
+/// # Errors
+///
+/// Returns an error if the note cannot be read or written, or its tags
+/// cannot be parsed.
+pub fn mark_done(path: &Path, done_tag: &str, todo_tag: &str) -> Result<()> {
+    frontmatter::edit(path, |fm| {
+        fm.remove_tag(todo_tag)?;
+        fm.add_tag(done_tag)?;
+        Ok(())
+    })
+}
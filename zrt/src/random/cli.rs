@@ -0,0 +1,122 @@
+use anyhow::Result;
+use clap::Args;
+use rand::SeedableRng as _;
+use rand::rngs::StdRng;
+use std::path::PathBuf;
+
+use crate::core::scanner::ScanOptions;
+use crate::init::ZrtConfig;
+use crate::output::exec::ExecArgs;
+use crate::output::files_from::FilesFromArgs;
+use crate::output::open::OpenArgs;
+use crate::output::scan;
+use crate::output::titles::TitleArgs;
+use crate::random::{Weight, candidates, pick};
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser, Debug)]
+    struct TestArgs {
+        #[command(flatten)]
+        random: RandomArgs,
+    }
+
+    #[test]
+    fn test_should_default_to_uniform_todo_pick() {
+        // REQ-RAND-004
+        let args = TestArgs::parse_from(["program"]);
+
+        assert!(args.random.tag.is_empty());
+        assert_eq!(args.random.weight, Weight::Uniform);
+        assert_eq!(args.random.seed, None);
+    }
+
+    #[test]
+    fn test_should_accept_tags_weight_and_seed() {
+        // REQ-RAND-004
+        let args = TestArgs::parse_from([
+            "program", "--tag", "draft", "wip", "--weight", "words", "--seed", "9",
+        ]);
+
+        assert_eq!(args.random.tag, vec!["draft", "wip"]);
+        assert_eq!(args.random.weight, Weight::Words);
+        assert_eq!(args.random.seed, Some(9));
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+#[derive(Args, Debug)]
+pub struct RandomArgs {
+    /// Directories to scan (space-separated, defaults to current directory)
+    #[arg(short = 'd', long = "dir", num_args = 0.., default_values = &["."])]
+    pub directories: Vec<PathBuf>,
+
+    /// Directories to exclude (space-separated)
+    #[arg(short, long, num_args = 0..)]
+    pub exclude: Vec<String>,
+
+    /// Pick among notes with any of these tags (default: the configured todo tag)
+    #[arg(short, long, num_args = 1..)]
+    pub tag: Vec<String>,
+
+    /// Uniform pick, or weighted toward longer notes
+    #[arg(short, long, value_enum, default_value_t)]
+    pub weight: Weight,
+
+    /// Seed for a repeatable pick
+    #[arg(long)]
+    pub seed: Option<u64>,
+
+    #[command(flatten)]
+    pub files_from: FilesFromArgs,
+
+    #[command(flatten)]
+    pub titles: TitleArgs,
+
+    #[command(flatten)]
+    pub open: OpenArgs,
+
+    #[command(flatten)]
+    pub exec: ExecArgs,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+pub fn run(args: RandomArgs, config: &ZrtConfig) -> Result<()> {
+    let tags = if args.tag.is_empty() {
+        vec![config.refactor.todo_tag.clone()]
+    } else {
+        args.tag.clone()
+    };
+    let tag_refs: Vec<&str> = tags.iter().map(String::as_str).collect();
+    let exclude_dirs: Vec<&str> = args.exclude.iter().map(String::as_str).collect();
+
+    let options = args
+        .files_from
+        .apply(ScanOptions::new(&args.directories).exclude(&exclude_dirs))?;
+    let scanner = scan::attach(options).build()?;
+    let notes = candidates(&scanner, &tag_refs)?;
+
+    let mut rng = match args.seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_os_rng(),
+    };
+    let Some(note) = pick(&notes, args.weight, &mut rng) else {
+        anyhow::bail!("No notes tagged {}", tags.join(" or "));
+    };
+
+    println!("{}", args.titles.label(&note.path));
+    args.exec.run(&[&note.path])?;
+    args.open.open(&[&note.path])
+}
@@ -0,0 +1,154 @@
+pub mod cli;
+
+use anyhow::Result;
+use clap::ValueEnum;
+use rand::Rng;
+use rand::distr::Distribution as _;
+use rand::distr::weighted::WeightedIndex;
+
+use crate::core::scanner::{NoteRecord, Scanner};
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::scanner::ScanOptions;
+    use crate::core::vfs::MemoryFs;
+    use rand::SeedableRng as _;
+    use rand::rngs::StdRng;
+    use std::path::{Path, PathBuf};
+
+    fn note(path: &str, words: usize) -> NoteRecord {
+        NoteRecord {
+            path: PathBuf::from(path),
+            tags: vec!["to_refactor".to_owned()],
+            words,
+            priority: None,
+            title: None,
+            created: None,
+        }
+    }
+
+    #[test]
+    fn test_should_only_pick_tagged_markdown_notes() -> Result<()> {
+        // REQ-RAND-001
+        // Given a vault with one tagged note, one untagged note and a tagged non-note
+        let vfs = MemoryFs::new()
+            .with_file("/vault/todo.md", "---\ntags: [to_refactor]\n---\nbody")
+            .with_file("/vault/done.md", "---\ntags: [refactored]\n---\nbody")
+            .with_file("/vault/todo.txt", "---\ntags: [to_refactor]\n---\nbody");
+        let scanner = ScanOptions::new(["/vault"]).vfs(vfs).build()?;
+
+        // When collecting candidates
+        let notes = candidates(&scanner, &["to_refactor"])?;
+
+        // Then only the tagged markdown note qualifies
+        let paths: Vec<_> = notes.iter().map(|n| n.path.clone()).collect();
+        assert_eq!(paths, vec![PathBuf::from("/vault/todo.md")]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_pick_nothing_from_empty_list() {
+        // REQ-RAND-002
+        let mut rng = StdRng::seed_from_u64(1);
+        assert!(pick(&[], Weight::Uniform, &mut rng).is_none());
+        assert!(pick(&[], Weight::Words, &mut rng).is_none());
+    }
+
+    #[test]
+    fn test_should_repeat_picks_with_the_same_seed() {
+        // REQ-RAND-002
+        let notes: Vec<_> = (0..20).map(|i| note(&format!("{i}.md"), 10)).collect();
+
+        let first = pick(&notes, Weight::Uniform, &mut StdRng::seed_from_u64(7));
+        let second = pick(&notes, Weight::Uniform, &mut StdRng::seed_from_u64(7));
+
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_should_favour_longer_notes_when_weighted() {
+        // REQ-RAND-003
+        // Given a very long note and a very short one
+        let notes = [note("long.md", 10_000), note("short.md", 1)];
+        let mut rng = StdRng::seed_from_u64(42);
+
+        // When picking many times weighted by words
+        let long_picks = (0..200)
+            .filter_map(|_| pick(&notes, Weight::Words, &mut rng))
+            .filter(|n| n.path == Path::new("long.md"))
+            .count();
+
+        // Then the long note wins nearly every time
+        assert!(long_picks > 190, "long note picked {long_picks} times");
+    }
+
+    #[test]
+    fn test_should_still_pick_empty_notes_when_weighted() {
+        // REQ-RAND-003
+        let notes = [note("empty.md", 0)];
+        let mut rng = StdRng::seed_from_u64(3);
+
+        assert_eq!(
+            pick(&notes, Weight::Words, &mut rng).map(|n| n.path.clone()),
+            Some(PathBuf::from("empty.md"))
+        );
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// How likely each candidate note is to be picked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum Weight {
+    /// Every note is equally likely
+    #[default]
+    Uniform,
+    /// Longer notes are proportionally more likely
+    Words,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+/// Markdown notes carrying any of `tags`, or every markdown note when `tags` is empty.
+///
+/// # Errors
+///
+/// Returns an error if the scan fails.
+pub fn candidates(scanner: &Scanner, tags: &[&str]) -> Result<Vec<NoteRecord>> {
+    let mut notes = Vec::new();
+    for record in scanner.iter() {
+        let record = record?;
+        let is_note = scanner.is_note(&record.path);
+        if is_note && (tags.is_empty() || record.has_any_tag(tags)) {
+            notes.push(record);
+        }
+    }
+    Ok(notes)
+}
+
+/// Pick one note at random, or `None` if there are none.
+pub fn pick<'a, R: Rng + ?Sized>(
+    notes: &'a [NoteRecord],
+    weight: Weight,
+    rng: &mut R,
+) -> Option<&'a NoteRecord> {
+    if notes.is_empty() {
+        return None;
+    }
+    let index = match weight {
+        Weight::Uniform => rng.random_range(0..notes.len()),
+        // Empty notes keep a small chance so they are never unreachable.
+        Weight::Words => WeightedIndex::new(notes.iter().map(|n| n.words.max(1)))
+            .ok()?
+            .sample(rng),
+    };
+    notes.get(index)
+}
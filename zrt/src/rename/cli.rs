@@ -0,0 +1,99 @@
+use anyhow::Result;
+use clap::Args;
+use std::path::PathBuf;
+
+use crate::core::links::{apply_relocation, relocate};
+use crate::core::scanner::ScanOptions;
+use crate::init::ZrtConfig;
+use crate::output::{moves, scan};
+use crate::query::Query;
+use crate::rename::{NamePattern, renames};
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser, Debug)]
+    struct TestArgs {
+        #[command(flatten)]
+        rename: RenameArgs,
+    }
+
+    #[test]
+    fn test_should_accept_a_pattern_and_query() -> Result<()> {
+        // REQ-RENAME-004
+        let args = TestArgs::parse_from(["program"]).rename;
+        assert!(args.query.is_none() && args.to.is_none() && !args.dry_run);
+
+        let args =
+            TestArgs::parse_from(["program", "--to", "{id}.md", "FROM #idea", "--dry-run"]).rename;
+        assert_eq!(
+            args.to,
+            Some("{id}.md".parse().map_err(anyhow::Error::msg)?)
+        );
+        assert!(args.query.is_some() && args.dry_run);
+        assert!(TestArgs::try_parse_from(["program", "--to", "{nope}.md"]).is_err());
+        Ok(())
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+#[derive(Args, Debug)]
+pub struct RenameArgs {
+    /// Notes to rename, as a `zrt query` (default: every note),
+    /// e.g. "FROM #refactored"
+    pub query: Option<Query>,
+
+    /// File name pattern with {id}, {title}, {slug}, {stem} and {created}
+    /// (default: rename.pattern from config)
+    #[arg(long, value_name = "PATTERN")]
+    pub to: Option<NamePattern>,
+
+    /// Show what would be renamed without changing any file
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Directories to scan (space-separated, defaults to current directory)
+    #[arg(short = 'd', long = "dir", num_args = 0.., default_values = &["."])]
+    pub directories: Vec<PathBuf>,
+
+    /// Directories to exclude (space-separated)
+    #[arg(short, long, num_args = 0..)]
+    pub exclude: Vec<String>,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+pub fn run(args: RenameArgs, config: &ZrtConfig) -> Result<()> {
+    let pattern = match args.to {
+        Some(pattern) => pattern,
+        None => {
+            let pattern = config.rename.pattern.clone();
+            pattern.parse().map_err(|error| {
+                anyhow::anyhow!("Invalid rename.pattern {pattern} in .zrt/config.toml: {error}")
+            })?
+        }
+    };
+    let exclude_dirs: Vec<&str> = args.exclude.iter().map(String::as_str).collect();
+    let options = ScanOptions::new(&args.directories).exclude(&exclude_dirs);
+    let scanner = scan::attach(options).build()?;
+
+    let query = args.query.unwrap_or_default();
+    let relocated = relocate(&scanner, &renames(&scanner, &query, &pattern)?)?;
+    for line in moves::report(&relocated, args.dry_run) {
+        println!("{line}");
+    }
+    if !args.dry_run {
+        apply_relocation(&relocated)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,284 @@
+pub mod cli;
+
+use anyhow::Result;
+use std::fmt;
+use std::path::PathBuf;
+use std::str::FromStr;
+use tracing::warn;
+
+use crate::core::frontmatter::zettel_id;
+use crate::core::note::NoteAnalysis;
+use crate::core::scanner::Scanner;
+use crate::query::{Query, run_query};
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::scanner::ScanOptions;
+    use crate::core::vfs::MemoryFs;
+    use std::path::Path;
+
+    fn note(path: &str, content: &str) -> NoteAnalysis {
+        NoteAnalysis::from_content(Path::new(path), content.to_owned())
+    }
+
+    #[test]
+    fn test_should_fill_in_names_from_the_note() -> Result<(), String> {
+        // REQ-RENAME-001
+        let pattern: NamePattern = "{id}-{slug}.md".parse()?;
+        let titled = note(
+            "/v/202401311542.md",
+            "---\ntitle: \"Feedback Loops: why?\"\n---\n",
+        );
+
+        assert_eq!(
+            pattern.render(&titled).as_deref(),
+            Ok("202401311542-feedback-loops-why.md")
+        );
+        assert_eq!(
+            "{created} {title}.md"
+                .parse::<NamePattern>()?
+                .render(&titled),
+            Ok("2024-01-31 Feedback Loops why.md".to_owned())
+        );
+        assert_eq!(
+            pattern.render(&note("/v/a.md", "---\nid: 7\n---\n# Über Café\n")),
+            Ok("7-über-café.md".to_owned())
+        );
+        assert_eq!(
+            "{stem}.md"
+                .parse::<NamePattern>()?
+                .render(&note("/v/x y.md", "")),
+            Ok("x y.md".to_owned())
+        );
+        assert_eq!(pattern.render(&note("/v/a.md", "")), Err(NameField::Id));
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_reject_invalid_patterns() {
+        // REQ-RENAME-002
+        let error = |pattern: &str| pattern.parse::<NamePattern>().unwrap_err();
+
+        assert_eq!(
+            error("{uid}.md"),
+            "unknown placeholder {uid}; use {id}, {title}, {slug}, {stem} or {created}"
+        );
+        assert_eq!(error("{id.md"), "unclosed { in {id.md");
+        assert_eq!(error("notes/{id}.md"), "a file name cannot contain folders");
+        assert_eq!(error("{id}"), "a file name must end with .md");
+    }
+
+    #[test]
+    fn test_should_rename_matching_notes_in_their_folder() -> Result<()> {
+        // REQ-RENAME-003
+        let vfs = MemoryFs::new()
+            .with_file(
+                "/v/notes/202401311542.md",
+                "---\ntags: [idea]\n---\n# Flows",
+            )
+            .with_file(
+                "/v/202402011200-stocks.md",
+                "---\ntags: [idea]\n---\n# Stocks",
+            )
+            .with_file("/v/untitled.md", "---\ntags: [idea]\n---\nno id")
+            .with_file("/v/other.md", "# Other");
+        let scanner = ScanOptions::new(["/v"]).vfs(vfs).build()?;
+        let query: Query = "FROM #idea".parse().map_err(anyhow::Error::msg)?;
+        let pattern: NamePattern = "{id}-{slug}.md".parse().map_err(anyhow::Error::msg)?;
+
+        assert_eq!(
+            renames(&scanner, &query, &pattern)?,
+            vec![(
+                PathBuf::from("/v/notes/202401311542.md"),
+                PathBuf::from("/v/notes/202401311542-flows.md")
+            )]
+        );
+        Ok(())
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// A note value a file name pattern can use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NameField {
+    /// The zettel ID the file name starts with, else the frontmatter `id`
+    Id,
+    /// The note's title, falling back to the file name, without characters
+    /// that break file names or wikilinks
+    Title,
+    /// The title in lower case with words joined by `-`
+    Slug,
+    /// The current file name without its extension
+    Stem,
+    /// The creation date as `YYYY-MM-DD`
+    Created,
+}
+
+/// A file name with `{field}` placeholders, e.g. `{id}-{slug}.md`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NamePattern {
+    parts: Vec<Part>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Part {
+    Text(String),
+    Field(NameField),
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+impl NameField {
+    const ALL: [Self; 5] = [Self::Id, Self::Title, Self::Slug, Self::Stem, Self::Created];
+
+    #[inline]
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Id => "id",
+            Self::Title => "title",
+            Self::Slug => "slug",
+            Self::Stem => "stem",
+            Self::Created => "created",
+        }
+    }
+
+    fn value(self, note: &NoteAnalysis) -> Option<String> {
+        let stem = note
+            .path
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let title = || note.title().unwrap_or_else(|| stem.clone());
+        let value = match self {
+            Self::Id => zettel_id(&stem)
+                .map(str::to_owned)
+                .or_else(|| note.frontmatter.property("id")),
+            Self::Title => Some(
+                title()
+                    .chars()
+                    .filter(|c| !"/\\:*?\"<>|#^[]".contains(*c))
+                    .collect::<String>()
+                    .split_whitespace()
+                    .collect::<Vec<_>>()
+                    .join(" "),
+            ),
+            Self::Slug => Some(
+                title()
+                    .split(|c: char| !c.is_alphanumeric())
+                    .filter(|word| !word.is_empty())
+                    .map(str::to_lowercase)
+                    .collect::<Vec<_>>()
+                    .join("-"),
+            ),
+            Self::Stem => Some(stem.clone()),
+            Self::Created => note.created().map(|date| date.to_string()),
+        };
+        value.filter(|value| !value.trim().is_empty())
+    }
+}
+
+impl fmt::Display for NameField {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{{}}}", self.name())
+    }
+}
+
+impl FromStr for NamePattern {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.contains(['/', '\\']) {
+            return Err("a file name cannot contain folders".to_owned());
+        }
+        if !s.ends_with(".md") {
+            return Err("a file name must end with .md".to_owned());
+        }
+        let mut parts = Vec::new();
+        let mut rest = s;
+        while let Some(open) = rest.find('{') {
+            if open > 0 {
+                parts.push(Part::Text(rest[..open].to_owned()));
+            }
+            let close = rest[open..]
+                .find('}')
+                .ok_or_else(|| format!("unclosed {{ in {s}"))?;
+            let name = &rest[open + 1..open + close];
+            let field = NameField::ALL
+                .into_iter()
+                .find(|field| field.name() == name)
+                .ok_or_else(|| {
+                    format!(
+                        "unknown placeholder {{{name}}}; use {{id}}, {{title}}, {{slug}}, {{stem}} or {{created}}"
+                    )
+                })?;
+            parts.push(Part::Field(field));
+            rest = &rest[open + close + 1..];
+        }
+        if !rest.is_empty() {
+            parts.push(Part::Text(rest.to_owned()));
+        }
+        Ok(Self { parts })
+    }
+}
+
+impl NamePattern {
+    /// The file name this pattern gives `note`, or the first placeholder the
+    /// note has no value for.
+    ///
+    /// # Errors
+    ///
+    /// Returns the placeholder that cannot be filled in.
+    #[inline]
+    pub fn render(&self, note: &NoteAnalysis) -> Result<String, NameField> {
+        let mut name = String::new();
+        for part in &self.parts {
+            match part {
+                Part::Text(text) => name.push_str(text),
+                Part::Field(field) => name.push_str(&field.value(note).ok_or(*field)?),
+            }
+        }
+        Ok(name)
+    }
+}
+
+/// Notes matching `query` whose name differs from the one `pattern` gives
+/// them, each with its new path in the same folder. Notes missing a value the
+/// pattern needs are skipped with a warning.
+///
+/// # Errors
+///
+/// Returns an error if the scan fails.
+pub fn renames(
+    scanner: &Scanner,
+    query: &Query,
+    pattern: &NamePattern,
+) -> Result<Vec<(PathBuf, PathBuf)>> {
+    let mut renames = Vec::new();
+    for row in run_query(scanner, query)? {
+        let Some(content) = scanner.read_note(&row.path)? else {
+            continue;
+        };
+        let note = NoteAnalysis::from_content(&row.path, content);
+        match pattern.render(&note) {
+            Ok(name) => {
+                let to = row.path.with_file_name(name);
+                if to != row.path {
+                    renames.push((row.path, to));
+                }
+            }
+            Err(field) => warn!(path = %row.path.display(), "no {field} to rename with"),
+        }
+    }
+    Ok(renames)
+}
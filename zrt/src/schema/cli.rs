@@ -0,0 +1,84 @@
+use anyhow::{Result, bail};
+use clap::Args;
+use serde_json::{Map, Value};
+
+use crate::schema::{find, schemas};
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser, Debug)]
+    struct TestArgs {
+        #[command(flatten)]
+        schema: SchemaArgs,
+    }
+
+    #[test]
+    fn test_should_default_to_every_schema() {
+        // REQ-SCHEMA-003
+        let args = TestArgs::parse_from(["program"]);
+
+        assert_eq!(args.schema.name, None);
+        assert!(!args.schema.list);
+    }
+
+    #[test]
+    fn test_should_accept_output_name_or_list() {
+        // REQ-SCHEMA-003
+        let args = TestArgs::parse_from(["program", "graph"]);
+        assert_eq!(args.schema.name.as_deref(), Some("graph"));
+
+        let args = TestArgs::parse_from(["program", "--list"]);
+        assert!(args.schema.list);
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+#[derive(Args, Debug)]
+pub struct SchemaArgs {
+    /// Output to print the schema of (default: all, keyed by name)
+    pub name: Option<String>,
+
+    /// List the outputs that have a schema
+    #[arg(long, conflicts_with = "name")]
+    pub list: bool,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+pub fn run(args: SchemaArgs) -> Result<()> {
+    if args.list {
+        for output in schemas() {
+            println!("{:<10} {}", output.name, output.description);
+        }
+        return Ok(());
+    }
+
+    let document = match args.name {
+        Some(name) => match find(&name) {
+            Some(output) => output.schema,
+            None => {
+                let names: Vec<&str> = schemas().iter().map(|output| output.name).collect();
+                bail!("No schema named '{name}' (available: {})", names.join(", "));
+            }
+        },
+        None => Value::Object(
+            schemas()
+                .into_iter()
+                .map(|output| (output.name.to_owned(), output.schema))
+                .collect::<Map<_, _>>(),
+        ),
+    };
+    println!("{}", serde_json::to_string_pretty(&document)?);
+    Ok(())
+}
@@ -0,0 +1,487 @@
+pub mod cli;
+
+use serde_json::{Value, json};
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check::Baseline;
+    use crate::core::history::Snapshot;
+    use crate::core::links::{LinkIndex, note_from_content};
+    use crate::core::scanner::NoteRecord;
+    use crate::graph::{GraphFilter, build_graph, render_json};
+    use crate::serve::{VaultIndex, handle};
+    use crate::tags::TagShare;
+    use anyhow::Result;
+    use std::path::{Path, PathBuf};
+
+    /// Check `value` against the subset of JSON Schema the documents use.
+    fn conforms(value: &Value, schema: &Value) -> bool {
+        let typed = match schema["type"].as_str() {
+            Some("object") => value.is_object(),
+            Some("array") => value.is_array(),
+            Some("string") => value.is_string(),
+            Some("integer") => value.is_u64() || value.is_i64(),
+            Some("number") => value.is_number(),
+            Some(_) => false,
+            None => true,
+        };
+        let allowed = schema["enum"]
+            .as_array()
+            .is_none_or(|values| values.contains(value));
+        let required = schema["required"].as_array().is_none_or(|keys| {
+            keys.iter()
+                .all(|key| key.as_str().is_some_and(|key| value.get(key).is_some()))
+        });
+        let fields = value.as_object().is_none_or(|object| {
+            object
+                .iter()
+                .all(|(key, field)| match &schema["properties"][key] {
+                    Value::Null => match &schema["additionalProperties"] {
+                        Value::Bool(allowed) => *allowed,
+                        Value::Null => true,
+                        extra => conforms(field, extra),
+                    },
+                    property => conforms(field, property),
+                })
+        });
+        let items = value.as_array().is_none_or(|items| {
+            schema["items"].is_null() || items.iter().all(|item| conforms(item, &schema["items"]))
+        });
+        typed && allowed && required && fields && items
+    }
+
+    fn schema(name: &str) -> Value {
+        find(name)
+            .map(|output| output.schema)
+            .unwrap_or(Value::Null)
+    }
+
+    #[test]
+    fn test_should_publish_versioned_schema_per_output() {
+        // REQ-SCHEMA-001
+        let outputs = schemas();
+        let names: Vec<&str> = outputs.iter().map(|output| output.name).collect();
+
+        assert_eq!(
+            names,
+            vec![
+                "baseline", "compare", "graph", "notes", "query", "snapshot", "stats", "tags"
+            ]
+        );
+        for output in &outputs {
+            assert_eq!(output.schema["$schema"], DIALECT);
+            assert_eq!(
+                output.schema["$id"],
+                format!("{SCHEMA_BASE}/v{SCHEMA_VERSION}/{}.json", output.name)
+            );
+            assert!(output.schema["type"].is_string());
+        }
+        assert!(find("graph").is_some());
+        assert!(find("missing").is_none());
+    }
+
+    #[test]
+    fn test_should_project_values_onto_schema_version() {
+        // REQ-SCHEMA-004
+        // Given graph output carrying fields version 1 doesn't know
+        let output = json!({
+            "nodes": [{"id": "a.md", "title": "A", "tags": [], "words": 1,
+                       "status": "todo", "links": [], "aliases": ["x"]}],
+            "edges": [],
+            "generated": "2024-01-31",
+        });
+        let schema = versioned("graph", 1).map(|output| output.schema);
+
+        // When projecting it onto the version 1 schema
+        let projected = project(&output, &schema.unwrap_or(Value::Null));
+
+        // Then only the version 1 fields remain, at every depth
+        assert_eq!(
+            projected,
+            json!({
+                "nodes": [{"id": "a.md", "title": "A", "tags": [], "words": 1,
+                           "status": "todo", "links": []}],
+                "edges": [],
+            })
+        );
+        assert!(versioned("graph", SCHEMA_VERSION + 1).is_none());
+        assert!(versioned("graph", 0).is_none());
+    }
+
+    #[test]
+    fn test_should_keep_open_maps_when_projecting() {
+        // REQ-SCHEMA-004
+        let output =
+            json!({"tags": {"idea": 2}, "notes": {"a.md": {"status": "done", "words": 3}}});
+        let schema = schema("snapshot");
+
+        assert_eq!(project(&output, &schema), output);
+    }
+
+    #[test]
+    fn test_should_describe_graph_json() {
+        // REQ-SCHEMA-002
+        // Given a rendered graph
+        let index = LinkIndex::from_notes(vec![
+            note_from_content(Path::new("/v/a.md"), "---\ntags: [x]\n---\n[[b]]"),
+            note_from_content(Path::new("/v/b.md"), "# B"),
+        ]);
+        let graph = build_graph(&index, &GraphFilter::default(), "refactored", "to_refactor");
+        let output: Value =
+            serde_json::from_str(&render_json(&graph).unwrap_or_default()).unwrap_or(Value::Null);
+
+        // Then it conforms to the graph schema, which rejects a missing edge list
+        assert!(conforms(&output, &schema("graph")));
+        assert!(!conforms(&json!({"nodes": []}), &schema("graph")));
+    }
+
+    #[test]
+    fn test_should_describe_snapshot_and_baseline() -> Result<()> {
+        // REQ-SCHEMA-002
+        let snapshot: Snapshot = serde_json::from_str(
+            r#"{"timestamp":"2024-01-31T12:00:00Z","files":2,"words":30,"done_files":1,
+            "todo_files":1,"done_words":10,"todo_words":20,"percentage":33.33,
+            "tags":{"idea":1},"notes":{"a.md":{"status":"done","words":10}}}"#,
+        )?;
+        let baseline = Baseline {
+            percentage: 33.33,
+            files: 2,
+            done_files: 1,
+            todo_files: 1,
+            untagged_files: 0,
+        };
+
+        assert!(conforms(
+            &serde_json::to_value(&snapshot)?,
+            &schema("snapshot")
+        ));
+        assert!(conforms(
+            &serde_json::to_value(&baseline)?,
+            &schema("baseline")
+        ));
+        assert!(!conforms(
+            &json!({"percentage": "high", "files": 1, "done_files": 0, "todo_files": 0, "untagged_files": 0}),
+            &schema("baseline")
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_describe_tags_json() -> Result<()> {
+        // REQ-SCHEMA-002
+        let shares = vec![TagShare {
+            tag: "idea".to_owned(),
+            files: 2,
+            words: 30,
+            percentage: 42.86,
+        }];
+        assert!(conforms(&serde_json::to_value(&shares)?, &schema("tags")));
+        assert!(!conforms(&json!([{"tag": "idea"}]), &schema("tags")));
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_describe_serve_responses() {
+        // REQ-SCHEMA-002
+        let index = VaultIndex::from_notes(vec![NoteRecord {
+            path: PathBuf::from("/v/a.md"),
+            tags: vec!["refactored".to_owned()],
+            words: 10,
+            priority: None,
+            title: None,
+            created: None,
+        }]);
+        let body = |url: &str| -> Value {
+            serde_json::from_str(&handle(&index, "refactored", "to_refactor", url).body)
+                .unwrap_or(Value::Null)
+        };
+
+        assert!(conforms(&body("/stats"), &schema("stats")));
+        assert!(conforms(&body("/compare"), &schema("compare")));
+        assert!(conforms(&body("/words"), &schema("notes")));
+        assert!(conforms(&body("/query"), &schema("notes")));
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// Version of the published output schemas. Bump it whenever a field is
+/// removed, renamed or changes type; new optional fields keep the version.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// JSON Schema dialect the documents are written in.
+pub const DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+const SCHEMA_BASE: &str = "https://github.com/craigtkhill/zettelkasten-refactor-tool/schemas";
+
+/// The JSON Schema of one machine-readable output.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutputSchema {
+    pub name: &'static str,
+    /// Which command or endpoint produces the output
+    pub description: &'static str,
+    pub schema: Value,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+/// Schemas of every machine-readable output, sorted by name.
+#[must_use]
+pub fn schemas() -> Vec<OutputSchema> {
+    vec![
+        document(
+            "baseline",
+            "zrt check baseline file (zrt-baseline.json)",
+            object(
+                json!({
+                    "percentage": number("Percentage of words in notes tagged done"),
+                    "files": count(),
+                    "done_files": count(),
+                    "todo_files": count(),
+                    "untagged_files": count(),
+                }),
+                false,
+            ),
+        ),
+        document(
+            "compare",
+            "zrt serve /compare response",
+            object(
+                json!({
+                    "done": stats(),
+                    "todo": stats(),
+                    "total": stats(),
+                    "percentage": number("Percentage of words in notes tagged done"),
+                }),
+                false,
+            ),
+        ),
+        document("graph", "zrt graph --format json output", graph()),
+        document(
+            "notes",
+            "zrt serve /words and /query response",
+            json!({
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "path": { "type": "string" },
+                        "words": count(),
+                        "tags": strings(),
+                    },
+                    "required": ["path", "words"],
+                    "additionalProperties": false,
+                },
+            }),
+        ),
+        document(
+            "query",
+            "zrt query --format json output",
+            json!({
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "description": "The note's path and the value of every field the query uses; lists are arrays and missing values null",
+                    "properties": { "path": { "type": "string" } },
+                    "required": ["path"],
+                    "additionalProperties": true,
+                },
+            }),
+        ),
+        document("snapshot", "one line of .zrt/history.jsonl", snapshot()),
+        document("stats", "zrt serve /stats response", stats()),
+        document(
+            "tags",
+            "zrt tags --format json output",
+            json!({
+                "type": "array",
+                "items": object(
+                    json!({
+                        "tag": { "type": "string" },
+                        "files": count(),
+                        "words": count(),
+                        "percentage": number("Percentage of all scanned words in notes with the tag"),
+                    }),
+                    false,
+                ),
+            }),
+        ),
+    ]
+}
+
+/// The schema of the output called `name`.
+#[must_use]
+pub fn find(name: &str) -> Option<OutputSchema> {
+    schemas().into_iter().find(|output| output.name == name)
+}
+
+/// The schema of the output called `name` as published at `version`.
+///
+/// When [`SCHEMA_VERSION`] is bumped, the previous documents belong here so
+/// output pinned to an older version keeps its shape.
+#[must_use]
+pub fn versioned(name: &str, version: u32) -> Option<OutputSchema> {
+    if version == SCHEMA_VERSION {
+        find(name)
+    } else {
+        None
+    }
+}
+
+/// Reduce `value` to the shape `schema` describes, dropping object fields
+/// the schema doesn't declare where it forbids additional properties.
+///
+/// This keeps output pinned to an older schema version stable as the
+/// structs behind it gain fields.
+#[must_use]
+pub fn project(value: &Value, schema: &Value) -> Value {
+    match value {
+        Value::Object(object) => Value::Object(
+            object
+                .iter()
+                .filter_map(|(key, field)| {
+                    let field_schema = match &schema["properties"][key] {
+                        Value::Null => match &schema["additionalProperties"] {
+                            Value::Bool(false) => return None,
+                            extra => extra,
+                        },
+                        property => property,
+                    };
+                    Some((key.clone(), project(field, field_schema)))
+                })
+                .collect(),
+        ),
+        Value::Array(items) => Value::Array(
+            items
+                .iter()
+                .map(|item| project(item, &schema["items"]))
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+fn document(name: &'static str, description: &'static str, schema: Value) -> OutputSchema {
+    let mut document = json!({
+        "$schema": DIALECT,
+        "$id": format!("{SCHEMA_BASE}/v{SCHEMA_VERSION}/{name}.json"),
+        "title": format!("zrt {name}"),
+        "description": description,
+    });
+    if let (Some(document), Value::Object(schema)) = (document.as_object_mut(), schema) {
+        document.extend(schema);
+    }
+    OutputSchema {
+        name,
+        description,
+        schema: document,
+    }
+}
+
+/// An object schema requiring every listed property.
+fn object(properties: Value, additional: bool) -> Value {
+    let required: Vec<&String> = properties
+        .as_object()
+        .map(|p| p.keys().collect())
+        .unwrap_or_default();
+    json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+        "additionalProperties": additional,
+    })
+}
+
+fn count() -> Value {
+    json!({ "type": "integer", "minimum": 0 })
+}
+
+fn number(description: &str) -> Value {
+    json!({ "type": "number", "description": description })
+}
+
+fn strings() -> Value {
+    json!({ "type": "array", "items": { "type": "string" } })
+}
+
+fn status() -> Value {
+    json!({ "type": "string", "enum": ["done", "todo", "untracked"] })
+}
+
+fn stats() -> Value {
+    object(json!({ "files": count(), "words": count() }), false)
+}
+
+fn graph() -> Value {
+    let node = object(
+        json!({
+            "id": { "type": "string", "description": "Note path" },
+            "title": { "type": "string" },
+            "tags": strings(),
+            "words": count(),
+            "status": status(),
+            "links": {
+                "type": "array",
+                "description": "Ids of the nodes this note links to",
+                "items": { "type": "string" },
+            },
+        }),
+        false,
+    );
+    let edge = object(
+        json!({
+            "source": { "type": "string" },
+            "target": { "type": "string" },
+        }),
+        false,
+    );
+    object(
+        json!({
+            "nodes": { "type": "array", "items": node },
+            "edges": { "type": "array", "items": edge },
+        }),
+        false,
+    )
+}
+
+fn snapshot() -> Value {
+    let mut schema = object(
+        json!({
+            "timestamp": { "type": "string", "format": "date-time" },
+            "files": count(),
+            "words": count(),
+            "done_files": count(),
+            "todo_files": count(),
+            "done_words": count(),
+            "todo_words": count(),
+            "percentage": number("Percentage of words in notes tagged done"),
+            "tags": {
+                "type": "object",
+                "description": "Number of notes carrying each tag",
+                "additionalProperties": count(),
+            },
+            "notes": {
+                "type": "object",
+                "description": "Status and word count of every note, keyed by path",
+                "additionalProperties": object(
+                    json!({ "status": status(), "words": count() }),
+                    false,
+                ),
+            },
+        }),
+        false,
+    );
+    // Snapshots written before per-note tracking have no `notes`
+    if let Some(required) = schema["required"].as_array_mut() {
+        required.retain(|key| key != "notes");
+    }
+    schema
+}
@@ -2,6 +2,14 @@ use anyhow::Result;
 use clap::Args;
 use std::path::PathBuf;
 
+use crate::output::exec::ExecArgs;
+use crate::output::exit::NoMatches;
+use crate::output::open::OpenArgs;
+use crate::output::scan;
+use crate::output::snippet::{SNIPPET_WIDTH, highlight, snippet};
+use crate::output::table::is_tty;
+use crate::output::titles::TitleArgs;
+
 // ============================================
 // TESTS
 // ============================================
@@ -99,11 +107,49 @@ mod tests {
         // REQ-SEARCH-007
 
         // Given / When
-        let args = TestArgs::parse_from(["program", "--tags", "refactor", "-e", "node_modules", "target"]);
+        let args = TestArgs::parse_from([
+            "program",
+            "--tags",
+            "refactor",
+            "-e",
+            "node_modules",
+            "target",
+        ]);
 
         // Then
         assert_eq!(args.search.exclude.len(), 2);
     }
+
+    #[test]
+    fn test_should_accept_snippets_flag() {
+        // REQ-SEARCH-018
+        assert!(
+            TestArgs::parse_from(["program", "--tags", "a", "--snippets"])
+                .search
+                .snippets
+        );
+        assert!(
+            !TestArgs::parse_from(["program", "--tags", "a"])
+                .search
+                .snippets
+        );
+    }
+
+    #[test]
+    fn test_search_reports_when_nothing_matches() -> Result<()> {
+        // REQ-SEARCH-013
+        let dir = tempfile::TempDir::new()?;
+        std::fs::write(dir.path().join("a.md"), "---\ntags: [draft]\n---\n")?;
+        let dir = dir.path().to_string_lossy().into_owned();
+        let search = |tag: &str| {
+            let args = TestArgs::parse_from(["program", "-d", &dir, "--tags", tag]);
+            run(args.search)
+        };
+
+        assert!(search("draft").is_ok());
+        assert!(search("refactor").is_err_and(|error| error.is::<NoMatches>()));
+        Ok(())
+    }
 }
 
 // ============================================
@@ -127,6 +173,20 @@ pub struct SearchArgs {
     /// Find files that have no tags
     #[arg(long, conflicts_with = "tags")]
     pub no_tags: bool,
+
+    /// Print an excerpt of each note below its path, around the first
+    /// mention of a searched tag, highlighted on a terminal
+    #[arg(long)]
+    pub snippets: bool,
+
+    #[command(flatten)]
+    pub titles: TitleArgs,
+
+    #[command(flatten)]
+    pub open: OpenArgs,
+
+    #[command(flatten)]
+    pub exec: ExecArgs,
 }
 
 // ============================================
@@ -140,18 +200,29 @@ pub fn run(args: SearchArgs) -> Result<()> {
 
     let exclude_dirs: Vec<&str> = args.exclude.iter().map(String::as_str).collect();
 
-    if let Some(tags) = args.tags {
+    let scanner = scan::scanner(&args.directories, &exclude_dirs)?;
+    let files = if let Some(tags) = &args.tags {
         let tag_refs: Vec<&str> = tags.iter().map(String::as_str).collect();
-        let files = crate::search::search_exactly(&args.directories, &tag_refs, &exclude_dirs)?;
-        for file in &files {
-            println!("{}", file);
-        }
-    } else if args.no_tags {
-        let files = crate::search::search_missing_tags(&args.directories, &exclude_dirs)?;
-        for file in &files {
-            println!("{}", file);
+        crate::search::search_exactly_in(&scanner, &tag_refs)?
+    } else {
+        crate::search::search_missing_tags_in(&scanner)?
+    };
+    let terms: Vec<&str> = args.tags.iter().flatten().map(String::as_str).collect();
+    for file in &files {
+        println!("{}", args.titles.label(std::path::Path::new(file)));
+        if args.snippets {
+            let content = scanner
+                .read_text(std::path::Path::new(file))?
+                .unwrap_or_default();
+            let excerpt = snippet(&content, &terms, SNIPPET_WIDTH);
+            println!("    {}", highlight(&excerpt, &terms, is_tty()));
         }
     }
+    args.exec.run(&files)?;
+    args.open.open(&files)?;
 
+    if files.is_empty() {
+        return Err(NoMatches.into());
+    }
     Ok(())
 }
@@ -2,11 +2,8 @@ pub mod cli;
 
 use anyhow::Result;
 use std::path::PathBuf;
-use walkdir::WalkDir;
 
-use crate::core::filter::utils::should_exclude;
-use crate::core::frontmatter::parse_frontmatter;
-use crate::core::ignore::load_ignore_patterns;
+use crate::core::scanner::{ScanOptions, Scanner};
 
 // ============================================
 // TESTS
@@ -28,7 +25,11 @@ mod tests {
         // REQ-SEARCH-001
         let dir = TempDir::new()?;
         create_test_file(&dir, "exact.md", "---\ntags: [refactor]\n---\nContent")?;
-        create_test_file(&dir, "extra.md", "---\ntags: [refactor, draft]\n---\nContent")?;
+        create_test_file(
+            &dir,
+            "extra.md",
+            "---\ntags: [refactor, draft]\n---\nContent",
+        )?;
         create_test_file(&dir, "none.md", "No tags")?;
 
         let files = search_exactly(&[dir.path().to_path_buf()], &["refactor"], &[])?;
@@ -41,9 +42,17 @@ mod tests {
     fn test_should_find_files_with_exactly_multiple_tags() -> Result<()> {
         // REQ-SEARCH-002
         let dir = TempDir::new()?;
-        create_test_file(&dir, "exact.md", "---\ntags: [refactor, draft]\n---\nContent")?;
+        create_test_file(
+            &dir,
+            "exact.md",
+            "---\ntags: [refactor, draft]\n---\nContent",
+        )?;
         create_test_file(&dir, "partial.md", "---\ntags: [refactor]\n---\nContent")?;
-        create_test_file(&dir, "extra.md", "---\ntags: [refactor, draft, wip]\n---\nContent")?;
+        create_test_file(
+            &dir,
+            "extra.md",
+            "---\ntags: [refactor, draft, wip]\n---\nContent",
+        )?;
 
         let files = search_exactly(&[dir.path().to_path_buf()], &["refactor", "draft"], &[])?;
         assert_eq!(files.len(), 1);
@@ -56,7 +65,11 @@ mod tests {
         // REQ-SEARCH-003
         let dir = TempDir::new()?;
         create_test_file(&dir, "exact.md", "---\ntags: [refactor]\n---\nContent")?;
-        create_test_file(&dir, "extra.md", "---\ntags: [refactor, draft]\n---\nContent")?;
+        create_test_file(
+            &dir,
+            "extra.md",
+            "---\ntags: [refactor, draft]\n---\nContent",
+        )?;
 
         let files = search_exactly(&[dir.path().to_path_buf()], &["refactor"], &[])?;
         assert_eq!(files.len(), 1);
@@ -68,7 +81,11 @@ mod tests {
     fn test_should_exclude_files_missing_tags() -> Result<()> {
         // REQ-SEARCH-004
         let dir = TempDir::new()?;
-        create_test_file(&dir, "exact.md", "---\ntags: [refactor, draft]\n---\nContent")?;
+        create_test_file(
+            &dir,
+            "exact.md",
+            "---\ntags: [refactor, draft]\n---\nContent",
+        )?;
         create_test_file(&dir, "partial.md", "---\ntags: [refactor]\n---\nContent")?;
 
         let files = search_exactly(&[dir.path().to_path_buf()], &["refactor", "draft"], &[])?;
@@ -123,7 +140,11 @@ mod tests {
     #[test]
     fn test_should_handle_tag_order_independently() -> Result<()> {
         let dir = TempDir::new()?;
-        create_test_file(&dir, "file.md", "---\ntags: [draft, refactor]\n---\nContent")?;
+        create_test_file(
+            &dir,
+            "file.md",
+            "---\ntags: [draft, refactor]\n---\nContent",
+        )?;
 
         let files = search_exactly(&[dir.path().to_path_buf()], &["refactor", "draft"], &[])?;
         assert_eq!(files.len(), 1);
@@ -136,7 +157,11 @@ mod tests {
         create_test_file(&dir, "file.md", "---\ntags:\n  - refactored\n---\nContent")?;
 
         let files = search_exactly(&[dir.path().to_path_buf()], &["refactored"], &[])?;
-        assert_eq!(files.len(), 1, "Should find file with YAML list format tags");
+        assert_eq!(
+            files.len(),
+            1,
+            "Should find file with YAML list format tags"
+        );
         Ok(())
     }
 
@@ -187,10 +212,8 @@ mod tests {
         create_test_file(&dir2, "b.md", "No frontmatter")?;
 
         // When
-        let files = search_missing_tags(
-            &[dir1.path().to_path_buf(), dir2.path().to_path_buf()],
-            &[],
-        )?;
+        let files =
+            search_missing_tags(&[dir1.path().to_path_buf(), dir2.path().to_path_buf()], &[])?;
 
         // Then
         assert_eq!(files.len(), 2);
@@ -228,78 +251,38 @@ mod tests {
 
 /// Search for files that have no tags (missing tags field or no frontmatter)
 pub fn search_missing_tags(dirs: &[PathBuf], exclude: &[&str]) -> Result<Vec<String>> {
-    let mut matching_files = Vec::new();
+    search_missing_tags_in(&ScanOptions::new(dirs).exclude(exclude).build()?)
+}
 
-    for dir in dirs {
-        let absolute_dir = if dir.is_absolute() {
-            dir.clone()
-        } else {
-            std::env::current_dir()?.join(dir)
-        };
-
-        let ignore_patterns = load_ignore_patterns(&absolute_dir)?;
-
-        for entry in WalkDir::new(&absolute_dir)
-            .follow_links(true)
-            .into_iter()
-            .filter_entry(|e| !should_exclude(e, exclude, Some(&ignore_patterns)))
-        {
-            let entry = entry?;
-            if !entry.file_type().is_file() {
-                continue;
-            }
+/// Search for files that have exactly the specified tags (no more, no less)
+pub fn search_exactly(dirs: &[PathBuf], tags: &[&str], exclude: &[&str]) -> Result<Vec<String>> {
+    search_exactly_in(&ScanOptions::new(dirs).exclude(exclude).build()?, tags)
+}
 
-            if let Ok(content) = std::fs::read_to_string(entry.path()) {
-                let missing = match parse_frontmatter(&content) {
-                    Ok(fm) => fm.tags.is_none(),
-                    Err(_) => true,
-                };
-                if missing {
-                    matching_files.push(entry.path().display().to_string());
-                }
-            }
+/// [`search_missing_tags`] using an already configured scanner
+pub fn search_missing_tags_in(scanner: &Scanner) -> Result<Vec<String>> {
+    let mut matching_files = Vec::new();
+    for note in scanner.note_headers() {
+        let note = note?;
+        if note.frontmatter.tags.is_none() {
+            matching_files.push(note.path.display().to_string());
         }
     }
-
     Ok(matching_files)
 }
 
-/// Search for files that have exactly the specified tags (no more, no less)
-pub fn search_exactly(dirs: &[PathBuf], tags: &[&str], exclude: &[&str]) -> Result<Vec<String>> {
+/// [`search_exactly`] using an already configured scanner
+pub fn search_exactly_in(scanner: &Scanner, tags: &[&str]) -> Result<Vec<String>> {
     let mut matching_files = Vec::new();
-
-    for dir in dirs {
-        let absolute_dir = if dir.is_absolute() {
-            dir.clone()
-        } else {
-            std::env::current_dir()?.join(dir)
-        };
-
-        let ignore_patterns = load_ignore_patterns(&absolute_dir)?;
-
-        for entry in WalkDir::new(&absolute_dir)
-            .follow_links(true)
-            .into_iter()
-            .filter_entry(|e| !should_exclude(e, exclude, Some(&ignore_patterns)))
-        {
-            let entry = entry?;
-            if !entry.file_type().is_file() {
-                continue;
-            }
-
-            if let Ok(content) = std::fs::read_to_string(entry.path()) {
-                if let Ok(frontmatter) = parse_frontmatter(&content) {
-                    if let Some(file_tags) = frontmatter.tags {
-                        if file_tags.len() == tags.len()
-                            && tags.iter().all(|tag| file_tags.contains(&tag.to_string()))
-                        {
-                            matching_files.push(entry.path().display().to_string());
-                        }
-                    }
-                }
+    for note in scanner.note_headers() {
+        let note = note?;
+        if let Some(file_tags) = &note.frontmatter.tags {
+            if file_tags.len() == tags.len()
+                && tags.iter().all(|tag| file_tags.contains(&tag.to_string()))
+            {
+                matching_files.push(note.path.display().to_string());
             }
         }
     }
-
     Ok(matching_files)
 }
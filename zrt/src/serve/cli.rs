@@ -0,0 +1,136 @@
+use anyhow::{Result, anyhow};
+use clap::Args;
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+use tracing::{debug, warn};
+
+use crate::core::scanner::ScanOptions;
+use crate::init::ZrtConfig;
+use crate::output::scan;
+use crate::serve::{VaultIndex, handle};
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser, Debug)]
+    struct TestArgs {
+        #[command(flatten)]
+        serve: ServeArgs,
+    }
+
+    #[test]
+    fn test_should_default_to_local_port_8080() {
+        // REQ-SERVE-007
+        let args = TestArgs::parse_from(["program"]);
+
+        assert_eq!(args.serve.host, "127.0.0.1");
+        assert_eq!(args.serve.port, 8080);
+    }
+
+    #[test]
+    fn test_should_accept_host_and_port() {
+        // REQ-SERVE-007
+        let args = TestArgs::parse_from(["program", "--host", "0.0.0.0", "-p", "9000"]);
+
+        assert_eq!(args.serve.host, "0.0.0.0");
+        assert_eq!(args.serve.port, 9000);
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+#[derive(Args, Debug)]
+pub struct ServeArgs {
+    /// Directories to scan (space-separated, defaults to current directory)
+    #[arg(short = 'd', long = "dir", num_args = 0.., default_values = &["."])]
+    pub directories: Vec<PathBuf>,
+
+    /// Directories to exclude (space-separated)
+    #[arg(short, long, num_args = 0..)]
+    pub exclude: Vec<String>,
+
+    /// Address to listen on
+    #[arg(long, default_value = "127.0.0.1")]
+    pub host: String,
+
+    /// Port to listen on
+    #[arg(short, long, default_value = "8080")]
+    pub port: u16,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+pub fn run(args: ServeArgs, config: &ZrtConfig) -> Result<()> {
+    let exclude_dirs: Vec<&str> = args.exclude.iter().map(String::as_str).collect();
+
+    let scanner =
+        scan::attach(ScanOptions::new(&args.directories).exclude(&exclude_dirs)).build()?;
+    let index = Arc::new(RwLock::new(VaultIndex::build(&scanner)?));
+
+    let refreshed = Arc::clone(&index);
+    let directories = args.directories.clone();
+    let exclude = args.exclude.clone();
+    std::thread::spawn(move || {
+        let exclude_dirs: Vec<&str> = exclude.iter().map(String::as_str).collect();
+        let result = crate::watch::watch(
+            &directories,
+            &exclude_dirs,
+            Duration::from_millis(300),
+            || {
+                let fresh = VaultIndex::build(&scanner)?;
+                debug!(notes = fresh.notes().len(), "refreshed index");
+                *refreshed
+                    .write()
+                    .map_err(|_| anyhow!("Index lock poisoned"))? = fresh;
+                Ok(())
+            },
+        );
+        if let Err(error) = result {
+            warn!(%error, "stopped refreshing the index");
+        }
+    });
+
+    let address = format!("{}:{}", args.host, args.port);
+    let server = tiny_http::Server::http(&address)
+        .map_err(|e| anyhow!("Cannot listen on {address}: {e}"))?;
+    eprintln!("Serving vault stats on http://{address}");
+
+    let content_type = tiny_http::Header::from_bytes("Content-Type", "application/json")
+        .map_err(|()| anyhow!("Invalid content type header"))?;
+    for request in server.incoming_requests() {
+        let response = if *request.method() == tiny_http::Method::Get {
+            let index = index.read().map_err(|_| anyhow!("Index lock poisoned"))?;
+            handle(
+                &index,
+                &config.refactor.done_tag,
+                &config.refactor.todo_tag,
+                request.url(),
+            )
+        } else {
+            crate::serve::Response {
+                status: 405,
+                body: r#"{"error":"method not allowed"}"#.to_owned(),
+            }
+        };
+        debug!(url = request.url(), status = response.status, "request");
+
+        let reply = tiny_http::Response::from_string(response.body)
+            .with_status_code(response.status)
+            .with_header(content_type.clone());
+        if let Err(error) = request.respond(reply) {
+            warn!(%error, "failed to send response");
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,345 @@
+pub mod cli;
+
+use anyhow::Result;
+use serde_json::{Value, json};
+
+use crate::core::history::percentage;
+use crate::core::scanner::{NoteRecord, Scanner};
+use crate::core::status::RefactorStatus;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::scanner::ScanOptions;
+    use crate::core::vfs::MemoryFs;
+    use std::path::PathBuf;
+
+    fn index() -> VaultIndex {
+        VaultIndex::from_notes(vec![
+            note("/v/done.md", &["refactored"], 30),
+            note("/v/todo.md", &["to_refactor", "idea"], 60),
+            note("/v/loose.md", &[], 10),
+        ])
+    }
+
+    fn note(path: &str, tags: &[&str], words: usize) -> NoteRecord {
+        NoteRecord {
+            path: PathBuf::from(path),
+            tags: tags.iter().map(|t| (*t).to_owned()).collect(),
+            words,
+            priority: None,
+            title: None,
+            created: None,
+        }
+    }
+
+    fn get(url: &str) -> (u16, Value) {
+        let response = handle(&index(), "refactored", "to_refactor", url);
+        let body = serde_json::from_str(&response.body).unwrap_or(Value::Null);
+        (response.status, body)
+    }
+
+    #[test]
+    fn test_should_build_index_from_scan() -> Result<()> {
+        // REQ-SERVE-001
+        // Given a vault with two notes
+        let vfs = MemoryFs::new()
+            .with_file("/vault/a.md", "---\ntags: [x]\n---\none two")
+            .with_file("/vault/b.md", "three");
+        let scanner = ScanOptions::new(["/vault"]).vfs(vfs).build()?;
+
+        // When indexing it
+        let index = VaultIndex::build(&scanner)?;
+
+        // Then every note is held in memory
+        assert_eq!(index.notes().len(), 2);
+        assert_eq!(index.notes()[0].words, 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_report_totals_on_stats() {
+        // REQ-SERVE-002
+        let (status, body) = get("/stats");
+
+        assert_eq!(status, 200);
+        assert_eq!(body["files"], 3);
+        assert_eq!(body["words"], 100);
+    }
+
+    #[test]
+    fn test_should_filter_stats_by_tag() {
+        // REQ-SERVE-002
+        let (_, body) = get("/stats?tag=idea&tag=refactored");
+
+        assert_eq!(body["files"], 2);
+        assert_eq!(body["words"], 90);
+    }
+
+    #[test]
+    fn test_should_compare_done_and_todo() {
+        // REQ-SERVE-003
+        let (status, body) = get("/compare");
+
+        assert_eq!(status, 200);
+        assert_eq!(body["done"]["words"], 30);
+        assert_eq!(body["todo"]["files"], 1);
+        assert_eq!(body["total"]["words"], 100);
+        assert_eq!(body["percentage"], 30.0);
+    }
+
+    #[test]
+    fn test_should_compare_custom_tags() {
+        // REQ-SERVE-003
+        let (_, body) = get("/compare?done=idea&todo=refactored");
+
+        assert_eq!(body["done"]["words"], 60);
+        assert_eq!(body["todo"]["words"], 30);
+    }
+
+    #[test]
+    fn test_should_list_words_longest_first() {
+        // REQ-SERVE-004
+        let (status, body) = get("/words?limit=2");
+
+        assert_eq!(status, 200);
+        assert_eq!(
+            body,
+            json!([
+                {"path": "/v/todo.md", "words": 60},
+                {"path": "/v/done.md", "words": 30},
+            ])
+        );
+    }
+
+    #[test]
+    fn test_should_query_notes_by_tag() {
+        // REQ-SERVE-005
+        let (status, body) = get("/query?tag=to%5Frefactor");
+
+        assert_eq!(status, 200);
+        assert_eq!(body.as_array().map(Vec::len), Some(1));
+        assert_eq!(body[0]["path"], "/v/todo.md");
+        assert_eq!(body[0]["tags"], json!(["to_refactor", "idea"]));
+    }
+
+    #[test]
+    fn test_should_reject_unknown_routes_and_bad_parameters() {
+        // REQ-SERVE-006
+        let (status, body) = get("/nope");
+        assert_eq!(status, 404);
+        assert!(body["error"].is_string());
+
+        let (status, _) = get("/words?limit=many");
+        assert_eq!(status, 400);
+    }
+
+    #[test]
+    fn test_should_decode_query_strings() {
+        // REQ-SERVE-006
+        assert_eq!(
+            parse_query("tag=to%20do&tag=a+b&flag"),
+            vec![
+                ("tag".to_owned(), "to do".to_owned()),
+                ("tag".to_owned(), "a b".to_owned()),
+                ("flag".to_owned(), String::new()),
+            ]
+        );
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// Every note of a scan held in memory, so requests never touch the disk.
+#[derive(Debug, Clone, Default)]
+pub struct VaultIndex {
+    notes: Vec<NoteRecord>,
+}
+
+/// A JSON reply to an API request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+impl VaultIndex {
+    /// Read every note the scanner reaches.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the scan fails.
+    #[inline]
+    pub fn build(scanner: &Scanner) -> Result<Self> {
+        let notes = scanner.iter().collect::<Result<Vec<_>, _>>()?;
+        Ok(Self::from_notes(notes))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn from_notes(notes: Vec<NoteRecord>) -> Self {
+        Self { notes }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn notes(&self) -> &[NoteRecord] {
+        &self.notes
+    }
+
+    fn tagged<'a>(&'a self, tags: &'a [&str]) -> impl Iterator<Item = &'a NoteRecord> {
+        self.notes
+            .iter()
+            .filter(move |note| tags.is_empty() || note.has_any_tag(tags))
+    }
+}
+
+impl Response {
+    fn ok(body: &Value) -> Self {
+        Self {
+            status: 200,
+            body: body.to_string(),
+        }
+    }
+
+    fn error(status: u16, message: &str) -> Self {
+        Self {
+            status,
+            body: json!({ "error": message }).to_string(),
+        }
+    }
+}
+
+/// Answer a GET request for `url` (path and query string) from the index.
+///
+/// Routes:
+/// * `/stats?tag=...` - files and words, optionally only in notes with any given tag
+/// * `/compare?done=...&todo=...` - done, todo and total stats with the done percentage
+/// * `/words?tag=...&limit=N` - notes by word count, longest first
+/// * `/query?tag=...` - notes with any given tag, with their tags and word counts
+#[must_use]
+pub fn handle(index: &VaultIndex, done_tag: &str, todo_tag: &str, url: &str) -> Response {
+    let (path, query) = url.split_once('?').unwrap_or((url, ""));
+    let params = parse_query(query);
+    let values = |key: &str| -> Vec<&str> {
+        params
+            .iter()
+            .filter(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+            .collect()
+    };
+    let tags = values("tag");
+
+    match path.trim_end_matches('/') {
+        "/stats" => {
+            let (files, words) = totals(index.tagged(&tags));
+            Response::ok(&json!({ "files": files, "words": words }))
+        }
+        "/compare" => {
+            let done_tag = values("done").first().copied().unwrap_or(done_tag);
+            let todo_tag = values("todo").first().copied().unwrap_or(todo_tag);
+            Response::ok(&compare(index, done_tag, todo_tag))
+        }
+        "/words" => {
+            let limit = match values("limit").first().map(|l| l.parse::<usize>()) {
+                None => usize::MAX,
+                Some(Ok(limit)) => limit,
+                Some(Err(_)) => return Response::error(400, "limit must be a number"),
+            };
+            let mut notes: Vec<&NoteRecord> = index.tagged(&tags).collect();
+            notes.sort_by(|a, b| b.words.cmp(&a.words).then(a.path.cmp(&b.path)));
+            let body: Vec<Value> = notes
+                .into_iter()
+                .take(limit)
+                .map(|note| json!({ "path": note.path, "words": note.words }))
+                .collect();
+            Response::ok(&Value::Array(body))
+        }
+        "/query" => {
+            let body: Vec<Value> = index
+                .tagged(&tags)
+                .map(|note| json!({ "path": note.path, "tags": note.tags, "words": note.words }))
+                .collect();
+            Response::ok(&Value::Array(body))
+        }
+        _ => Response::error(404, "not found"),
+    }
+}
+
+fn totals<'a>(notes: impl Iterator<Item = &'a NoteRecord>) -> (usize, usize) {
+    notes.fold((0, 0), |(files, words), note| {
+        (files + 1, words + note.words)
+    })
+}
+
+fn compare(index: &VaultIndex, done_tag: &str, todo_tag: &str) -> Value {
+    let status = |note: &&NoteRecord| RefactorStatus::of(&note.tags, done_tag, todo_tag);
+    let done = totals(
+        index
+            .notes
+            .iter()
+            .filter(|n| status(n) == RefactorStatus::Done),
+    );
+    let todo = totals(
+        index
+            .notes
+            .iter()
+            .filter(|n| status(n) == RefactorStatus::Todo),
+    );
+    let total = totals(index.notes.iter());
+    let stats = |(files, words): (usize, usize)| json!({ "files": files, "words": words });
+
+    json!({
+        "done": stats(done),
+        "todo": stats(todo),
+        "total": stats(total),
+        "percentage": percentage(done.1, total.1),
+    })
+}
+
+/// Split a query string into decoded key/value pairs, keeping repeated keys.
+#[must_use]
+pub fn parse_query(query: &str) -> Vec<(String, String)> {
+    query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (decode(key), decode(value))
+        })
+        .collect()
+}
+
+fn decode(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => decoded.push(b' '),
+            b'%' => {
+                let byte = text
+                    .get(i + 1..i + 3)
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+                if let Some(byte) = byte {
+                    decoded.push(byte);
+                    i += 2;
+                } else {
+                    decoded.push(b'%');
+                }
+            }
+            byte => decoded.push(byte),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
@@ -2,6 +2,8 @@ use anyhow::Result;
 use clap::Args;
 use std::path::PathBuf;
 
+use crate::output::scan;
+
 // ============================================
 // TESTS
 // ============================================
@@ -41,6 +43,20 @@ mod tests {
         assert_eq!(args.similar.directories[0], PathBuf::from("."));
     }
 
+    #[test]
+    fn test_similar_with_note() {
+        let args = TestArgs::parse_from(["program", "my-note", "--limit", "5"]);
+        assert_eq!(args.similar.note.as_deref(), Some("my-note"));
+        assert_eq!(args.similar.limit, 5);
+    }
+
+    #[test]
+    fn test_similar_default_note_and_limit() {
+        let args = TestArgs::parse_from(["program"]);
+        assert!(args.similar.note.is_none());
+        assert_eq!(args.similar.limit, 10);
+    }
+
     #[test]
     fn test_similar_with_exclude() {
         let args = TestArgs::parse_from(["program", "-e", "node_modules", "target"]);
@@ -54,6 +70,9 @@ mod tests {
 
 #[derive(Args, Debug)]
 pub struct SimilarArgs {
+    /// Suggest notes related to this note (path, note name, title or alias) instead of listing similar pairs
+    pub note: Option<String>,
+
     /// Directories to scan (space-separated, defaults to current directory)
     #[arg(short = 'd', long = "dir", num_args = 0.., default_values = &["."])]
     pub directories: Vec<PathBuf>,
@@ -62,9 +81,13 @@ pub struct SimilarArgs {
     #[arg(short, long, num_args = 0..)]
     pub exclude: Vec<String>,
 
-    /// Similarity threshold (0.0-1.0)
+    /// Similarity threshold (0.0-1.0) for pairs
     #[arg(long, default_value = "0.5")]
     pub threshold: f64,
+
+    /// Maximum number of related notes to show for a single note
+    #[arg(long, default_value = "10")]
+    pub limit: usize,
 }
 
 // ============================================
@@ -73,8 +96,17 @@ pub struct SimilarArgs {
 
 pub fn run(args: SimilarArgs) -> Result<()> {
     let exclude_dirs: Vec<&str> = args.exclude.iter().map(String::as_str).collect();
+    let scanner = scan::scanner(&args.directories, &exclude_dirs)?;
+
+    if let Some(note) = &args.note {
+        let related = crate::similar::find_similar_to_in(&scanner, note)?;
+        for (_, path) in related.iter().take(args.limit) {
+            println!("{}", path.display());
+        }
+        return Ok(());
+    }
 
-    let pairs = crate::similar::find_similar(&args.directories, args.threshold, &exclude_dirs)?;
+    let pairs = crate::similar::find_similar_in(&scanner, args.threshold)?;
 
     for (_, path1, path2) in &pairs {
         println!("{} {}", path1.display(), path2.display());
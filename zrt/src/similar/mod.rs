@@ -2,12 +2,10 @@ pub mod cli;
 
 use anyhow::Result;
 use std::collections::{HashMap, HashSet};
-use std::path::PathBuf;
-use walkdir::WalkDir;
+use std::path::{Path, PathBuf};
 
-use crate::core::filter::utils::should_exclude;
-use crate::core::frontmatter::{parse_frontmatter, strip_frontmatter};
-use crate::core::ignore::load_ignore_patterns;
+use crate::core::links::{NoteNames, find_note_named};
+use crate::core::scanner::{ScanOptions, Scanner};
 
 // ============================================
 // TESTS
@@ -220,7 +218,8 @@ mod tests {
     #[test]
     fn test_should_parse_exclude_similarity_field() -> Result<()> {
         // REQ-SIM-303
-        let exclusions = parse_exclude_similarity("exclude_similarity:\n  - [[note2]]\n  - [[note3]]");
+        let exclusions =
+            parse_exclude_similarity("exclude_similarity:\n  - [[note2]]\n  - [[note3]]");
 
         assert_eq!(exclusions.len(), 2);
         assert!(exclusions.contains("note2"));
@@ -232,7 +231,11 @@ mod tests {
     fn test_should_skip_excluded_pairs() -> Result<()> {
         // REQ-SIM-304
         let dir = TempDir::new()?;
-        create_test_file(&dir, "note1.md", "---\nexclude_similarity:\n  - [[note2]]\n---\napple banana")?;
+        create_test_file(
+            &dir,
+            "note1.md",
+            "---\nexclude_similarity:\n  - [[note2]]\n---\napple banana",
+        )?;
         create_test_file(&dir, "note2.md", "apple banana")?;
 
         let pairs = find_similar(&[dir.path().to_path_buf()], 0.0, &[])?;
@@ -245,9 +248,8 @@ mod tests {
     #[test]
     fn test_should_support_yaml_list_format() -> Result<()> {
         // REQ-SIM-305
-        let exclusions = parse_exclude_similarity(
-            "exclude_similarity:\n  - [[note1]]\n  - [[note2]]\n"
-        );
+        let exclusions =
+            parse_exclude_similarity("exclude_similarity:\n  - [[note1]]\n  - [[note2]]\n");
 
         assert_eq!(exclusions.len(), 2);
         assert!(exclusions.contains("note1"));
@@ -313,7 +315,7 @@ mod tests {
         let dir = TempDir::new()?;
         create_test_file(&dir, "note1.md", "content")?;
         let binary_path = dir.path().join("binary.md");
-        fs::write(&binary_path, &[0xFF, 0xFE, 0x00])?;
+        fs::write(&binary_path, [0xFF, 0xFE, 0x00])?;
 
         let pairs = find_similar(&[dir.path().to_path_buf()], 0.0, &[])?;
 
@@ -333,6 +335,140 @@ mod tests {
         assert_eq!(similarity, 0.0);
         Ok(())
     }
+
+    // Related Note Suggestion Tests
+    #[test]
+    fn test_should_rank_related_notes_by_tfidf_cosine() -> Result<()> {
+        // REQ-SIM-601
+
+        // Given
+        let dir = TempDir::new()?;
+        create_test_file(
+            &dir,
+            "target.md",
+            "the zettelkasten method uses atomic notes",
+        )?;
+        create_test_file(&dir, "close.md", "atomic notes in the zettelkasten method")?;
+        create_test_file(&dir, "loose.md", "the method of cooking pasta")?;
+        create_test_file(&dir, "unrelated.md", "weather report sunny")?;
+
+        // When
+        let related = find_similar_to(&[dir.path().to_path_buf()], "target", &[])?;
+
+        // Then
+        let names: Vec<String> = related
+            .iter()
+            .map(|(_, p)| p.file_name().unwrap().to_string_lossy().to_string())
+            .collect();
+        assert_eq!(names, vec!["close.md", "loose.md"]);
+        assert!(related[0].0 > related[1].0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_weight_rare_terms_above_common_terms() -> Result<()> {
+        // REQ-SIM-602
+
+        // Given
+        let dir = TempDir::new()?;
+        create_test_file(&dir, "target.md", "common spaced")?;
+        create_test_file(&dir, "rare.md", "spaced")?;
+        create_test_file(&dir, "common.md", "common")?;
+        create_test_file(&dir, "other.md", "common")?;
+
+        // When
+        let related = find_similar_to(&[dir.path().to_path_buf()], "target", &[])?;
+
+        // Then
+        assert_eq!(related[0].1.file_name().unwrap(), "rare.md");
+        assert_eq!(related[1].1.file_name().unwrap(), "common.md");
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_find_target_note_by_path() -> Result<()> {
+        // REQ-SIM-603
+
+        // Given
+        let dir = TempDir::new()?;
+        let target = create_test_file(&dir, "target.md", "alpha beta")?;
+        create_test_file(&dir, "other.md", "alpha gamma")?;
+
+        // When
+        let related = find_similar_to(&[dir.path().to_path_buf()], &target.to_string_lossy(), &[])?;
+
+        // Then
+        assert_eq!(related.len(), 1);
+        assert_eq!(related[0].1.file_name().unwrap(), "other.md");
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_find_target_note_by_title_or_alias() -> Result<()> {
+        // REQ-SIM-608
+        let dir = TempDir::new()?;
+        create_test_file(
+            &dir,
+            "202401311542.md",
+            "---\ntitle: Feedback loops\naliases: [Loops]\n---\nalpha beta",
+        )?;
+        create_test_file(&dir, "other.md", "alpha gamma")?;
+
+        for name in ["feedback loops", "loops"] {
+            let related = find_similar_to(&[dir.path().to_path_buf()], name, &[])?;
+            assert_eq!(related.len(), 1);
+            assert!(related[0].1.ends_with("other.md"));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_error_when_target_note_missing() -> Result<()> {
+        // REQ-SIM-604
+        let dir = TempDir::new()?;
+        create_test_file(&dir, "note.md", "content")?;
+
+        let result = find_similar_to(&[dir.path().to_path_buf()], "missing", &[]);
+
+        assert!(result.is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_respect_exclusions_for_related_notes() -> Result<()> {
+        // REQ-SIM-605
+
+        // Given
+        let dir = TempDir::new()?;
+        create_test_file(
+            &dir,
+            "target.md",
+            "---\nexclude_similarity:\n  - [[copy]]\n---\nshared words here",
+        )?;
+        create_test_file(&dir, "copy.md", "shared words here")?;
+        create_test_file(&dir, "other.md", "---\ntags: [x]\n---\nshared words")?;
+
+        // When
+        let related = find_similar_to(&[dir.path().to_path_buf()], "target", &[])?;
+
+        // Then
+        assert_eq!(related.len(), 1);
+        assert_eq!(related[0].1.file_name().unwrap(), "other.md");
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_compute_cosine_similarity() {
+        // REQ-SIM-606
+        let vec1: HashMap<String, f64> = [("a".to_string(), 1.0)].into_iter().collect();
+        let vec2: HashMap<String, f64> = [("a".to_string(), 2.0), ("b".to_string(), 0.0)]
+            .into_iter()
+            .collect();
+        let empty: HashMap<String, f64> = HashMap::new();
+
+        assert!((cosine_similarity(&vec1, &vec2) - 1.0).abs() < 1e-9);
+        assert_eq!(cosine_similarity(&vec1, &empty), 0.0);
+    }
 }
 
 // ============================================
@@ -388,63 +524,86 @@ pub fn parse_exclude_similarity(frontmatter: &str) -> HashSet<String> {
     exclusions
 }
 
-/// Find similar note pairs
-pub fn find_similar(
-    dirs: &[PathBuf],
-    threshold: f64,
-    exclude: &[&str],
-) -> Result<Vec<(f64, PathBuf, PathBuf)>> {
-    let mut note_contents: HashMap<PathBuf, String> = HashMap::new();
-    let mut note_exclusions: HashMap<PathBuf, HashSet<String>> = HashMap::new();
+/// Note bodies and `exclude_similarity` entries gathered from a scan
+struct NoteCorpus {
+    contents: HashMap<PathBuf, String>,
+    exclusions: HashMap<PathBuf, HashSet<String>>,
+    /// Title and aliases of each note, for looking one up by name
+    names: HashMap<PathBuf, (Option<String>, Vec<String>)>,
+}
 
-    // Collect all notes
-    for dir in dirs {
-        let absolute_dir = if dir.is_absolute() {
-            dir.clone()
-        } else {
-            std::env::current_dir()?.join(dir)
+impl NoteCorpus {
+    /// Whether either note lists the other in its `exclude_similarity` field
+    fn is_excluded(&self, path1: &Path, path2: &Path) -> bool {
+        let stem = |path: &Path| {
+            path.file_stem()
+                .and_then(|s| s.to_str())
+                .unwrap_or("")
+                .to_owned()
         };
+        self.exclusions
+            .get(path1)
+            .is_some_and(|exclusions| exclusions.contains(&stem(path2)))
+            || self
+                .exclusions
+                .get(path2)
+                .is_some_and(|exclusions| exclusions.contains(&stem(path1)))
+    }
+}
 
-        let ignore_patterns = load_ignore_patterns(&absolute_dir)?;
-
-        for entry in WalkDir::new(&absolute_dir)
-            .follow_links(true)
-            .into_iter()
-            .filter_entry(|e| !should_exclude(e, exclude, Some(&ignore_patterns)))
-        {
-            let entry = entry?;
-            if !entry.file_type().is_file() {
-                continue;
-            }
+/// Collect markdown note bodies (without frontmatter) and their similarity exclusions
+fn collect_notes(scanner: &Scanner) -> Result<NoteCorpus> {
+    let mut note_contents: HashMap<PathBuf, String> = HashMap::new();
+    let mut note_exclusions: HashMap<PathBuf, HashSet<String>> = HashMap::new();
+    let mut note_names: HashMap<PathBuf, (Option<String>, Vec<String>)> = HashMap::new();
 
-            let path = entry.path();
-            if let Some(ext) = path.extension() {
-                if ext != "md" {
-                    continue;
-                }
-            } else {
-                continue;
-            }
+    // Collect all notes
+    for note in scanner.notes() {
+        let note = note?;
+        if !scanner.is_note(&note.path) {
+            continue;
+        }
+        let path = note.path.as_path();
+        note_contents.insert(path.to_path_buf(), note.body().to_owned());
+        note_names.insert(
+            path.to_path_buf(),
+            (note.title(), note.frontmatter.aliases.clone()),
+        );
 
-            if let Ok(content) = std::fs::read_to_string(path) {
-                let body = strip_frontmatter(&content).to_string();
-                note_contents.insert(path.to_path_buf(), body);
-
-                if let Ok(_frontmatter) = parse_frontmatter(&content) {
-                    if let Some(fm_text) = content.split("---").nth(1) {
-                        let exclusions = parse_exclude_similarity(fm_text);
-                        if !exclusions.is_empty() {
-                            note_exclusions.insert(path.to_path_buf(), exclusions);
-                        }
-                    }
+        if note.frontmatter_error.is_none() {
+            if let Some(fm_text) = note.content.split("---").nth(1) {
+                let exclusions = parse_exclude_similarity(fm_text);
+                if !exclusions.is_empty() {
+                    note_exclusions.insert(path.to_path_buf(), exclusions);
                 }
             }
         }
     }
 
+    Ok(NoteCorpus {
+        contents: note_contents,
+        exclusions: note_exclusions,
+        names: note_names,
+    })
+}
+
+/// Find similar note pairs
+pub fn find_similar(
+    dirs: &[PathBuf],
+    threshold: f64,
+    exclude: &[&str],
+) -> Result<Vec<(f64, PathBuf, PathBuf)>> {
+    find_similar_in(&scanner(dirs, exclude)?, threshold)
+}
+
+/// [`find_similar`] using an already configured scanner
+pub fn find_similar_in(scanner: &Scanner, threshold: f64) -> Result<Vec<(f64, PathBuf, PathBuf)>> {
+    let corpus = collect_notes(scanner)?;
+    let note_contents = &corpus.contents;
+
     // Tokenize all notes
     let mut note_tokens: HashMap<PathBuf, HashSet<String>> = HashMap::new();
-    for (path, content) in &note_contents {
+    for (path, content) in note_contents {
         note_tokens.insert(path.clone(), tokenize(content));
     }
 
@@ -458,22 +617,8 @@ pub fn find_similar(
             let path2 = note_paths[j];
 
             // Check exclusions
-            let note1_stem = path1.file_stem()
-                .and_then(|s| s.to_str())
-                .unwrap_or("");
-            let note2_stem = path2.file_stem()
-                .and_then(|s| s.to_str())
-                .unwrap_or("");
-
-            if let Some(exclusions) = note_exclusions.get(path1) {
-                if exclusions.contains(note2_stem) {
-                    continue;
-                }
-            }
-            if let Some(exclusions) = note_exclusions.get(path2) {
-                if exclusions.contains(note1_stem) {
-                    continue;
-                }
+            if corpus.is_excluded(path1, path2) {
+                continue;
             }
 
             // Compute similarity
@@ -505,3 +650,118 @@ pub fn find_similar(
 
     Ok(pairs)
 }
+
+/// Count how often each lowercase alphanumeric word occurs in text
+pub fn term_counts(text: &str) -> HashMap<String, usize> {
+    let mut counts = HashMap::new();
+    for word in text
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|s| !s.is_empty())
+    {
+        *counts.entry(word.to_lowercase()).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// Compute cosine similarity between two sparse term-weight vectors
+pub fn cosine_similarity(vec1: &HashMap<String, f64>, vec2: &HashMap<String, f64>) -> f64 {
+    let norm1 = vec1.values().map(|w| w * w).sum::<f64>().sqrt();
+    let norm2 = vec2.values().map(|w| w * w).sum::<f64>().sqrt();
+    if norm1 == 0.0 || norm2 == 0.0 {
+        return 0.0;
+    }
+
+    let dot: f64 = vec1
+        .iter()
+        .filter_map(|(term, w1)| vec2.get(term).map(|w2| w1 * w2))
+        .sum();
+
+    dot / (norm1 * norm2)
+}
+
+/// Find notes related to a single note using TF-IDF weighted cosine similarity
+///
+/// The note may be given as a path or a bare note name. Rare words shared
+/// with the note count for more than common ones, so the top results are
+/// good candidates to link to or merge with. Only notes with a positive
+/// score are returned, best match first.
+pub fn find_similar_to(
+    dirs: &[PathBuf],
+    note: &str,
+    exclude: &[&str],
+) -> Result<Vec<(f64, PathBuf)>> {
+    find_similar_to_in(&scanner(dirs, exclude)?, note)
+}
+
+/// [`find_similar_to`] using an already configured scanner
+pub fn find_similar_to_in(scanner: &Scanner, note: &str) -> Result<Vec<(f64, PathBuf)>> {
+    let corpus = collect_notes(scanner)?;
+
+    let mut paths: Vec<&PathBuf> = corpus.contents.keys().collect();
+    paths.sort();
+
+    let lookup: Vec<NoteNames<'_>> = paths
+        .iter()
+        .map(|path| {
+            let (title, aliases) = &corpus.names[*path];
+            NoteNames {
+                path,
+                title: title.as_deref(),
+                aliases,
+            }
+        })
+        .collect();
+    let Some(target) = find_note_named(&lookup, note) else {
+        anyhow::bail!("Note not found: {note}");
+    };
+
+    let counts: Vec<HashMap<String, usize>> = paths
+        .iter()
+        .map(|path| term_counts(&corpus.contents[*path]))
+        .collect();
+
+    // Document frequency of each term across the vault
+    let mut document_frequency: HashMap<&str, usize> = HashMap::new();
+    for terms in &counts {
+        for term in terms.keys() {
+            *document_frequency.entry(term.as_str()).or_insert(0) += 1;
+        }
+    }
+
+    // Smoothed inverse document frequency keeps terms found in every note above zero
+    let total = counts.len() as f64;
+    let weights: Vec<HashMap<String, f64>> = counts
+        .iter()
+        .map(|terms| {
+            terms
+                .iter()
+                .map(|(term, &count)| {
+                    let df = document_frequency[term.as_str()] as f64;
+                    let idf = ((1.0 + total) / (1.0 + df)).ln() + 1.0;
+                    (term.clone(), count as f64 * idf)
+                })
+                .collect()
+        })
+        .collect();
+
+    let mut results = Vec::new();
+    for (i, path) in paths.iter().enumerate() {
+        if i == target || corpus.is_excluded(paths[target], path) {
+            continue;
+        }
+
+        let score = cosine_similarity(&weights[target], &weights[i]);
+        if score > 0.0 {
+            results.push((score, (*path).clone()));
+        }
+    }
+
+    // Sort by similarity descending, keeping path order for ties
+    results.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap());
+
+    Ok(results)
+}
+
+fn scanner(dirs: &[PathBuf], exclude: &[&str]) -> Result<Scanner> {
+    Ok(ScanOptions::new(dirs).exclude(exclude).build()?)
+}
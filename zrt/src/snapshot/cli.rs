@@ -0,0 +1,108 @@
+use anyhow::Result;
+use clap::Args;
+use std::path::PathBuf;
+
+use crate::core::history::{HISTORY_FILE, load_history};
+use crate::init::ZrtConfig;
+use crate::output::{hooks, number, scan, webhook};
+use crate::snapshot::record_snapshot_in;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser, Debug)]
+    struct TestArgs {
+        #[command(flatten)]
+        snapshot: SnapshotArgs,
+    }
+
+    #[test]
+    fn test_should_default_to_history_file() {
+        // REQ-SNAP-003
+
+        // Given / When
+        let args = TestArgs::parse_from(["program"]);
+
+        // Then
+        assert_eq!(args.snapshot.output, PathBuf::from(".zrt/history.jsonl"));
+        assert_eq!(args.snapshot.directories, vec![PathBuf::from(".")]);
+    }
+
+    #[test]
+    fn test_should_accept_output_file() {
+        // REQ-SNAP-003
+
+        // Given / When
+        let args = TestArgs::parse_from(["program", "-o", "before.jsonl", "-e", "archive"]);
+
+        // Then
+        assert_eq!(args.snapshot.output, PathBuf::from("before.jsonl"));
+        assert_eq!(args.snapshot.exclude, vec!["archive"]);
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+#[derive(Args, Debug)]
+pub struct SnapshotArgs {
+    /// Directories to scan (space-separated, defaults to current directory)
+    #[arg(short = 'd', long = "dir", num_args = 0.., default_values = &["."])]
+    pub directories: Vec<PathBuf>,
+
+    /// Directories to exclude (space-separated)
+    #[arg(short, long, num_args = 0..)]
+    pub exclude: Vec<String>,
+
+    /// History file the snapshot is appended to
+    #[arg(short, long, default_value = HISTORY_FILE)]
+    pub output: PathBuf,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+pub fn run(args: SnapshotArgs, config: &ZrtConfig) -> Result<()> {
+    let exclude_dirs: Vec<&str> = args.exclude.iter().map(String::as_str).collect();
+    hooks::pre_snapshot(&config.hooks)?;
+    let previous = if config.notify.webhook.is_some() {
+        load_history(&args.output)?.last().map(|s| s.percentage)
+    } else {
+        None
+    };
+
+    let snapshot = record_snapshot_in(
+        &scan::scanner(&args.directories, &exclude_dirs)?,
+        &args.output,
+        &config.refactor.done_tag,
+        &config.refactor.todo_tag,
+    )?;
+
+    println!(
+        "Recorded snapshot in {}: {:.2}% done ({} done, {} todo, {} files)",
+        args.output.display(),
+        snapshot.percentage,
+        number::count(snapshot.done_files),
+        number::count(snapshot.todo_files),
+        number::count(snapshot.files)
+    );
+
+    // The first snapshot only sets the baseline milestones are measured from.
+    if let Some(previous) = previous {
+        webhook::announce_milestones(&config.notify, previous, &snapshot);
+    }
+
+    if !config.hooks.post_snapshot.is_empty() {
+        let report = serde_json::to_value(&snapshot)?;
+        hooks::post_snapshot(&config.hooks, &report)?;
+    }
+
+    Ok(())
+}
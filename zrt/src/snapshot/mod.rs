@@ -0,0 +1,116 @@
+pub mod cli;
+
+use anyhow::Result;
+use std::path::{Path, PathBuf};
+
+use crate::core::history::{Snapshot, append_snapshot};
+use crate::core::scanner::{ScanOptions, Scanner};
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::history::load_history;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn create_test_file(dir: &TempDir, name: &str, content: &str) -> Result<PathBuf> {
+        let path = dir.path().join(name);
+        fs::write(&path, content)?;
+        Ok(path)
+    }
+
+    #[test]
+    fn test_should_append_snapshot_to_history() -> Result<()> {
+        // REQ-SNAP-001
+
+        // Given
+        let dir = TempDir::new()?;
+        create_test_file(&dir, "a.md", "---\ntags: [refactored]\n---\none two three")?;
+        create_test_file(&dir, "b.md", "---\ntags: [to_refactor]\n---\none")?;
+        let history = dir.path().join(".zrt/history.jsonl");
+
+        // When
+        record_snapshot(
+            &[dir.path().to_path_buf()],
+            &[],
+            &history,
+            "refactored",
+            "to_refactor",
+        )?;
+        record_snapshot(
+            &[dir.path().to_path_buf()],
+            &[],
+            &history,
+            "refactored",
+            "to_refactor",
+        )?;
+
+        // Then
+        let snapshots = load_history(&history)?;
+        assert_eq!(snapshots.len(), 2);
+        assert_eq!(snapshots[1].done_files, 1);
+        assert_eq!(snapshots[1].todo_files, 1);
+        assert_eq!(snapshots[1].percentage, 75.0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_respect_excluded_directories() -> Result<()> {
+        // REQ-SNAP-002
+
+        // Given
+        let dir = TempDir::new()?;
+        create_test_file(&dir, "a.md", "one")?;
+        fs::create_dir(dir.path().join("archive"))?;
+        create_test_file(&dir, "archive/b.md", "two")?;
+        let history = dir.path().join("history.jsonl");
+
+        // When
+        let snapshot = record_snapshot(
+            &[dir.path().to_path_buf()],
+            &["archive"],
+            &history,
+            "refactored",
+            "to_refactor",
+        )?;
+
+        // Then
+        assert_eq!(snapshot.files, 1);
+        Ok(())
+    }
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+/// Scan the directories and append their current statistics to the history file.
+pub fn record_snapshot(
+    dirs: &[PathBuf],
+    exclude: &[&str],
+    history: &Path,
+    done_tag: &str,
+    todo_tag: &str,
+) -> Result<Snapshot> {
+    record_snapshot_in(
+        &ScanOptions::new(dirs).exclude(exclude).build()?,
+        history,
+        done_tag,
+        todo_tag,
+    )
+}
+
+/// [`record_snapshot`] using an already configured scanner
+pub fn record_snapshot_in(
+    scanner: &Scanner,
+    history: &Path,
+    done_tag: &str,
+    todo_tag: &str,
+) -> Result<Snapshot> {
+    let snapshot = Snapshot::capture_in(scanner, done_tag, todo_tag)?;
+    append_snapshot(history, &snapshot)?;
+    Ok(snapshot)
+}
@@ -0,0 +1,164 @@
+use anyhow::Result;
+use clap::Args;
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use crate::core::scanner::ScanOptions;
+use crate::output::exec::ExecArgs;
+use crate::output::files_from::FilesFromArgs;
+use crate::output::open::OpenArgs;
+use crate::output::titles::TitleArgs;
+use crate::output::{number, scan};
+use crate::stale::{AgeFrom, AgeStats, StaleReport, stale_notes};
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stale::StaleNote;
+    use clap::Parser;
+
+    #[derive(Parser, Debug)]
+    struct TestArgs {
+        #[command(flatten)]
+        stale: StaleArgs,
+    }
+
+    #[test]
+    fn test_should_default_to_a_year_without_tag_filter() {
+        // REQ-STALE-004
+        let args = TestArgs::parse_from(["program"]);
+
+        assert_eq!(args.stale.days, 365);
+        assert!(args.stale.tag.is_empty());
+    }
+
+    #[test]
+    fn test_should_accept_days_and_tags() {
+        // REQ-STALE-004
+        let args = TestArgs::parse_from(["program", "--days", "90", "-t", "to_refactor"]);
+
+        assert_eq!(args.stale.days, 90);
+        assert_eq!(args.stale.tag, vec!["to_refactor"]);
+        assert!(!args.stale.created);
+        assert!(TestArgs::parse_from(["program", "--created"]).stale.created);
+    }
+
+    #[test]
+    fn test_should_summarize_stale_share_and_ages() {
+        // REQ-STALE-003
+        let report = StaleReport {
+            stale: vec![StaleNote {
+                path: PathBuf::from("a.md"),
+                days: 400,
+            }],
+            ages: vec![400, 10, 30],
+        };
+
+        assert_eq!(
+            summary(&report, 365, AgeFrom::Modified).as_deref(),
+            Some("1 of 3 notes untouched for 365+ days (oldest 400, median 30, mean 146.7 days)")
+        );
+        assert_eq!(
+            summary(&report, 365, AgeFrom::Created).as_deref(),
+            Some("1 of 3 notes created 365+ days ago (oldest 400, median 30, mean 146.7 days)")
+        );
+        assert_eq!(
+            summary(&StaleReport::default(), 365, AgeFrom::Modified),
+            None
+        );
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+#[derive(Args, Debug)]
+pub struct StaleArgs {
+    /// Directories to scan (space-separated, defaults to current directory)
+    #[arg(short = 'd', long = "dir", num_args = 0.., default_values = &["."])]
+    pub directories: Vec<PathBuf>,
+
+    /// Directories to exclude (space-separated)
+    #[arg(short, long, num_args = 0..)]
+    pub exclude: Vec<String>,
+
+    /// List notes not modified in at least this many days
+    #[arg(long, default_value = "365")]
+    pub days: u64,
+
+    /// Measure age from the creation date (frontmatter `created` or the zettel
+    /// ID in the file name) instead of the modification time
+    #[arg(long)]
+    pub created: bool,
+
+    /// Only consider notes with any of these tags (default: every note)
+    #[arg(short, long, num_args = 1..)]
+    pub tag: Vec<String>,
+
+    #[command(flatten)]
+    pub files_from: FilesFromArgs,
+
+    #[command(flatten)]
+    pub titles: TitleArgs,
+
+    #[command(flatten)]
+    pub open: OpenArgs,
+
+    #[command(flatten)]
+    pub exec: ExecArgs,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+pub fn run(args: StaleArgs) -> Result<()> {
+    let tag_refs: Vec<&str> = args.tag.iter().map(String::as_str).collect();
+    let exclude_dirs: Vec<&str> = args.exclude.iter().map(String::as_str).collect();
+
+    let options = args
+        .files_from
+        .apply(ScanOptions::new(&args.directories).exclude(&exclude_dirs))?;
+    let scanner = scan::attach(options).build()?;
+    let from = if args.created {
+        AgeFrom::Created
+    } else {
+        AgeFrom::Modified
+    };
+    let report = stale_notes(&scanner, &tag_refs, args.days, from, SystemTime::now())?;
+
+    for note in &report.stale {
+        println!("{} {}", note.days, args.titles.label(&note.path));
+    }
+    if let Some(summary) = summary(&report, args.days, from) {
+        if !report.stale.is_empty() {
+            println!();
+        }
+        println!("{summary}");
+    }
+
+    let paths: Vec<&PathBuf> = report.stale.iter().map(|note| &note.path).collect();
+    args.exec.run(&paths)?;
+    args.open.open(&paths)
+}
+
+/// One line on how many of the considered notes are stale and how old they are.
+fn summary(report: &StaleReport, days: u64, from: AgeFrom) -> Option<String> {
+    let stats = AgeStats::new(&report.ages)?;
+    let age = match from {
+        AgeFrom::Modified => format!("untouched for {days}+ days"),
+        AgeFrom::Created => format!("created {days}+ days ago"),
+    };
+    Some(format!(
+        "{} of {} notes {age} (oldest {}, median {}, mean {:.1} days)",
+        number::count(report.stale.len()),
+        number::count(report.ages.len()),
+        stats.oldest,
+        stats.median,
+        stats.mean
+    ))
+}
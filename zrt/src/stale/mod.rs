@@ -0,0 +1,234 @@
+pub mod cli;
+
+use anyhow::Result;
+use chrono::{DateTime, Local};
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use crate::core::scanner::Scanner;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::scanner::ScanOptions;
+    use std::fs;
+    use std::time::Duration;
+    use tempfile::TempDir;
+
+    const DAY: u64 = 86_400;
+
+    fn note(dir: &TempDir, name: &str, content: &str, days_old: u64) -> Result<PathBuf> {
+        let path = dir.path().join(name);
+        fs::write(&path, content)?;
+        let modified = SystemTime::now() - Duration::from_secs(days_old * DAY + 60);
+        fs::File::options()
+            .write(true)
+            .open(&path)?
+            .set_modified(modified)?;
+        Ok(path)
+    }
+
+    #[test]
+    fn test_should_list_notes_untouched_for_n_days_oldest_first() -> Result<()> {
+        // REQ-STALE-001
+        // Given notes last modified 10, 400 and 800 days ago, and an old non-note
+        let dir = TempDir::new()?;
+        note(&dir, "fresh.md", "x", 10)?;
+        let old = note(&dir, "old.md", "x", 400)?;
+        let older = note(&dir, "older.md", "x", 800)?;
+        note(&dir, "image.png", "x", 900)?;
+        let scanner = ScanOptions::new([dir.path()]).build()?;
+
+        // When listing notes stale for a year
+        let report = stale_notes(&scanner, &[], 365, AgeFrom::Modified, SystemTime::now())?;
+
+        // Then the two old notes are listed, oldest first
+        let found: Vec<_> = report
+            .stale
+            .iter()
+            .map(|n| (n.path.clone(), n.days))
+            .collect();
+        assert_eq!(found, vec![(older, 800), (old, 400)]);
+        assert_eq!(report.ages.len(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_filter_stale_notes_by_tag() -> Result<()> {
+        // REQ-STALE-002
+        let dir = TempDir::new()?;
+        let todo = note(&dir, "a.md", "---\ntags: [to_refactor]\n---\nx", 500)?;
+        note(&dir, "b.md", "---\ntags: [refactored]\n---\nx", 500)?;
+        note(&dir, "c.md", "---\ntags: [to_refactor]\n---\nx", 5)?;
+        let scanner = ScanOptions::new([dir.path()]).build()?;
+
+        let report = stale_notes(
+            &scanner,
+            &["to_refactor"],
+            365,
+            AgeFrom::Modified,
+            SystemTime::now(),
+        )?;
+
+        let found: Vec<_> = report.stale.iter().map(|n| n.path.clone()).collect();
+        assert_eq!(found, vec![todo]);
+        assert_eq!(report.ages.len(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_measure_age_from_creation_date() -> Result<()> {
+        // REQ-STALE-005
+        // Given freshly modified notes created long ago, and one without a creation date
+        let dir = TempDir::new()?;
+        let zettel = note(&dir, "201001011200-old-idea.md", "x", 0)?;
+        let dated = note(&dir, "b.md", "---\ncreated: 2011-06-01\n---\nx", 0)?;
+        note(&dir, "undated.md", "x", 0)?;
+        let scanner = ScanOptions::new([dir.path()]).build()?;
+
+        // When measuring age from creation
+        let report = stale_notes(&scanner, &[], 365, AgeFrom::Created, SystemTime::now())?;
+
+        // Then both dated notes are stale and the undated one is left out
+        let found: Vec<_> = report.stale.iter().map(|n| n.path.clone()).collect();
+        assert_eq!(found, vec![zettel, dated]);
+        assert_eq!(report.ages.len(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_summarize_note_ages() {
+        // REQ-STALE-003
+        let stats = AgeStats::new(&[400, 10, 800, 30]).expect("ages");
+
+        assert_eq!(stats.oldest, 800);
+        assert_eq!(stats.median, 215);
+        assert!((stats.mean - 310.0).abs() < f64::EPSILON);
+        assert_eq!(AgeStats::new(&[7]).map(|s| s.median), Some(7));
+        assert_eq!(AgeStats::new(&[]), None);
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// Which date a note's age is measured from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AgeFrom {
+    /// The file's modification time
+    #[default]
+    Modified,
+    /// The frontmatter `created` date or the zettel ID in the file name
+    Created,
+}
+
+/// A note that has not been modified for a while.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StaleNote {
+    pub path: PathBuf,
+    /// Whole days since the file was last modified (or created)
+    pub days: u64,
+}
+
+/// Stale notes among the notes considered, with every considered note's age.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StaleReport {
+    /// Notes untouched for at least the requested days, oldest first
+    pub stale: Vec<StaleNote>,
+    /// Age in days of every considered note, in walk order
+    pub ages: Vec<u64>,
+}
+
+/// Summary of note ages in days.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AgeStats {
+    pub oldest: u64,
+    pub median: u64,
+    pub mean: f64,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+impl AgeStats {
+    /// Statistics over `ages`, or `None` if there are none.
+    ///
+    /// The median of an even count is the mean of the middle two, rounded down.
+    #[must_use]
+    pub fn new(ages: &[u64]) -> Option<Self> {
+        let mut sorted = ages.to_vec();
+        sorted.sort_unstable();
+        let oldest = *sorted.last()?;
+        let mid = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[mid - 1] + sorted[mid]) / 2
+        } else {
+            sorted[mid]
+        };
+        let mean = sorted.iter().sum::<u64>() as f64 / sorted.len() as f64;
+        Some(Self {
+            oldest,
+            median,
+            mean,
+        })
+    }
+}
+
+/// Markdown notes carrying any of `tags` (or every note when `tags` is empty)
+/// that were last modified (or created) at least `days` days before `now`.
+///
+/// Notes whose modification time cannot be read, or that have no creation
+/// date when ages are measured from it, are left out.
+///
+/// # Errors
+///
+/// Returns an error if the scan fails.
+pub fn stale_notes(
+    scanner: &Scanner,
+    tags: &[&str],
+    days: u64,
+    from: AgeFrom,
+    now: SystemTime,
+) -> Result<StaleReport> {
+    let today = DateTime::<Local>::from(now).date_naive();
+    let mut report = StaleReport::default();
+    for record in scanner.iter() {
+        let record = record?;
+        let is_note = scanner.is_note(&record.path);
+        if !is_note || !(tags.is_empty() || record.has_any_tag(tags)) {
+            continue;
+        }
+        let age = match from {
+            AgeFrom::Modified => std::fs::metadata(&record.path)
+                .and_then(|meta| meta.modified())
+                .ok()
+                .map(|modified| {
+                    now.duration_since(modified)
+                        .map_or(0, |elapsed| elapsed.as_secs() / 86_400)
+                }),
+            AgeFrom::Created => record
+                .created
+                .map(|created| u64::try_from((today - created).num_days()).unwrap_or(0)),
+        };
+        let Some(age) = age else {
+            continue;
+        };
+        report.ages.push(age);
+        if age >= days {
+            report.stale.push(StaleNote {
+                path: record.path,
+                days: age,
+            });
+        }
+    }
+
+    report
+        .stale
+        .sort_by(|a, b| b.days.cmp(&a.days).then_with(|| a.path.cmp(&b.path)));
+    Ok(report)
+}
@@ -0,0 +1,78 @@
+use anyhow::Result;
+use chrono::Utc;
+use clap::Args;
+use std::path::PathBuf;
+
+use crate::core::history::{HISTORY_FILE, load_history};
+use crate::streak::{daily_completions, streak};
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser, Debug)]
+    struct TestArgs {
+        #[command(flatten)]
+        streak: StreakArgs,
+    }
+
+    #[test]
+    fn test_should_default_to_one_note_per_day() {
+        // REQ-STREAK-004
+
+        // Given / When
+        let args = TestArgs::parse_from(["program"]);
+
+        // Then
+        assert_eq!(args.streak.min, 1);
+        assert_eq!(args.streak.history, PathBuf::from(".zrt/history.jsonl"));
+    }
+
+    #[test]
+    fn test_should_accept_minimum() {
+        // REQ-STREAK-004
+        let args = TestArgs::parse_from(["program", "-n", "3"]);
+        assert_eq!(args.streak.min, 3);
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+#[derive(Args, Debug)]
+pub struct StreakArgs {
+    /// Notes that must move from todo to done for a day to count
+    #[arg(short = 'n', long, default_value = "1")]
+    pub min: usize,
+
+    /// History file to read snapshots from
+    #[arg(long, default_value = HISTORY_FILE)]
+    pub history: PathBuf,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+pub fn run(args: StreakArgs) -> Result<()> {
+    let history = load_history(&args.history)?;
+    if history.is_empty() {
+        anyhow::bail!(
+            "No snapshots found in {}; record one daily with `zrt snapshot`",
+            args.history.display()
+        );
+    }
+
+    let days = daily_completions(&history);
+    let streak = streak(&days, args.min, Utc::now().date_naive());
+
+    println!("current: {} days", streak.current);
+    println!("longest: {} days", streak.longest);
+
+    Ok(())
+}
@@ -0,0 +1,219 @@
+pub mod cli;
+
+use chrono::{NaiveDate, TimeDelta};
+
+use crate::core::history::Snapshot;
+use crate::core::status::RefactorStatus;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::links::note_from_content;
+    use chrono::DateTime;
+    use std::path::Path;
+
+    /// Snapshot where the first `done` of `total` notes are done and the rest todo.
+    fn snapshot(timestamp: &str, done: usize, total: usize) -> Snapshot {
+        let notes: Vec<_> = (0..total)
+            .map(|i| {
+                let tag = if i < done {
+                    "refactored"
+                } else {
+                    "to_refactor"
+                };
+                note_from_content(
+                    Path::new(&format!("n{i}.md")),
+                    &format!("---\ntags: [{tag}]\n---\nword"),
+                )
+            })
+            .collect();
+        Snapshot::from_notes(
+            DateTime::parse_from_rfc3339(timestamp).unwrap().to_utc(),
+            &notes,
+            "refactored",
+            "to_refactor",
+        )
+    }
+
+    fn date(date: &str) -> NaiveDate {
+        date.parse().unwrap()
+    }
+
+    #[test]
+    fn test_should_count_notes_moving_from_todo_to_done_per_day() {
+        // REQ-STREAK-001
+
+        // Given
+        let history = vec![
+            snapshot("2026-03-01T08:00:00Z", 0, 10),
+            snapshot("2026-03-02T08:00:00Z", 1, 10),
+            snapshot("2026-03-02T20:00:00Z", 3, 10),
+            snapshot("2026-03-04T20:00:00Z", 4, 10),
+        ];
+
+        // When
+        let days = daily_completions(&history);
+
+        // Then
+        assert_eq!(
+            days,
+            vec![
+                DailyProgress {
+                    date: date("2026-03-02"),
+                    completed: 3
+                },
+                DailyProgress {
+                    date: date("2026-03-04"),
+                    completed: 1
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_should_find_current_and_longest_streak() {
+        // REQ-STREAK-002
+
+        // Given
+        let days: Vec<DailyProgress> = [
+            ("2026-03-01", 2),
+            ("2026-03-02", 1),
+            ("2026-03-03", 3),
+            ("2026-03-04", 0),
+            ("2026-03-05", 1),
+            ("2026-03-06", 2),
+        ]
+        .iter()
+        .map(|(d, completed)| DailyProgress {
+            date: date(d),
+            completed: *completed,
+        })
+        .collect();
+
+        // When / Then
+        assert_eq!(
+            streak(&days, 1, date("2026-03-06")),
+            Streak {
+                current: 2,
+                longest: 3
+            }
+        );
+        assert_eq!(
+            streak(&days, 2, date("2026-03-07")),
+            Streak {
+                current: 1,
+                longest: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_should_break_streak_on_missing_days() {
+        // REQ-STREAK-003
+
+        // Given
+        let days: Vec<DailyProgress> = [("2026-03-01", 1), ("2026-03-03", 1)]
+            .iter()
+            .map(|(d, completed)| DailyProgress {
+                date: date(d),
+                completed: *completed,
+            })
+            .collect();
+
+        // When / Then
+        assert_eq!(
+            streak(&days, 1, date("2026-03-03")),
+            Streak {
+                current: 1,
+                longest: 1
+            }
+        );
+        assert_eq!(streak(&days, 1, date("2026-03-05")).current, 0);
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// Notes that moved from todo to done during one day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DailyProgress {
+    pub date: NaiveDate,
+    pub completed: usize,
+}
+
+/// Consecutive days meeting the daily goal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Streak {
+    /// Streak ending today, or yesterday if today is not recorded yet
+    pub current: usize,
+    pub longest: usize,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+/// Count notes moving from todo to done on each recorded day, comparing the
+/// day's last snapshot with the last snapshot of the previous recorded day.
+pub fn daily_completions(history: &[Snapshot]) -> Vec<DailyProgress> {
+    let mut snapshots: Vec<&Snapshot> = history.iter().collect();
+    snapshots.sort_by_key(|s| s.timestamp);
+
+    // Last snapshot of each day
+    let mut days: Vec<(NaiveDate, &Snapshot)> = Vec::new();
+    for snapshot in snapshots {
+        let date = snapshot.timestamp.date_naive();
+        match days.last_mut() {
+            Some(last) if last.0 == date => last.1 = snapshot,
+            _ => days.push((date, snapshot)),
+        }
+    }
+
+    days.windows(2)
+        .map(|pair| {
+            let (before, (date, after)) = (pair[0].1, pair[1]);
+            let completed = after
+                .notes
+                .iter()
+                .filter(|(path, note)| {
+                    note.status == RefactorStatus::Done
+                        && before
+                            .notes
+                            .get(*path)
+                            .is_some_and(|old| old.status == RefactorStatus::Todo)
+                })
+                .count();
+            DailyProgress { date, completed }
+        })
+        .collect()
+}
+
+/// Find the current and longest run of consecutive days with at least `min`
+/// completions. Days without a snapshot break a streak.
+pub fn streak(days: &[DailyProgress], min: usize, today: NaiveDate) -> Streak {
+    let mut longest = 0;
+    let mut run = 0;
+    let mut previous: Option<NaiveDate> = None;
+
+    for day in days {
+        let consecutive = previous.is_some_and(|p| p + TimeDelta::days(1) == day.date);
+        if day.completed >= min {
+            run = if consecutive { run + 1 } else { 1 };
+            longest = longest.max(run);
+        } else {
+            run = 0;
+        }
+        previous = Some(day.date);
+    }
+
+    let alive = previous.is_some_and(|last| last + TimeDelta::days(1) >= today);
+    Streak {
+        current: if alive { run } else { 0 },
+        longest,
+    }
+}
@@ -0,0 +1,111 @@
+use anyhow::Result;
+use clap::Args;
+use std::path::PathBuf;
+
+use crate::core::scanner::ScanOptions;
+use crate::init::ZrtConfig;
+use crate::output::files_from::FilesFromArgs;
+use crate::output::{number, scan};
+use crate::suggest_splits::suggest_splits;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser, Debug)]
+    struct TestArgs {
+        #[command(flatten)]
+        splits: SuggestSplitsArgs,
+    }
+
+    #[test]
+    fn test_should_default_thresholds_to_config() {
+        // REQ-SPLIT-003
+        let args = TestArgs::parse_from(["program"]);
+
+        assert_eq!(args.splits.words, None);
+        assert_eq!(args.splits.headings, None);
+    }
+
+    #[test]
+    fn test_should_accept_threshold_overrides() {
+        // REQ-SPLIT-003
+        let args = TestArgs::parse_from(["program", "--words", "800", "--headings", "4"]);
+
+        assert_eq!(args.splits.words, Some(800));
+        assert_eq!(args.splits.headings, Some(4));
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+#[derive(Args, Debug)]
+pub struct SuggestSplitsArgs {
+    /// Directories to scan (space-separated, defaults to current directory)
+    #[arg(short = 'd', long = "dir", num_args = 0.., default_values = &["."])]
+    pub directories: Vec<PathBuf>,
+
+    /// Directories to exclude (space-separated)
+    #[arg(short, long, num_args = 0..)]
+    pub exclude: Vec<String>,
+
+    /// Report notes with at least this many words (default: refactor.word_threshold)
+    #[arg(long)]
+    pub words: Option<usize>,
+
+    /// Report notes with at least this many top-level sections
+    /// (default: refactor.heading_threshold)
+    #[arg(long)]
+    pub headings: Option<usize>,
+
+    #[command(flatten)]
+    pub files_from: FilesFromArgs,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+pub fn run(args: SuggestSplitsArgs, config: &ZrtConfig) -> Result<()> {
+    let exclude_dirs: Vec<&str> = args.exclude.iter().map(String::as_str).collect();
+
+    let options = args
+        .files_from
+        .apply(ScanOptions::new(&args.directories).exclude(&exclude_dirs))?;
+    let scanner = scan::attach(options).build()?;
+    let candidates = suggest_splits(
+        &scanner,
+        args.words.unwrap_or(config.refactor.word_threshold),
+        args.headings.unwrap_or(config.refactor.heading_threshold),
+    )?;
+
+    for (i, candidate) in candidates.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        println!(
+            "{} ({} words, {} sections)",
+            candidate.path.display(),
+            number::count(candidate.words),
+            candidate.split_points()
+        );
+        if candidate.split_points() == 0 {
+            println!("  no headings to split at");
+            continue;
+        }
+        for section in &candidate.sections {
+            let heading = section
+                .heading
+                .as_deref()
+                .unwrap_or("(before first section)");
+            println!("  {:>6} {heading}", number::count(section.words));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,200 @@
+pub mod cli;
+
+use anyhow::Result;
+use std::path::PathBuf;
+
+use crate::core::scanner::Scanner;
+use crate::outline::heading;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::scanner::ScanOptions;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn summary(sections: &[Section]) -> Vec<(Option<&str>, usize)> {
+        sections
+            .iter()
+            .map(|s| (s.heading.as_deref(), s.words))
+            .collect()
+    }
+
+    #[test]
+    fn test_should_split_at_top_level_sections() {
+        // REQ-SPLIT-001
+        let body = "# Title\nintro words here\n## One\na b\n### Sub\nc\n```\n## not a split\n```\n## Two\nd e f";
+
+        assert_eq!(
+            summary(&sections(body)),
+            vec![(None, 3), (Some("One"), 9), (Some("Two"), 3)]
+        );
+    }
+
+    #[test]
+    fn test_should_split_at_h1s_when_a_note_has_several() {
+        // REQ-SPLIT-001
+        let body = "# First\none\n## Inner\ntwo\n# Second\nthree";
+
+        assert_eq!(
+            summary(&sections(body)),
+            vec![(Some("First"), 2), (Some("Second"), 1)]
+        );
+        assert_eq!(summary(&sections("just text")), vec![(None, 2)]);
+    }
+
+    #[test]
+    fn test_should_flag_notes_over_either_threshold() -> Result<()> {
+        // REQ-SPLIT-002
+        // Given a long note, a note with many sections and a short note
+        let dir = TempDir::new()?;
+        fs::write(
+            dir.path().join("a.md"),
+            "# Long\none two three four five six",
+        )?;
+        fs::write(dir.path().join("b.md"), "## A\nx\n## B\ny\n## C\nz")?;
+        fs::write(dir.path().join("c.md"), "## A\nshort")?;
+        let scanner = ScanOptions::new([dir.path()]).build()?;
+
+        // When looking for split candidates with 5 words or 3 sections
+        let candidates = suggest_splits(&scanner, 5, 3)?;
+
+        // Then the long note and the sectioned note are suggested
+        let found: Vec<_> = candidates
+            .iter()
+            .map(|c| (c.path.clone(), c.words, c.sections.len()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (dir.path().join("a.md"), 6, 1),
+                (dir.path().join("b.md"), 3, 3),
+            ]
+        );
+        Ok(())
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// A part of a note that could become a note of its own.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section {
+    /// The heading the section starts at, or `None` for text before the
+    /// first split point
+    pub heading: Option<String>,
+    /// Words in the section, headings excluded
+    pub words: usize,
+}
+
+/// A note worth splitting, with its candidate sections in order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SplitCandidate {
+    pub path: PathBuf,
+    /// Words in the body, excluding frontmatter and headings
+    pub words: usize,
+    pub sections: Vec<Section>,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+impl SplitCandidate {
+    /// Sections that start at a heading, i.e. the proposed split points.
+    #[inline]
+    #[must_use]
+    pub fn split_points(&self) -> usize {
+        self.sections.iter().filter(|s| s.heading.is_some()).count()
+    }
+}
+
+/// Divide `body` at its top-level headings.
+///
+/// Top-level headings are the H1s when a note has more than one, and
+/// otherwise the shallowest headings below the H1. Text before the first of
+/// them, including a single H1, forms an unnamed leading section, which is
+/// dropped when empty.
+#[must_use]
+pub fn sections(body: &str) -> Vec<Section> {
+    let mut lines = Vec::new();
+    let mut in_fence = false;
+    for line in body.lines() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+            lines.push((line, None));
+            continue;
+        }
+        lines.push((line, if in_fence { None } else { heading(line) }));
+    }
+
+    let levels: Vec<usize> = lines
+        .iter()
+        .filter_map(|(_, h)| h.as_ref().map(|h| h.level))
+        .collect();
+    let split_level = if levels.iter().filter(|&&level| level == 1).count() > 1 {
+        Some(1)
+    } else {
+        levels.iter().copied().filter(|&level| level > 1).min()
+    };
+
+    let mut found = vec![Section {
+        heading: None,
+        words: 0,
+    }];
+    for (line, parsed) in lines {
+        match parsed {
+            Some(h) if Some(h.level) == split_level => found.push(Section {
+                heading: Some(h.text),
+                words: 0,
+            }),
+            Some(_) => {}
+            None => {
+                if let Some(current) = found.last_mut() {
+                    current.words += line.split_whitespace().count();
+                }
+            }
+        }
+    }
+
+    if found.len() > 1 && found[0].words == 0 {
+        found.remove(0);
+    }
+    found
+}
+
+/// Markdown notes with at least `word_threshold` words or `heading_threshold`
+/// top-level sections, in walk order, with their proposed sections.
+///
+/// # Errors
+///
+/// Returns an error if the scan fails or a note cannot be read.
+pub fn suggest_splits(
+    scanner: &Scanner,
+    word_threshold: usize,
+    heading_threshold: usize,
+) -> Result<Vec<SplitCandidate>> {
+    let mut candidates = Vec::new();
+    for note in scanner.notes() {
+        let note = note?;
+        if !scanner.is_note(&note.path) {
+            continue;
+        }
+        let sections = sections(note.body());
+        let candidate = SplitCandidate {
+            words: sections.iter().map(|s| s.words).sum(),
+            path: note.path,
+            sections,
+        };
+        if candidate.words >= word_threshold || candidate.split_points() >= heading_threshold {
+            candidates.push(candidate);
+        }
+    }
+    Ok(candidates)
+}
@@ -0,0 +1,108 @@
+use anyhow::{Result, bail};
+use chrono::Utc;
+use clap::Args;
+use std::path::PathBuf;
+
+use crate::core::history::{HISTORY_FILE, Snapshot, load_history, note_key};
+use crate::core::links::LinkIndex;
+use crate::init::ZrtConfig;
+use crate::output::scan;
+use crate::summary::{parse_since, render_markdown, starting_snapshot, summarize};
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser, Debug)]
+    struct TestArgs {
+        #[command(flatten)]
+        summary: SummaryArgs,
+    }
+
+    #[test]
+    fn test_should_default_to_last_week() {
+        // REQ-SUMMARY-005
+        let args = TestArgs::parse_from(["program"]);
+
+        assert_eq!(args.summary.since, "7d");
+        assert_eq!(args.summary.top, 5);
+        assert_eq!(args.summary.history, PathBuf::from(HISTORY_FILE));
+    }
+
+    #[test]
+    fn test_should_accept_since_and_top() {
+        // REQ-SUMMARY-005
+        let args = TestArgs::parse_from(["program", "--since", "2w", "--top", "10"]);
+
+        assert_eq!(args.summary.since, "2w");
+        assert_eq!(args.summary.top, 10);
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+#[derive(Args, Debug)]
+pub struct SummaryArgs {
+    /// Start of the period: days (7d), weeks (2w) or a date (YYYY-MM-DD)
+    #[arg(long, default_value = "7d")]
+    pub since: String,
+
+    /// Number of notes to list under most growth
+    #[arg(long, default_value_t = 5)]
+    pub top: usize,
+
+    /// History file to read snapshots from
+    #[arg(long, default_value = HISTORY_FILE)]
+    pub history: PathBuf,
+
+    /// Directories to scan (space-separated, defaults to current directory)
+    #[arg(short = 'd', long = "dir", num_args = 0.., default_values = &["."])]
+    pub directories: Vec<PathBuf>,
+
+    /// Directories to exclude (space-separated)
+    #[arg(short, long, num_args = 0..)]
+    pub exclude: Vec<String>,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+pub fn run(args: SummaryArgs, config: &ZrtConfig) -> Result<()> {
+    let now = Utc::now();
+    let cutoff = parse_since(&args.since, now)?;
+    let history = load_history(&args.history)?;
+    let Some(old) = starting_snapshot(&history, cutoff) else {
+        bail!(
+            "No snapshots found in {}; record one with `zrt snapshot`",
+            args.history.display()
+        );
+    };
+
+    let exclude_dirs: Vec<&str> = args.exclude.iter().map(String::as_str).collect();
+    let index = LinkIndex::build_in(&scan::scanner(&args.directories, &exclude_dirs)?)?;
+    let new = Snapshot::from_notes(
+        now,
+        &index.notes,
+        &config.refactor.done_tag,
+        &config.refactor.todo_tag,
+    );
+    let untagged: Vec<String> = index
+        .notes
+        .iter()
+        .filter(|note| note.tags.is_empty())
+        .map(|note| note_key(&note.path))
+        .collect();
+
+    print!(
+        "{}",
+        render_markdown(&summarize(old, &new, &untagged), args.top)
+    );
+    Ok(())
+}
@@ -0,0 +1,336 @@
+pub mod cli;
+
+use anyhow::{Context as _, Result, bail};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use std::fmt::Write as _;
+use std::path::Path;
+
+use crate::core::history::Snapshot;
+use crate::core::status::RefactorStatus;
+use crate::diff::{Change, SnapshotDiff, diff_snapshots};
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::history::NoteSnapshot;
+    use std::collections::BTreeMap;
+
+    fn at(date: &str) -> DateTime<Utc> {
+        NaiveDate::parse_from_str(date, "%Y-%m-%d")
+            .ok()
+            .and_then(|d| d.and_hms_opt(12, 0, 0))
+            .map(|d| d.and_utc())
+            .unwrap_or_default()
+    }
+
+    fn snapshot(date: &str, notes: &[(&str, RefactorStatus, usize)]) -> Snapshot {
+        let notes: BTreeMap<String, NoteSnapshot> = notes
+            .iter()
+            .map(|(path, status, words)| {
+                (
+                    (*path).to_owned(),
+                    NoteSnapshot {
+                        status: *status,
+                        words: *words,
+                    },
+                )
+            })
+            .collect();
+        let count = |status| notes.values().filter(|n| n.status == status).count();
+        Snapshot {
+            timestamp: at(date),
+            files: notes.len(),
+            words: notes.values().map(|n| n.words).sum(),
+            done_files: count(RefactorStatus::Done),
+            todo_files: count(RefactorStatus::Todo),
+            done_words: 0,
+            todo_words: 0,
+            percentage: 0.0,
+            tags: BTreeMap::new(),
+            notes,
+        }
+    }
+
+    #[test]
+    fn test_should_parse_since_as_days_weeks_or_date() -> Result<()> {
+        // REQ-SUMMARY-001
+        let now = at("2024-01-31");
+
+        assert_eq!(parse_since("7d", now)?, at("2024-01-24"));
+        assert_eq!(parse_since("2w", now)?, at("2024-01-17"));
+        assert_eq!(
+            parse_since("2024-01-01", now)?,
+            NaiveDate::from_ymd_opt(2024, 1, 1)
+                .and_then(|d| d.and_hms_opt(0, 0, 0))
+                .map(|d| d.and_utc())
+                .unwrap_or_default()
+        );
+        assert!(parse_since("soon", now).is_err());
+        assert!(parse_since("d", now).is_err());
+        assert!(parse_since("7é", now).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_start_from_last_snapshot_before_cutoff() {
+        // REQ-SUMMARY-002
+        let history = vec![
+            snapshot("2024-01-10", &[]),
+            snapshot("2024-01-20", &[]),
+            snapshot("2024-01-30", &[]),
+        ];
+
+        let start = |date| starting_snapshot(&history, at(date)).map(|s| s.timestamp);
+
+        assert_eq!(start("2024-01-25"), Some(at("2024-01-20")));
+        assert_eq!(start("2024-01-20"), Some(at("2024-01-20")));
+        // Before any snapshot, the oldest one is the best available start
+        assert_eq!(start("2024-01-01"), Some(at("2024-01-10")));
+        assert_eq!(starting_snapshot(&[], at("2024-01-01")), None);
+    }
+
+    #[test]
+    fn test_should_collect_completed_marked_and_untagged_notes() {
+        // REQ-SUMMARY-003
+        // Given a week in which one note was finished, one marked to
+        // refactor, one grew and two were added, one of them without tags
+        let old = snapshot(
+            "2024-01-24",
+            &[
+                ("a.md", RefactorStatus::Todo, 100),
+                ("b.md", RefactorStatus::Untracked, 50),
+                ("c.md", RefactorStatus::Untracked, 10),
+            ],
+        );
+        let new = snapshot(
+            "2024-01-31",
+            &[
+                ("a.md", RefactorStatus::Done, 120),
+                ("b.md", RefactorStatus::Todo, 50),
+                ("c.md", RefactorStatus::Untracked, 400),
+                ("d.md", RefactorStatus::Untracked, 30),
+                ("e.md", RefactorStatus::Untracked, 5),
+            ],
+        );
+        let untagged = vec!["c.md".to_owned(), "d.md".to_owned()];
+
+        // When summarizing the week
+        let summary = summarize(&old, &new, &untagged);
+
+        // Then each change lands in its section, and growth is largest first
+        assert_eq!(summary.diff.newly_done, vec!["a.md"]);
+        assert_eq!(summary.newly_todo, vec!["b.md"]);
+        assert_eq!(summary.new_untagged, vec!["d.md"]);
+        assert_eq!(
+            summary.growth,
+            vec![
+                ("c.md".to_owned(), 390),
+                ("d.md".to_owned(), 30),
+                ("a.md".to_owned(), 20),
+                ("e.md".to_owned(), 5),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_should_render_markdown_digest() {
+        // REQ-SUMMARY-004
+        let old = snapshot("2024-01-24", &[("notes/a.md", RefactorStatus::Todo, 100)]);
+        let new = snapshot(
+            "2024-01-31",
+            &[
+                ("notes/a.md", RefactorStatus::Done, 120),
+                ("b.md", RefactorStatus::Untracked, 30),
+            ],
+        );
+        let summary = summarize(&old, &new, &["b.md".to_owned()]);
+
+        let digest = render_markdown(&summary, 5);
+
+        assert_eq!(
+            digest,
+            "## Summary 2024-01-24 to 2024-01-31\n\
+             \n\
+             - Notes: 1 → 2 (+1)\n\
+             - Words: 100 → 150 (+50)\n\
+             - Done: 0.00% → 0.00% (+0.00)\n\
+             - Done notes: 0 → 1 (+1)\n\
+             - Notes to refactor: 1 → 0 (-1)\n\
+             \n\
+             ### Completed (1)\n\
+             \n\
+             - [[a]]\n\
+             \n\
+             ### New untagged notes (1)\n\
+             \n\
+             - [[b]]\n\
+             \n\
+             ### Most growth\n\
+             \n\
+             - +30 [[b]]\n\
+             - +20 [[a]]\n"
+        );
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// What changed in the vault over a review period.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Summary {
+    /// When the snapshot the period starts from was taken
+    pub from: DateTime<Utc>,
+    /// When the period ends
+    pub to: DateTime<Utc>,
+    pub diff: SnapshotDiff,
+    /// Notes newly marked to refactor, including new notes
+    pub newly_todo: Vec<String>,
+    /// Notes added over the period that have no tags
+    pub new_untagged: Vec<String>,
+    /// Notes that gained words, most words first
+    pub growth: Vec<(String, i64)>,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+/// The start of a review period: `Nd` or `Nw` before `now`, or a
+/// `YYYY-MM-DD` date.
+///
+/// # Errors
+///
+/// Returns an error if `since` is neither form.
+pub fn parse_since(since: &str, now: DateTime<Utc>) -> Result<DateTime<Utc>> {
+    if let Ok(date) = NaiveDate::parse_from_str(since, "%Y-%m-%d") {
+        return Ok(date.and_time(chrono::NaiveTime::MIN).and_utc());
+    }
+    let invalid = || format!("Invalid --since '{since}': expected 7d, 2w or YYYY-MM-DD");
+    let (count, days_per_unit) = if let Some(days) = since.strip_suffix('d') {
+        (days, 1)
+    } else if let Some(weeks) = since.strip_suffix('w') {
+        (weeks, 7)
+    } else {
+        bail!(invalid());
+    };
+    let count: i64 = count.parse().ok().with_context(invalid)?;
+    let period = Duration::days(count * days_per_unit);
+    Ok(now - period)
+}
+
+/// The latest snapshot taken at or before `cutoff`, or the oldest one when
+/// the history starts later.
+#[must_use]
+pub fn starting_snapshot(history: &[Snapshot], cutoff: DateTime<Utc>) -> Option<&Snapshot> {
+    history
+        .iter()
+        .filter(|snapshot| snapshot.timestamp <= cutoff)
+        .max_by_key(|snapshot| snapshot.timestamp)
+        .or_else(|| history.iter().min_by_key(|snapshot| snapshot.timestamp))
+}
+
+/// Summarize the changes from `old` to `new`. `untagged` are the keys of
+/// the notes in `new` without any tags.
+#[must_use]
+pub fn summarize(old: &Snapshot, new: &Snapshot, untagged: &[String]) -> Summary {
+    let diff = diff_snapshots(old, new);
+
+    let newly_todo = new
+        .notes
+        .iter()
+        .filter(|(path, note)| {
+            note.status == RefactorStatus::Todo
+                && old.notes.get(*path).map(|n| n.status) != Some(RefactorStatus::Todo)
+        })
+        .map(|(path, _)| path.clone())
+        .collect();
+    let new_untagged = untagged
+        .iter()
+        .filter(|path| !old.notes.contains_key(*path))
+        .cloned()
+        .collect();
+    let mut growth: Vec<(String, i64)> = diff
+        .word_changes
+        .iter()
+        .filter(|(_, delta)| *delta > 0)
+        .cloned()
+        .collect();
+    growth.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+
+    Summary {
+        from: old.timestamp,
+        to: new.timestamp,
+        diff,
+        newly_todo,
+        new_untagged,
+        growth,
+    }
+}
+
+/// A Markdown digest of `summary` for a weekly review note, listing at
+/// most `top` notes by growth. Notes are written as wikilinks.
+#[must_use]
+pub fn render_markdown(summary: &Summary, top: usize) -> String {
+    let diff = &summary.diff;
+    let mut out = format!(
+        "## Summary {} to {}\n\n",
+        summary.from.format("%Y-%m-%d"),
+        summary.to.format("%Y-%m-%d")
+    );
+    for (label, change) in [("Notes", diff.files), ("Words", diff.words)] {
+        let _ = writeln!(out, "- {label}: {}", change_line(change));
+    }
+    let _ = writeln!(
+        out,
+        "- Done: {:.2}% → {:.2}% ({:+.2})",
+        diff.percentage.0,
+        diff.percentage.1,
+        diff.percentage_delta()
+    );
+    for (label, change) in [
+        ("Done notes", diff.done_files),
+        ("Notes to refactor", diff.todo_files),
+    ] {
+        let _ = writeln!(out, "- {label}: {}", change_line(change));
+    }
+
+    for (title, notes) in [
+        ("Completed", &diff.newly_done),
+        ("Marked to refactor", &summary.newly_todo),
+        ("Regressed", &diff.regressed),
+        ("New untagged notes", &summary.new_untagged),
+    ] {
+        if notes.is_empty() {
+            continue;
+        }
+        let _ = writeln!(out, "\n### {title} ({})\n", notes.len());
+        for note in notes {
+            let _ = writeln!(out, "- {}", wikilink(note));
+        }
+    }
+
+    if !summary.growth.is_empty() && top > 0 {
+        out.push_str("\n### Most growth\n\n");
+        for (note, delta) in summary.growth.iter().take(top) {
+            let _ = writeln!(out, "- {delta:+} {}", wikilink(note));
+        }
+    }
+    out
+}
+
+fn change_line(change: Change) -> String {
+    format!("{} → {} ({:+})", change.from, change.to, change.delta())
+}
+
+fn wikilink(path: &str) -> String {
+    let path = Path::new(path);
+    let name = path
+        .file_stem()
+        .map_or_else(|| path.to_string_lossy(), |stem| stem.to_string_lossy());
+    format!("[[{name}]]")
+}
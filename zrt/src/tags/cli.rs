@@ -1,6 +1,11 @@
 use anyhow::Result;
 use clap::Args;
 use std::path::PathBuf;
+use std::str::FromStr;
+
+use crate::output::format::JsonFormat;
+use crate::output::{chart, csv, scan, table};
+use crate::tags::{count_tags_in, tag_shares_in, tag_totals_in};
 
 // ============================================
 // TESTS
@@ -38,6 +43,38 @@ mod tests {
         assert_eq!(args.tags.limit, Some(5));
     }
 
+    #[test]
+    fn test_should_accept_chart_of_words() {
+        // REQ-TAGS-009
+
+        // Given / When
+        let args = TestArgs::parse_from(["program", "--chart", "--words"]);
+
+        // Then
+        assert!(args.tags.chart);
+        assert!(args.tags.words);
+        assert!(TestArgs::try_parse_from(["program", "--words"]).is_err());
+    }
+
+    #[test]
+    fn test_should_accept_export_formats() {
+        // REQ-TAGS-010
+
+        // Given / When
+        let args = TestArgs::parse_from(["program", "--format", "csv"]);
+
+        // Then
+        assert_eq!(args.tags.format, Some(TagsFormat::Csv));
+        assert_eq!(
+            TestArgs::parse_from(["program", "--format", "json@1"])
+                .tags
+                .format,
+            Some(TagsFormat::Json(JsonFormat { version: 1 }))
+        );
+        assert!(TestArgs::try_parse_from(["program", "--format", "yaml"]).is_err());
+        assert!(TestArgs::try_parse_from(["program", "--format", "csv", "--chart"]).is_err());
+    }
+
     #[test]
     fn test_should_default_to_current_directory() {
         // REQ-TAGS-006
@@ -54,6 +91,15 @@ mod tests {
 // TYPE DEFINITIONS
 // ============================================
 
+/// Export format of `zrt tags`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TagsFormat {
+    /// Comma-separated values with a header row
+    Csv,
+    /// An array of tag objects, see `zrt schema tags`
+    Json(JsonFormat),
+}
+
 #[derive(Args, Debug)]
 pub struct TagsArgs {
     /// Directories to scan (space-separated, defaults to current directory)
@@ -71,17 +117,90 @@ pub struct TagsArgs {
     /// Show only the top N tags
     #[arg(long)]
     pub limit: Option<usize>,
+
+    /// Draw a bar chart of notes per tag, scaled to the terminal width
+    #[arg(long)]
+    pub chart: bool,
+
+    /// Chart words per tag instead of notes
+    #[arg(long, requires = "chart")]
+    pub words: bool,
+
+    /// Export every tag with its notes, words and percentage of all words:
+    /// csv, json, or json@VERSION to pin the JSON shape
+    #[arg(long, conflicts_with = "chart")]
+    pub format: Option<TagsFormat>,
 }
 
 // ============================================
 // IMPLEMENTATIONS
 // ============================================
 
+impl FromStr for TagsFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "csv" {
+            Ok(Self::Csv)
+        } else {
+            s.parse().map(Self::Json)
+        }
+    }
+}
+
 pub fn run(args: TagsArgs) -> Result<()> {
     let exclude_dirs: Vec<&str> = args.exclude.iter().map(String::as_str).collect();
     let exclude_tags: Vec<&str> = args.exclude_tag.iter().map(String::as_str).collect();
+    let scanner = scan::scanner(&args.directories, &exclude_dirs)?;
+
+    if let Some(format) = args.format {
+        let mut shares = tag_shares_in(&scanner, &exclude_tags)?;
+        shares.truncate(args.limit.unwrap_or(usize::MAX));
+        match format {
+            TagsFormat::Csv => {
+                println!("{}", csv::record(["tag", "files", "words", "percentage"]));
+                for share in &shares {
+                    println!(
+                        "{}",
+                        csv::record([
+                            share.tag.clone(),
+                            share.files.to_string(),
+                            share.words.to_string(),
+                            format!("{:.2}", share.percentage),
+                        ])
+                    );
+                }
+            }
+            TagsFormat::Json(format) => {
+                println!(
+                    "{}",
+                    format.render("tags", &serde_json::to_value(&shares)?)?
+                );
+            }
+        }
+        return Ok(());
+    }
+
+    if args.chart {
+        let mut totals = tag_totals_in(&scanner, &exclude_tags)?;
+        if args.words {
+            totals.sort_by(|a, b| b.words.cmp(&a.words).then(a.tag.cmp(&b.tag)));
+        }
+        totals.truncate(args.limit.unwrap_or(usize::MAX));
+        let rows: Vec<(String, usize)> = totals
+            .into_iter()
+            .map(|total| {
+                let value = if args.words { total.words } else { total.files };
+                (total.tag, value)
+            })
+            .collect();
+        for line in chart::bar_chart(&rows, table::width()) {
+            println!("{line}");
+        }
+        return Ok(());
+    }
 
-    let results = crate::tags::count_tags(&args.directories, &exclude_tags, &exclude_dirs)?;
+    let results = count_tags_in(&scanner, &exclude_tags)?;
 
     let output = match args.limit {
         Some(n) => &results[..n.min(results.len())],
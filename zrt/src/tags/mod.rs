@@ -1,13 +1,12 @@
 pub mod cli;
 
 use anyhow::Result;
+use serde::Serialize;
 use std::collections::HashMap;
 use std::path::PathBuf;
-use walkdir::WalkDir;
 
-use crate::core::filter::utils::should_exclude;
-use crate::core::frontmatter::parse_frontmatter;
-use crate::core::ignore::load_ignore_patterns;
+use crate::core::history::percentage;
+use crate::core::scanner::{ScanOptions, Scanner};
 
 // ============================================
 // TESTS
@@ -39,7 +38,10 @@ mod tests {
         let results = count_tags(&[dir.path().to_path_buf()], &[], &[])?;
 
         // Then
-        let writing_count = results.iter().find(|(t, _)| t == "writing").map(|(_, c)| *c);
+        let writing_count = results
+            .iter()
+            .find(|(t, _)| t == "writing")
+            .map(|(_, c)| *c);
         assert_eq!(writing_count, Some(2));
         Ok(())
     }
@@ -63,6 +65,71 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_should_total_files_and_words_per_tag() -> Result<()> {
+        // REQ-TAGS-008
+
+        // Given
+        let dir = TempDir::new()?;
+        create_test_file(&dir, "a.md", "---\ntags: [writing]\n---\none two three")?;
+        create_test_file(&dir, "b.md", "---\ntags: [writing, ideas]\n---\nfour")?;
+
+        // When
+        let totals = tag_totals(&[dir.path().to_path_buf()], &[], &[])?;
+
+        // Then
+        assert_eq!(
+            totals,
+            vec![
+                TagTotal {
+                    tag: "writing".to_owned(),
+                    files: 2,
+                    words: 4,
+                },
+                TagTotal {
+                    tag: "ideas".to_owned(),
+                    files: 1,
+                    words: 1,
+                },
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_share_vault_words_per_tag() -> Result<()> {
+        // REQ-TAGS-010
+
+        // Given
+        let dir = TempDir::new()?;
+        create_test_file(&dir, "a.md", "---\ntags: [writing]\n---\none two three")?;
+        create_test_file(&dir, "b.md", "---\ntags: [writing, ideas]\n---\nfour")?;
+        create_test_file(&dir, "c.md", "five six seven eight")?;
+
+        // When
+        let shares = tag_shares(&[dir.path().to_path_buf()], &[], &[])?;
+
+        // Then
+        assert_eq!(
+            shares,
+            vec![
+                TagShare {
+                    tag: "writing".to_owned(),
+                    files: 2,
+                    words: 4,
+                    percentage: 50.0,
+                },
+                TagShare {
+                    tag: "ideas".to_owned(),
+                    files: 1,
+                    words: 1,
+                    percentage: 12.5,
+                },
+            ]
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_should_exclude_specified_tags() -> Result<()> {
         // REQ-TAGS-004
@@ -97,7 +164,10 @@ mod tests {
         )?;
 
         // Then
-        let writing_count = results.iter().find(|(t, _)| t == "writing").map(|(_, c)| *c);
+        let writing_count = results
+            .iter()
+            .find(|(t, _)| t == "writing")
+            .map(|(_, c)| *c);
         assert_eq!(writing_count, Some(2));
         Ok(())
     }
@@ -122,6 +192,29 @@ mod tests {
     }
 }
 
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// How many notes carry a tag and how many words they hold together.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagTotal {
+    pub tag: String,
+    pub files: usize,
+    pub words: usize,
+}
+
+/// A tag's totals with the share of the vault's words its notes hold, as
+/// exported by `zrt tags --format`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TagShare {
+    pub tag: String,
+    pub files: usize,
+    pub words: usize,
+    /// Percentage of the words in every scanned note held by notes with the tag
+    pub percentage: f64,
+}
+
 // ============================================
 // IMPLEMENTATIONS
 // ============================================
@@ -133,42 +226,85 @@ pub fn count_tags(
     exclude_tags: &[&str],
     exclude_dirs: &[&str],
 ) -> Result<Vec<(String, usize)>> {
-    let mut counts: HashMap<String, usize> = HashMap::new();
-
-    for dir in dirs {
-        let absolute_dir = if dir.is_absolute() {
-            dir.clone()
-        } else {
-            std::env::current_dir()?.join(dir)
-        };
-
-        let ignore_patterns = load_ignore_patterns(&absolute_dir)?;
-
-        for entry in WalkDir::new(&absolute_dir)
-            .follow_links(true)
-            .into_iter()
-            .filter_entry(|e| !should_exclude(e, exclude_dirs, Some(&ignore_patterns)))
-        {
-            let entry = entry?;
-            if !entry.file_type().is_file() {
-                continue;
-            }
+    count_tags_in(&scanner(dirs, exclude_dirs)?, exclude_tags)
+}
 
-            if let Ok(content) = std::fs::read_to_string(entry.path()) {
-                if let Ok(frontmatter) = parse_frontmatter(&content) {
-                    if let Some(tags) = frontmatter.tags {
-                        for tag in tags {
-                            if !exclude_tags.contains(&tag.as_str()) {
-                                *counts.entry(tag).or_insert(0) += 1;
-                            }
-                        }
-                    }
-                }
+/// Count the notes and words under each tag across all markdown files in the
+/// given directories, sorted by note count descending. Tags in `exclude_tags`
+/// are left out.
+pub fn tag_totals(
+    dirs: &[PathBuf],
+    exclude_tags: &[&str],
+    exclude_dirs: &[&str],
+) -> Result<Vec<TagTotal>> {
+    tag_totals_in(&scanner(dirs, exclude_dirs)?, exclude_tags)
+}
+
+/// [`tag_totals`] with each tag's share of the words in every scanned note,
+/// tagged or not, in the same order.
+pub fn tag_shares(
+    dirs: &[PathBuf],
+    exclude_tags: &[&str],
+    exclude_dirs: &[&str],
+) -> Result<Vec<TagShare>> {
+    tag_shares_in(&scanner(dirs, exclude_dirs)?, exclude_tags)
+}
+
+/// [`count_tags`] using an already configured scanner
+pub fn count_tags_in(scanner: &Scanner, exclude_tags: &[&str]) -> Result<Vec<(String, usize)>> {
+    Ok(tag_totals_in(scanner, exclude_tags)?
+        .into_iter()
+        .map(|total| (total.tag, total.files))
+        .collect())
+}
+
+/// [`tag_totals`] using an already configured scanner
+pub fn tag_totals_in(scanner: &Scanner, exclude_tags: &[&str]) -> Result<Vec<TagTotal>> {
+    Ok(tag_totals_with_total(scanner, exclude_tags)?.0)
+}
+
+/// [`tag_shares`] using an already configured scanner
+pub fn tag_shares_in(scanner: &Scanner, exclude_tags: &[&str]) -> Result<Vec<TagShare>> {
+    let (totals, total_words) = tag_totals_with_total(scanner, exclude_tags)?;
+    Ok(totals
+        .into_iter()
+        .map(|total| TagShare {
+            percentage: percentage(total.words, total_words),
+            tag: total.tag,
+            files: total.files,
+            words: total.words,
+        })
+        .collect())
+}
+
+/// [`tag_totals`] along with the words in every scanned note.
+fn tag_totals_with_total(
+    scanner: &Scanner,
+    exclude_tags: &[&str],
+) -> Result<(Vec<TagTotal>, usize)> {
+    let mut totals: HashMap<String, (usize, usize)> = HashMap::new();
+    let mut total_words = 0;
+
+    for record in scanner.iter() {
+        let record = record?;
+        total_words += record.words;
+        for tag in record.tags {
+            if !exclude_tags.contains(&tag.as_str()) {
+                let total = totals.entry(tag).or_insert((0, 0));
+                total.0 += 1;
+                total.1 += record.words;
             }
         }
     }
 
-    let mut result: Vec<(String, usize)> = counts.into_iter().collect();
-    result.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
-    Ok(result)
+    let mut result: Vec<TagTotal> = totals
+        .into_iter()
+        .map(|(tag, (files, words))| TagTotal { tag, files, words })
+        .collect();
+    result.sort_by(|a, b| b.files.cmp(&a.files).then(a.tag.cmp(&b.tag)));
+    Ok((result, total_words))
+}
+
+fn scanner(dirs: &[PathBuf], exclude_dirs: &[&str]) -> Result<Scanner> {
+    Ok(ScanOptions::new(dirs).exclude(exclude_dirs).build()?)
 }
@@ -0,0 +1,132 @@
+use anyhow::Result;
+use clap::Args;
+use comfy_table::Cell;
+use std::path::PathBuf;
+
+use crate::core::scanner::ScanOptions;
+use crate::core::tasks::TaskCounts;
+use crate::output::files_from::FilesFromArgs;
+use crate::output::{scan, table};
+use crate::tasks::scan_tasks;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser, Debug)]
+    struct TestArgs {
+        #[command(flatten)]
+        tasks: TasksArgs,
+    }
+
+    #[test]
+    fn test_should_list_files_by_default() {
+        // REQ-TASKS-002
+        let args = TestArgs::parse_from(["program"]);
+
+        assert!(!args.tasks.total);
+        assert!(!args.tasks.open_only);
+        assert_eq!(args.tasks.directories, vec![PathBuf::from(".")]);
+    }
+
+    #[test]
+    fn test_should_accept_total_and_open_only() {
+        // REQ-TASKS-002
+        let args = TestArgs::parse_from(["program", "--total", "--open-only"]);
+
+        assert!(args.tasks.total);
+        assert!(args.tasks.open_only);
+    }
+
+    #[test]
+    fn test_should_summarize_counts() {
+        // REQ-TASKS-002
+        let tasks = TaskCounts { open: 3, closed: 1 };
+
+        assert_eq!(summary(&tasks), "3 open, 1 closed (25.00% closed)");
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+#[derive(Args, Debug)]
+pub struct TasksArgs {
+    /// Directories to scan (space-separated, defaults to current directory)
+    #[arg(short = 'd', long = "dir", num_args = 0.., default_values = &["."])]
+    pub directories: Vec<PathBuf>,
+
+    /// Directories to exclude (space-separated)
+    #[arg(short, long, num_args = 0..)]
+    pub exclude: Vec<String>,
+
+    /// Only print the vault-wide total
+    #[arg(long)]
+    pub total: bool,
+
+    /// Only list notes that still have open tasks
+    #[arg(long)]
+    pub open_only: bool,
+
+    #[command(flatten)]
+    pub files_from: FilesFromArgs,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+pub fn run(args: TasksArgs) -> Result<()> {
+    let exclude_dirs: Vec<&str> = args.exclude.iter().map(String::as_str).collect();
+
+    let options = args
+        .files_from
+        .apply(ScanOptions::new(&args.directories).exclude(&exclude_dirs))?;
+    let report = scan_tasks(&scan::attach(options).build()?)?;
+
+    let mut rows: Vec<(String, TaskCounts)> = Vec::new();
+    if !args.total {
+        rows.extend(
+            report
+                .files
+                .iter()
+                .filter(|file| !args.open_only || file.tasks.open > 0)
+                .map(|file| (file.path.display().to_string(), file.tasks)),
+        );
+    }
+    rows.push(("total".to_owned(), report.total));
+
+    if table::is_tty() {
+        let mut out = table::table(["file", "done%", "closed", "open", "tasks"]);
+        for (label, tasks) in rows {
+            out.add_row(vec![
+                Cell::new(label),
+                table::percentage(tasks.percentage()),
+                table::done(tasks.closed),
+                table::todo(tasks.open),
+                table::number(tasks.total()),
+            ]);
+        }
+        println!("{out}");
+    } else {
+        for (label, tasks) in rows {
+            println!("{label}: {}", summary(&tasks));
+        }
+    }
+
+    Ok(())
+}
+
+fn summary(tasks: &TaskCounts) -> String {
+    format!(
+        "{} open, {} closed ({:.2}% closed)",
+        tasks.open,
+        tasks.closed,
+        tasks.percentage()
+    )
+}
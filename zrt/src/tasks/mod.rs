@@ -0,0 +1,105 @@
+pub mod cli;
+
+use anyhow::Result;
+use std::path::PathBuf;
+
+use crate::core::scanner::Scanner;
+use crate::core::tasks::{TaskCounts, count_tasks};
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::scanner::ScanOptions;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_should_report_tasks_per_file_and_in_total() -> Result<()> {
+        // REQ-TASKS-001
+        // Given notes with and without tasks, and a non-note with tasks
+        let dir = TempDir::new()?;
+        fs::write(dir.path().join("a.md"), "- [ ] one\n- [x] two\n")?;
+        fs::write(dir.path().join("b.md"), "no tasks here")?;
+        fs::write(dir.path().join("c.md"), "- [x] done\n- [X] done too\n")?;
+        fs::write(dir.path().join("d.txt"), "- [ ] not a note\n")?;
+        let scanner = ScanOptions::new([dir.path()]).build()?;
+
+        // When scanning for tasks
+        let report = scan_tasks(&scanner)?;
+
+        // Then only notes with tasks are listed, and the total covers them all
+        let files: Vec<_> = report
+            .files
+            .iter()
+            .map(|file| (file.path.file_name().map(PathBuf::from), file.tasks))
+            .collect();
+        assert_eq!(
+            files,
+            vec![
+                (
+                    Some(PathBuf::from("a.md")),
+                    TaskCounts { open: 1, closed: 1 }
+                ),
+                (
+                    Some(PathBuf::from("c.md")),
+                    TaskCounts { open: 0, closed: 2 }
+                ),
+            ]
+        );
+        assert_eq!(report.total, TaskCounts { open: 1, closed: 3 });
+        Ok(())
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// Tasks in a single note.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileTasks {
+    pub path: PathBuf,
+    pub tasks: TaskCounts,
+}
+
+/// Tasks per note and across the whole scan.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TaskReport {
+    /// Notes with at least one task, in walk order
+    pub files: Vec<FileTasks>,
+    pub total: TaskCounts,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+/// Count the tasks in every markdown note the scanner visits.
+///
+/// Files that cannot be read as text are skipped, see [`Scanner::notes`].
+///
+/// # Errors
+///
+/// Returns an error if the scan fails.
+pub fn scan_tasks(scanner: &Scanner) -> Result<TaskReport> {
+    let mut report = TaskReport::default();
+    for note in scanner.notes() {
+        let note = note?;
+        if !scanner.is_note(&note.path) {
+            continue;
+        }
+
+        let tasks = count_tasks(&note.content);
+        if tasks.total() > 0 {
+            report.total += tasks;
+            report.files.push(FileTasks {
+                path: note.path,
+                tasks,
+            });
+        }
+    }
+    Ok(report)
+}
@@ -0,0 +1,138 @@
+use anyhow::Result;
+use clap::Args;
+use comfy_table::Cell;
+use std::path::PathBuf;
+
+use crate::core::history::{HISTORY_FILE, load_history};
+use crate::output::table;
+use crate::trend::{Period, TrendPoint, Velocity, sparkline, trend, velocity};
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser, Debug)]
+    struct TestArgs {
+        #[command(flatten)]
+        trend: TrendArgs,
+    }
+
+    #[test]
+    fn test_should_default_to_daily_trend_from_history_file() {
+        // REQ-TREND-004
+
+        // Given / When
+        let args = TestArgs::parse_from(["program"]);
+
+        // Then
+        assert_eq!(args.trend.by, Period::Day);
+        assert_eq!(args.trend.history, PathBuf::from(".zrt/history.jsonl"));
+    }
+
+    #[test]
+    fn test_should_accept_weekly_trend() {
+        // REQ-TREND-004
+
+        // Given / When
+        let args = TestArgs::parse_from(["program", "--by", "week", "--history", "h.jsonl"]);
+
+        // Then
+        assert_eq!(args.trend.by, Period::Week);
+        assert_eq!(args.trend.history, PathBuf::from("h.jsonl"));
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+#[derive(Args, Debug)]
+pub struct TrendArgs {
+    /// Group snapshots per day or per ISO week
+    #[arg(long, value_enum, default_value = "day")]
+    pub by: Period,
+
+    /// History file to read snapshots from
+    #[arg(long, default_value = HISTORY_FILE)]
+    pub history: PathBuf,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+pub fn run(args: TrendArgs) -> Result<()> {
+    let history = load_history(&args.history)?;
+    if history.is_empty() {
+        anyhow::bail!(
+            "No snapshots found in {}; record one with `zrt snapshot`",
+            args.history.display()
+        );
+    }
+
+    let points = trend(&history, args.by);
+
+    print_trend(&points);
+    print_velocity(velocity(&history).as_ref());
+
+    Ok(())
+}
+
+/// Print trend points as a table followed by a sparkline of the done percentage.
+pub fn print_trend(points: &[TrendPoint]) {
+    if table::is_tty() {
+        let mut out = table::table(["period", "done%", "done", "todo", "files"]);
+        for point in points {
+            out.add_row(vec![
+                Cell::new(&point.period),
+                table::percentage(point.percentage),
+                table::done(point.done_files),
+                table::todo(point.todo_files),
+                table::number(point.files),
+            ]);
+        }
+        println!("{out}");
+    } else {
+        let width = points
+            .iter()
+            .map(|p| p.period.len())
+            .max()
+            .unwrap_or(0)
+            .max("period".len());
+
+        println!(
+            "{:<width$} {:>7} {:>6} {:>6} {:>6}",
+            "period", "done%", "done", "todo", "files"
+        );
+        for point in points {
+            println!(
+                "{:<width$} {:>7.2} {:>6} {:>6} {:>6}",
+                point.period, point.percentage, point.done_files, point.todo_files, point.files
+            );
+        }
+    }
+
+    let percentages: Vec<f64> = points.iter().map(|p| p.percentage).collect();
+    println!("{}", sparkline(&percentages));
+}
+
+/// Print the refactoring velocity and projected completion date.
+pub fn print_velocity(velocity: Option<&Velocity>) {
+    let Some(velocity) = velocity else {
+        println!("velocity: not enough history");
+        return;
+    };
+
+    println!(
+        "velocity: {:.2} files/week, {:.0} words/week",
+        velocity.files_per_week, velocity.words_per_week
+    );
+    match velocity.eta {
+        Some(eta) => println!("eta: {}", eta.format("%Y-%m-%d")),
+        None => println!("eta: none (no progress)"),
+    }
+}
@@ -0,0 +1,288 @@
+pub mod cli;
+
+use chrono::{DateTime, Datelike, TimeDelta, Utc};
+use clap::ValueEnum;
+
+use crate::core::history::Snapshot;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::links::note_from_content;
+    use std::path::Path;
+
+    fn at(date: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(date).unwrap().to_utc()
+    }
+
+    fn snapshot(timestamp: &str, done: usize, todo: usize) -> Snapshot {
+        let mut notes = Vec::new();
+        for i in 0..done {
+            let path = format!("done{i}.md");
+            notes.push(note_from_content(
+                Path::new(&path),
+                "---\ntags: [refactored]\n---\nword",
+            ));
+        }
+        for i in 0..todo {
+            let path = format!("todo{i}.md");
+            notes.push(note_from_content(
+                Path::new(&path),
+                "---\ntags: [to_refactor]\n---\nword",
+            ));
+        }
+        Snapshot::from_notes(at(timestamp), &notes, "refactored", "to_refactor")
+    }
+
+    #[test]
+    fn test_should_keep_latest_snapshot_per_day() {
+        // REQ-TREND-001
+
+        // Given
+        let history = vec![
+            snapshot("2026-03-02T09:00:00Z", 1, 3),
+            snapshot("2026-03-02T18:00:00Z", 2, 2),
+            snapshot("2026-03-01T12:00:00Z", 0, 4),
+        ];
+
+        // When
+        let points = trend(&history, Period::Day);
+
+        // Then
+        let periods: Vec<&str> = points.iter().map(|p| p.period.as_str()).collect();
+        assert_eq!(periods, vec!["2026-03-01", "2026-03-02"]);
+        assert_eq!(points[1].done_files, 2);
+        assert_eq!(points[1].percentage, 50.0);
+    }
+
+    #[test]
+    fn test_should_group_snapshots_by_iso_week() {
+        // REQ-TREND-002
+
+        // Given
+        let history = vec![
+            snapshot("2026-03-02T09:00:00Z", 1, 3),
+            snapshot("2026-03-08T09:00:00Z", 2, 2),
+            snapshot("2026-03-09T09:00:00Z", 3, 1),
+        ];
+
+        // When
+        let points = trend(&history, Period::Week);
+
+        // Then
+        let periods: Vec<&str> = points.iter().map(|p| p.period.as_str()).collect();
+        assert_eq!(periods, vec!["2026-W10", "2026-W11"]);
+        assert_eq!(points[0].done_files, 2);
+    }
+
+    #[test]
+    fn test_should_keep_every_snapshot_without_period() {
+        // REQ-TREND-008
+        let history = vec![
+            snapshot("2026-03-02T18:00:00Z", 2, 2),
+            snapshot("2026-03-02T09:00:00Z", 1, 3),
+        ];
+
+        let points = points(&history, None);
+
+        let periods: Vec<&str> = points.iter().map(|p| p.period.as_str()).collect();
+        assert_eq!(periods, vec!["2026-03-02 09:00", "2026-03-02 18:00"]);
+        assert_eq!(points[0].todo_words, 3);
+    }
+
+    #[test]
+    fn test_should_estimate_velocity_and_completion_date() {
+        // REQ-TREND-006
+
+        // Given: one more done file (one word) per week, two words left
+        let history = vec![
+            snapshot("2026-03-01T00:00:00Z", 1, 3),
+            snapshot("2026-03-15T00:00:00Z", 3, 1),
+        ];
+
+        // When
+        let velocity = velocity(&history).unwrap();
+
+        // Then
+        assert_eq!(velocity.files_per_week, 1.0);
+        assert_eq!(velocity.words_per_week, 1.0);
+        assert_eq!(velocity.eta, Some(at("2026-03-22T00:00:00Z")));
+    }
+
+    #[test]
+    fn test_should_have_no_eta_without_progress() {
+        // REQ-TREND-007
+        let history = vec![
+            snapshot("2026-03-01T00:00:00Z", 2, 2),
+            snapshot("2026-03-08T00:00:00Z", 2, 2),
+        ];
+
+        let stalled = velocity(&history).unwrap();
+
+        assert_eq!(stalled.words_per_week, 0.0);
+        assert_eq!(stalled.eta, None);
+        assert!(velocity(&history[..1]).is_none());
+    }
+
+    #[test]
+    fn test_should_render_sparkline_scaled_to_range() {
+        // REQ-TREND-003
+        assert_eq!(sparkline(&[0.0, 50.0, 100.0]), "▁▅█");
+        assert_eq!(sparkline(&[10.0, 10.0]), "▁▁");
+        assert_eq!(sparkline(&[]), "");
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// Granularity of a trend.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Period {
+    Day,
+    Week,
+}
+
+/// Progress at the end of one period.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrendPoint {
+    /// `YYYY-MM-DD` for days, `YYYY-Www` for ISO weeks
+    pub period: String,
+    pub percentage: f64,
+    pub done_files: usize,
+    pub todo_files: usize,
+    pub todo_words: usize,
+    pub files: usize,
+}
+
+/// Refactoring pace between the first and last snapshot.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Velocity {
+    /// Net notes newly tagged done per week
+    pub files_per_week: f64,
+    /// Net words in done notes gained per week
+    pub words_per_week: f64,
+    /// Projected date for reaching 100% done at the current pace, if progressing
+    pub eta: Option<DateTime<Utc>>,
+}
+
+const SPARKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+impl TrendPoint {
+    #[inline]
+    #[must_use]
+    pub fn new(period: String, snapshot: &Snapshot) -> Self {
+        Self {
+            period,
+            percentage: snapshot.percentage,
+            done_files: snapshot.done_files,
+            todo_files: snapshot.todo_files,
+            todo_words: snapshot.todo_words,
+            files: snapshot.files,
+        }
+    }
+}
+
+impl Period {
+    fn label(self, snapshot: &Snapshot) -> String {
+        match self {
+            Self::Day => snapshot.timestamp.format("%Y-%m-%d").to_string(),
+            Self::Week => {
+                let week = snapshot.timestamp.iso_week();
+                format!("{}-W{:02}", week.year(), week.week())
+            }
+        }
+    }
+}
+
+/// Reduce a snapshot history to one point per period, using the last snapshot
+/// taken in each period, in chronological order.
+pub fn trend(history: &[Snapshot], period: Period) -> Vec<TrendPoint> {
+    let mut snapshots: Vec<&Snapshot> = history.iter().collect();
+    snapshots.sort_by_key(|s| s.timestamp);
+
+    let mut points: Vec<TrendPoint> = Vec::new();
+    for snapshot in snapshots {
+        let point = TrendPoint::new(period.label(snapshot), snapshot);
+        match points.last_mut() {
+            Some(last) if last.period == point.period => *last = point,
+            _ => points.push(point),
+        }
+    }
+    points
+}
+
+/// One point per period when `period` is given, otherwise one point per
+/// snapshot labelled with its time, in chronological order.
+pub fn points(history: &[Snapshot], period: Option<Period>) -> Vec<TrendPoint> {
+    if let Some(period) = period {
+        return trend(history, period);
+    }
+
+    let mut snapshots: Vec<&Snapshot> = history.iter().collect();
+    snapshots.sort_by_key(|s| s.timestamp);
+    snapshots
+        .into_iter()
+        .map(|s| TrendPoint::new(s.timestamp.format("%Y-%m-%d %H:%M").to_string(), s))
+        .collect()
+}
+
+/// Estimate velocity from the first and last snapshot of a history.
+///
+/// Returns `None` when fewer than two snapshots span any time. The completion
+/// date extrapolates the words-per-week pace over the words not yet done,
+/// matching how the done percentage is computed.
+pub fn velocity(history: &[Snapshot]) -> Option<Velocity> {
+    let first = history.iter().min_by_key(|s| s.timestamp)?;
+    let last = history.iter().max_by_key(|s| s.timestamp)?;
+
+    let seconds = (last.timestamp - first.timestamp).num_seconds();
+    if seconds <= 0 {
+        return None;
+    }
+    let weeks = seconds as f64 / TimeDelta::weeks(1).num_seconds() as f64;
+
+    let files_per_week = (last.done_files as f64 - first.done_files as f64) / weeks;
+    let words_per_week = (last.done_words as f64 - first.done_words as f64) / weeks;
+
+    let remaining = last.words.saturating_sub(last.done_words) as f64;
+    let eta = (words_per_week > 0.0)
+        .then(|| {
+            let seconds = remaining / words_per_week * TimeDelta::weeks(1).num_seconds() as f64;
+            TimeDelta::try_seconds(seconds.round() as i64)
+        })
+        .flatten()
+        .and_then(|delta| last.timestamp.checked_add_signed(delta));
+
+    Some(Velocity {
+        files_per_week,
+        words_per_week,
+        eta,
+    })
+}
+
+/// Render values as a sparkline scaled between their minimum and maximum.
+pub fn sparkline(values: &[f64]) -> String {
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let range = max - min;
+
+    values
+        .iter()
+        .map(|&value| {
+            if range <= 0.0 {
+                return SPARKS[0];
+            }
+            let level = ((value - min) / range * (SPARKS.len() - 1) as f64).round() as usize;
+            SPARKS[level.min(SPARKS.len() - 1)]
+        })
+        .collect()
+}
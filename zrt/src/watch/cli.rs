@@ -0,0 +1,216 @@
+use anyhow::Result;
+use clap::Args;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::cli::Commands;
+use crate::core::history::Snapshot;
+use crate::init::ZrtConfig;
+use crate::output::{exit, scan, webhook};
+use crate::watch::watch;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser, Debug)]
+    struct TestArgs {
+        #[command(flatten)]
+        watch: WatchArgs,
+    }
+
+    #[test]
+    fn test_should_default_to_progress_summary() -> Result<()> {
+        // REQ-WATCH-003
+        let args = TestArgs::parse_from(["program", "-d", "notes", "-e", "archive"]);
+
+        let command = command_line(&args.watch, None);
+
+        assert_eq!(
+            command,
+            vec!["zrt", "progress", "-d", "notes", "-e", "archive"]
+        );
+        assert!(!args.watch.clear);
+        assert_eq!(args.watch.debounce, 300);
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_rerun_the_command_after_double_dash() {
+        // REQ-WATCH-003
+        let args = TestArgs::parse_from([
+            "program",
+            "--clear",
+            "--",
+            "count",
+            "--percentage",
+            "refactored",
+        ]);
+
+        assert!(args.watch.clear);
+        assert_eq!(
+            command_line(&args.watch, None),
+            vec!["zrt", "count", "--percentage", "refactored"]
+        );
+    }
+
+    #[test]
+    fn test_should_rerun_the_command_with_the_selected_profile() {
+        // REQ-WATCH-003
+        let args = TestArgs::parse_from(["program", "--", "count", "--files"]);
+
+        assert_eq!(
+            command_line(&args.watch, Some("work")),
+            vec!["zrt", "--profile=work", "count", "--files"]
+        );
+    }
+
+    #[test]
+    fn test_should_reject_invalid_or_nested_commands() {
+        // REQ-WATCH-003
+        let nested = TestArgs::parse_from(["program", "--", "watch"]);
+        let invalid = TestArgs::parse_from(["program", "--", "no-such-command"]);
+
+        assert!(parse_command(&nested.watch, None).is_err());
+        assert!(parse_command(&invalid.watch, None).is_err());
+    }
+
+    #[test]
+    fn test_should_not_report_reruns_without_matches() -> Result<()> {
+        // REQ-WATCH-006
+        let dir = tempfile::TempDir::new()?;
+        std::fs::write(dir.path().join("a.md"), "---\ntags: [refactored]\n---\n")?;
+        let dir = dir.path().display().to_string();
+        let args = TestArgs::parse_from([
+            "program",
+            "--",
+            "count",
+            "--files",
+            "-t",
+            "to_refactor",
+            "-d",
+            &dir,
+        ]);
+        let parsed = crate::cli::parse_from(&parse_command(&args.watch, None)?)?;
+
+        let result = crate::cli::run(parsed);
+
+        assert!(result.as_ref().is_err_and(|e| e.is::<exit::NoMatches>()));
+        assert!(exit::reported(&result).is_none());
+        Ok(())
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+#[derive(Args, Debug)]
+pub struct WatchArgs {
+    /// Directories to watch (space-separated, defaults to current directory)
+    #[arg(short = 'd', long = "dir", num_args = 0.., default_values = &["."])]
+    pub directories: Vec<PathBuf>,
+
+    /// Directories whose changes are ignored (space-separated)
+    #[arg(short, long, num_args = 0..)]
+    pub exclude: Vec<String>,
+
+    /// Clear the screen before each re-run
+    #[arg(short, long)]
+    pub clear: bool,
+
+    /// Milliseconds without further changes to wait before re-running
+    #[arg(long, default_value = "300")]
+    pub debounce: u64,
+
+    /// zrt command to re-run, after `--` (default: progress for the watched directories)
+    #[arg(last = true)]
+    pub command: Vec<String>,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+pub fn run(args: WatchArgs, config: &ZrtConfig, profile: Option<&str>) -> Result<()> {
+    let command = parse_command(&args, profile)?;
+    let exclude_dirs: Vec<&str> = args.exclude.iter().map(String::as_str).collect();
+    let capture = || {
+        Snapshot::capture_in(
+            &scan::scanner(&args.directories, &exclude_dirs)?,
+            &config.refactor.done_tag,
+            &config.refactor.todo_tag,
+        )
+    };
+    // Milestones are measured from the scan before each change, starting
+    // from the vault as it was when watching began.
+    let mut done = if config.notify.webhook.is_some() {
+        Some(capture()?.percentage)
+    } else {
+        None
+    };
+
+    let mut rerun = || -> Result<()> {
+        if args.clear {
+            print!("\x1b[2J\x1b[H");
+        }
+        let parsed = crate::cli::parse_from(&command)?;
+        // Keep watching when a run fails, e.g. on a half-written note.
+        if let Some(e) = exit::reported(&crate::cli::run(parsed)) {
+            eprintln!("Error: {e:#}");
+        }
+        if let Some(before) = done {
+            match capture() {
+                Ok(snapshot) => {
+                    webhook::announce_milestones(&config.notify, before, &snapshot);
+                    done = Some(snapshot.percentage);
+                }
+                Err(e) => eprintln!("Error: {e:#}"),
+            }
+        }
+        Ok(())
+    };
+
+    rerun()?;
+    watch(
+        &args.directories,
+        &exclude_dirs,
+        Duration::from_millis(args.debounce),
+        rerun,
+    )
+}
+
+/// The full command line re-run on every change, with the `profile` zrt
+/// watch was given.
+fn command_line(args: &WatchArgs, profile: Option<&str>) -> Vec<String> {
+    let mut command = vec!["zrt".to_owned()];
+    if let Some(profile) = profile {
+        command.push(format!("--profile={profile}"));
+    }
+    if args.command.is_empty() {
+        command.push("progress".to_owned());
+        command.push("-d".to_owned());
+        command.extend(args.directories.iter().map(|d| d.display().to_string()));
+        if !args.exclude.is_empty() {
+            command.push("-e".to_owned());
+            command.extend(args.exclude.iter().cloned());
+        }
+    } else {
+        command.extend(args.command.iter().cloned());
+    }
+    command
+}
+
+/// Validate the command once up front so typos fail immediately.
+fn parse_command(args: &WatchArgs, profile: Option<&str>) -> Result<Vec<String>> {
+    let command = command_line(args, profile);
+    let parsed = crate::cli::parse_from(&command)?;
+    if matches!(parsed.command, Commands::Watch(_)) {
+        anyhow::bail!("zrt watch cannot re-run itself");
+    }
+    Ok(command)
+}
@@ -0,0 +1,131 @@
+pub mod cli;
+
+use anyhow::{Context as _, Result};
+use notify::{RecursiveMode, Watcher as _};
+use std::path::{Component, Path, PathBuf};
+use std::sync::mpsc;
+use std::time::Duration;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_should_rescan_for_note_changes() {
+        // REQ-WATCH-001
+        let roots = [PathBuf::from("/vault")];
+
+        assert!(is_relevant(Path::new("/vault/note.md"), &roots, &[]));
+        assert!(is_relevant(Path::new("/vault/sub/note.md"), &roots, &[]));
+    }
+
+    #[test]
+    fn test_should_ignore_hidden_and_excluded_paths() {
+        // REQ-WATCH-002
+        let roots = [PathBuf::from("/vault")];
+
+        assert!(!is_relevant(
+            Path::new("/vault/.zrt/history.jsonl"),
+            &roots,
+            &[]
+        ));
+        assert!(!is_relevant(Path::new("/vault/.git/index"), &roots, &[]));
+        assert!(!is_relevant(
+            Path::new("/vault/archive/old.md"),
+            &roots,
+            &["archive"]
+        ));
+    }
+
+    #[test]
+    fn test_should_judge_paths_relative_to_the_watched_root() {
+        // REQ-WATCH-002
+        // Given a vault that itself lives under a hidden directory
+        let roots = [PathBuf::from("/home/me/.notes")];
+
+        // Then notes inside it still count
+        assert!(is_relevant(
+            Path::new("/home/me/.notes/note.md"),
+            &roots,
+            &[]
+        ));
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+/// Whether a change to `path` should trigger a re-scan: hidden files and
+/// directories (such as `.git` or the `.zrt` history) and excluded
+/// directories below a watched root are ignored.
+#[must_use]
+pub fn is_relevant(path: &Path, roots: &[PathBuf], exclude: &[&str]) -> bool {
+    let relative = roots
+        .iter()
+        .find_map(|root| path.strip_prefix(root).ok())
+        .unwrap_or(path);
+
+    relative.components().all(|component| match component {
+        Component::Normal(name) => name
+            .to_str()
+            .is_none_or(|name| !name.starts_with('.') && !exclude.contains(&name)),
+        _ => true,
+    })
+}
+
+/// Watch `dirs` recursively and call `on_change` after each burst of relevant
+/// changes, once no further events have arrived for `debounce`.
+///
+/// Runs until the watcher shuts down.
+pub fn watch<F>(
+    dirs: &[PathBuf],
+    exclude: &[&str],
+    debounce: Duration,
+    mut on_change: F,
+) -> Result<()>
+where
+    F: FnMut() -> Result<()>,
+{
+    let roots = dirs
+        .iter()
+        .map(|dir| {
+            dir.canonicalize()
+                .with_context(|| format!("Cannot watch {}", dir.display()))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(tx)?;
+    for root in &roots {
+        watcher.watch(root, RecursiveMode::Recursive)?;
+    }
+
+    let relevant = |event: notify::Result<notify::Event>| {
+        event.is_ok_and(|event| {
+            !event.kind.is_access()
+                && event
+                    .paths
+                    .iter()
+                    .any(|path| is_relevant(path, &roots, exclude))
+        })
+    };
+
+    while let Ok(event) = rx.recv() {
+        if !relevant(event) {
+            continue;
+        }
+        // Let editors finish writing (temp file, rename, metadata) before re-scanning.
+        while rx.recv_timeout(debounce).is_ok() {}
+        on_change()?;
+    }
+
+    Ok(())
+}
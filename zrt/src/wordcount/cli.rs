@@ -3,7 +3,11 @@ use clap::Args;
 use std::path::PathBuf;
 
 use crate::init::{SortBy, ZrtConfig};
-use crate::wordcount::{count_file_metrics, count_words, print_file_metrics, print_top_files};
+use crate::output::exec::ExecArgs;
+use crate::output::number;
+use crate::output::open::OpenArgs;
+use crate::output::scan;
+use crate::wordcount::{SizeStats, count_file_metrics_in, sort_file_metrics};
 
 // ============================================
 // TESTS
@@ -56,6 +60,30 @@ mod tests {
         let args = TestArgs::parse_from(["program", "--sort-by", "lines"]);
         assert!(args.wc.sort_by.is_some());
     }
+
+    #[test]
+    fn test_wordcount_sort_by_size_and_stats() {
+        // REQ-WC-012
+        let args = TestArgs::parse_from(["program", "--sort", "size", "--stats"]);
+        assert!(matches!(args.wc.sort_by, Some(SortBy::Size)));
+        assert!(args.wc.stats);
+    }
+
+    #[test]
+    fn test_wordcount_include_frontmatter() {
+        // REQ-WC-013
+        assert!(!TestArgs::parse_from(["program"]).wc.include_frontmatter);
+        let args = TestArgs::parse_from(["program", "--include-frontmatter"]);
+        assert!(args.wc.include_frontmatter);
+    }
+
+    #[test]
+    fn test_should_format_sizes_for_humans() {
+        // REQ-WC-011
+        assert_eq!(format_size(512), "512 B");
+        assert_eq!(format_size(1536), "1.5 KB");
+        assert_eq!(format_size(5 * 1024 * 1024), "5.0 MB");
+    }
 }
 
 // ============================================
@@ -84,46 +112,100 @@ pub struct WordcountArgs {
     #[arg(long)]
     pub exceeds: bool,
 
-    /// Sort by words or lines (overrides config)
-    #[arg(long, value_enum)]
+    /// Sort by words, lines or size in bytes (overrides config)
+    #[arg(long, value_enum, alias = "sort")]
     pub sort_by: Option<SortBy>,
+
+    /// Report total and average file size and the largest files instead of
+    /// listing files
+    #[arg(long)]
+    pub stats: bool,
+
+    /// Count words and lines in frontmatter too, instead of only the body
+    #[arg(long)]
+    pub include_frontmatter: bool,
+
+    #[command(flatten)]
+    pub open: OpenArgs,
+
+    #[command(flatten)]
+    pub exec: ExecArgs,
 }
 
 // ============================================
 // IMPLEMENTATIONS
 // ============================================
 
-pub fn run(args: WordcountArgs) -> Result<()> {
+pub fn run(args: WordcountArgs, config: &ZrtConfig) -> Result<()> {
     let exclude_dirs: Vec<&str> = args.exclude.iter().map(String::as_str).collect();
     let filter_tags: Vec<&str> = args.filter_out.iter().map(String::as_str).collect();
-
-    if args.exceeds {
-        let config = ZrtConfig::load_or_default();
-        let sort_preference = args.sort_by.unwrap_or(config.refactor.sort_by);
-
-        let metrics = count_file_metrics(
-            &args.directories,
-            &exclude_dirs,
+    let scanner = scan::scanner(&args.directories, &exclude_dirs)?;
+
+    if args.stats {
+        let metrics =
+            count_file_metrics_in(&scanner, &filter_tags, None, args.include_frontmatter)?;
+        print_stats(&SizeStats::new(&metrics, args.top));
+    } else if args.exceeds {
+        let sort_preference = args
+            .sort_by
+            .unwrap_or_else(|| config.refactor.sort_by.clone());
+
+        let mut metrics = count_file_metrics_in(
+            &scanner,
             &filter_tags,
             Some((
                 config.refactor.word_threshold,
                 config.refactor.line_threshold,
             )),
+            args.include_frontmatter,
         )?;
 
-        print_file_metrics(&metrics, args.top, sort_preference);
+        sort_file_metrics(&mut metrics, sort_preference);
+        metrics.truncate(args.top);
+        for file in &metrics {
+            println!("{}", file.path.display());
+        }
+        let paths: Vec<&PathBuf> = metrics.iter().map(|f| &f.path).collect();
+        args.exec.run(&paths)?;
+        args.open.open(&paths)?;
     } else {
-        let files = count_words(
-            &args.directories,
-            &exclude_dirs,
-            if filter_tags.is_empty() {
-                None
-            } else {
-                Some(filter_tags[0])
-            },
-        )?;
-        print_top_files(&files, args.top);
+        let mut files =
+            count_file_metrics_in(&scanner, &filter_tags, None, args.include_frontmatter)?;
+        sort_file_metrics(&mut files, args.sort_by.unwrap_or(SortBy::Words));
+        let files = &files[..files.len().min(args.top)];
+        for file in files {
+            println!("{}", file.path.display());
+        }
+        let paths: Vec<&PathBuf> = files.iter().map(|f| &f.path).collect();
+        args.exec.run(&paths)?;
+        args.open.open(&paths)?;
     }
 
     Ok(())
 }
+
+fn print_stats(stats: &SizeStats) {
+    println!("Files: {}", number::count(stats.files));
+    println!("Words: {}", number::count(stats.words));
+    println!("Total size: {}", format_size(stats.bytes));
+    println!("Average size: {}", format_size(stats.average_bytes()));
+    println!("Largest files:");
+    for file in &stats.largest {
+        println!("  {} {}", format_size(file.bytes), file.path.display());
+    }
+}
+
+/// Bytes in the largest fitting binary unit, with one decimal above bytes.
+fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.1} {}", UNITS[unit])
+}
@@ -1,7 +1,9 @@
+#[cfg(feature = "cli")]
 pub mod cli;
 pub mod models;
-pub mod print;
+pub mod sort;
 pub mod word;
 
-pub use print::{print_file_metrics, print_top_files};
-pub use word::{count_file_metrics, count_words};
+pub use models::SizeStats;
+pub use sort::sort_file_metrics;
+pub use word::{count_file_metrics, count_file_metrics_in, count_words, count_words_in};
@@ -28,6 +28,26 @@ mod tests {
         assert!(metrics.exceeds_thresholds(100, 20)); // Equal counts (>= comparison)
     }
 
+    #[test]
+    fn test_should_summarize_sizes() {
+        // REQ-WC-011
+        let files = vec![
+            FileMetrics::new(PathBuf::from("a.md"), 10, 1).with_bytes(100),
+            FileMetrics::new(PathBuf::from("b.md"), 30, 2).with_bytes(500),
+            FileMetrics::new(PathBuf::from("c.md"), 20, 3).with_bytes(300),
+        ];
+
+        let stats = SizeStats::new(&files, 2);
+
+        assert_eq!(stats.files, 3);
+        assert_eq!(stats.bytes, 900);
+        assert_eq!(stats.words, 60);
+        assert_eq!(stats.average_bytes(), 300);
+        let largest: Vec<_> = stats.largest.iter().map(|f| f.path.clone()).collect();
+        assert_eq!(largest, vec![PathBuf::from("b.md"), PathBuf::from("c.md")]);
+        assert_eq!(SizeStats::new(&[], 2).average_bytes(), 0);
+    }
+
     #[test]
     fn test_conversion_to_file_word_count() {
         let metrics = FileMetrics::new(PathBuf::from("test.md"), 150, 25);
@@ -47,6 +67,8 @@ pub struct FileMetrics {
     pub path: PathBuf,
     pub words: usize,
     pub lines: usize,
+    /// Size of the whole file, frontmatter included, in bytes
+    pub bytes: u64,
 }
 
 #[derive(Debug)]
@@ -55,6 +77,16 @@ pub struct FileWordCount {
     pub words: usize,
 }
 
+/// Vault-wide size totals and the largest files.
+#[derive(Debug, Clone)]
+pub struct SizeStats {
+    pub files: usize,
+    pub bytes: u64,
+    pub words: usize,
+    /// The biggest files by bytes, largest first
+    pub largest: Vec<FileMetrics>,
+}
+
 // ============================================
 // IMPLEMENTATIONS
 // ============================================
@@ -67,9 +99,17 @@ impl FileMetrics {
             path,
             words,
             lines,
+            bytes: 0,
         }
     }
 
+    #[inline]
+    #[must_use]
+    pub const fn with_bytes(mut self, bytes: u64) -> Self {
+        self.bytes = bytes;
+        self
+    }
+
     #[inline]
     #[must_use]
     pub fn exceeds_thresholds(&self, word_threshold: usize, line_threshold: usize) -> bool {
@@ -77,6 +117,33 @@ impl FileMetrics {
     }
 }
 
+impl SizeStats {
+    /// Totals over `files`, keeping the `top` largest.
+    #[inline]
+    #[must_use]
+    pub fn new(files: &[FileMetrics], top: usize) -> Self {
+        let mut largest = files.to_vec();
+        largest.sort_by_key(|f| std::cmp::Reverse(f.bytes));
+        largest.truncate(top);
+        Self {
+            files: files.len(),
+            bytes: files.iter().map(|f| f.bytes).sum(),
+            words: files.iter().map(|f| f.words).sum(),
+            largest,
+        }
+    }
+
+    /// Mean file size in bytes, rounded down, or 0 without files.
+    #[inline]
+    #[must_use]
+    pub fn average_bytes(&self) -> u64 {
+        u64::try_from(self.files)
+            .ok()
+            .and_then(|files| self.bytes.checked_div(files))
+            .unwrap_or(0)
+    }
+}
+
 impl From<FileMetrics> for FileWordCount {
     #[inline]
     fn from(metrics: FileMetrics) -> Self {
@@ -0,0 +1,50 @@
+use crate::init::SortBy;
+use crate::wordcount::models::FileMetrics;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_sort_file_metrics() {
+        let mut files = vec![
+            FileMetrics::new(PathBuf::from("short.md"), 50, 80),
+            FileMetrics::new(PathBuf::from("long.md"), 400, 10),
+            FileMetrics::new(PathBuf::from("image.md"), 1, 1).with_bytes(90_000),
+        ];
+
+        sort_file_metrics(&mut files, SortBy::Words);
+        assert_eq!(files[0].path, PathBuf::from("long.md"));
+
+        sort_file_metrics(&mut files, SortBy::Lines);
+        assert_eq!(files[0].path, PathBuf::from("short.md"));
+
+        // REQ-WC-012
+        sort_file_metrics(&mut files, SortBy::Size);
+        assert_eq!(files[0].path, PathBuf::from("image.md"));
+    }
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+/// Sorts file metrics in descending order of the chosen measure.
+#[inline]
+pub fn sort_file_metrics(files: &mut [FileMetrics], sort_by: SortBy) {
+    match sort_by {
+        SortBy::Words => {
+            files.sort_by_key(|f| std::cmp::Reverse(f.words));
+        }
+        SortBy::Lines => {
+            files.sort_by_key(|f| std::cmp::Reverse(f.lines));
+        }
+        SortBy::Size => {
+            files.sort_by_key(|f| std::cmp::Reverse(f.bytes));
+        }
+    }
+}
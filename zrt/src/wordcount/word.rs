@@ -1,12 +1,8 @@
 use anyhow::Result;
 use std::env;
-use std::fs;
 use std::path::PathBuf;
-use walkdir::WalkDir;
 
-use crate::core::filter::utils::should_exclude;
-use crate::core::frontmatter::{parse_frontmatter, strip_frontmatter};
-use crate::core::ignore::load_ignore_patterns;
+use crate::core::scanner::{ScanOptions, Scanner};
 use crate::wordcount::models::{FileMetrics, FileWordCount};
 
 /// Counts words in all files within one or more directories and their subdirectories.
@@ -16,6 +12,7 @@ use crate::wordcount::models::{FileMetrics, FileWordCount};
 /// * `dirs` - The directory paths to scan. If empty, defaults to current directory.
 /// * `exclude_dirs` - A list of directory names to exclude from the scan
 /// * `filter_out` - Optional tag to exclude files containing this tag
+/// * `include_frontmatter` - Count frontmatter words too instead of only the body
 ///
 /// # Returns
 ///
@@ -34,58 +31,39 @@ pub fn count_words(
     dirs: &[PathBuf],
     exclude_dirs: &[&str],
     filter_out: Option<&str>,
+    include_frontmatter: bool,
 ) -> Result<Vec<FileWordCount>> {
-    let mut files = Vec::new();
-
-    // Default to current directory if no directories specified
-    let directories: Vec<PathBuf> = if dirs.is_empty() {
-        vec![env::current_dir()?]
-    } else {
-        dirs.to_vec()
-    };
-
-    for dir in directories {
-        let absolute_dir = if dir.is_absolute() {
-            dir.clone()
-        } else {
-            env::current_dir()?.join(dir)
-        };
-
-        let ignore_patterns = load_ignore_patterns(&absolute_dir)?;
-
-        for entry in WalkDir::new(&absolute_dir)
-            .follow_links(true)
-            .into_iter()
-            .filter_entry(|e| !should_exclude(e, exclude_dirs, Some(&ignore_patterns)))
-        {
-            let entry = entry?;
-            if !entry.file_type().is_file() {
-                continue;
-            }
+    count_words_in(
+        &scanner(dirs, exclude_dirs)?,
+        filter_out,
+        include_frontmatter,
+    )
+}
 
-            let path = entry.path();
-            if let Ok(content) = fs::read_to_string(path) {
-                if let Some(tag) = filter_out {
-                    if let Ok(frontmatter) = parse_frontmatter(&content) {
-                        if let Some(tags) = frontmatter.tags {
-                            if tags.iter().any(|t| t == tag) {
-                                continue;
-                            }
-                        }
-                    }
-                }
-
-                let body = strip_frontmatter(&content);
-                let word_count = body.split_whitespace().count();
-                files.push(FileWordCount {
-                    path: path.to_path_buf(),
-                    words: word_count,
-                });
-            }
+/// [`count_words`] using an already configured scanner
+///
+/// # Errors
+///
+/// Returns an error if the scan fails.
+#[inline]
+pub fn count_words_in(
+    scanner: &Scanner,
+    filter_out: Option<&str>,
+    include_frontmatter: bool,
+) -> Result<Vec<FileWordCount>> {
+    let mut files = Vec::new();
+    for note in scanner.notes() {
+        let note = note?;
+        if filter_out.is_some_and(|tag| note.has_any_tag(&[tag])) {
+            continue;
         }
+        files.push(FileWordCount {
+            words: note.word_count(include_frontmatter),
+            path: note.path,
+        });
     }
 
-    files.sort_by(|a, b| b.words.cmp(&a.words));
+    files.sort_by_key(|f| std::cmp::Reverse(f.words));
     Ok(files)
 }
 
@@ -97,6 +75,7 @@ pub fn count_words(
 /// * `exclude_dirs` - A list of directory names to exclude from the scan
 /// * `filter_tags` - A list of tags to exclude files containing these tags
 /// * `thresholds` - Optional (word_threshold, line_threshold) to filter results
+/// * `include_frontmatter` - Count frontmatter words and lines too instead of only the body
 ///
 /// # Returns
 ///
@@ -116,94 +95,74 @@ pub fn count_file_metrics(
     exclude_dirs: &[&str],
     filter_tags: &[&str],
     thresholds: Option<(usize, usize)>,
+    include_frontmatter: bool,
 ) -> Result<Vec<FileMetrics>> {
-    let mut files = Vec::new();
-
-    // Default to current directory if no directories specified
-    let directories: Vec<PathBuf> = if dirs.is_empty() {
-        vec![env::current_dir()?]
-    } else {
-        dirs.to_vec()
-    };
+    count_file_metrics_in(
+        &scanner(dirs, exclude_dirs)?,
+        filter_tags,
+        thresholds,
+        include_frontmatter,
+    )
+}
 
-    for dir in directories {
-        let absolute_dir = if dir.is_absolute() {
-            dir.clone()
-        } else {
-            env::current_dir()?.join(dir)
-        };
-
-        let ignore_patterns = load_ignore_patterns(&absolute_dir)?;
-
-        for entry in WalkDir::new(&absolute_dir)
-            .follow_links(true)
-            .into_iter()
-            .filter_entry(|e| !should_exclude(e, exclude_dirs, Some(&ignore_patterns)))
-        {
-            let entry = entry?;
-            if !entry.file_type().is_file() {
-                continue;
-            }
+/// [`count_file_metrics`] using an already configured scanner
+///
+/// # Errors
+///
+/// Returns an error if the scan fails.
+#[inline]
+pub fn count_file_metrics_in(
+    scanner: &Scanner,
+    filter_tags: &[&str],
+    thresholds: Option<(usize, usize)>,
+    include_frontmatter: bool,
+) -> Result<Vec<FileMetrics>> {
+    let mut files = Vec::new();
+    for note in scanner.notes() {
+        let note = note?;
+        // Skip files that contain any of the filtered tags
+        if note.has_any_tag(filter_tags) {
+            continue;
+        }
 
-            let path = entry.path();
-            if let Ok(content) = fs::read_to_string(path) {
-                let mut file_tags = Vec::new();
-                let content_without_frontmatter: String;
-
-                // Parse frontmatter and extract tags
-                if let Ok(frontmatter) = parse_frontmatter(&content) {
-                    if let Some(tags) = frontmatter.tags {
-                        file_tags = tags;
-                    }
-
-                    // Remove frontmatter from content for accurate word/line counting
-                    let lines: Vec<&str> = content.lines().collect();
-                    if lines.len() > 2 && lines.first().is_some_and(|line| *line == "---") {
-                        if let Some(end_index) = lines.iter().skip(1).position(|&line| line == "---") {
-                            content_without_frontmatter =
-                                lines.get(end_index.saturating_add(2)..).map_or_else(
-                                    || content.clone(),
-                                    |content_slice| content_slice.join("\n"),
-                                );
-                        } else {
-                            content_without_frontmatter = content.clone();
-                        }
-                    } else {
-                        content_without_frontmatter = content.clone();
-                    }
-                } else {
-                    content_without_frontmatter = content.clone();
-                }
-
-                // Skip files that contain any of the filtered tags
-                if !filter_tags.is_empty()
-                    && file_tags
-                        .iter()
-                        .any(|tag| filter_tags.contains(&tag.as_str()))
-                {
-                    continue;
-                }
-
-                let word_count = content_without_frontmatter.split_whitespace().count();
-                let line_count = content_without_frontmatter.lines().count();
-
-                let metrics = FileMetrics::new(path.to_path_buf(), word_count, line_count);
-
-                // If thresholds are provided, only include files that exceed them
-                if let Some((word_threshold, line_threshold)) = thresholds {
-                    if metrics.exceeds_thresholds(word_threshold, line_threshold) {
-                        files.push(metrics);
-                    }
-                } else {
-                    files.push(metrics);
-                }
+        let text = note.countable_text(include_frontmatter);
+        let metrics = FileMetrics::new(
+            note.path.clone(),
+            note.word_count(include_frontmatter),
+            text.lines().count(),
+        )
+        // The file's size, not that of text decoded with replacements
+        .with_bytes(
+            scanner
+                .metadata(&note.path)
+                .map_or(note.content.len() as u64, |meta| meta.len),
+        );
+
+        // If thresholds are provided, only include files that exceed them
+        if let Some((word_threshold, line_threshold)) = thresholds {
+            if metrics.exceeds_thresholds(word_threshold, line_threshold) {
+                files.push(metrics);
             }
+        } else {
+            files.push(metrics);
         }
     }
 
     Ok(files)
 }
 
+/// A scanner over `dirs`, or the current directory if none are given.
+fn scanner(dirs: &[PathBuf], exclude_dirs: &[&str]) -> Result<Scanner> {
+    let directories: Vec<PathBuf> = if dirs.is_empty() {
+        vec![env::current_dir()?]
+    } else {
+        dirs.to_vec()
+    };
+    Ok(ScanOptions::new(directories)
+        .exclude(exclude_dirs)
+        .build()?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -214,14 +173,14 @@ mod tests {
     #[test]
     fn test_count_words() -> Result<()> {
         let dir = setup_test_directory()?;
-        let files = count_words(&[dir.path().to_path_buf()], &[], None)?;
+        let files = count_words(&[dir.path().to_path_buf()], &[], None, false)?;
         assert_eq!(files.len(), 4, "Should process all non-hidden files");
         let file2 = files
             .iter()
             .find(|f| f.path.ends_with("file2.md"))
             .expect("file2.md should exist");
         assert_eq!(file2.words, 7, "file2.md should have 7 words");
-        let files = count_words(&[dir.path().to_path_buf()], &[], Some("draft"))?;
+        let files = count_words(&[dir.path().to_path_buf()], &[], Some("draft"), false)?;
         assert_eq!(files.len(), 3, "Should exclude file with 'draft' tag");
 
         Ok(())
@@ -232,19 +191,58 @@ mod tests {
         let temp_dir = TempDir::new()?;
 
         // Create a valid UTF-8 markdown file
-        create_test_file(&temp_dir, "valid.md", "---\ntags: [test]\n---\nValid content")?;
+        create_test_file(
+            &temp_dir,
+            "valid.md",
+            "---\ntags: [test]\n---\nValid content",
+        )?;
 
         // Create a binary file with invalid UTF-8 bytes
         let binary_path = temp_dir.path().join("binary.md");
-        std::fs::write(&binary_path, &[0xFF, 0xFE, 0x00, 0x48, 0x65, 0x6C, 0x6C, 0x6F])?;
+        std::fs::write(
+            &binary_path,
+            [0xFF, 0xFE, 0x00, 0x48, 0x65, 0x6C, 0x6C, 0x6F],
+        )?;
 
         // These functions should not panic and should skip the invalid UTF-8 file
-        let word_counts = count_words(&[temp_dir.path().to_path_buf()], &[], None)?;
+        let word_counts = count_words(&[temp_dir.path().to_path_buf()], &[], None, false)?;
         assert_eq!(word_counts.len(), 1, "Should only process UTF-8 files");
 
-        let file_metrics = count_file_metrics(&[temp_dir.path().to_path_buf()], &[], &[], None)?;
+        let file_metrics =
+            count_file_metrics(&[temp_dir.path().to_path_buf()], &[], &[], None, false)?;
         assert_eq!(file_metrics.len(), 1, "Should only process UTF-8 files");
+        assert_eq!(file_metrics[0].bytes, 34, "Sizes include the frontmatter");
+
+        Ok(())
+    }
 
+    #[test]
+    fn test_should_take_sizes_from_the_scanned_filesystem() -> Result<()> {
+        // REQ-WC-012
+        let vfs = crate::core::vfs::MemoryFs::new().with_file("/vault/latin.md", b"caf\xe9");
+        let scanner = ScanOptions::new(["/vault"]).vfs(vfs).lossy(true).build()?;
+
+        let metrics = count_file_metrics_in(&scanner, &[], None, false)?;
+
+        // Four bytes on disk, though the replacement character takes three
+        assert_eq!(metrics[0].bytes, 4);
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_count_body_in_listing_like_word_stats() -> Result<()> {
+        // REQ-WC-013
+        let dir = TempDir::new()?;
+        create_test_file(&dir, "note.md", "---\ntags: [a, b]\n---\nOne two\nthree")?;
+        let dirs = [dir.path().to_path_buf()];
+
+        let body = count_file_metrics(&dirs, &[], &[], None, false)?;
+        let whole = count_file_metrics(&dirs, &[], &[], None, true)?;
+
+        assert_eq!((body[0].words, body[0].lines), (3, 2));
+        assert_eq!((whole[0].words, whole[0].lines), (8, 5));
+        assert_eq!(count_words(&dirs, &[], None, false)?[0].words, 3);
+        assert_eq!(count_words(&dirs, &[], None, true)?[0].words, 8);
         Ok(())
     }
 
@@ -258,7 +256,7 @@ mod tests {
         create_test_file(&dir2, "file2.md", "---\ntags: [test]\n---\nContent two")?;
 
         let dirs = vec![dir1.path().to_path_buf(), dir2.path().to_path_buf()];
-        let files = count_words(&dirs, &[], None)?;
+        let files = count_words(&dirs, &[], None, false)?;
 
         assert_eq!(files.len(), 2, "Should include files from both directories");
 
@@ -275,10 +273,13 @@ mod tests {
         create_test_file(&dir2, "large.md", "One two three four five six")?;
 
         let dirs = vec![dir1.path().to_path_buf(), dir2.path().to_path_buf()];
-        let files = count_words(&dirs, &[], None)?;
+        let files = count_words(&dirs, &[], None, false)?;
 
         assert_eq!(files.len(), 2);
-        assert!(files[0].words > files[1].words, "Files should be sorted by word count descending");
+        assert!(
+            files[0].words > files[1].words,
+            "Files should be sorted by word count descending"
+        );
 
         Ok(())
     }
@@ -293,7 +294,7 @@ mod tests {
         create_test_file(&dir2, "file2.md", "Content")?;
 
         let dirs = vec![dir1.path().to_path_buf(), dir2.path().to_path_buf()];
-        let files = count_words(&dirs, &[], None)?;
+        let files = count_words(&dirs, &[], None, false)?;
 
         assert_eq!(files.len(), 2, "Should scan both directories");
 
@@ -312,9 +313,13 @@ mod tests {
         create_test_file(&dir2, "file4.md", "---\ntags: [keep]\n---\nContent")?;
 
         let dirs = vec![dir1.path().to_path_buf(), dir2.path().to_path_buf()];
-        let files = count_words(&dirs, &[], Some("filtered"))?;
+        let files = count_words(&dirs, &[], Some("filtered"), false)?;
 
-        assert_eq!(files.len(), 2, "Should filter out tagged files from both directories");
+        assert_eq!(
+            files.len(),
+            2,
+            "Should filter out tagged files from both directories"
+        );
 
         Ok(())
     }
@@ -331,7 +336,7 @@ mod tests {
         create_test_file(&dir2, "file2.md", "Content")?;
 
         let dirs = vec![dir1.path().to_path_buf(), dir2.path().to_path_buf()];
-        let files = count_words(&dirs, &[".git"], None)?;
+        let files = count_words(&dirs, &[".git"], None, false)?;
 
         assert_eq!(files.len(), 2, "Should exclude .git in both directories");
 
@@ -341,7 +346,7 @@ mod tests {
     // REQ-WC-MULTI-003: When no directories specified, defaults to current directory
     #[test]
     fn test_wordcount_should_default_to_current_directory() -> Result<()> {
-        let files = count_words(&[], &[], None)?;
+        let files = count_words(&[], &[], None, false)?;
         // Should not panic and should return valid results
         let _ = files.len();
         Ok(())
@@ -0,0 +1,170 @@
+use anyhow::Result;
+use clap::Args;
+use comfy_table::Cell;
+use std::path::PathBuf;
+
+use crate::core::history::percentage;
+use crate::core::scanner::ScanOptions;
+use crate::init::ZrtConfig;
+use crate::output::{scan, table};
+use crate::workflow::{WorkflowStats, workflow_stats};
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::workflow::StateCount;
+    use clap::Parser;
+
+    #[derive(Parser, Debug)]
+    struct TestArgs {
+        #[command(flatten)]
+        workflow: WorkflowArgs,
+    }
+
+    #[test]
+    fn test_should_accept_field_and_states() {
+        // REQ-FLOW-003
+        let args =
+            TestArgs::parse_from(["program", "--field", "stage", "--states", "seed", "tree"]);
+
+        assert_eq!(args.workflow.field.as_deref(), Some("stage"));
+        assert_eq!(args.workflow.states, vec!["seed", "tree"]);
+    }
+
+    #[test]
+    fn test_should_print_states_then_transitions() {
+        // REQ-FLOW-003
+        let stats = WorkflowStats {
+            states: vec![
+                StateCount {
+                    state: "draft".to_owned(),
+                    files: 3,
+                },
+                StateCount {
+                    state: "done".to_owned(),
+                    files: 1,
+                },
+            ],
+            unset: 2,
+            other: 0,
+        };
+
+        assert_eq!(
+            lines(&stats),
+            vec![
+                "draft 3 (75.00%, 100.00% reached)",
+                "done 1 (25.00%, 25.00% reached)",
+                "no status 2",
+                "draft -> done 25.00%",
+            ]
+        );
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+#[derive(Args, Debug)]
+pub struct WorkflowArgs {
+    /// Directories to scan (space-separated, defaults to current directory)
+    #[arg(short = 'd', long = "dir", num_args = 0.., default_values = &["."])]
+    pub directories: Vec<PathBuf>,
+
+    /// Directories to exclude (space-separated)
+    #[arg(short, long, num_args = 0..)]
+    pub exclude: Vec<String>,
+
+    /// Frontmatter property holding the state (default: workflow.field from config)
+    #[arg(long)]
+    pub field: Option<String>,
+
+    /// States in workflow order (default: workflow.states from config)
+    #[arg(long, num_args = 1..)]
+    pub states: Vec<String>,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+pub fn run(args: WorkflowArgs, config: &ZrtConfig) -> Result<()> {
+    let mut workflow = config.workflow.clone();
+    if let Some(field) = args.field {
+        workflow.field = field;
+    }
+    if !args.states.is_empty() {
+        workflow.states = args.states;
+    }
+    if workflow.states.is_empty() {
+        anyhow::bail!("No workflow states configured");
+    }
+    let exclude_dirs: Vec<&str> = args.exclude.iter().map(String::as_str).collect();
+
+    let scanner =
+        scan::attach(ScanOptions::new(&args.directories).exclude(&exclude_dirs)).build()?;
+    let stats = workflow_stats(&scanner, &workflow)?;
+
+    if table::is_tty() {
+        let tracked = stats.tracked();
+        let mut out = table::table(["state", "files", "share%", "reached%"]);
+        for (i, state) in stats.states.iter().enumerate() {
+            out.add_row(vec![
+                Cell::new(&state.state),
+                table::number(state.files),
+                table::percentage(percentage(state.files, tracked)),
+                table::percentage(stats.reached(i)),
+            ]);
+        }
+        println!("{out}");
+        println!(
+            "{} without {}, {} in other states",
+            stats.unset, workflow.field, stats.other
+        );
+        for (from, to, pct) in stats.transitions() {
+            println!("{from} -> {to} {pct:.2}%");
+        }
+    } else {
+        for line in lines(&stats) {
+            println!("{line}");
+        }
+    }
+
+    Ok(())
+}
+
+/// Plain report: one line per state, the untracked counts when non-zero,
+/// then one line per transition.
+fn lines(stats: &WorkflowStats) -> Vec<String> {
+    let tracked = stats.tracked();
+    let mut lines: Vec<String> = stats
+        .states
+        .iter()
+        .enumerate()
+        .map(|(i, state)| {
+            format!(
+                "{} {} ({:.2}%, {:.2}% reached)",
+                state.state,
+                state.files,
+                percentage(state.files, tracked),
+                stats.reached(i)
+            )
+        })
+        .collect();
+    if stats.unset > 0 {
+        lines.push(format!("no status {}", stats.unset));
+    }
+    if stats.other > 0 {
+        lines.push(format!("other {}", stats.other));
+    }
+    lines.extend(
+        stats
+            .transitions()
+            .into_iter()
+            .map(|(from, to, pct)| format!("{from} -> {to} {pct:.2}%")),
+    );
+    lines
+}
@@ -0,0 +1,200 @@
+pub mod cli;
+
+use anyhow::Result;
+
+use crate::core::history::percentage;
+use crate::core::scanner::Scanner;
+use crate::init::WorkflowConfig;
+
+// ============================================
+// TESTS
+// ============================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::scanner::ScanOptions;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn stats(counts: &[usize]) -> WorkflowStats {
+        WorkflowStats {
+            states: counts
+                .iter()
+                .enumerate()
+                .map(|(i, files)| StateCount {
+                    state: format!("s{i}"),
+                    files: *files,
+                })
+                .collect(),
+            ..WorkflowStats::default()
+        }
+    }
+
+    #[test]
+    fn test_should_count_notes_per_state() -> Result<()> {
+        // REQ-FLOW-001
+        // Given notes in several states, one without a status and one with an unknown status
+        let dir = TempDir::new()?;
+        fs::write(dir.path().join("a.md"), "---\nstatus: inbox\n---\nA")?;
+        fs::write(dir.path().join("b.md"), "---\nstatus: Draft\n---\nB")?;
+        fs::write(dir.path().join("c.md"), "---\nstatus: draft\n---\nC")?;
+        fs::write(dir.path().join("d.md"), "---\nstatus: archived\n---\nD")?;
+        fs::write(dir.path().join("e.md"), "no frontmatter")?;
+        let scanner = ScanOptions::new([dir.path()]).build()?;
+
+        // When counting with the default workflow
+        let stats = workflow_stats(&scanner, &WorkflowConfig::default())?;
+
+        // Then every state is listed in order, matched case-insensitively
+        let counts: Vec<_> = stats
+            .states
+            .iter()
+            .map(|s| (s.state.as_str(), s.files))
+            .collect();
+        assert_eq!(
+            counts,
+            vec![
+                ("inbox", 1),
+                ("draft", 2),
+                ("refactored", 0),
+                ("evergreen", 0)
+            ]
+        );
+        assert_eq!((stats.unset, stats.other), (1, 1));
+        assert_eq!(stats.tracked(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_compute_reached_and_transition_percentages() {
+        // REQ-FLOW-002
+        // Given 4 notes in the first state, 2 in the second, 1 in the third and 1 in the last
+        let stats = stats(&[4, 2, 1, 1]);
+
+        // Then reached counts the notes at a state or any later one
+        assert_eq!(stats.reached(0), 100.0);
+        assert_eq!(stats.reached(1), 50.0);
+        assert_eq!(stats.reached(3), 12.5);
+
+        // And each transition is the share of notes that moved on
+        let transitions: Vec<f64> = stats.transitions().iter().map(|t| t.2).collect();
+        assert_eq!(transitions, vec![50.0, 50.0, 50.0]);
+    }
+
+    #[test]
+    fn test_should_report_zero_for_empty_workflow() {
+        // REQ-FLOW-002
+        let stats = stats(&[0, 0]);
+
+        assert_eq!(stats.reached(1), 0.0);
+        assert_eq!(stats.transitions()[0].2, 0.0);
+    }
+}
+
+// ============================================
+// TYPE DEFINITIONS
+// ============================================
+
+/// Notes currently in one workflow state.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StateCount {
+    pub state: String,
+    pub files: usize,
+}
+
+/// How the scanned notes spread over the workflow states.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WorkflowStats {
+    /// Every configured state in workflow order, including empty ones
+    pub states: Vec<StateCount>,
+    /// Notes without the status property
+    pub unset: usize,
+    /// Notes whose status is not a configured state
+    pub other: usize,
+}
+
+// ============================================
+// IMPLEMENTATIONS
+// ============================================
+
+impl WorkflowStats {
+    /// Notes in any configured state.
+    #[inline]
+    #[must_use]
+    pub fn tracked(&self) -> usize {
+        self.states.iter().map(|s| s.files).sum()
+    }
+
+    /// Percentage of tracked notes at state `index` or a later one.
+    #[inline]
+    #[must_use]
+    pub fn reached(&self, index: usize) -> f64 {
+        percentage(self.reached_files(index), self.tracked())
+    }
+
+    /// For each pair of consecutive states, the percentage of notes that
+    /// reached the first and went on to the second.
+    #[inline]
+    #[must_use]
+    pub fn transitions(&self) -> Vec<(&str, &str, f64)> {
+        self.states
+            .windows(2)
+            .enumerate()
+            .map(|(i, pair)| {
+                (
+                    pair[0].state.as_str(),
+                    pair[1].state.as_str(),
+                    percentage(self.reached_files(i + 1), self.reached_files(i)),
+                )
+            })
+            .collect()
+    }
+
+    fn reached_files(&self, index: usize) -> usize {
+        self.states.iter().skip(index).map(|s| s.files).sum()
+    }
+}
+
+/// Count the markdown notes in each state of `workflow`, read from its
+/// status property. States match case-insensitively; notes whose
+/// frontmatter cannot be parsed count as unset and are listed on the
+/// scanner's report.
+///
+/// # Errors
+///
+/// Returns an error if the scan fails.
+pub fn workflow_stats(scanner: &Scanner, workflow: &WorkflowConfig) -> Result<WorkflowStats> {
+    let mut stats = WorkflowStats {
+        states: workflow
+            .states
+            .iter()
+            .map(|state| StateCount {
+                state: state.clone(),
+                files: 0,
+            })
+            .collect(),
+        ..WorkflowStats::default()
+    };
+
+    for note in scanner.notes() {
+        let note = note?;
+        if !scanner.is_note(&note.path) {
+            continue;
+        }
+
+        let status = note.frontmatter.property(&workflow.field);
+        let Some(status) = status else {
+            stats.unset += 1;
+            continue;
+        };
+        match stats
+            .states
+            .iter_mut()
+            .find(|s| s.state.eq_ignore_ascii_case(status.trim()))
+        {
+            Some(state) => state.files += 1,
+            None => stats.other += 1,
+        }
+    }
+    Ok(stats)
+}